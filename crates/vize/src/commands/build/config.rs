@@ -62,6 +62,8 @@ pub(crate) struct CompileOutput {
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
     pub script_lang: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dts: Option<String>,
 }
 
 /// Detailed timing information for a single file.
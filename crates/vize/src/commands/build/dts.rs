@@ -0,0 +1,237 @@
+//! Best-effort `.vue.d.ts` generation for `vize build --emit-dts`.
+//!
+//! Declares each component as a `DefineComponent` built from the macro types
+//! collected by croquis (`defineProps`, `defineEmits`, `defineSlots`,
+//! `defineModel`). Imports and local type declarations from the script blocks
+//! are carried over so the macro types still resolve in the declaration file.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Declaration, Statement};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use vize_atelier_sfc::SfcDescriptor;
+use vize_carton::{append, String};
+use vize_croquis::{macros::MacroKind, Analyzer, AnalyzerOptions};
+
+const DTS_PRELUDE: &str = "import type { ComponentOptionsMixin as __VLS_Mixin, DefineComponent as __VLS_DefineComponent, VNode as __VLS_VNode } from 'vue';\n";
+
+const SHORT_EMITS_HELPER: &str = "type __VLS_ShortEmits<T> = { [K in keyof T]: (...args: T[K] extends any[] ? T[K] : any[]) => any };\n";
+
+/// Generate the `.vue.d.ts` content for a parsed SFC.
+pub(crate) fn generate_component_dts(descriptor: &SfcDescriptor<'_>) -> String {
+    let mut out = String::from(DTS_PRELUDE);
+
+    // Imports and local types the macro type arguments may refer to
+    for block in [descriptor.script.as_ref(), descriptor.script_setup.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        collect_type_context(&block.content, &mut out);
+    }
+    out.push('\n');
+
+    let Some(setup) = descriptor.script_setup.as_ref() else {
+        // Options API: nothing to resolve statically
+        out.push_str(
+            "declare const _default: __VLS_DefineComponent<{}>;\nexport default _default;\n",
+        );
+        return out;
+    };
+
+    let mut analyzer = Analyzer::with_options(AnalyzerOptions {
+        analyze_script: true,
+        ..Default::default()
+    });
+    analyzer.analyze_script_setup(&setup.content);
+    let croquis = analyzer.finish();
+    let macros = &croquis.macros;
+
+    // Props
+    let mut props =
+        if let Some(type_args) = macros.define_props().and_then(|c| c.type_args.as_ref()) {
+            String::from(type_arg(type_args))
+        } else {
+            let mut props = String::from("{");
+            for prop in macros.props() {
+                append!(
+                    props,
+                    " '{}'{}: any;",
+                    prop.name,
+                    if prop.required { "" } else { "?" }
+                );
+            }
+            props.push_str(" }");
+            props
+        };
+
+    // Emits (call-signature and runtime emits are collected by name)
+    let mut emits = String::from("{");
+    let mut short_emits = None;
+    if let Some(type_args) = macros.define_emits().and_then(|c| c.type_args.as_ref()) {
+        if macros.emits().is_empty() {
+            // `defineEmits<{ change: [id: number] }>()`
+            short_emits = Some(type_arg(type_args));
+        }
+    }
+    for emit in macros.emits() {
+        append!(emits, " '{}': (...args: any[]) => any;", emit.name);
+    }
+
+    // defineModel adds a prop and an `update:` event per model
+    let model_types = macros
+        .all_calls()
+        .iter()
+        .filter(|call| call.kind == MacroKind::DefineModel)
+        .map(|call| call.type_args.as_deref().map(type_arg));
+    let mut model_props = String::new();
+    for (model, model_type) in macros.models().iter().zip(model_types) {
+        let model_type = model_type.unwrap_or("any");
+        append!(
+            model_props,
+            " '{}'{}: {};",
+            model.name,
+            if model.required { "" } else { "?" },
+            model_type
+        );
+        append!(
+            emits,
+            " 'update:{}': (value: {}) => any;",
+            model.name,
+            model_type
+        );
+    }
+    if !model_props.is_empty() {
+        append!(props, " & {{{} }}", model_props);
+    }
+    emits.push_str(" }");
+
+    if let Some(short) = short_emits {
+        out.push_str(SHORT_EMITS_HELPER);
+        append!(emits, " & __VLS_ShortEmits<{}>", short);
+    }
+
+    append!(out, "type __VLS_Props = {};\n", props);
+    append!(out, "type __VLS_Emits = {};\n", emits);
+
+    if let Some(generic) = setup.attrs.get("generic") {
+        // Generic components can't go through DefineComponent
+        append!(
+            out,
+            "declare const _default: <{}>(props: __VLS_Props) => __VLS_VNode;\n",
+            generic
+        );
+        out.push_str("export default _default;\n");
+        return out;
+    }
+
+    out.push_str("declare const __VLS_component: __VLS_DefineComponent<__VLS_Props, {}, {}, {}, {}, __VLS_Mixin, __VLS_Mixin, __VLS_Emits>;\n");
+    if let Some(slots) = macros.define_slots().and_then(|c| c.type_args.as_ref()) {
+        append!(
+            out,
+            "declare const _default: typeof __VLS_component & {{ new (): {{ $slots: {} }} }};\n",
+            type_arg(slots)
+        );
+    } else {
+        out.push_str("declare const _default: typeof __VLS_component;\n");
+    }
+    out.push_str("export default _default;\n");
+    out
+}
+
+/// Strip the angle brackets croquis keeps around macro type arguments.
+fn type_arg(type_args: &str) -> &str {
+    type_args
+        .strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .unwrap_or(type_args)
+        .trim()
+}
+
+/// Copy imports and interface/type alias declarations from a script block.
+fn collect_type_context(source: &str, out: &mut String) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default()
+        .with_module(true)
+        .with_typescript(true);
+    let ret = Parser::new(&allocator, source, source_type).parse();
+
+    for statement in &ret.program.body {
+        let span = match statement {
+            // Side-effect imports (`import './style.css'`) carry no types
+            Statement::ImportDeclaration(import) if import.specifiers.is_some() => import.span,
+            Statement::TSInterfaceDeclaration(decl) => decl.span,
+            Statement::TSTypeAliasDeclaration(decl) => decl.span,
+            Statement::ExportNamedDeclaration(export)
+                if matches!(
+                    export.declaration,
+                    Some(
+                        Declaration::TSInterfaceDeclaration(_)
+                            | Declaration::TSTypeAliasDeclaration(_)
+                    )
+                ) =>
+            {
+                export.span
+            }
+            _ => continue,
+        };
+        out.push_str(&source[span.start as usize..span.end as usize]);
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_component_dts;
+    use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+
+    fn dts(source: &str) -> vize_carton::String {
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("parse");
+        generate_component_dts(&descriptor)
+    }
+
+    #[test]
+    fn declares_props_emits_and_slots() {
+        let out = dts(r#"<script setup lang="ts">
+import type { Item } from './types'
+import './style.css'
+interface Props { items: Item[]; title?: string }
+const props = defineProps<Props>()
+const emit = defineEmits<{ (e: 'select', item: Item): void }>()
+defineSlots<{ default(props: { item: Item }): any }>()
+const count = ref(0)
+</script>"#);
+
+        assert!(out.contains("import type { Item } from './types'\n"));
+        assert!(!out.contains("style.css"));
+        assert!(out.contains("interface Props { items: Item[]; title?: string }\n"));
+        assert!(!out.contains("ref(0)"));
+        assert!(out.contains("type __VLS_Props = Props;\n"));
+        assert!(out.contains("type __VLS_Emits = { 'select': (...args: any[]) => any; };\n"));
+        assert!(out.contains("$slots: { default(props: { item: Item }): any }"));
+        assert!(out.ends_with("export default _default;\n"));
+    }
+
+    #[test]
+    fn declares_models_and_short_emits() {
+        let out = dts(r#"<script setup lang="ts">
+const model = defineModel<string>()
+defineEmits<{ change: [id: number] }>()
+</script>"#);
+
+        assert!(out.contains("type __VLS_Props = { } & { 'modelValue'?: string; };\n"));
+        assert!(out.contains(
+            "type __VLS_Emits = { 'update:modelValue': (value: string) => any; } & __VLS_ShortEmits<{ change: [id: number] }>;\n"
+        ));
+    }
+
+    #[test]
+    fn declares_generic_components_as_functions() {
+        let out = dts(r#"<script setup lang="ts" generic="T extends string">
+defineProps<{ value: T }>()
+</script>"#);
+
+        assert!(out.contains(
+            "declare const _default: <T extends string>(props: __VLS_Props) => __VLS_VNode;\n"
+        ));
+    }
+}
@@ -4,6 +4,7 @@
 //! with parallel processing, profiling, and error collection.
 
 mod config;
+mod dts;
 mod runner;
 
 use clap::{Args, ValueEnum};
@@ -29,6 +30,41 @@ pub enum ScriptExtension {
     Downcompile,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum ScriptTarget {
+    Es2015,
+    Es2016,
+    Es2017,
+    Es2018,
+    Es2019,
+    Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    Es2024,
+    /// Keep modern syntax as written
+    #[default]
+    Esnext,
+}
+
+impl ScriptTarget {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Es2015 => "es2015",
+            Self::Es2016 => "es2016",
+            Self::Es2017 => "es2017",
+            Self::Es2018 => "es2018",
+            Self::Es2019 => "es2019",
+            Self::Es2020 => "es2020",
+            Self::Es2021 => "es2021",
+            Self::Es2022 => "es2022",
+            Self::Es2023 => "es2023",
+            Self::Es2024 => "es2024",
+            Self::Esnext => "esnext",
+        }
+    }
+}
+
 #[derive(Args, Default)]
 #[allow(clippy::disallowed_types)]
 pub struct BuildArgs {
@@ -40,6 +76,10 @@ pub struct BuildArgs {
     #[arg(short, long, default_value = "./dist")]
     pub output: PathBuf,
 
+    /// Override the output file extension (e.g. `mjs`); defaults to the format/script language
+    #[arg(long, value_name = "EXT")]
+    pub out_ext: Option<String>,
+
     /// Output format
     #[arg(short, long, value_enum, default_value = "js")]
     pub format: OutputFormat,
@@ -52,6 +92,10 @@ pub struct BuildArgs {
     #[arg(long, value_enum, default_value = "downcompile")]
     pub script_ext: ScriptExtension,
 
+    /// ECMAScript target for downcompiled scripts; newer syntax is lowered (default: esnext)
+    #[arg(long, value_enum, default_value = "esnext")]
+    pub target: ScriptTarget,
+
     /// Number of threads (default: number of CPUs)
     #[arg(short = 'j', long)]
     pub threads: Option<usize>,
@@ -60,6 +104,10 @@ pub struct BuildArgs {
     #[arg(long)]
     pub profile: bool,
 
+    /// Write a Chrome trace of per-file compile phases (open in chrome://tracing or Perfetto)
+    #[arg(long, value_name = "FILE")]
+    pub trace: Option<PathBuf>,
+
     /// Slow file threshold in milliseconds (default: 100)
     #[arg(long, default_value = "100")]
     pub slow_threshold: u64,
@@ -67,6 +115,14 @@ pub struct BuildArgs {
     /// Continue on errors (collect all errors and show at end)
     #[arg(long)]
     pub continue_on_error: bool,
+
+    /// Emit a `.vue.d.ts` declaration next to each compiled component
+    #[arg(long)]
+    pub emit_dts: bool,
+
+    /// Module the compiled output imports Vue runtime helpers from (default: vue)
+    #[arg(long)]
+    pub runtime_module: Option<String>,
 }
 
 pub fn run(args: BuildArgs) {
@@ -7,13 +7,14 @@
 
 use std::{
     fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{atomic::Ordering, Mutex},
     time::{Duration, Instant},
 };
 
 use ignore::Walk;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use vize_atelier_dom::DomCompilerOptions;
 use vize_atelier_sfc::{
     compile_sfc, parse_sfc, ScriptCompileOptions, SfcCompileOptions, SfcParseOptions,
     StyleCompileOptions, TemplateCompileOptions,
@@ -32,7 +33,7 @@ use super::{
     config::{
         get_output_extension, CompileError, CompileOutput, CompileStats, ErrorPhase, FileProfile,
     },
-    BuildArgs, OutputFormat, ScriptExtension,
+    dts, BuildArgs, OutputFormat, ScriptExtension, ScriptTarget,
 };
 
 /// Main entry point for the build command.
@@ -57,10 +58,16 @@ pub(crate) fn run(args: BuildArgs) {
     let stats = CompileStats::new(files.len());
     let collect_elapsed = start.elapsed();
 
-    if args.profile {
+    if args.profile || args.trace.is_some() {
         let profiler = global_profiler();
         profiler.clear();
         profiler.enable();
+        if args.trace.is_some() {
+            profiler.enable_tracing();
+        }
+    }
+
+    if args.profile {
         eprintln!(
             "Found {} files in {:.4}s. Compiling using {} threads...",
             files.len(),
@@ -78,11 +85,19 @@ pub(crate) fn run(args: BuildArgs) {
     let compile_start = Instant::now();
     let results: Vec<_> = files
         .par_iter()
-        .map(|path| {
+        .map(|(path, relative)| {
             let source_size = fs::metadata(path).map(|m| m.len() as usize).unwrap_or(0);
             stats.total_bytes.fetch_add(source_size, Ordering::Relaxed);
 
-            match compile_file_with_profile(path, args.ssr, args.script_ext, &stats) {
+            match compile_file_with_profile(
+                path,
+                args.ssr,
+                args.script_ext,
+                args.target,
+                args.emit_dts,
+                args.runtime_module.as_deref(),
+                &stats,
+            ) {
                 Ok((output, profile)) => {
                     stats.success.fetch_add(1, Ordering::Relaxed);
                     stats
@@ -102,7 +117,7 @@ pub(crate) fn run(args: BuildArgs) {
                         }
                     }
 
-                    Some((path.clone(), output))
+                    Some((path.clone(), relative.clone(), output))
                 }
                 Err(err) => {
                     stats.failed.fetch_add(1, Ordering::Relaxed);
@@ -124,18 +139,17 @@ pub(crate) fn run(args: BuildArgs) {
         OutputFormat::Js | OutputFormat::Json => {
             fs::create_dir_all(&args.output).expect("Failed to create output directory");
 
-            for (path, output) in results.into_iter().flatten() {
-                let ext = match args.format {
-                    OutputFormat::Js => get_output_extension(&output.script_lang, args.script_ext),
-                    OutputFormat::Json => "json",
-                    OutputFormat::Stats => unreachable!(),
+            for (path, relative, output) in results.into_iter().flatten() {
+                let ext = match (args.out_ext.as_deref(), args.format) {
+                    (Some(ext), _) => ext.trim_start_matches('.'),
+                    (None, OutputFormat::Js) => {
+                        get_output_extension(&output.script_lang, args.script_ext)
+                    }
+                    (None, OutputFormat::Json) => "json",
+                    (None, OutputFormat::Stats) => unreachable!(),
                 };
 
-                let filename = path
-                    .file_name()
-                    .map(|f| PathBuf::from(f).with_extension(ext))
-                    .unwrap_or_else(|| PathBuf::from("output").with_extension(ext));
-                let out_path = args.output.join(filename);
+                let out_path = args.output.join(relative_output_path(&relative, ext));
 
                 if let Some(parent) = out_path.parent() {
                     fs::create_dir_all(parent).expect("Failed to create output subdirectory");
@@ -156,6 +170,21 @@ pub(crate) fn run(args: BuildArgs) {
                 fs::write(&out_path, content).unwrap_or_else(|e| {
                     eprintln!("Failed to write {}: {}", out_path.display(), e);
                 });
+
+                if let (OutputFormat::Js, Some(dts)) = (args.format, output.dts) {
+                    let dts_path = out_path.with_file_name(
+                        path.file_name()
+                            .map(|f| {
+                                let mut name = f.to_os_string();
+                                name.push(".d.ts");
+                                name
+                            })
+                            .unwrap_or_else(|| "output.vue.d.ts".into()),
+                    );
+                    fs::write(&dts_path, dts).unwrap_or_else(|e| {
+                        eprintln!("Failed to write {}: {}", dts_path.display(), e);
+                    });
+                }
             }
         }
     }
@@ -388,6 +417,20 @@ pub(crate) fn run(args: BuildArgs) {
         print_profile_report(&report);
     }
 
+    if let Some(trace_path) = &args.trace {
+        let profiler = global_profiler();
+        profiler.disable_tracing();
+        profiler.disable();
+        match fs::write(trace_path, profiler.to_chrome_trace()) {
+            Ok(()) => eprintln!("Trace written to {}", trace_path.display()),
+            Err(e) => eprintln!(
+                "\x1b[31mFailed to write trace {}: {}\x1b[0m",
+                trace_path.display(),
+                e
+            ),
+        }
+    }
+
     // Final summary
     if failed > 0 {
         eprintln!(
@@ -409,8 +452,11 @@ pub(crate) fn run(args: BuildArgs) {
 }
 
 /// Collect `.vue` files matching the given glob patterns.
+///
+/// Each file is paired with its path relative to the root its pattern was
+/// matched from, so the output directory can mirror the source layout.
 #[allow(clippy::disallowed_types)]
-fn collect_files(patterns: &[std::string::String]) -> Vec<PathBuf> {
+fn collect_files(patterns: &[std::string::String]) -> Vec<(PathBuf, PathBuf)> {
     let mut files = Vec::new();
 
     for pattern in patterns {
@@ -422,16 +468,38 @@ fn collect_files(patterns: &[std::string::String]) -> Vec<PathBuf> {
             if path.extension().is_some_and(|ext| ext == "vue")
                 && pattern_matches(path, &glob_pattern)
             {
-                files.push(path.to_path_buf());
+                let relative = path
+                    .strip_prefix(root.as_str())
+                    .ok()
+                    .filter(|rel| !rel.as_os_str().is_empty())
+                    .or_else(|| path.file_name().map(Path::new))
+                    .unwrap_or(path);
+                files.push((path.to_path_buf(), relative.to_path_buf()));
             }
         }
     }
 
     files.sort();
-    files.dedup();
+    files.dedup_by(|a, b| a.0 == b.0);
     files
 }
 
+/// Build the output path for a source file relative to its matched root.
+///
+/// Only normal path components are kept, so the result always stays inside
+/// the output directory.
+fn relative_output_path(relative: &Path, ext: &str) -> PathBuf {
+    let mut out: PathBuf = relative
+        .components()
+        .filter(|c| matches!(c, std::path::Component::Normal(_)))
+        .collect();
+    if out.as_os_str().is_empty() {
+        out.push("output");
+    }
+    out.set_extension(ext);
+    out
+}
+
 /// Extract a root directory and glob pattern from a user-provided pattern string.
 fn parse_pattern(pattern: &str) -> (String, String) {
     if let Some(pos) = pattern.find(['*', '?']) {
@@ -490,28 +558,18 @@ fn pattern_matches(path: &std::path::Path, pattern: &str) -> bool {
     path_str.ends_with(".vue")
 }
 
-/// Detect the script language from `<script lang="...">` in the SFC source.
-fn detect_script_lang(source: &str) -> String {
-    let script_pattern = regex_lite::Regex::new(r#"<script[^>]*\blang\s*=\s*["']([^"']+)["']"#)
-        .expect("Invalid regex");
-
-    if let Some(captures) = script_pattern.captures(source) {
-        if let Some(lang) = captures.get(1) {
-            return lang.as_str().to_compact_string();
-        }
-    }
-
-    "js".into()
-}
-
 /// Compile a single `.vue` file with profiling information.
 fn compile_file_with_profile(
     path: &PathBuf,
     ssr: bool,
     script_ext: ScriptExtension,
+    target: ScriptTarget,
+    emit_dts: bool,
+    runtime_module: Option<&str>,
     stats: &CompileStats,
 ) -> Result<(CompileOutput, FileProfile), CompileError> {
     let file_start = Instant::now();
+    let _file_span = global_profiler().file_span("build.file", &path.to_string_lossy());
 
     // Read file
     let source = fs::read_to_string(path).map_err(|e| CompileError {
@@ -528,8 +586,6 @@ fn compile_file_with_profile(
         .unwrap_or("anonymous.vue")
         .into();
 
-    let script_lang = detect_script_lang(&source);
-
     // Parse
     let parse_start = Instant::now();
     let parse_opts = SfcParseOptions {
@@ -548,6 +604,13 @@ fn compile_file_with_profile(
     let parse_time = parse_start.elapsed();
     stats.add_parse_time(parse_time);
 
+    let script_lang: String = descriptor
+        .script_setup
+        .as_ref()
+        .or(descriptor.script.as_ref())
+        .map_or("js", |script| script.effective_lang())
+        .into();
+
     // Calculate sizes
     let template_size = descriptor
         .template
@@ -570,6 +633,8 @@ fn compile_file_with_profile(
     let compile_start = Instant::now();
     let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
     let is_ts = matches!(script_ext, ScriptExtension::Preserve);
+    let dts = emit_dts.then(|| profile!("build.dts", dts::generate_component_dts(&descriptor)));
+
     let compile_opts = SfcCompileOptions {
         parse: SfcParseOptions {
             filename: filename.clone(),
@@ -578,6 +643,7 @@ fn compile_file_with_profile(
         script: ScriptCompileOptions {
             id: Some(filename.clone()),
             is_ts,
+            target: Some(target.as_str().into()),
             ..Default::default()
         },
         template: TemplateCompileOptions {
@@ -585,6 +651,10 @@ fn compile_file_with_profile(
             scoped: has_scoped,
             ssr,
             is_ts,
+            compiler_options: runtime_module.map(|name| DomCompilerOptions {
+                runtime_module_name: Some(name.into()),
+                ..Default::default()
+            }),
             ..Default::default()
         },
         style: StyleCompileOptions {
@@ -594,6 +664,8 @@ fn compile_file_with_profile(
         },
         vapor: false,
         scope_id: None,
+        hmr: false,
+        hmr_rerender_only: false,
     };
 
     let result = profile!(
@@ -628,7 +700,38 @@ fn compile_file_with_profile(
         errors: result.errors.into_iter().map(|e| e.message).collect(),
         warnings: result.warnings.into_iter().map(|e| e.message).collect(),
         script_lang,
+        dts,
     };
 
     Ok((output, profile))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pattern, relative_output_path};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn glob_root_is_static_prefix() {
+        let (root, _) = parse_pattern("src/**/*.vue");
+        assert_eq!(root.as_str(), "src");
+        let (root, _) = parse_pattern("**/*.vue");
+        assert_eq!(root.as_str(), ".");
+    }
+
+    #[test]
+    fn output_path_mirrors_source_layout() {
+        assert_eq!(
+            relative_output_path(Path::new("components/Button.vue"), "js"),
+            PathBuf::from("components/Button.js")
+        );
+        assert_eq!(
+            relative_output_path(Path::new("./pages/Button.vue"), "mjs"),
+            PathBuf::from("pages/Button.mjs")
+        );
+        assert_eq!(
+            relative_output_path(Path::new("../outside/App.vue"), "ts"),
+            PathBuf::from("outside/App.ts")
+        );
+    }
+}
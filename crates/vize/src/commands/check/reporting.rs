@@ -1,4 +1,7 @@
 //! Reporting data structures for `vize check`.
+//!
+//! The JSON schema mirrors `vize lint --format json` so type and lint results
+//! can be merged into a single CI report.
 
 use serde::Serialize;
 
@@ -22,5 +25,47 @@ pub(crate) struct JsonFileResult {
     pub file: std::string::String,
     #[serde(rename = "virtualTs")]
     pub virtual_ts: std::string::String,
-    pub diagnostics: Vec<std::string::String>,
+    pub messages: Vec<JsonMessage>,
+    #[serde(rename = "errorCount")]
+    pub error_count: usize,
+    #[serde(rename = "warningCount")]
+    pub warning_count: usize,
+}
+
+/// A single type diagnostic in JSON output.
+///
+/// Positions are 1-based. Only the start position is reported by Corsa, so
+/// the end position equals the start.
+#[derive(Serialize)]
+#[allow(clippy::disallowed_types)]
+pub(crate) struct JsonMessage {
+    /// TypeScript error code (e.g. `TS2322`)
+    pub code: Option<std::string::String>,
+    /// 2 for errors, 1 for everything else (same as lint output)
+    pub severity: u8,
+    pub message: std::string::String,
+    pub line: u32,
+    pub column: u32,
+    #[serde(rename = "endLine")]
+    pub end_line: u32,
+    #[serde(rename = "endColumn")]
+    pub end_column: u32,
+}
+
+impl JsonFileResult {
+    #[allow(clippy::disallowed_types)]
+    pub(crate) fn new(
+        file: std::string::String,
+        virtual_ts: std::string::String,
+        messages: Vec<JsonMessage>,
+    ) -> Self {
+        let error_count = messages.iter().filter(|m| m.severity == 2).count();
+        Self {
+            file,
+            virtual_ts,
+            warning_count: messages.len() - error_count,
+            error_count,
+            messages,
+        }
+    }
 }
@@ -24,7 +24,7 @@ use crate::commands::profile::{
 };
 
 use super::{
-    reporting::{JsonFileResult, JsonOutput},
+    reporting::{JsonFileResult, JsonMessage, JsonOutput},
     tsconfig_inputs::collect_default_check_files,
     CheckArgs,
 };
@@ -141,6 +141,39 @@ pub(crate) fn run_with_socket(args: &CheckArgs, socket_path: &str) {
     }
     let request_time = request_start.elapsed();
 
+    if args.format == "json" {
+        let files_json = results
+            .into_iter()
+            .map(|(filename, result)| {
+                let messages = result
+                    .diagnostics
+                    .into_iter()
+                    .map(|diagnostic| JsonMessage {
+                        code: diagnostic.code,
+                        severity: if diagnostic.severity == "error" { 2 } else { 1 },
+                        message: diagnostic.message,
+                        line: diagnostic.line,
+                        column: diagnostic.column,
+                        end_line: diagnostic.line,
+                        end_column: diagnostic.column,
+                    })
+                    .collect();
+                JsonFileResult::new(filename, result.virtual_ts, messages)
+            })
+            .collect();
+        let json_output = JsonOutput {
+            files: files_json,
+            error_count: total_errors,
+            file_count: files.len(),
+            declarations: None,
+        };
+        println!("{}", serde_json::to_string_pretty(&json_output).unwrap());
+        if total_errors > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let render_start = Instant::now();
     if !args.quiet {
         for (filename, result) in &results {
@@ -366,7 +399,7 @@ pub(crate) fn run_direct(args: &CheckArgs) {
     };
     let emit_time = emit_start.elapsed();
     let diagnostics_render_start = Instant::now();
-    let diagnostics = render_diagnostics(&result.diagnostics);
+    let diagnostics = group_diagnostics(&result.diagnostics);
     let diagnostics_render_time = diagnostics_render_start.elapsed();
     let total_time = start.elapsed();
     let total_errors = result.error_count();
@@ -463,12 +496,18 @@ pub(crate) fn run_direct(args: &CheckArgs) {
         let mut files_json: Vec<JsonFileResult> = virtual_files
             .iter()
             .map(|file| {
-                let key = file.original_path.to_string_lossy().into_owned();
-                JsonFileResult {
-                    file: display_path(&cwd, &file.original_path).into(),
-                    virtual_ts: file.content.clone().into(),
-                    diagnostics: diagnostics.get(key.as_str()).cloned().unwrap_or_default(),
-                }
+                let key = file.original_path.to_string_lossy();
+                let messages = diagnostics
+                    .get(key.as_ref())
+                    .map(|file_diagnostics| {
+                        file_diagnostics.iter().map(|d| json_message(d)).collect()
+                    })
+                    .unwrap_or_default();
+                JsonFileResult::new(
+                    display_path(&cwd, &file.original_path).into(),
+                    file.content.clone().into(),
+                    messages,
+                )
             })
             .collect();
         files_json.sort_by(|left, right| left.file.cmp(&right.file));
@@ -506,12 +545,12 @@ pub(crate) fn run_direct(args: &CheckArgs) {
 
             println!("\n\x1b[4m{}\x1b[0m", key);
             for diagnostic in file_diagnostics {
-                let color = if diagnostic.starts_with("error") {
+                let color = if diagnostic.severity == 1 {
                     "\x1b[31m"
                 } else {
                     "\x1b[33m"
                 };
-                println!("  {}{}\x1b[0m", color, diagnostic);
+                println!("  {}{}\x1b[0m", color, render_diagnostic(diagnostic));
             }
         }
     }
@@ -563,57 +602,65 @@ pub(crate) fn run_direct(args: &CheckArgs) {
     }
 }
 
+/// Group diagnostics by original file, sorted by position.
 #[allow(clippy::disallowed_types)]
-fn render_diagnostics(
+fn group_diagnostics(
     diagnostics: &[vize_canon::BatchDiagnostic],
-) -> std::collections::BTreeMap<std::string::String, Vec<std::string::String>> {
-    let mut grouped = std::collections::BTreeMap::<
-        std::string::String,
-        Vec<(u32, u32, std::string::String)>,
-    >::new();
+) -> std::collections::BTreeMap<std::string::String, Vec<&vize_canon::BatchDiagnostic>> {
+    let mut grouped: std::collections::BTreeMap<_, Vec<_>> = std::collections::BTreeMap::new();
 
     for diagnostic in diagnostics {
-        let severity = match diagnostic.severity {
-            1 => "error",
-            2 => "warning",
-            3 => "info",
-            _ => "hint",
-        };
-        let code = diagnostic
-            .code
-            .map(|code| cstr!(" [TS{}]", code))
-            .unwrap_or_default();
-        let rendered = cstr!(
-            "{}:{}:{}{} {}",
-            severity,
-            diagnostic.line + 1,
-            diagnostic.column + 1,
-            code,
-            diagnostic.message
-        )
-        .into();
         grouped
             .entry(diagnostic.file.to_string_lossy().into_owned())
             .or_default()
-            .push((diagnostic.line, diagnostic.column, rendered));
+            .push(diagnostic);
+    }
+
+    for file_diagnostics in grouped.values_mut() {
+        file_diagnostics.sort_by(|left, right| {
+            left.line
+                .cmp(&right.line)
+                .then_with(|| left.column.cmp(&right.column))
+                .then_with(|| left.message.cmp(&right.message))
+        });
     }
 
     grouped
-        .into_iter()
-        .map(|(file, mut diagnostics)| {
-            diagnostics.sort_by(|left, right| {
-                left.0
-                    .cmp(&right.0)
-                    .then_with(|| left.1.cmp(&right.1))
-                    .then_with(|| left.2.cmp(&right.2))
-            });
-            let rendered = diagnostics
-                .into_iter()
-                .map(|(_, _, rendered)| rendered)
-                .collect();
-            (file, rendered)
-        })
-        .collect()
+}
+
+fn render_diagnostic(diagnostic: &vize_canon::BatchDiagnostic) -> String {
+    let severity = match diagnostic.severity {
+        1 => "error",
+        2 => "warning",
+        3 => "info",
+        _ => "hint",
+    };
+    let code = diagnostic
+        .code
+        .map(|code| cstr!(" [TS{}]", code))
+        .unwrap_or_default();
+    cstr!(
+        "{}:{}:{}{} {}",
+        severity,
+        diagnostic.line + 1,
+        diagnostic.column + 1,
+        code,
+        diagnostic.message
+    )
+}
+
+fn json_message(diagnostic: &vize_canon::BatchDiagnostic) -> JsonMessage {
+    let line = diagnostic.line + 1;
+    let column = diagnostic.column + 1;
+    JsonMessage {
+        code: diagnostic.code.map(|code| cstr!("TS{}", code).into()),
+        severity: if diagnostic.severity == 1 { 2 } else { 1 },
+        message: diagnostic.message.as_str().into(),
+        line,
+        column,
+        end_line: line,
+        end_column: column,
+    }
 }
 
 fn write_profile_virtual_ts(files: &[&vize_canon::VirtualFile]) {
@@ -962,7 +1009,8 @@ fn parse_dts_globals(
 #[cfg(test)]
 mod tests {
     use super::{
-        base_dir_from_pattern, collect_check_files, collect_vue_files, resolve_declaration_dir,
+        base_dir_from_pattern, collect_check_files, collect_vue_files, group_diagnostics,
+        json_message, resolve_declaration_dir, JsonFileResult,
     };
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -1034,4 +1082,35 @@ mod tests {
             project_root.join("types")
         );
     }
+
+    #[test]
+    fn json_messages_are_sorted_one_based_and_counted() {
+        let diagnostic = |line, column, severity, code| vize_canon::BatchDiagnostic {
+            file: PathBuf::from("src/App.vue"),
+            line,
+            column,
+            message: "Type mismatch".into(),
+            code,
+            severity,
+            block_type: None,
+        };
+        let diagnostics = [diagnostic(4, 2, 2, None), diagnostic(1, 0, 1, Some(2322))];
+
+        let grouped = group_diagnostics(&diagnostics);
+        let messages: Vec<_> = grouped["src/App.vue"]
+            .iter()
+            .map(|d| json_message(d))
+            .collect();
+        let result =
+            JsonFileResult::new("src/App.vue".into(), std::string::String::new(), messages);
+
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.warning_count, 1);
+        let first = &result.messages[0];
+        assert_eq!(first.code.as_deref(), Some("TS2322"));
+        assert_eq!((first.severity, first.line, first.column), (2, 2, 1));
+        assert_eq!((first.end_line, first.end_column), (2, 1));
+        assert_eq!(result.messages[1].code, None);
+        assert_eq!(result.messages[1].severity, 1);
+    }
 }
@@ -143,6 +143,11 @@ impl<'a> Parser<'a> {
 
         let mut attr_node = AttributeNode::new(attr.name.clone(), loc);
         attr_node.name_loc = name_loc;
+        attr_node.quote = match quote {
+            QuoteType::Double => Some('"'),
+            QuoteType::Single => Some('\''),
+            QuoteType::NoValue | QuoteType::Unquoted => None,
+        };
 
         // Add value if present
         if let (Some(v_start), Some(v_end), Some(v_content)) =
@@ -4,7 +4,7 @@
 //! with the parser by implementing the `Callbacks` trait.
 
 use crate::tokenizer::{Callbacks, QuoteType};
-use vize_relief::errors::ErrorCode;
+use vize_relief::{ast::Namespace, errors::ErrorCode};
 
 use super::Parser;
 
@@ -119,4 +119,11 @@ impl<'a, 'p> Callbacks for ParserCallbacks<'a, 'p> {
     fn is_in_v_pre(&self) -> bool {
         self.parser.in_v_pre
     }
+
+    fn is_in_foreign_content(&self) -> bool {
+        self.parser
+            .current_element
+            .as_ref()
+            .is_some_and(|element| element.ns != Namespace::Html)
+    }
 }
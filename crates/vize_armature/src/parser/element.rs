@@ -3,7 +3,7 @@
 //! Handles text, interpolation, open/close tags, element type determination,
 //! comments, and error reporting.
 
-use vize_carton::{directive::parse_vize_directive, Box};
+use vize_carton::{cstr, directive::parse_vize_directive, is_html_tag, Box};
 use vize_relief::{
     ast::*,
     errors::{CompilerError, ErrorCode},
@@ -30,6 +30,11 @@ impl<'a> Parser<'a> {
 
     /// Append or merge text node
     fn append_or_merge_text(&mut self, content: &str, start: usize, end: usize) {
+        // Only whitespace may separate a type-check directive from its element
+        if !content.trim().is_empty() {
+            self.pending_type_check_directive = None;
+        }
+
         let merge_start_off = match self.stack.last().and_then(|e| e.element.children.last()) {
             Some(TemplateChildNode::Text(t)) => Some(t.loc.start.offset as usize),
             _ => None,
@@ -56,6 +61,7 @@ impl<'a> Parser<'a> {
 
     /// Process interpolation
     pub(super) fn on_interpolation_impl(&mut self, start: usize, end: usize) {
+        self.pending_type_check_directive = None;
         let raw_content = self.get_source(start, end);
         let content = raw_content.trim();
 
@@ -108,9 +114,7 @@ impl<'a> Parser<'a> {
             element.ns = current.ns;
             element.is_self_closing = current.is_self_closing;
             element.props = current.props;
-
-            // Determine element type
-            element.tag_type = self.determine_element_type(&element);
+            element.type_check_directive = self.pending_type_check_directive.take();
 
             // Check for pre tags
             let is_pre = (self.options.is_pre_tag)(element.tag.as_str());
@@ -119,6 +123,15 @@ impl<'a> Parser<'a> {
                 .iter()
                 .any(|p| matches!(p, PropNode::Directive(d) if d.name == "pre"));
 
+            // Determine element type. Everything under v-pre (including the v-pre
+            // element itself) renders as a plain element, so components and slots
+            // are not resolved there.
+            element.tag_type = if has_v_pre || self.in_v_pre {
+                ElementType::Element
+            } else {
+                self.determine_element_type(&element)
+            };
+
             // When v-pre is on this element, convert all directives (except v-pre itself)
             // back to raw attribute nodes, since v-pre means "skip compilation"
             if has_v_pre {
@@ -164,6 +177,7 @@ impl<'a> Parser<'a> {
                                 name: attr_name,
                                 name_loc: dir.loc.clone(),
                                 value: attr_value,
+                                quote: None,
                                 loc: dir.loc.clone(),
                             },
                             allocator,
@@ -200,14 +214,31 @@ impl<'a> Parser<'a> {
 
     /// Process close tag
     pub(super) fn on_close_tag_impl(&mut self, start: usize, end: usize) {
+        // A directive as the last child has no element to apply to
+        self.pending_type_check_directive = None;
         let tag = self.get_source(start, end);
 
         // Find matching open tag
         let mut found = false;
         for i in (0..self.stack.len()).rev() {
-            if self.stack[i].element.tag.eq_ignore_ascii_case(tag) {
+            let open_tag = self.stack[i].element.tag.as_str();
+            if open_tag.eq_ignore_ascii_case(tag) {
                 found = true;
 
+                // Native HTML tag names are case-insensitive, component names are not.
+                // Still close the component so the rest of the tree stays intact.
+                if open_tag != tag && !is_html_tag(&open_tag.to_ascii_lowercase()) {
+                    let message = cstr!(
+                        "End tag </{tag}> does not match the case of <{open_tag}>. Component names are case-sensitive."
+                    );
+                    let loc = self.create_loc(start - 2, end + 1); // Include </ and >
+                    self.errors.push(CompilerError::with_message(
+                        ErrorCode::EndTagCaseMismatch,
+                        message,
+                        Some(loc),
+                    ));
+                }
+
                 // Pop all elements up to and including the match
                 let mut elements: vize_carton::Vec<'a, ParserStackEntry<'a>> =
                     vize_carton::Vec::new_in(self.allocator);
@@ -295,6 +326,11 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // User-configured native tags
+        if self.options.tag_config.is_additional_tag(tag) {
+            return false;
+        }
+
         // Native tag check
         if let Some(is_native) = self.options.is_native_tag {
             if !is_native(tag) {
@@ -318,6 +354,12 @@ impl<'a> Parser<'a> {
         // Check for @vize: directive
         let directive = parse_vize_directive(content, loc.start.line, loc.start.offset);
 
+        // `@vue-ignore` / `@vue-skip` attach to the next element, whether or not
+        // comments are kept in the AST
+        if let Some(type_check) = TypeCheckDirective::from_comment(content) {
+            self.pending_type_check_directive = Some(type_check);
+        }
+
         // Always preserve directive comments (even when options.comments = false)
         // so they can be explicitly handled by codegen and linter
         if directive.is_none() && !self.options.comments {
@@ -53,6 +53,8 @@ pub struct Parser<'a> {
     in_pre: bool,
     /// Whether in v-pre block
     in_v_pre: bool,
+    /// Type-check comment directive waiting for the next element
+    pending_type_check_directive: Option<TypeCheckDirective>,
 }
 
 /// Stack entry for tracking parent elements
@@ -121,6 +123,7 @@ impl<'a> Parser<'a> {
             newlines: Vec::new_in(allocator),
             in_pre: false,
             in_v_pre: false,
+            pending_type_check_directive: None,
         }
     }
 
@@ -3,7 +3,7 @@
 use super::{parse, parse_with_options};
 use vize_carton::Bump;
 use vize_relief::{
-    ast::{ElementType, ExpressionNode, PropNode, TemplateChildNode},
+    ast::{ElementType, ExpressionNode, PropNode, TemplateChildNode, TypeCheckDirective},
     errors::ErrorCode,
     options::ParserOptions,
 };
@@ -315,6 +315,45 @@ fn test_parse_dynamic_directive_arg() {
     }
 }
 
+#[test]
+fn test_parse_compound_dynamic_directive_arg() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(
+        &allocator,
+        r#"<div :[prefix+name]="val" @[type+'Event']="handler"></div>"#,
+    );
+    assert!(errors.is_empty());
+    let TemplateChildNode::Element(el) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    let args: Vec<_> = el
+        .props
+        .iter()
+        .filter_map(|prop| match prop {
+            PropNode::Directive(dir) => match &dir.arg {
+                Some(ExpressionNode::Simple(arg)) => Some((arg.content.as_str(), arg.is_static)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(args, [("prefix+name", false), ("type+'Event'", false)]);
+}
+
+#[test]
+fn test_parse_unclosed_dynamic_directive_arg() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, r#"<div :[attr="val"><span /></div>"#);
+    assert!(errors
+        .iter()
+        .any(|e| e.code == ErrorCode::MissingDynamicDirectiveArgumentEnd));
+    // The rest of the template is still parsed
+    let TemplateChildNode::Element(el) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    assert_eq!(el.children.len(), 1);
+}
+
 #[test]
 fn test_parse_shorthand_slot() {
     let allocator = Bump::new();
@@ -376,6 +415,71 @@ fn test_parse_whitespace_condense() {
     }
 }
 
+#[test]
+fn test_parse_tag_config_extends_native_tags() {
+    let allocator = Bump::new();
+    let source = "<selectedcontent></selectedcontent>";
+    let tag_type = |options: ParserOptions| {
+        let (root, _) = parse_with_options(&allocator, source, options);
+        match &root.children[0] {
+            TemplateChildNode::Element(el) => el.tag_type,
+            _ => panic!("Expected element node"),
+        }
+    };
+
+    let is_native_tag = Some(vize_carton::is_native_tag as fn(&str) -> bool);
+    assert_eq!(
+        tag_type(ParserOptions {
+            is_native_tag,
+            ..ParserOptions::default()
+        }),
+        ElementType::Component
+    );
+    assert_eq!(
+        tag_type(ParserOptions {
+            is_native_tag,
+            tag_config: vize_carton::TagConfig::default().with_html_tags(["selectedcontent"]),
+            ..ParserOptions::default()
+        }),
+        ElementType::Element
+    );
+}
+
+#[test]
+fn test_parse_interpolation_custom_delimiters() {
+    let allocator = Bump::new();
+    let (root, errors) = parse_with_options(
+        &allocator,
+        "<p>[[ msg ]] {{ raw }} [[ list[0] ]]</p>",
+        ParserOptions {
+            delimiters: ("[[".into(), "]]".into()),
+            ..ParserOptions::default()
+        },
+    );
+    assert!(errors.is_empty());
+    let TemplateChildNode::Element(el) = &root.children[0] else {
+        panic!("Expected element node");
+    };
+    let interpolations: std::vec::Vec<_> = el
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            TemplateChildNode::Interpolation(interp) => Some(interp),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(interpolations.len(), 2);
+    let ExpressionNode::Simple(expr) = &interpolations[0].content else {
+        panic!("Expected simple expression");
+    };
+    assert_eq!(expr.content.as_str(), "msg");
+    assert_eq!(interpolations[0].loc.source.as_str(), "[[ msg ]]");
+    let ExpressionNode::Simple(expr) = &interpolations[1].content else {
+        panic!("Expected simple expression");
+    };
+    assert_eq!(expr.content.as_str(), "list[0]");
+}
+
 #[test]
 fn test_parse_whitespace_condense_skips_comment_gaps_when_comments_disabled() {
     let allocator = Bump::new();
@@ -430,6 +534,66 @@ fn test_parse_error_missing_end_tag() {
     assert!(errors.iter().any(|e| e.code == ErrorCode::MissingEndTag));
 }
 
+#[test]
+fn test_parse_end_tag_case_insensitive_for_native_tags() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, "<Div><span>hi</SPAN></div>");
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(root.children.len(), 1);
+    if let TemplateChildNode::Element(el) = &root.children[0] {
+        assert_eq!(el.children.len(), 1);
+    } else {
+        panic!("Expected element");
+    }
+}
+
+#[test]
+fn test_parse_error_component_end_tag_case_mismatch() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, "<MyButton>Click</mybutton><p>after</p>");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, ErrorCode::EndTagCaseMismatch);
+    assert!(errors[0].message.contains("</mybutton>"));
+    assert!(errors[0].message.contains("<MyButton>"));
+    // The component is still closed, so the sibling is not swallowed
+    assert_eq!(root.children.len(), 2);
+}
+
+#[test]
+fn test_parse_stray_doctype() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, "<!DOCTYPE html>\n<div></div>");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, ErrorCode::UnexpectedDoctype);
+    assert_eq!(errors[0].loc.as_ref().unwrap().start.offset, 0);
+    // The doctype is dropped and parsing continues
+    assert!(root
+        .children
+        .iter()
+        .any(|child| matches!(child, TemplateChildNode::Element(el) if el.tag == "div")));
+}
+
+#[test]
+fn test_parse_textarea_as_rcdata() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, "<textarea><b>{{ x }}</b></textarea><p></p>");
+
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(root.children.len(), 2);
+    let TemplateChildNode::Element(textarea) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    assert_eq!(textarea.children.len(), 3);
+    assert!(matches!(&textarea.children[0], TemplateChildNode::Text(t) if t.content == "<b>"));
+    assert!(matches!(
+        &textarea.children[1],
+        TemplateChildNode::Interpolation(_)
+    ));
+    assert!(matches!(&textarea.children[2], TemplateChildNode::Text(t) if t.content == "</b>"));
+}
+
 #[test]
 fn test_parse_error_duplicate_attribute() {
     let allocator = Bump::new();
@@ -521,6 +685,33 @@ fn test_empty_quoted_attribute_single() {
     }
 }
 
+#[test]
+fn test_attribute_quote_style() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, r#"<input a="x" b='y' c=z d />"#);
+    assert!(errors.is_empty());
+    let TemplateChildNode::Element(el) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    let quotes: Vec<_> = el
+        .props
+        .iter()
+        .map(|p| match p {
+            PropNode::Attribute(attr) => (attr.name.as_str(), attr.quote),
+            PropNode::Directive(_) => panic!("Expected attribute prop"),
+        })
+        .collect();
+    assert_eq!(
+        quotes,
+        vec![
+            ("a", Some('"')),
+            ("b", Some('\'')),
+            ("c", None),
+            ("d", None)
+        ]
+    );
+}
+
 #[test]
 fn test_empty_quoted_attribute_disabled() {
     let allocator = Bump::new();
@@ -701,3 +892,88 @@ fn test_parse_directive_value_entity_is_decoded() {
         }
     }
 }
+
+#[test]
+fn test_parse_v_pre_skips_compilation_of_subtree() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(
+        &allocator,
+        r#"<span v-pre :id="x">{{ raw }}<Foo @click="go" /><slot /></span>"#,
+    );
+    assert!(errors.is_empty());
+
+    let TemplateChildNode::Element(span) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    assert_eq!(span.props.len(), 1);
+    assert!(matches!(&span.props[0], PropNode::Attribute(attr) if attr.name == ":id"));
+    assert!(matches!(&span.children[0], TemplateChildNode::Text(t) if t.content == "{{ raw }}"));
+
+    let TemplateChildNode::Element(foo) = &span.children[1] else {
+        panic!("Expected element");
+    };
+    assert_eq!(foo.tag_type, ElementType::Element);
+    assert!(matches!(&foo.props[0], PropNode::Attribute(attr) if attr.name == "@click"));
+
+    let TemplateChildNode::Element(slot) = &span.children[2] else {
+        panic!("Expected element");
+    };
+    assert_eq!(slot.tag_type, ElementType::Element);
+}
+
+#[test]
+fn test_parse_v_pre_scope_ends_with_element() {
+    let allocator = Bump::new();
+    let (root, errors) = parse(&allocator, "<div><p v-pre>{{ a }}</p><p>{{ b }}</p></div>");
+    assert!(errors.is_empty());
+
+    let TemplateChildNode::Element(div) = &root.children[0] else {
+        panic!("Expected element");
+    };
+    let TemplateChildNode::Element(second) = &div.children[1] else {
+        panic!("Expected element");
+    };
+    assert!(matches!(
+        &second.children[0],
+        TemplateChildNode::Interpolation(_)
+    ));
+}
+
+#[test]
+fn test_parse_type_check_comment_directives() {
+    let allocator = Bump::new();
+    let source = "<div>\n  <!-- @vue-ignore -->\n  <Comp :foo=\"bar\" />\n  <span />\n  \
+                  <!--@vue-skip--> text <p />\n  <!-- @vue-skip -->\n</div>";
+    let (root, errors) = parse_with_options(
+        &allocator,
+        source,
+        ParserOptions {
+            comments: false,
+            ..Default::default()
+        },
+    );
+    assert!(errors.is_empty());
+
+    let TemplateChildNode::Element(div) = &root.children[0] else {
+        panic!("Expected element node");
+    };
+    let directives: Vec<_> = div
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            TemplateChildNode::Element(el) => Some((el.tag.as_str(), el.type_check_directive)),
+            _ => None,
+        })
+        .collect();
+
+    // Attached across whitespace, cleared by text and by the parent closing
+    assert_eq!(
+        directives,
+        [
+            ("Comp", Some(TypeCheckDirective::Ignore)),
+            ("span", None),
+            ("p", None),
+        ]
+    );
+    assert_eq!(div.type_check_directive, None);
+}
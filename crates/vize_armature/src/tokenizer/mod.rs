@@ -35,11 +35,23 @@ pub struct Tokenizer<'a, C: Callbacks> {
     delimiter_close: &'a [u8],
     /// Current delimiter index
     delimiter_index: usize,
+    /// Nesting depth of `{` inside the current interpolation expression
+    interpolation_brace_depth: usize,
+    /// Open string quote inside the current interpolation expression
+    interpolation_quote: Option<u8>,
+    /// Whether the previous byte in an interpolation string was a backslash
+    interpolation_escape: bool,
     /// In pre tag
     #[allow(dead_code)]
     in_pre: bool,
     /// The start of the last entity.
     entity_start: usize,
+    /// Nesting depth of `[` inside a dynamic directive argument
+    dynamic_arg_depth: usize,
+    /// End sequence (e.g. `</textarea`) of the raw text element being read
+    raw_text_end: Option<&'static [u8]>,
+    /// Number of bytes of `raw_text_end` matched so far
+    sequence_index: usize,
 }
 
 impl<'a, C: Callbacks> Tokenizer<'a, C> {
@@ -65,8 +77,14 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
             delimiter_open,
             delimiter_close,
             delimiter_index: 0,
+            interpolation_brace_depth: 0,
+            interpolation_quote: None,
+            interpolation_escape: false,
             in_pre: false,
             entity_start: 0,
+            dynamic_arg_depth: 0,
+            raw_text_end: None,
+            sequence_index: 0,
             base_state: State::Text,
         }
     }
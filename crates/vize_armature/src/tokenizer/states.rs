@@ -14,11 +14,25 @@ use super::{
 use super::entity_decode::try_decode_entity;
 use htmlize::Context;
 
+// End sequences of elements whose content is not parsed as markup
+const SCRIPT_END: &[u8] = b"</script";
+const STYLE_END: &[u8] = b"</style";
+const TEXTAREA_END: &[u8] = b"</textarea";
+const TITLE_END: &[u8] = b"</title";
+
+/// Get the end sequence for a raw text (`<script>`, `<style>`) or RCDATA
+/// (`<textarea>`, `<title>`) element.
+fn raw_text_end_sequence(tag: &[u8]) -> Option<&'static [u8]> {
+    [SCRIPT_END, STYLE_END, TEXTAREA_END, TITLE_END]
+        .into_iter()
+        .find(|end| end[2..].eq_ignore_ascii_case(tag))
+}
+
 impl<'a, C: Callbacks> Tokenizer<'a, C> {
     pub(super) fn cleanup(&mut self) {
         if self.section_start < self.index {
             match self.state {
-                State::Text | State::Interpolation => {
+                State::Text | State::Interpolation | State::InRCDATA => {
                     self.callbacks.on_text(self.section_start, self.index);
                 }
                 State::InTagName
@@ -76,7 +90,13 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
                 self.section_start = self.index + 1;
                 self.state = State::Interpolation;
                 self.delimiter_index = 0;
+                self.interpolation_brace_depth = 0;
+                self.interpolation_quote = None;
+                self.interpolation_escape = false;
             }
+        } else if self.raw_text_end.is_some() {
+            self.state = State::InRCDATA;
+            self.state_in_rcdata(c);
         } else {
             self.state = State::Text;
             self.state_text(c);
@@ -84,10 +104,26 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
     }
 
     pub(super) fn state_interpolation(&mut self, c: u8) {
-        if c == self.delimiter_close[0] {
+        // String literals and nested braces belong to the expression, so a
+        // close delimiter inside them (e.g. `{{ { a: 1 }.a }}`) does not end it.
+        if let Some(quote) = self.interpolation_quote {
+            if self.interpolation_escape {
+                self.interpolation_escape = false;
+            } else if c == b'\\' {
+                self.interpolation_escape = true;
+            } else if c == quote {
+                self.interpolation_quote = None;
+            }
+        } else if self.interpolation_brace_depth == 0 && c == self.delimiter_close[0] {
             self.state = State::InterpolationClose;
             self.delimiter_index = 0;
             self.state_interpolation_close(c);
+        } else if matches!(c, b'"' | b'\'' | b'`') {
+            self.interpolation_quote = Some(c);
+        } else if c == b'{' {
+            self.interpolation_brace_depth += 1;
+        } else if c == b'}' && self.interpolation_brace_depth > 0 {
+            self.interpolation_brace_depth -= 1;
         }
     }
 
@@ -100,7 +136,7 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
                     self.index + 1 - self.delimiter_close.len(),
                 );
                 self.section_start = self.index + 1;
-                self.state = State::Text;
+                self.state = self.text_state();
             }
         } else {
             self.state = State::Interpolation;
@@ -128,8 +164,15 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
 
     pub(super) fn state_in_tag_name(&mut self, c: u8) {
         if is_end_of_tag_section(c) {
+            let input = self.input;
+            let tag = &input[self.section_start..self.index];
             self.callbacks
                 .on_open_tag_name(self.section_start, self.index);
+            self.raw_text_end = if self.callbacks.is_in_foreign_content() {
+                None
+            } else {
+                raw_text_end_sequence(tag)
+            };
             self.section_start = self.index;
             self.state = State::BeforeAttrName;
             self.state_before_attr_name(c);
@@ -139,6 +182,7 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
     pub(super) fn state_in_self_closing_tag(&mut self, c: u8) {
         if c == GT {
             self.callbacks.on_self_closing_tag(self.index);
+            self.raw_text_end = None;
             self.state = State::Text;
             self.section_start = self.index + 1;
         } else if !is_whitespace(c) {
@@ -183,7 +227,8 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
     pub(super) fn state_before_attr_name(&mut self, c: u8) {
         if c == GT {
             self.callbacks.on_open_tag_end(self.index);
-            self.state = State::Text;
+            self.sequence_index = 0;
+            self.state = self.text_state();
             self.section_start = self.index + 1;
         } else if c == SLASH {
             self.state = State::InSelfClosingTag;
@@ -240,6 +285,7 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
         } else if c == LEFT_SQUARE {
             self.callbacks.on_dir_name(self.section_start, self.index);
             self.state = State::InDirDynamicArg;
+            self.dynamic_arg_depth = 0;
             self.section_start = self.index + 1;
         }
     }
@@ -258,6 +304,7 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
                 self.callbacks.on_dir_arg(self.section_start, self.index);
             }
             self.state = State::InDirDynamicArg;
+            self.dynamic_arg_depth = 0;
             self.section_start = self.index + 1;
         } else if c == DOT {
             if self.section_start < self.index {
@@ -269,10 +316,26 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
     }
 
     pub(super) fn state_in_dir_dynamic_arg(&mut self, c: u8) {
-        if c == RIGHT_SQUARE {
+        if c == LEFT_SQUARE {
+            // Nested brackets belong to the expression, e.g. `:[keys[0]]`
+            self.dynamic_arg_depth += 1;
+        } else if c == RIGHT_SQUARE {
+            if self.dynamic_arg_depth > 0 {
+                self.dynamic_arg_depth -= 1;
+                return;
+            }
             self.callbacks.on_dir_arg(self.section_start, self.index);
             self.state = State::InDirArg;
             self.section_start = self.index + 1;
+        } else if c == EQ || is_end_of_tag_section(c) {
+            // Attribute names end here, so the argument was never closed
+            self.callbacks.on_dir_arg(self.section_start, self.index);
+            self.callbacks
+                .on_error(ErrorCode::MissingDynamicDirectiveArgumentEnd, self.index);
+            self.callbacks.on_attrib_name_end(self.index);
+            self.section_start = self.index;
+            self.state = State::AfterAttrName;
+            self.state_after_attr_name(c);
         }
     }
 
@@ -363,6 +426,16 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
             self.state = State::CDATASequence;
             self.section_start = self.index + 1;
         } else {
+            let input = self.input;
+            if input[self.index..]
+                .get(..7)
+                .is_some_and(|name| name.eq_ignore_ascii_case(b"doctype"))
+            {
+                // A doctype means nothing inside a template; skip it like any
+                // other bogus declaration
+                self.callbacks
+                    .on_error(ErrorCode::UnexpectedDoctype, self.section_start - 2);
+            }
             self.state = State::InDeclaration;
         }
     }
@@ -432,9 +505,55 @@ impl<'a, C: Callbacks> Tokenizer<'a, C> {
         self.state = State::InTagName;
     }
 
+    /// Content of `<script>`, `<style>`, `<textarea>` and `<title>` is text up
+    /// to the matching end tag. `<textarea>` and `<title>` (RCDATA) still
+    /// decode entities and interpolations.
     pub(super) fn state_in_rcdata(&mut self, c: u8) {
-        if c == LT {
-            self.state = State::BeforeTagName;
+        let Some(end) = self.raw_text_end else {
+            self.state = State::Text;
+            self.state_text(c);
+            return;
+        };
+
+        if self.sequence_index == end.len() {
+            if c == GT || is_whitespace(c) {
+                let end_of_text = self.index - end.len();
+                if self.section_start < end_of_text {
+                    self.callbacks.on_text(self.section_start, end_of_text);
+                }
+                // Skip over the `</`
+                self.section_start = end_of_text + 2;
+                self.raw_text_end = None;
+                self.state_in_closing_tag_name(c);
+                return;
+            }
+            self.sequence_index = 0;
+        }
+
+        if c.to_ascii_lowercase() == end[self.sequence_index] {
+            self.sequence_index += 1;
+        } else if self.sequence_index == 0 {
+            if end == TEXTAREA_END || end == TITLE_END {
+                if c == AMP {
+                    self.start_entity();
+                } else if !self.callbacks.is_in_v_pre() && c == self.delimiter_open[0] {
+                    self.state = State::InterpolationOpen;
+                    self.delimiter_index = 0;
+                    self.state_interpolation_open(c);
+                }
+            }
+        } else {
+            // `<` may start the end sequence again, e.g. `<</textarea>`
+            self.sequence_index = usize::from(c == LT);
+        }
+    }
+
+    /// State for text content: RCDATA inside raw text elements, text otherwise.
+    fn text_state(&self) -> State {
+        if self.raw_text_end.is_some() {
+            State::InRCDATA
+        } else {
+            State::Text
         }
     }
 
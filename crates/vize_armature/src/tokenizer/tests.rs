@@ -217,6 +217,26 @@ fn test_text_and_interpolation() {
     assert!(cb.events.contains(&TokenEvent::Text(16, 22)));
 }
 
+#[test]
+fn test_interpolation_with_nested_braces_and_strings() {
+    let cb = tokenize("{{ { a: 1 }.a }}");
+    assert!(cb.events.contains(&TokenEvent::Interpolation(2, 14)));
+
+    let cb = tokenize(r#"{{ '}}' + "\"}}" }}"#);
+    assert!(cb.events.contains(&TokenEvent::Interpolation(2, 17)));
+}
+
+#[test]
+fn test_interpolation_custom_delimiters() {
+    let mut tok =
+        Tokenizer::with_delimiters("a ${ { b: 1 }.b } c", TestCallbacks::default(), b"${", b"}");
+    tok.tokenize();
+    let cb = tok.callbacks;
+    assert!(cb.events.contains(&TokenEvent::Text(0, 2)));
+    assert!(cb.events.contains(&TokenEvent::Interpolation(4, 16)));
+    assert!(cb.events.contains(&TokenEvent::Text(17, 19)));
+}
+
 // ========================================================================
 // Attribute tests
 // ========================================================================
@@ -300,6 +320,31 @@ fn test_dynamic_arg() {
     assert!(cb.events.contains(&TokenEvent::DirArg(13, 17)));
 }
 
+#[test]
+fn test_dynamic_arg_with_nested_brackets() {
+    let cb = tokenize(r#"<div :[keys[0]+name]="v">"#);
+    assert!(cb.events.contains(&TokenEvent::DirArg(7, 19)));
+    assert_eq!(
+        cb.events
+            .iter()
+            .filter(|e| matches!(e, TokenEvent::DirArg(..)))
+            .count(),
+        1
+    );
+    assert!(cb.errors.is_empty());
+}
+
+#[test]
+fn test_unclosed_dynamic_arg() {
+    let cb = tokenize(r#"<div :[attr="v"></div>"#);
+    assert!(cb.events.contains(&TokenEvent::DirArg(7, 11)));
+    assert!(cb.events.contains(&TokenEvent::AttribData(13, 14)));
+    assert_eq!(
+        cb.errors,
+        vec![(ErrorCode::MissingDynamicDirectiveArgumentEnd, 11)]
+    );
+}
+
 // ========================================================================
 // Comment tests
 // ========================================================================
@@ -413,3 +458,54 @@ fn test_entity_in_unquoted_attr_value() {
         .events
         .contains(&TokenEvent::AttribEnd(QuoteType::Unquoted, 14)));
 }
+
+// ========================================================================
+// Declaration and raw text tests
+// ========================================================================
+
+#[test]
+fn test_doctype_is_skipped_with_error() {
+    let cb = tokenize("<!DOCTYPE html><div></div>");
+    assert_eq!(cb.errors, vec![(ErrorCode::UnexpectedDoctype, 0)]);
+    assert_eq!(cb.events[0], TokenEvent::OpenTagName(16, 19));
+}
+
+#[test]
+fn test_textarea_content_is_rcdata() {
+    let cb = tokenize("<textarea><div>&amp;{{ x }}</div></textarea>");
+    assert!(cb.errors.is_empty());
+    assert_eq!(
+        cb.events,
+        vec![
+            TokenEvent::OpenTagName(1, 9),
+            TokenEvent::OpenTagEnd(9),
+            TokenEvent::Text(10, 15),
+            TokenEvent::TextEntity('&', 15, 20),
+            TokenEvent::Interpolation(22, 25),
+            TokenEvent::Text(27, 33),
+            TokenEvent::CloseTag(35, 43),
+            TokenEvent::End,
+        ]
+    );
+}
+
+#[test]
+fn test_script_content_is_raw_text() {
+    let cb = tokenize("<script>a < b && {{ c }}</SCRIPT >");
+    assert_eq!(
+        cb.events,
+        vec![
+            TokenEvent::OpenTagName(1, 7),
+            TokenEvent::OpenTagEnd(7),
+            TokenEvent::Text(8, 24),
+            TokenEvent::CloseTag(26, 32),
+            TokenEvent::End,
+        ]
+    );
+}
+
+#[test]
+fn test_self_closing_textarea_is_not_rcdata() {
+    let cb = tokenize("<textarea/><b></b>");
+    assert!(cb.events.contains(&TokenEvent::OpenTagName(12, 13)));
+}
@@ -105,6 +105,13 @@ pub trait Callbacks {
     fn is_in_v_pre(&self) -> bool {
         false
     }
+
+    /// Check if the element whose tag name was just read is in a foreign
+    /// (SVG/MathML) namespace. `<title>` and friends are ordinary elements
+    /// there, so their content is parsed as markup.
+    fn is_in_foreign_content(&self) -> bool {
+        false
+    }
 }
 
 /// Check if character is a tag start character (a-z, A-Z)
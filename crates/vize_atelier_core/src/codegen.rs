@@ -68,13 +68,9 @@ pub fn generate(root: &RootNode<'_>, options: CodegenOptions) -> CodegenResult {
         );
     } else {
         // Multiple root children - wrap in fragment block
-        ctx.use_helper(RuntimeHelper::OpenBlock);
-        ctx.use_helper(RuntimeHelper::CreateElementBlock);
         ctx.use_helper(RuntimeHelper::Fragment);
-        ctx.push("(");
-        ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-        ctx.push("(), ");
-        ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+        ctx.push_open_block(false);
+        ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
         ctx.push("(");
         ctx.push(ctx.helper(RuntimeHelper::Fragment));
         ctx.push(", null, [");
@@ -91,7 +87,9 @@ pub fn generate(root: &RootNode<'_>, options: CodegenOptions) -> CodegenResult {
         }
         ctx.deindent();
         ctx.newline();
-        ctx.push("], 64 /* STABLE_FRAGMENT */))");
+        ctx.push("]");
+        ctx.push_patch_flag(", 64 /* STABLE_FRAGMENT */");
+        ctx.push("))");
     }
 
     ctx.deindent();
@@ -134,6 +132,7 @@ pub fn generate(root: &RootNode<'_>, options: CodegenOptions) -> CodegenResult {
         code: ctx.into_code(),
         preamble,
         map: None,
+        used_helpers: all_helpers,
     }
 }
 
@@ -241,6 +240,45 @@ mod tests {
         insta::assert_snapshot!(result.preamble.as_str());
     }
 
+    #[test]
+    fn test_codegen_module_resolves_components_inside_render() {
+        use crate::options::CodegenMode;
+        let options = super::CodegenOptions {
+            mode: CodegenMode::Module,
+            ..Default::default()
+        };
+        let result = compile!("<div><Foo /><Foo /></div>", options);
+
+        // Resolution needs the rendering instance, so it can't move to module scope
+        assert!(!result.preamble.contains("_resolveComponent(\"Foo\")"));
+        let render = result
+            .code
+            .find("export function render(")
+            .expect("render function");
+        let resolve = result
+            .code
+            .find("const _component_Foo = _resolveComponent(\"Foo\")")
+            .expect("component resolution");
+        assert!(render < resolve);
+        assert_eq!(result.code.matches("_resolveComponent(").count(), 1);
+    }
+
+    #[test]
+    fn test_codegen_reports_used_helpers() {
+        use crate::ast::RuntimeHelper;
+        let result = compile!("<div>hello</div>");
+        let helpers = &result.used_helpers;
+
+        assert!(helpers.contains(&RuntimeHelper::OpenBlock));
+        assert!(helpers.contains(&RuntimeHelper::CreateElementBlock));
+        // The root element becomes a block, so the plain vnode helper is dropped
+        assert!(!helpers.contains(&RuntimeHelper::CreateElementVNode));
+        assert!(helpers.windows(2).all(|w| w[0] < w[1]));
+
+        let result = compile!(r#"<Teleport to="body"><div /></Teleport>"#);
+        assert!(result.used_helpers.contains(&RuntimeHelper::Teleport));
+    }
+
     #[test]
     fn test_codegen_v_model_on_component() {
         let result = compile!(r#"<MyComponent v-model="msg" />"#);
@@ -458,4 +496,281 @@ mod tests {
         );
         assert_codegen_snapshot!(result);
     }
+
+    #[test]
+    fn test_codegen_output_is_deterministic() {
+        let template = r#"<div id="app" class="a" :class="{ b: isB }" style="color: red" :style="extra" :title="title" :data-a="a" :data-b="b" :[dynKey]="dynValue" v-bind="attrs" @click="onClick" @keyup.enter="onEnter" @click.once="onOnce" @focus="onFocus"><MyComp v-model="model" v-model:title="title" :x="x" :y="y" :z="z" @update="onUpdate"><template #header="{ item }">{{ item.label }}</template></MyComp><input v-model.trim="text" v-focus v-tooltip:top.lazy="tip" v-show="visible"><p v-if="ok" v-custom:[arg].a.b="value">{{ text }}</p><p v-else>fallback</p><span v-for="item in items" :key="item.id" :a="item.a" :b="item.b">{{ item.text }}</span></div>"#;
+
+        let expected = result_output(&compile!(template));
+        for _ in 0..100 {
+            let output = result_output(&compile!(template));
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn test_codegen_hoists_static_props_of_element_with_dynamic_children() {
+        use crate::options::{CodegenOptions, TransformOptions};
+        use crate::parser::parse;
+        use crate::transform::transform;
+        use bumpalo::Bump;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div class="container"><ul class="list"><li v-for="item in items">{{ item }}</li></ul><MyComp class="child" /></div>"#,
+        );
+
+        transform(
+            &allocator,
+            &mut root,
+            TransformOptions {
+                hoist_static: true,
+                ..Default::default()
+            },
+            None,
+        );
+
+        let result = super::generate(&root, CodegenOptions::default());
+        let output = result_output(&result);
+
+        assert!(output.contains(r#"const _hoisted_1 = { class: "container" }"#));
+        assert!(output.contains(r#"const _hoisted_2 = { class: "list" }"#));
+        assert!(output.contains(r#"_createElementBlock("div", _hoisted_1, ["#));
+        assert!(output.contains(r#"_createElementVNode("ul", _hoisted_2, ["#));
+        // Component props are never hoisted
+        assert!(!output.contains(r#"= { class: "child" }"#));
+    }
+
+    #[test]
+    fn test_codegen_hoists_static_subtrees_inside_dynamic_parent() {
+        use crate::options::{CodegenOptions, TransformOptions};
+        use crate::parser::parse;
+        use crate::transform::transform;
+        use bumpalo::Bump;
+
+        let template = r#"<div :class="cls"><p class="a">Hello <b>world</b></p><ul><li>one</li><li>two</li></ul><span>{{ msg }}</span></div>"#;
+
+        // Number of vnodes created inside the render function
+        let render_vnode_count = |hoist_static: bool| {
+            let allocator = Bump::new();
+            let (mut root, _) = parse(&allocator, template);
+            transform(
+                &allocator,
+                &mut root,
+                TransformOptions {
+                    hoist_static,
+                    ..Default::default()
+                },
+                None,
+            );
+            let output = result_output(&super::generate(&root, CodegenOptions::default()));
+            let render = output.split("function render").nth(1).unwrap_or_default();
+            (render.matches("_createElementVNode(").count(), output)
+        };
+
+        let (before, _) = render_vnode_count(false);
+        let (after, output) = render_vnode_count(true);
+        assert_eq!(before, 6, "{output}");
+        assert_eq!(after, 1, "{output}");
+
+        for expected in [
+            r#"const _hoisted_1 = /*#__PURE__*/ _createElementVNode("b", null, "world")"#,
+            r#"_createElementVNode("p", { class: "a" }, [_createTextVNode("Hello "), _hoisted_1])"#,
+            r#"_createElementVNode("ul", null, [_hoisted_3, _hoisted_4])"#,
+            "createTextVNode: _createTextVNode",
+        ] {
+            assert!(output.contains(expected), "{output}");
+        }
+        assert!(output.contains("_hoisted_2,\n"), "{output}");
+        assert!(output.contains("_hoisted_5,\n"), "{output}");
+    }
+
+    #[test]
+    fn test_codegen_ref_and_v_show_need_patch_and_are_not_hoisted() {
+        use crate::options::{CodegenOptions, TransformOptions};
+        use crate::parser::parse;
+        use crate::transform::transform;
+        use bumpalo::Bump;
+
+        let allocator = Bump::new();
+        let (mut root, _) = parse(
+            &allocator,
+            r#"<div><div ref="el"></div><div v-show="ok"></div><p ref="p" @mouseenter="hover"></p></div>"#,
+        );
+
+        transform(
+            &allocator,
+            &mut root,
+            TransformOptions {
+                hoist_static: true,
+                cache_handlers: true,
+                ..Default::default()
+            },
+            None,
+        );
+
+        let result = super::generate(
+            &root,
+            CodegenOptions {
+                cache_handlers: true,
+                ..Default::default()
+            },
+        );
+        let output = result_output(&result);
+
+        assert!(!output.contains("_hoisted"), "{output}");
+        assert!(
+            output.contains(
+                r#"_createElementVNode("div", { ref: "el" }, null, 512 /* NEED_PATCH */)"#
+            ),
+            "{output}"
+        );
+        assert!(
+            output.contains(r#"_createElementVNode("div", null, null, 512 /* NEED_PATCH */)"#),
+            "{output}"
+        );
+        assert!(
+            output.contains("null, 544 /* NEED_HYDRATION, NEED_PATCH */)"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_codegen_v_show_with_style_and_v_model() {
+        let result = compile!(
+            r#"<div><p v-show="ok" :style="s"></p><input v-show="ok" v-model="text"></div>"#
+        );
+        let output = result_output(&result);
+        let compact: vize_carton::String = output.split_whitespace().collect();
+
+        // Neither dropped nor duplicated, and v-model shares its withDirectives array
+        assert_eq!(output.matches("[_vShow, ok]").count(), 2, "{output}");
+        assert_eq!(output.matches("_withDirectives(").count(), 2, "{output}");
+        assert!(
+            output.contains(
+                r#"_createElementVNode("p", { style: _normalizeStyle(s) }, null, 4 /* STYLE */), ["#
+            ),
+            "{output}"
+        );
+        assert!(
+            compact.contains("[[_vModelText,text],[_vShow,ok]])"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_codegen_v_pre_renders_subtree_verbatim() {
+        let result = compile!(
+            r#"<div><span v-pre :id="x" @click="go">{{ raw }}<Foo v-if="ok" /></span></div>"#
+        );
+        let output = result_output(&result);
+
+        assert!(output.contains(r#"":id": "x""#));
+        assert!(output.contains(r#""@click": "go""#));
+        assert!(output.contains("{{ raw }}"));
+        assert!(output.contains(r#"_createElementVNode("Foo", { "v-if": "ok" }"#));
+        assert!(!output.contains("_toDisplayString"));
+        assert!(!output.contains("_resolveComponent"));
+        assert!(!output.contains("_createCommentVNode"));
+    }
+
+    #[test]
+    fn test_codegen_v_cloak_is_dropped() {
+        let result = compile!("<div v-cloak>{{ msg }}</div>");
+        let output = result_output(&result);
+
+        assert!(output.contains(r#"_createElementBlock("div", null, _toDisplayString(msg)"#));
+        assert!(!output.contains("cloak"));
+        assert!(!output.contains("_resolveDirective"));
+        assert!(!output.contains("_withDirectives"));
+    }
+
+    #[test]
+    fn test_codegen_custom_directive_is_resolved() {
+        let result = compile!(r#"<div v-my-dir:arg.mod="value" v-cloak></div>"#);
+        let output = result_output(&result);
+
+        assert!(output.contains(r#"const _directive_my_dir = _resolveDirective("my-dir")"#));
+        assert!(output.contains(r#"[_directive_my_dir, value, "arg", { mod: true }]"#));
+        assert!(output.contains("_withDirectives("));
+        assert_eq!(output.matches("_resolveDirective(").count(), 1);
+    }
+
+    #[test]
+    fn test_codegen_blocks_disabled() {
+        let result = compile!(
+            r#"<div><p :id="x">{{a}}</p><i v-for="i in l" :key="i">{{i}}</i><b v-if="ok"/></div>"#,
+            CodegenOptions {
+                blocks: false,
+                ..Default::default()
+            }
+        );
+        let output = result_output(&result);
+
+        assert!(
+            output.contains(r#"(_createElementVNode("div", null, ["#),
+            "{output}"
+        );
+        assert!(output.contains(r#"_createElementVNode("p", { id: x }, _toDisplayString(a))"#));
+        assert!(output.contains("(_createElementVNode(_Fragment, null, _renderList(l"));
+        assert!(!output.contains("_openBlock"), "{output}");
+        assert!(!output.contains("Block("), "{output}");
+        assert!(!output.contains(" */"), "{output}");
+    }
+
+    #[test]
+    fn test_codegen_v_html_keeps_scope_id() {
+        let result = compile!(
+            r#"<div><p v-html="raw"></p></div>"#,
+            CodegenOptions {
+                scope_id: Some("data-v-7ba5bd90".into()),
+                ..Default::default()
+            }
+        );
+        let output = result_output(&result);
+
+        assert!(output.contains("innerHTML: raw"), "{output}");
+        let host = output
+            .find(r#"_createElementVNode("p""#)
+            .expect("v-html host element should be generated");
+        assert!(
+            output[host..].contains(r#""data-v-7ba5bd90": """#),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_codegen_merge_props_preserves_spread_order() {
+        let result = compile!(r#"<div class="s" v-bind="a" :id="x" v-bind="b" :class="c"></div>"#);
+        let output = result_output(&result);
+
+        assert!(
+            output.contains(r#"_mergeProps({ class: "s" }, a, { id: x }, b, {"#),
+            "{output}"
+        );
+        assert!(output.contains("class: c"), "{output}");
+        assert!(!output.contains("_normalizeClass"), "{output}");
+
+        let result = compile!(r#"<p v-bind="a" v-bind="b"></p>"#);
+        let output = result_output(&result);
+        assert!(output.contains("_mergeProps(a, b)"), "{output}");
+
+        // v-if branches and v-for items keep the same order, with the key last
+        let result = compile!(r#"<div v-if="ok" v-bind="a" :id="x" v-bind="b"></div>"#);
+        let output = result_output(&result);
+        let output = output.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert!(
+            output.contains("_mergeProps(a, { id: x }, b, { key: 0 })"),
+            "{output}"
+        );
+
+        let result = compile!(r#"<i v-for="i in l" :key="i" v-bind="a" :id="x" v-on="h"></i>"#);
+        let output = result_output(&result);
+        let output = output.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert!(
+            output.contains("_mergeProps(a, { id: x }, _toHandlers(h, true), { key: i })"),
+            "{output}"
+        );
+    }
 }
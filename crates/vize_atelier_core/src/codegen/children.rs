@@ -169,7 +169,8 @@ fn generate_children_inner(
                         _ => {}
                     }
                 }
-                ctx.push(", 1 /* TEXT */)");
+                ctx.push_patch_flag(", 1 /* TEXT */");
+                ctx.push(")");
             } else {
                 // Only static text nodes
                 for (j, child) in run.iter().enumerate() {
@@ -54,6 +54,11 @@ pub struct CodegenResult {
     pub preamble: String,
     /// Source map (JSON)
     pub map: Option<String>,
+    /// Runtime helpers referenced by the generated code, sorted.
+    ///
+    /// Unlike `RootNode::helpers`, this excludes helpers that transforms
+    /// registered but codegen optimized away.
+    pub used_helpers: Vec<RuntimeHelper>,
 }
 
 impl CodegenContext {
@@ -111,6 +116,47 @@ impl CodegenContext {
         self.options.cache_handlers && !self.has_slot_params()
     }
 
+    /// Whether block tree optimization is enabled
+    #[inline]
+    pub fn blocks_enabled(&self) -> bool {
+        self.options.blocks
+    }
+
+    /// Open a block wrapper: `(_openBlock(), ` or, with `disable_tracking`,
+    /// `(_openBlock(true), `. With blocks disabled only the `(` is pushed so
+    /// the caller's closing parenthesis stays balanced.
+    pub fn push_open_block(&mut self, disable_tracking: bool) {
+        self.push("(");
+        if self.options.blocks {
+            self.use_helper(RuntimeHelper::OpenBlock);
+            self.push(self.helper(RuntimeHelper::OpenBlock));
+            self.push(if disable_tracking { "(true), " } else { "(), " });
+        }
+    }
+
+    /// Push a block creation helper (`createElementBlock` or `createBlock`),
+    /// or its plain vnode counterpart when blocks are disabled.
+    pub fn push_block_helper(&mut self, helper: RuntimeHelper) {
+        let helper = match helper {
+            RuntimeHelper::CreateElementBlock if !self.options.blocks => {
+                RuntimeHelper::CreateElementVNode
+            }
+            RuntimeHelper::CreateBlock if !self.options.blocks => RuntimeHelper::CreateVNode,
+            helper => helper,
+        };
+        self.use_helper(helper);
+        self.push(self.helper(helper));
+    }
+
+    /// Push a literal patch flag argument such as `, 64 /* STABLE_FRAGMENT */`,
+    /// unless blocks are disabled.
+    #[inline]
+    pub fn push_patch_flag(&mut self, flag: &str) {
+        if self.options.blocks {
+            self.push(flag);
+        }
+    }
+
     /// Get next cache index for v-once
     pub fn next_cache_index(&mut self) -> usize {
         let index = self.cache_index;
@@ -75,7 +75,8 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
         ctx.push("(");
     }
 
-    // Check for v-show directive (only if no custom directives or vmodel)
+    // Check for v-show directive (custom directives and v-model closings already
+    // append it to their own withDirectives array)
     let has_vshow = has_vshow_directive(el) && !has_vmodel && !has_custom_dirs;
     if has_vshow {
         ctx.use_helper(RuntimeHelper::WithDirectives);
@@ -142,29 +143,19 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
     let is_memo_component = memo_cache_index.is_some() && el.tag_type == ElementType::Component;
 
     if !is_memo_component {
-        // Track helpers for preamble
-        ctx.use_helper(RuntimeHelper::OpenBlock);
-
         // Open block wrapper
-        ctx.push("(");
-        ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-        ctx.push("(), ");
+        ctx.push_open_block(false);
     }
 
     match el.tag_type {
         ElementType::Element => {
-            ctx.use_helper(RuntimeHelper::CreateElementBlock);
-            ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+            ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
             ctx.push("(\"");
             ctx.push(&el.tag);
             ctx.push("\"");
 
             // Calculate patch flag and dynamic props
-            let (patch_flag, dynamic_props) = calculate_element_patch_info(
-                el,
-                ctx.options.binding_metadata.as_ref(),
-                ctx.cache_handlers_in_current_scope(),
-            );
+            let (patch_flag, dynamic_props) = calculate_element_patch_info(ctx, el);
             let has_patch_info = patch_flag.is_some() || dynamic_props.is_some();
 
             // Generate props (only if there are renderable props, not just v-show)
@@ -251,8 +242,7 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
                 ctx.use_helper(RuntimeHelper::CreateVNode);
                 ctx.push(ctx.helper(RuntimeHelper::CreateVNode));
             } else {
-                ctx.use_helper(RuntimeHelper::CreateBlock);
-                ctx.push(ctx.helper(RuntimeHelper::CreateBlock));
+                ctx.push_block_helper(RuntimeHelper::CreateBlock);
             }
             ctx.push("(");
 
@@ -319,17 +309,9 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             // Calculate patch flag and dynamic props for component
             // For dynamic components, skip the :is binding from patch flag calculation
             let (mut patch_flag, dynamic_props) = if is_dynamic_component {
-                calculate_element_patch_info_skip_is(
-                    el,
-                    ctx.options.binding_metadata.as_ref(),
-                    ctx.cache_handlers_in_current_scope(),
-                )
+                calculate_element_patch_info_skip_is(ctx, el)
             } else {
-                calculate_element_patch_info(
-                    el,
-                    ctx.options.binding_metadata.as_ref(),
-                    ctx.cache_handlers_in_current_scope(),
-                )
+                calculate_element_patch_info(ctx, el)
             };
 
             // For components with slot children, remove TEXT flag (1) since text is inside slot
@@ -342,7 +324,9 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
 
             // Add DYNAMIC_SLOTS flag (1024) if component has dynamic slots
             // KeepAlive always gets DYNAMIC_SLOTS
-            if el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el) {
+            if ctx.blocks_enabled()
+                && (el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el))
+            {
                 let dynamic_slots_flag = 1024;
                 patch_flag = Some(patch_flag.unwrap_or(0) | dynamic_slots_flag);
             }
@@ -501,9 +485,8 @@ pub fn generate_element_block(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             }
         }
         ElementType::Template => {
-            ctx.use_helper(RuntimeHelper::CreateElementBlock);
             ctx.use_helper(RuntimeHelper::Fragment);
-            ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+            ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
             ctx.push("(");
             ctx.push(ctx.helper(RuntimeHelper::Fragment));
             ctx.push(", null, ");
@@ -83,6 +83,9 @@ pub fn generate_vmodel_closing(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             ctx.push("]");
         }
 
+        // v-show shares the withDirectives array with v-model
+        generate_vshow_entry(ctx, el);
+
         ctx.newline();
         ctx.push("])");
     }
@@ -180,26 +183,32 @@ pub fn generate_custom_directives_closing(ctx: &mut CodegenContext, el: &Element
     }
 
     // Also include v-show in the same withDirectives array if present
-    if has_vshow_directive(el) {
-        for prop in &el.props {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name.as_str() == "show" {
-                    if let Some(exp) = &dir.exp {
-                        ctx.push(",");
-                        ctx.newline();
-                        ctx.push("  [");
-                        ctx.use_helper(RuntimeHelper::VShow);
-                        ctx.push(ctx.helper(RuntimeHelper::VShow));
-                        ctx.push(", ");
-                        generate_expression(ctx, exp);
-                        ctx.push("]");
-                    }
-                    break;
+    generate_vshow_entry(ctx, el);
+
+    ctx.newline();
+    ctx.push("])");
+}
+
+/// Append a `[_vShow, expr]` entry to an open withDirectives array if present
+fn generate_vshow_entry(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
+    if !has_vshow_directive(el) {
+        return;
+    }
+    for prop in &el.props {
+        if let PropNode::Directive(dir) = prop {
+            if dir.name.as_str() == "show" {
+                if let Some(exp) = &dir.exp {
+                    ctx.push(",");
+                    ctx.newline();
+                    ctx.push("  [");
+                    ctx.use_helper(RuntimeHelper::VShow);
+                    ctx.push(ctx.helper(RuntimeHelper::VShow));
+                    ctx.push(", ");
+                    generate_expression(ctx, exp);
+                    ctx.push("]");
                 }
+                break;
             }
         }
     }
-
-    ctx.newline();
-    ctx.push("])");
 }
@@ -65,7 +65,7 @@ pub fn generate_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
                 ctx.push("(");
             }
 
-            // Check for v-show directive (only if no v-model)
+            // Check for v-show directive (the v-model closing already appends it)
             let has_vshow = has_vshow_directive(el) && !has_vmodel;
             if has_vshow {
                 ctx.use_helper(RuntimeHelper::WithDirectives);
@@ -83,11 +83,7 @@ pub fn generate_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             ctx.push("\"");
 
             // Calculate patch flag and dynamic props
-            let (patch_flag, dynamic_props) = calculate_element_patch_info(
-                el,
-                ctx.options.binding_metadata.as_ref(),
-                ctx.cache_handlers_in_current_scope(),
-            );
+            let (patch_flag, dynamic_props) = calculate_element_patch_info(ctx, el);
             let has_patch_info = patch_flag.is_some() || dynamic_props.is_some();
 
             // Generate props (only if there are renderable props, not just v-show)
@@ -219,17 +215,9 @@ pub fn generate_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
             // Calculate patch flag and dynamic props for component
             // For dynamic components, skip the :is binding from patch flag calculation
             let (mut patch_flag, dynamic_props) = if is_dynamic_component {
-                calculate_element_patch_info_skip_is(
-                    el,
-                    ctx.options.binding_metadata.as_ref(),
-                    ctx.cache_handlers_in_current_scope(),
-                )
+                calculate_element_patch_info_skip_is(ctx, el)
             } else {
-                calculate_element_patch_info(
-                    el,
-                    ctx.options.binding_metadata.as_ref(),
-                    ctx.cache_handlers_in_current_scope(),
-                )
+                calculate_element_patch_info(ctx, el)
             };
 
             // Slot content is patched through the slot object, so the component vnode
@@ -243,7 +231,9 @@ pub fn generate_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
 
             // KeepAlive always needs DYNAMIC_SLOTS. Other components need it when
             // slot structure is dynamic.
-            if el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el) {
+            if ctx.blocks_enabled()
+                && (el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el))
+            {
                 patch_flag = Some(patch_flag.unwrap_or(0) | 1024);
             }
 
@@ -85,11 +85,7 @@ pub fn generate_v_once_element(ctx: &mut CodegenContext, el: &ElementNode<'_>) {
         }
 
         // v-once still needs patch flag for dynamic bindings (class/style)
-        let (patch_flag, _) = calculate_element_patch_info(
-            el,
-            ctx.options.binding_metadata.as_ref(),
-            ctx.cache_handlers_in_current_scope(),
-        );
+        let (patch_flag, _) = calculate_element_patch_info(ctx, el);
         if let Some(flag) = patch_flag {
             // Only emit CLASS/STYLE flags for v-once, ignore PROPS
             let filtered_flag = flag & (2 | 4); // CLASS | STYLE
@@ -215,7 +211,9 @@ pub fn generate_v_once_child(ctx: &mut CodegenContext, node: &TemplateChildNode<
             ctx.push(ctx.helper(RuntimeHelper::ToDisplayString));
             ctx.push("(");
             generate_expression(ctx, &interp.content);
-            ctx.push("), 1 /* TEXT */)");
+            ctx.push(")");
+            ctx.push_patch_flag(", 1 /* TEXT */");
+            ctx.push(")");
         }
         _ => generate_node(ctx, node),
     }
@@ -5,7 +5,7 @@
 
 use crate::ast::{
     DynamicProps, ExpressionNode, JsChildNode, PropsExpression, RootNode, RuntimeHelper,
-    TemplateTextChildNode, VNodeCall, VNodeChildren, VNodeTag,
+    TemplateChildNode, TemplateTextChildNode, VNodeCall, VNodeChildren, VNodeTag,
 };
 
 use super::{context::CodegenContext, helpers::escape_js_string};
@@ -80,6 +80,16 @@ fn collect_helpers_from_vnode_call(vnode: &VNodeCall<'_>, helpers: &mut Vec<Runt
     if let Some(props) = &vnode.props {
         collect_helpers_from_props(props, helpers);
     }
+
+    // Text siblings of hoisted elements are created as text vnodes
+    if let Some(VNodeChildren::Multiple(nodes)) = &vnode.children {
+        if nodes
+            .iter()
+            .any(|node| matches!(node, TemplateChildNode::Text(_)))
+        {
+            helpers.push(RuntimeHelper::CreateText);
+        }
+    }
 }
 
 fn collect_helpers_from_props(props: &PropsExpression<'_>, helpers: &mut Vec<RuntimeHelper>) {
@@ -171,11 +181,14 @@ fn generate_vnode_call_to_bytes(ctx: &CodegenContext, vnode: &VNodeCall<'_>, out
         VNodeTag::Call(_) => out.extend_from_slice(b"null"),
     }
 
+    // Patch flags are only emitted for block tree optimization
+    let patch_flag = vnode.patch_flag.as_ref().filter(|_| ctx.blocks_enabled());
+
     // Props
     if let Some(props) = &vnode.props {
         out.extend_from_slice(b", ");
         generate_props_expression_to_bytes(ctx, props, out);
-    } else if vnode.children.is_some() || vnode.patch_flag.is_some() {
+    } else if vnode.children.is_some() || patch_flag.is_some() {
         out.extend_from_slice(b", null");
     }
 
@@ -183,12 +196,12 @@ fn generate_vnode_call_to_bytes(ctx: &CodegenContext, vnode: &VNodeCall<'_>, out
     if let Some(children) = &vnode.children {
         out.extend_from_slice(b", ");
         generate_vnode_children_to_bytes(ctx, children, out);
-    } else if vnode.patch_flag.is_some() {
+    } else if patch_flag.is_some() {
         out.extend_from_slice(b", null");
     }
 
     // Patch flag
-    if let Some(patch_flag) = &vnode.patch_flag {
+    if let Some(patch_flag) = patch_flag {
         out.extend_from_slice(b", ");
         out.extend_from_slice(patch_flag.bits().to_compact_string().as_bytes());
         out.extend_from_slice(b" /* ");
@@ -270,7 +283,7 @@ fn generate_props_expression_to_bytes(
 
 /// Generate `VNodeChildren` to bytes.
 fn generate_vnode_children_to_bytes(
-    _ctx: &CodegenContext,
+    ctx: &CodegenContext,
     children: &VNodeChildren<'_>,
     out: &mut Vec<u8>,
 ) {
@@ -284,6 +297,28 @@ fn generate_vnode_children_to_bytes(
             TemplateTextChildNode::Interpolation(_) => out.extend_from_slice(b"null"),
             TemplateTextChildNode::Compound(_) => out.extend_from_slice(b"null"),
         },
+        VNodeChildren::Multiple(nodes) => {
+            out.push(b'[');
+            for (i, node) in nodes.iter().enumerate() {
+                if i > 0 {
+                    out.extend_from_slice(b", ");
+                }
+                match node {
+                    TemplateChildNode::Hoisted(index) => {
+                        out.extend_from_slice(b"_hoisted_");
+                        out.extend_from_slice((index + 1).to_compact_string().as_bytes());
+                    }
+                    TemplateChildNode::Text(text) => {
+                        out.extend_from_slice(ctx.helper(RuntimeHelper::CreateText).as_bytes());
+                        out.extend_from_slice(b"(\"");
+                        out.extend_from_slice(escape_js_string(&text.content).as_bytes());
+                        out.extend_from_slice(b"\")");
+                    }
+                    _ => out.extend_from_slice(b"null"),
+                }
+            }
+            out.push(b']');
+        }
         VNodeChildren::Simple(exp) => {
             if exp.is_static {
                 out.push(b'"');
@@ -1,5 +1,6 @@
 //! Patch flag calculation and naming functions.
 
+use super::context::CodegenContext;
 use super::helpers::{camelize, is_constant_simple_expression};
 use crate::ast::*;
 use crate::options::{BindingMetadata, BindingType};
@@ -64,22 +65,38 @@ fn is_static_bound_expression(dir: &DirectiveNode<'_>, bindings: Option<&Binding
 }
 
 /// Calculate patch flag and dynamic props for an element.
-/// `skip_is_prop`: when true, skip `:is` binding (used for `<component :is="...">`)
+///
+/// Returns no patch info when block tree optimization is disabled.
 pub fn calculate_element_patch_info(
+    ctx: &CodegenContext,
     el: &ElementNode<'_>,
-    bindings: Option<&BindingMetadata>,
-    cache_handlers: bool,
 ) -> (Option<i32>, Option<Vec<String>>) {
-    calculate_element_patch_info_inner(el, bindings, cache_handlers, false)
+    if !ctx.blocks_enabled() {
+        return (None, None);
+    }
+    calculate_element_patch_info_inner(
+        el,
+        ctx.options.binding_metadata.as_ref(),
+        ctx.cache_handlers_in_current_scope(),
+        false,
+    )
 }
 
-/// Same as `calculate_element_patch_info` but allows skipping the `is` prop.
+/// Same as `calculate_element_patch_info` but skips the `:is` binding
+/// (used for `<component :is="...">`).
 pub fn calculate_element_patch_info_skip_is(
+    ctx: &CodegenContext,
     el: &ElementNode<'_>,
-    bindings: Option<&BindingMetadata>,
-    cache_handlers: bool,
 ) -> (Option<i32>, Option<Vec<String>>) {
-    calculate_element_patch_info_inner(el, bindings, cache_handlers, true)
+    if !ctx.blocks_enabled() {
+        return (None, None);
+    }
+    calculate_element_patch_info_inner(
+        el,
+        ctx.options.binding_metadata.as_ref(),
+        ctx.cache_handlers_in_current_scope(),
+        true,
+    )
 }
 
 fn calculate_element_patch_info_inner(
@@ -371,11 +388,12 @@ fn calculate_element_patch_info_inner(
     }
 
     // Add NEED_PATCH for v-show, custom directives, or ref only if no other dynamic bindings exist
+    // (NEED_HYDRATION alone does not make the element patched, so it still needs the flag)
     // Custom directives only need NEED_PATCH when the element has no children
     // (children already cause the element to be tracked for patching by the runtime)
     // This must come after TEXT flag check so we don't add NEED_PATCH when TEXT is about to be set
     let custom_dir_needs_patch = has_custom_directive && el.children.is_empty();
-    if (has_vshow || has_vmodel || custom_dir_needs_patch || has_ref) && flag == 0 {
+    if (has_vshow || has_vmodel || custom_dir_needs_patch || has_ref) && (flag == 0 || flag == 32) {
         flag |= 512; // NEED_PATCH
     }
 
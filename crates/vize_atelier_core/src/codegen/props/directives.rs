@@ -57,6 +57,8 @@ pub fn generate_directive_prop_with_static(
         }
         "html" => {
             // v-html="rawHtml" -> innerHTML: _ctx.rawHtml
+            // The host element still receives the scope attribute from
+            // generate_props; the injected markup itself is never scoped.
             ctx.push("innerHTML: ");
             if let Some(exp) = &dir.exp {
                 generate_expression(ctx, exp);
@@ -196,28 +198,9 @@ fn generate_vbind_prop(
             }
             // Merge static style if present (needed even inside mergeProps)
             if let Some(static_val) = static_style {
-                ctx.push("[{");
-                // Parse static style and convert to object
-                for (i, part) in static_val
-                    .split(';')
-                    .filter(|s| !s.trim().is_empty())
-                    .enumerate()
-                {
-                    if i > 0 {
-                        ctx.push(",");
-                    }
-                    let parts: Vec<&str> = part.splitn(2, ':').collect();
-                    if parts.len() == 2 {
-                        let key = parts[0].trim();
-                        let value = parts[1].trim();
-                        ctx.push("\"");
-                        ctx.push(key);
-                        ctx.push("\":\"");
-                        ctx.push(value);
-                        ctx.push("\"");
-                    }
-                }
-                ctx.push("}, ");
+                ctx.push("[");
+                generate_static_style_object(ctx, static_val);
+                ctx.push(", ");
                 generate_expression(ctx, exp);
                 ctx.push("]");
             } else {
@@ -234,6 +217,75 @@ fn generate_vbind_prop(
     }
 }
 
+/// Generate a static `style` attribute as an object literal (`{"color":"red"}`).
+///
+/// Follows Vue's `parseStringStyle`: comments are dropped, `;` inside parentheses
+/// (e.g. `url(data:...;base64,...)`) does not split declarations, and a repeated
+/// property keeps its first position with the last value.
+fn generate_static_style_object(ctx: &mut CodegenContext, css: &str) {
+    let stripped = strip_css_comments(css);
+    let stripped = stripped.as_deref().unwrap_or(css);
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in stripped.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => {
+                items.push(&stripped[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&stripped[start..]);
+
+    let mut declarations: Vec<(&str, &str)> = Vec::with_capacity(items.len());
+    for (key, value) in items.iter().filter_map(|item| item.split_once(':')) {
+        let (key, value) = (key.trim(), value.trim());
+        if let Some(existing) = declarations.iter_mut().find(|(k, _)| *k == key) {
+            existing.1 = value;
+        } else {
+            declarations.push((key, value));
+        }
+    }
+
+    ctx.push("{");
+    for (i, (key, value)) in declarations.iter().enumerate() {
+        if i > 0 {
+            ctx.push(",");
+        }
+        ctx.push("\"");
+        ctx.push(&escape_js_string(key));
+        ctx.push("\":\"");
+        ctx.push(&escape_js_string(value));
+        ctx.push("\"");
+    }
+    ctx.push("}");
+}
+
+/// Remove `/* ... */` comments from inline CSS, or `None` if there are none.
+fn strip_css_comments(css: &str) -> Option<String> {
+    if !css.contains("/*") {
+        return None;
+    }
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(open) = rest.find("/*") {
+        out.push_str(&rest[..open]);
+        match rest[open + 2..].find("*/") {
+            Some(close) => rest = &rest[open + 2 + close + 2..],
+            None => {
+                rest = "";
+                break;
+            }
+        }
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
 /// Generate v-on directive as a prop
 fn generate_von_prop(ctx: &mut CodegenContext, dir: &DirectiveNode<'_>) {
     // Get event name first to determine context for modifiers
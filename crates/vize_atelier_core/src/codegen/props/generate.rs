@@ -11,7 +11,7 @@ use super::{
     directives::{generate_directive_prop_with_static, is_supported_directive},
     events::{count_event_names, generate_merged_event_handlers, get_von_event_key},
     generate_vbind_object_exp, generate_von_object_exp, has_dynamic_key, has_dynamic_vmodel,
-    has_other_props, has_vbind_object, has_von_object,
+    has_other_props, has_vbind_object, has_von_object, is_object_spread,
 };
 use vize_carton::{FxHashSet, String};
 
@@ -41,50 +41,37 @@ pub fn generate_props(ctx: &mut CodegenContext, props: &[PropNode<'_>]) {
     let has_vbind_obj = has_vbind_object(props);
     let has_von_obj = has_von_object(props);
     let has_other = has_other_props(props);
+    let spread_count = props.iter().filter(|p| is_object_spread(p)).count();
 
     // Handle cases with object spreads (v-bind="obj" or v-on="obj")
     if has_vbind_obj || has_von_obj {
-        if has_other || (has_vbind_obj && has_von_obj) {
+        if has_other || spread_count > 1 {
             // Multiple spreads or spread with other props: _mergeProps(...)
-            ctx.use_helper(RuntimeHelper::MergeProps);
-            ctx.push(ctx.helper(RuntimeHelper::MergeProps));
-            ctx.push("(");
-
-            let mut first_merge_arg = true;
-
-            // Add v-bind object spread
-            if has_vbind_obj {
-                generate_vbind_object_exp(ctx, props);
-                first_merge_arg = false;
-            }
-
-            // Add v-on object spread (wrapped with toHandlers)
-            if has_von_obj {
-                if !first_merge_arg {
-                    ctx.push(", ");
-                }
-                generate_von_object_exp(ctx, props);
-                first_merge_arg = false;
-            }
-
-            // Add other props as object (includes scope_id)
-            // Inside mergeProps, skip normalizeClass/normalizeStyle - mergeProps handles it
-            if has_other {
-                if !first_merge_arg {
-                    ctx.push(", ");
-                }
-                generate_props_object_inner(ctx, props, true, true);
-            } else if let Some(ref sid) = scope_id {
-                // No other props but we have scope_id, add it as separate object
-                if !first_merge_arg {
-                    ctx.push(", ");
+            generate_merge_props(ctx, props, |ctx, segment, position| {
+                if has_other_props(segment) {
+                    if position.needs_separator {
+                        ctx.push(", ");
+                    }
+                    // The scope_id is only added to the trailing object
+                    let prev_skip_scope_id = ctx.skip_scope_id;
+                    ctx.skip_scope_id |= !position.trailing;
+                    // Inside mergeProps, skip normalizeClass/normalizeStyle - mergeProps handles it
+                    generate_props_object_inner(ctx, segment, true, true);
+                    ctx.skip_scope_id = prev_skip_scope_id;
+                    true
+                } else if let Some(sid) = scope_id.as_ref().filter(|_| position.trailing) {
+                    // No other props but we have scope_id, add it as separate object
+                    if position.needs_separator {
+                        ctx.push(", ");
+                    }
+                    ctx.push("{ \"");
+                    ctx.push(sid);
+                    ctx.push("\": \"\" }");
+                    true
+                } else {
+                    false
                 }
-                ctx.push("{ \"");
-                ctx.push(sid);
-                ctx.push("\": \"\" }");
-            }
-
-            ctx.push(")");
+            });
         } else if has_vbind_obj {
             // v-bind="attrs" alone
             // If we have scope_id, we need to merge it with the bound object
@@ -164,6 +151,69 @@ fn generate_props_object(
     generate_props_object_inner(ctx, props, skip_object_spreads, false);
 }
 
+/// Position of a props object among the `_mergeProps(...)` arguments.
+#[derive(Clone, Copy)]
+pub(crate) struct MergeSegment {
+    /// An argument was already written, so a `, ` separator is needed
+    pub needs_separator: bool,
+    /// These are the props after the last spread
+    pub trailing: bool,
+}
+
+/// Generate `_mergeProps(...)` for props containing v-bind/v-on object spreads.
+///
+/// Arguments follow source order so later props override earlier ones:
+/// `v-bind="a" :id="x" v-bind="b"` -> `_mergeProps(a, { id: x }, b)`.
+/// The props between spreads are passed to `object`, which writes them as an
+/// object argument and returns whether it wrote anything.
+pub(crate) fn generate_merge_props<'p, 'a>(
+    ctx: &mut CodegenContext,
+    props: &'p [PropNode<'a>],
+    mut object: impl FnMut(&mut CodegenContext, &'p [PropNode<'a>], MergeSegment) -> bool,
+) {
+    ctx.use_helper(RuntimeHelper::MergeProps);
+    ctx.push(ctx.helper(RuntimeHelper::MergeProps));
+    ctx.push("(");
+
+    let mut first_merge_arg = true;
+    let mut segment_start = 0;
+
+    for (i, prop) in props.iter().enumerate() {
+        if !is_object_spread(prop) {
+            continue;
+        }
+
+        // Flush the props written before this spread as their own object
+        let position = MergeSegment {
+            needs_separator: !first_merge_arg,
+            trailing: false,
+        };
+        if object(ctx, &props[segment_start..i], position) {
+            first_merge_arg = false;
+        }
+        segment_start = i + 1;
+
+        if !first_merge_arg {
+            ctx.push(", ");
+        }
+        let spread = std::slice::from_ref(prop);
+        if has_vbind_object(spread) {
+            generate_vbind_object_exp(ctx, spread);
+        } else {
+            // v-on object spread (wrapped with toHandlers)
+            generate_von_object_exp(ctx, spread);
+        }
+        first_merge_arg = false;
+    }
+
+    let position = MergeSegment {
+        needs_separator: !first_merge_arg,
+        trailing: true,
+    };
+    object(ctx, &props[segment_start..], position);
+    ctx.push(")");
+}
+
 /// Generate the props object with optional class/style normalization skipping.
 /// `inside_merge_props`: when true, skip normalizeClass/normalizeStyle wrappers
 /// because mergeProps handles normalization internally.
@@ -191,7 +241,8 @@ fn generate_props_object_inner(
     let static_class = props.iter().find_map(|p| {
         if let PropNode::Attribute(attr) = p {
             if attr.name == "class" {
-                return attr.value.as_ref().map(|v| v.content.as_str());
+                // A valueless attribute still merges (as "") so the key isn't emitted twice
+                return Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             }
         }
         None
@@ -200,7 +251,8 @@ fn generate_props_object_inner(
     let static_style = props.iter().find_map(|p| {
         if let PropNode::Attribute(attr) = p {
             if attr.name == "style" {
-                return attr.value.as_ref().map(|v| v.content.as_str());
+                // A valueless attribute still merges (as "") so the key isn't emitted twice
+                return Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             }
         }
         None
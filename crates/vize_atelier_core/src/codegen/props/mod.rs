@@ -9,6 +9,7 @@ use crate::ast::{ExpressionNode, PropNode, RuntimeHelper};
 use super::{context::CodegenContext, expression::generate_expression};
 
 pub use directives::{generate_directive_prop_with_static, is_supported_directive};
+pub(crate) use generate::generate_merge_props;
 pub use generate::generate_props;
 
 /// Check if there's a v-bind without argument (object spread)
@@ -31,6 +32,16 @@ pub(crate) fn has_von_object(props: &[PropNode<'_>]) -> bool {
     })
 }
 
+/// Check if a prop is a v-bind/v-on object spread (`v-bind="obj"`, `v-on="obj"`)
+fn is_object_spread(prop: &PropNode<'_>) -> bool {
+    match prop {
+        PropNode::Directive(dir) => {
+            dir.arg.is_none() && dir.exp.is_some() && (dir.name == "bind" || dir.name == "on")
+        }
+        PropNode::Attribute(_) => false,
+    }
+}
+
 /// Check if there are other props besides v-bind/v-on object spreads
 fn has_other_props(props: &[PropNode<'_>]) -> bool {
     props.iter().any(|p| match p {
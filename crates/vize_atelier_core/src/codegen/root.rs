@@ -90,6 +90,12 @@ pub(super) fn generate_function_signature(ctx: &mut CodegenContext) {
 }
 
 /// Generate asset resolution (components, directives).
+///
+/// Assets are resolved once at the top of the render function and are never
+/// hoisted to module scope, even with `hoist_static` in Module mode:
+/// `resolveComponent`/`resolveDirective` look up the current rendering
+/// instance (local registrations, then its app's global ones), so outside of
+/// render they only warn and return the raw name.
 pub(super) fn generate_assets(ctx: &mut CodegenContext, root: &RootNode<'_>) {
     let mut has_resolved_assets = false;
 
@@ -673,7 +673,8 @@ fn generate_slot_children(ctx: &mut CodegenContext, children: &[TemplateChildNod
         }
 
         if has_interpolation {
-            ctx.push(", 1 /* TEXT */)");
+            ctx.push_patch_flag(", 1 /* TEXT */");
+            ctx.push(")");
         } else {
             ctx.push(")");
         }
@@ -707,7 +708,9 @@ fn generate_slot_child_node(ctx: &mut CodegenContext, child: &TemplateChildNode<
             ctx.push("(");
             // Generate expression, stripping _ctx. prefix for slot params
             generate_slot_expression(ctx, &interp.content);
-            ctx.push("), 1 /* TEXT */)");
+            ctx.push(")");
+            ctx.push_patch_flag(", 1 /* TEXT */");
+            ctx.push(")");
         }
         _ => {
             generate_node(ctx, child);
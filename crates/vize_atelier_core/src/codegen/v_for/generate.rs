@@ -103,16 +103,13 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                     .iter()
                     .any(|c| matches!(c, TemplateChildNode::Interpolation(_)));
                 if has_interpolation {
-                    ctx.push(", 1 /* TEXT */");
+                    ctx.push_patch_flag(", 1 /* TEXT */");
                 }
 
                 ctx.push(")");
             } else {
                 // Dynamic list: wrap in block
-                ctx.use_helper(RuntimeHelper::OpenBlock);
-                ctx.push("(");
-                ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-                ctx.push("(), ");
+                ctx.push_open_block(false);
 
                 // Template with single child element optimization:
                 // unwrap the template and generate the child directly as a block
@@ -134,8 +131,7 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
 
                 if is_component {
                     // Component: use createBlock
-                    ctx.use_helper(RuntimeHelper::CreateBlock);
-                    ctx.push(ctx.helper(RuntimeHelper::CreateBlock));
+                    ctx.push_block_helper(RuntimeHelper::CreateBlock);
                     ctx.push("(");
                     // Handle dynamic component
                     if is_dynamic_component {
@@ -188,22 +184,19 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                     }
                 } else if gen_is_template {
                     // Template with multiple children: use Fragment
-                    ctx.use_helper(RuntimeHelper::CreateElementBlock);
                     ctx.use_helper(RuntimeHelper::Fragment);
-                    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+                    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
                     ctx.push("(");
                     ctx.push(ctx.helper(RuntimeHelper::Fragment));
                 } else if let Some(child_el) = unwrapped_child {
                     // Template with single child: unwrap to child element
-                    ctx.use_helper(RuntimeHelper::CreateElementBlock);
-                    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+                    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
                     ctx.push("(\"");
                     ctx.push(&child_el.tag);
                     ctx.push("\"");
                 } else {
                     // Regular element
-                    ctx.use_helper(RuntimeHelper::CreateElementBlock);
-                    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+                    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
                     ctx.push("(\"");
                     ctx.push(&el.tag);
                     ctx.push("\"");
@@ -248,17 +241,9 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                 // Add patch flag
                 if is_component {
                     let (mut patch_flag, dynamic_props) = if is_dynamic_component {
-                        calculate_element_patch_info_skip_is(
-                            el,
-                            ctx.options.binding_metadata.as_ref(),
-                            ctx.cache_handlers_in_current_scope(),
-                        )
+                        calculate_element_patch_info_skip_is(ctx, el)
                     } else {
-                        calculate_element_patch_info(
-                            el,
-                            ctx.options.binding_metadata.as_ref(),
-                            ctx.cache_handlers_in_current_scope(),
-                        )
+                        calculate_element_patch_info(ctx, el)
                     };
                     // Remove TEXT flag for components with slot children (text is inside slot)
                     if has_slot_children(el) {
@@ -268,7 +253,7 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                         }
                     }
                     // Inside v-for, component slots are always dynamic
-                    if ctx.in_v_for && has_slot_children(el) {
+                    if ctx.blocks_enabled() && ctx.in_v_for && has_slot_children(el) {
                         let dynamic_slots_flag = 1024;
                         patch_flag = Some(patch_flag.unwrap_or(0) | dynamic_slots_flag);
                     }
@@ -295,15 +280,11 @@ pub fn generate_for_item(ctx: &mut CodegenContext, node: &TemplateChildNode<'_>,
                         ctx.push("]");
                     }
                 } else if gen_is_template {
-                    ctx.push(", 64 /* STABLE_FRAGMENT */");
+                    ctx.push_patch_flag(", 64 /* STABLE_FRAGMENT */");
                 } else if !ctx.skip_v_memo {
                     // Skip patch flags for v-memo elements (memo handles reactivity)
                     let flag_el = unwrapped_child.unwrap_or(el);
-                    let (patch_flag, dynamic_props) = calculate_element_patch_info(
-                        flag_el,
-                        ctx.options.binding_metadata.as_ref(),
-                        ctx.cache_handlers_in_current_scope(),
-                    );
+                    let (patch_flag, dynamic_props) = calculate_element_patch_info(ctx, flag_el);
                     if let Some(flag) = patch_flag {
                         ctx.push(", ");
                         ctx.push(&flag.to_compact_string());
@@ -408,15 +389,7 @@ pub(crate) fn generate_for_item_props(
     let has_von_spread = super::super::props::has_von_object(&el.props);
 
     if has_vbind_spread || has_von_spread {
-        generate_for_item_props_merged(
-            ctx,
-            el,
-            key_exp,
-            &scope_id,
-            has_vbind_spread,
-            has_von_spread,
-            skip_is_prop,
-        );
+        generate_for_item_props_merged(ctx, el, key_exp, &scope_id, skip_is_prop);
         return;
     }
 
@@ -424,7 +397,8 @@ pub(crate) fn generate_for_item_props(
     let static_class = el.props.iter().find_map(|p| {
         if let PropNode::Attribute(attr) = p {
             if attr.name == "class" {
-                return attr.value.as_ref().map(|v| v.content.as_str());
+                // A valueless attribute still merges (as "") so the key isn't emitted twice
+                return Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             }
         }
         None
@@ -433,7 +407,8 @@ pub(crate) fn generate_for_item_props(
     let static_style = el.props.iter().find_map(|p| {
         if let PropNode::Attribute(attr) = p {
             if attr.name == "style" {
-                return attr.value.as_ref().map(|v| v.content.as_str());
+                // A valueless attribute still merges (as "") so the key isn't emitted twice
+                return Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             }
         }
         None
@@ -561,50 +536,41 @@ pub(crate) fn generate_for_item_props(
 }
 
 /// Generate props using _mergeProps when v-bind/v-on object spreads are present.
+///
+/// Spreads keep their source position; the key and scope id go into the
+/// object for the props after the last spread.
 fn generate_for_item_props_merged(
     ctx: &mut CodegenContext,
     el: &ElementNode<'_>,
     key_exp: Option<&ExpressionNode<'_>>,
     scope_id: &Option<vize_carton::String>,
-    has_vbind_spread: bool,
-    has_von_spread: bool,
     skip_is_prop: bool,
 ) {
-    ctx.use_helper(RuntimeHelper::MergeProps);
-    ctx.push(ctx.helper(RuntimeHelper::MergeProps));
-    ctx.push("(");
-
-    let mut first_merge_arg = true;
-
-    if has_vbind_spread {
-        super::super::props::generate_vbind_object_exp(ctx, &el.props);
-        first_merge_arg = false;
-    }
-
-    if has_von_spread {
-        if !first_merge_arg {
-            ctx.push(", ");
+    let is_remaining_prop = |p: &PropNode<'_>| {
+        if should_skip_prop(p) || (skip_is_prop && is_is_prop(p)) {
+            return false;
         }
-        super::super::props::generate_von_object_exp(ctx, &el.props);
-        first_merge_arg = false;
-    }
-
-    let has_remaining = key_exp.is_some()
-        || scope_id.is_some()
-        || el.props.iter().any(|p| {
-            if should_skip_prop(p) || (skip_is_prop && is_is_prop(p)) {
+        if let PropNode::Directive(dir) = p {
+            if dir.arg.is_none() && (dir.name == "bind" || dir.name == "on") {
                 return false;
             }
-            if let PropNode::Directive(dir) = p {
-                if dir.arg.is_none() && (dir.name == "bind" || dir.name == "on") {
-                    return false;
-                }
-            }
-            true
-        });
+        }
+        true
+    };
 
-    if has_remaining {
-        if !first_merge_arg {
+    super::super::props::generate_merge_props(ctx, &el.props, |ctx, props, position| {
+        let (key_exp, scope_id) = if position.trailing {
+            (key_exp, scope_id.as_ref())
+        } else {
+            (None, None)
+        };
+        let has_remaining =
+            key_exp.is_some() || scope_id.is_some() || props.iter().any(is_remaining_prop);
+        if !has_remaining {
+            return false;
+        }
+
+        if position.needs_separator {
             ctx.push(", ");
         }
         ctx.push("{");
@@ -618,15 +584,7 @@ fn generate_for_item_props_merged(
             first_prop = false;
         }
 
-        for prop in el.props.iter() {
-            if should_skip_prop(prop) || (skip_is_prop && is_is_prop(prop)) {
-                continue;
-            }
-            if let PropNode::Directive(dir) = prop {
-                if dir.arg.is_none() && (dir.name == "bind" || dir.name == "on") {
-                    continue;
-                }
-            }
+        for prop in props.iter().filter(|p| is_remaining_prop(p)) {
             if !first_prop {
                 ctx.push(",");
             }
@@ -635,7 +593,7 @@ fn generate_for_item_props_merged(
             first_prop = false;
         }
 
-        if let Some(ref sid) = scope_id {
+        if let Some(sid) = scope_id {
             if !first_prop {
                 ctx.push(",");
             }
@@ -648,9 +606,8 @@ fn generate_for_item_props_merged(
         ctx.deindent();
         ctx.newline();
         ctx.push("}");
-    }
-
-    ctx.push(")");
+        true
+    });
 }
 
 /// Generate a single prop (attribute or directive)
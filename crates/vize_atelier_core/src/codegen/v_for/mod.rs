@@ -29,8 +29,6 @@ pub fn generate_for(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
 }
 
 fn generate_for_inner(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
-    ctx.use_helper(RuntimeHelper::OpenBlock);
-    ctx.use_helper(RuntimeHelper::CreateElementBlock);
     ctx.use_helper(RuntimeHelper::Fragment);
     ctx.use_helper(RuntimeHelper::RenderList);
 
@@ -55,14 +53,8 @@ fn generate_for_inner(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
         256 // UNKEYED_FRAGMENT
     };
 
-    ctx.push("(");
-    ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-    if is_stable {
-        ctx.push("(), ");
-    } else {
-        ctx.push("(true), ");
-    }
-    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+    ctx.push_open_block(!is_stable);
+    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
     ctx.push("(");
     ctx.push(ctx.helper(RuntimeHelper::Fragment));
     ctx.push(", null, ");
@@ -182,19 +174,11 @@ fn generate_for_inner(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
 
         // Close callback, add _cache and index args
         let cache_index = ctx.next_cache_index();
-        let flag_name = match fragment_flag {
-            64 => "STABLE_FRAGMENT",
-            128 => "KEYED_FRAGMENT",
-            256 => "UNKEYED_FRAGMENT",
-            _ => "FRAGMENT",
-        };
         ctx.push("}, _cache, ");
         ctx.push(&cache_index.to_compact_string());
-        ctx.push("), ");
-        ctx.push(&fragment_flag.to_compact_string());
-        ctx.push(" /* ");
-        ctx.push(flag_name);
-        ctx.push(" */))");
+        ctx.push(")");
+        push_fragment_flag(ctx, fragment_flag);
+        ctx.push("))");
     } else {
         // Standard v-for (no v-memo)
         ctx.push(") => {");
@@ -222,19 +206,27 @@ fn generate_for_inner(ctx: &mut CodegenContext, for_node: &ForNode<'_>) {
         ctx.deindent();
         ctx.newline();
         // Close with fragment flag
-        let flag_name = match fragment_flag {
-            64 => "STABLE_FRAGMENT",
-            128 => "KEYED_FRAGMENT",
-            256 => "UNKEYED_FRAGMENT",
-            _ => "FRAGMENT",
-        };
-        ctx.push("}), ");
-        ctx.push(&fragment_flag.to_compact_string());
-        ctx.push(" /* ");
-        ctx.push(flag_name);
-        ctx.push(" */))");
+        ctx.push("})");
+        push_fragment_flag(ctx, fragment_flag);
+        ctx.push("))");
     }
 }
 
+/// Push the `, <flag> /* NAME */` fragment patch flag argument.
+fn push_fragment_flag(ctx: &mut CodegenContext, fragment_flag: i32) {
+    let flag_name = match fragment_flag {
+        64 => "STABLE_FRAGMENT",
+        128 => "KEYED_FRAGMENT",
+        256 => "UNKEYED_FRAGMENT",
+        _ => "FRAGMENT",
+    };
+    let mut flag = String::from(", ");
+    flag.push_str(&fragment_flag.to_compact_string());
+    flag.push_str(" /* ");
+    flag.push_str(flag_name);
+    flag.push_str(" */");
+    ctx.push_patch_flag(&flag);
+}
+
 // Note: Directive skipping behavior (v-for with custom directives, :key handling)
 // is tested via SFC snapshot tests in tests/fixtures/sfc/patches.toml.
@@ -26,10 +26,7 @@ use super::{
         },
         slots::{generate_slots, has_dynamic_slots_flag, has_slot_children},
     },
-    generate::{
-        extract_static_class_style, generate_if_branch_props_object, has_dynamic_class,
-        has_dynamic_style, has_vbind_spread, has_von_spread,
-    },
+    generate::generate_if_branch_props,
     generate_if_branch_key,
 };
 
@@ -159,11 +156,8 @@ fn generate_if_branch_component(
     // Components: skip scope_id in props -- Vue runtime applies it via __scopeId
     let prev_skip_scope_id = ctx.skip_scope_id;
     ctx.skip_scope_id = true;
-    ctx.use_helper(RuntimeHelper::CreateBlock);
-    ctx.push("(");
-    ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-    ctx.push("(), ");
-    ctx.push(ctx.helper(RuntimeHelper::CreateBlock));
+    ctx.push_open_block(false);
+    ctx.push_block_helper(RuntimeHelper::CreateBlock);
     ctx.push("(");
     // Generate component name
     // Handle dynamic component (<component :is="..."> / <Component :is="...">)
@@ -219,17 +213,9 @@ fn generate_if_branch_component(
     }
 
     let (mut patch_flag, dynamic_props) = if is_dynamic_component {
-        calculate_element_patch_info_skip_is(
-            el,
-            ctx.options.binding_metadata.as_ref(),
-            ctx.cache_handlers_in_current_scope(),
-        )
+        calculate_element_patch_info_skip_is(ctx, el)
     } else {
-        calculate_element_patch_info(
-            el,
-            ctx.options.binding_metadata.as_ref(),
-            ctx.cache_handlers_in_current_scope(),
-        )
+        calculate_element_patch_info(ctx, el)
     };
 
     if has_slot_children(el) {
@@ -239,88 +225,17 @@ fn generate_if_branch_component(
         }
     }
 
-    if el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el) {
+    if ctx.blocks_enabled()
+        && (el.tag == "KeepAlive" || el.tag == "keep-alive" || has_dynamic_slots_flag(el))
+    {
         patch_flag = Some(patch_flag.unwrap_or(0) | 1024);
     }
 
     let has_patch_info = patch_flag.is_some() || dynamic_props.is_some();
 
-    // Extract static class/style for merging with dynamic bindings
-    let (static_class, static_style) = extract_static_class_style(el);
-    let has_dyn_class = has_dynamic_class(el);
-    let has_dyn_style = has_dynamic_style(el);
-
-    // Check if component has v-bind spread or v-on spread
-    let has_vbind = has_vbind_spread(el);
-    let has_von = has_von_spread(el);
-    if has_vbind || has_von {
-        ctx.use_helper(RuntimeHelper::MergeProps);
-        ctx.push(", ");
-        ctx.push(ctx.helper(RuntimeHelper::MergeProps));
-        ctx.push("(");
-
-        let mut first_merge_arg = true;
-        // Add v-bind spreads
-        for prop in el.props.iter() {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name == "bind" && dir.arg.is_none() {
-                    if let Some(exp) = &dir.exp {
-                        if !first_merge_arg {
-                            ctx.push(", ");
-                        }
-                        generate_expression(ctx, exp);
-                        first_merge_arg = false;
-                    }
-                }
-            }
-        }
-
-        // Add v-on spreads wrapped with _toHandlers
-        for prop in el.props.iter() {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name == "on" && dir.arg.is_none() {
-                    if let Some(exp) = &dir.exp {
-                        if !first_merge_arg {
-                            ctx.push(", ");
-                        }
-                        ctx.use_helper(RuntimeHelper::ToHandlers);
-                        ctx.push(ctx.helper(RuntimeHelper::ToHandlers));
-                        ctx.push("(");
-                        generate_expression(ctx, exp);
-                        ctx.push(", true)");
-                        first_merge_arg = false;
-                    }
-                }
-            }
-        }
-
-        if !first_merge_arg {
-            ctx.push(", ");
-        }
-        generate_if_branch_props_object(
-            ctx,
-            el,
-            branch,
-            branch_index,
-            static_class,
-            static_style,
-            has_dyn_class,
-            has_dyn_style,
-        );
-        ctx.push(")");
-    } else {
-        ctx.push(", ");
-        generate_if_branch_props_object(
-            ctx,
-            el,
-            branch,
-            branch_index,
-            static_class,
-            static_style,
-            has_dyn_class,
-            has_dyn_style,
-        );
-    }
+    // Generate props with key and all other props (handle v-bind/v-on spreads)
+    ctx.push(", ");
+    generate_if_branch_props(ctx, el, branch, branch_index);
 
     ctx.skip_scope_id = prev_skip_scope_id;
 
@@ -407,91 +322,15 @@ fn generate_if_branch_element(
         ctx.push("(");
     }
 
-    ctx.use_helper(RuntimeHelper::CreateElementBlock);
-    ctx.push("(");
-    ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-    ctx.push("(), ");
-    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+    ctx.push_open_block(false);
+    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
     ctx.push("(\"");
     ctx.push(el.tag.as_str());
     ctx.push("\"");
 
-    // Extract static class/style for merging with dynamic bindings
-    let (static_class, static_style) = extract_static_class_style(el);
-    let has_dyn_class = has_dynamic_class(el);
-    let has_dyn_style = has_dynamic_style(el);
-
     // Generate props with key and all other props (handle v-bind/v-on spreads)
-    let has_vbind = has_vbind_spread(el);
-    let has_von = has_von_spread(el);
-    if has_vbind || has_von {
-        ctx.use_helper(RuntimeHelper::MergeProps);
-        ctx.push(", ");
-        ctx.push(ctx.helper(RuntimeHelper::MergeProps));
-        ctx.push("(");
-
-        // Add all v-bind spreads
-        let mut first_merge_arg = true;
-        for prop in el.props.iter() {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name == "bind" && dir.arg.is_none() {
-                    if let Some(exp) = &dir.exp {
-                        if !first_merge_arg {
-                            ctx.push(", ");
-                        }
-                        generate_expression(ctx, exp);
-                        first_merge_arg = false;
-                    }
-                }
-            }
-        }
-
-        // Add all v-on spreads wrapped with _toHandlers
-        for prop in el.props.iter() {
-            if let PropNode::Directive(dir) = prop {
-                if dir.name == "on" && dir.arg.is_none() {
-                    if let Some(exp) = &dir.exp {
-                        if !first_merge_arg {
-                            ctx.push(", ");
-                        }
-                        ctx.use_helper(RuntimeHelper::ToHandlers);
-                        ctx.push(ctx.helper(RuntimeHelper::ToHandlers));
-                        ctx.push("(");
-                        generate_expression(ctx, exp);
-                        ctx.push(", true)");
-                        first_merge_arg = false;
-                    }
-                }
-            }
-        }
-
-        if !first_merge_arg {
-            ctx.push(", ");
-        }
-        generate_if_branch_props_object(
-            ctx,
-            el,
-            branch,
-            branch_index,
-            static_class,
-            static_style,
-            has_dyn_class,
-            has_dyn_style,
-        );
-        ctx.push(")");
-    } else {
-        ctx.push(", ");
-        generate_if_branch_props_object(
-            ctx,
-            el,
-            branch,
-            branch_index,
-            static_class,
-            static_style,
-            has_dyn_class,
-            has_dyn_style,
-        );
-    }
+    ctx.push(", ");
+    generate_if_branch_props(ctx, el, branch, branch_index);
 
     // Generate children if any
     if !el.children.is_empty() {
@@ -529,19 +368,17 @@ fn generate_if_branch_template_fragment(
     branch: &IfBranchNode<'_>,
     branch_index: usize,
 ) {
-    ctx.use_helper(RuntimeHelper::CreateElementBlock);
     ctx.use_helper(RuntimeHelper::Fragment);
-    ctx.push("(");
-    ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-    ctx.push("(), ");
-    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+    ctx.push_open_block(false);
+    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
     ctx.push("(");
     ctx.push(ctx.helper(RuntimeHelper::Fragment));
     ctx.push(", { key: ");
     generate_if_branch_key(ctx, branch, branch_index);
     ctx.push(" }, ");
     generate_children_force_array(ctx, children);
-    ctx.push(", 64 /* STABLE_FRAGMENT */))");
+    ctx.push_patch_flag(", 64 /* STABLE_FRAGMENT */");
+    ctx.push("))");
 }
 
 /// Generate fragment wrapper for if branch with multiple children.
@@ -550,19 +387,17 @@ fn generate_if_branch_fragment(
     branch: &IfBranchNode<'_>,
     branch_index: usize,
 ) {
-    ctx.use_helper(RuntimeHelper::CreateElementBlock);
     ctx.use_helper(RuntimeHelper::Fragment);
-    ctx.push("(");
-    ctx.push(ctx.helper(RuntimeHelper::OpenBlock));
-    ctx.push("(), ");
-    ctx.push(ctx.helper(RuntimeHelper::CreateElementBlock));
+    ctx.push_open_block(false);
+    ctx.push_block_helper(RuntimeHelper::CreateElementBlock);
     ctx.push("(");
     ctx.push(ctx.helper(RuntimeHelper::Fragment));
     ctx.push(", { key: ");
     generate_if_branch_key(ctx, branch, branch_index);
     ctx.push(" }, ");
     generate_children_force_array(ctx, &branch.children);
-    ctx.push(", 64 /* STABLE_FRAGMENT */))");
+    ctx.push_patch_flag(", 64 /* STABLE_FRAGMENT */");
+    ctx.push("))");
 }
 
 /// Generate children for if branch element.
@@ -607,7 +442,7 @@ fn generate_if_branch_children(ctx: &mut CodegenContext, children: &[TemplateChi
         }
 
         if has_interpolation {
-            ctx.push(", 1 /* TEXT */");
+            ctx.push_patch_flag(", 1 /* TEXT */");
         }
     } else {
         // Complex branch children need the same text/interpolation grouping as
@@ -8,7 +8,7 @@ use crate::ast::{DirectiveNode, ElementNode, ExpressionNode, IfBranchNode, PropN
 use super::super::{
     context::CodegenContext,
     helpers::{camelize, capitalize_first, escape_js_string, is_valid_js_identifier},
-    props::{generate_directive_prop_with_static, is_supported_directive},
+    props::{generate_directive_prop_with_static, generate_merge_props, is_supported_directive},
 };
 use super::generate_if_branch_key;
 use vize_carton::FxHashSet;
@@ -51,30 +51,24 @@ pub(super) fn should_skip_prop_for_if(
 }
 
 /// Extract static class and style values from element props.
-pub(super) fn extract_static_class_style<'a>(
-    el: &'a ElementNode<'_>,
-) -> (Option<&'a str>, Option<&'a str>) {
+fn extract_static_class_style<'a>(props: &'a [PropNode<'_>]) -> (Option<&'a str>, Option<&'a str>) {
     let mut static_class = None;
     let mut static_style = None;
-    for prop in el.props.iter() {
+    for prop in props.iter() {
         if let PropNode::Attribute(attr) = prop {
             if attr.name == "class" {
-                if let Some(val) = &attr.value {
-                    static_class = Some(val.content.as_str());
-                }
+                static_class = Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             } else if attr.name == "style" {
-                if let Some(val) = &attr.value {
-                    static_style = Some(val.content.as_str());
-                }
+                static_style = Some(attr.value.as_ref().map_or("", |v| v.content.as_str()));
             }
         }
     }
     (static_class, static_style)
 }
 
-/// Check if element has dynamic `:class` binding.
-pub(super) fn has_dynamic_class(el: &ElementNode<'_>) -> bool {
-    el.props.iter().any(|p| {
+/// Check if props have a dynamic `:class` binding.
+fn has_dynamic_class(props: &[PropNode<'_>]) -> bool {
+    props.iter().any(|p| {
         if let PropNode::Directive(dir) = p {
             if dir.name == "bind" {
                 if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
@@ -86,9 +80,9 @@ pub(super) fn has_dynamic_class(el: &ElementNode<'_>) -> bool {
     })
 }
 
-/// Check if element has dynamic `:style` binding.
-pub(super) fn has_dynamic_style(el: &ElementNode<'_>) -> bool {
-    el.props.iter().any(|p| {
+/// Check if props have a dynamic `:style` binding.
+fn has_dynamic_style(props: &[PropNode<'_>]) -> bool {
+    props.iter().any(|p| {
         if let PropNode::Directive(dir) = p {
             if dir.name == "bind" {
                 if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
@@ -132,20 +126,42 @@ pub(super) fn generate_single_prop_for_if(
     }
 }
 
-/// Generate props object for v-if branch (with key and other props).
-#[allow(clippy::too_many_arguments)]
-pub(super) fn generate_if_branch_props_object(
+/// Generate the props of a v-if branch element: the `{ key, ... }` object, or
+/// `_mergeProps(...)` when the element has v-bind/v-on object spreads. Spreads
+/// keep their source position and the key goes into the trailing object.
+pub(super) fn generate_if_branch_props(
     ctx: &mut CodegenContext,
     el: &ElementNode<'_>,
     branch: &IfBranchNode<'_>,
     branch_index: usize,
-    static_class: Option<&str>,
-    static_style: Option<&str>,
-    has_dynamic_class: bool,
-    has_dynamic_style: bool,
 ) {
+    if has_vbind_spread(el) || has_von_spread(el) {
+        generate_merge_props(ctx, &el.props, |ctx, props, position| {
+            let key = position.trailing.then_some((branch, branch_index));
+            generate_if_branch_props_object(ctx, props, key, position.needs_separator)
+        });
+    } else {
+        generate_if_branch_props_object(ctx, &el.props, Some((branch, branch_index)), false);
+    }
+}
+
+/// Generate props object for v-if branch (with key and other props).
+///
+/// Objects without a key (props before a spread) also leave out the scope id
+/// and are skipped when empty. Returns whether an object was written.
+fn generate_if_branch_props_object(
+    ctx: &mut CodegenContext,
+    props: &[PropNode<'_>],
+    key: Option<(&IfBranchNode<'_>, usize)>,
+    needs_separator: bool,
+) -> bool {
+    // Extract static class/style for merging with dynamic bindings
+    let (static_class, static_style) = extract_static_class_style(props);
+    let has_dynamic_class = has_dynamic_class(props);
+    let has_dynamic_style = has_dynamic_style(props);
+
     // Check if there are other props besides key (skip excluded ones)
-    let has_other_props = el.props.iter().any(|p| {
+    let has_other_props = props.iter().any(|p| {
         // Skip unsupported directives (v-slot, v-tooltip, custom directives, etc.)
         if let PropNode::Directive(dir) = p {
             if !is_supported_directive(dir) {
@@ -157,31 +173,44 @@ pub(super) fn generate_if_branch_props_object(
             && !is_von_spread_prop(p)
     });
     // For component elements, skip_scope_id suppresses the attribute.
-    let scope_id = if ctx.skip_scope_id {
+    let scope_id = if ctx.skip_scope_id || key.is_none() {
         None
     } else {
         ctx.options.scope_id.clone()
     };
     let has_scope = scope_id.is_some();
 
+    if !has_other_props && key.is_none() {
+        return false;
+    }
+    if needs_separator {
+        ctx.push(", ");
+    }
+
     if !has_other_props && !has_scope {
-        // Key-only: use inline format { key: N }
-        ctx.push("{ key: ");
-        generate_if_branch_key(ctx, branch, branch_index);
-        ctx.push(" }");
-        return;
+        if let Some((branch, branch_index)) = key {
+            // Key-only: use inline format { key: N }
+            ctx.push("{ key: ");
+            generate_if_branch_key(ctx, branch, branch_index);
+            ctx.push(" }");
+            return true;
+        }
     }
 
     // Multiline format for key + other props
     ctx.push("{");
     ctx.indent();
-    ctx.newline();
-    ctx.push("key: ");
-    generate_if_branch_key(ctx, branch, branch_index);
+    let mut first_prop = true;
+    if let Some((branch, branch_index)) = key {
+        ctx.newline();
+        ctx.push("key: ");
+        generate_if_branch_key(ctx, branch, branch_index);
+        first_prop = false;
+    }
 
     let mut seen_events: FxHashSet<String> = FxHashSet::default();
 
-    for prop in el.props.iter() {
+    for prop in props.iter() {
         // Skip unsupported directives (v-slot, v-tooltip, custom directives, etc.)
         if let PropNode::Directive(dir) = prop {
             if !is_supported_directive(dir) {
@@ -206,9 +235,12 @@ pub(super) fn generate_if_branch_props_object(
                 }
             }
         }
-        ctx.push(",");
+        if !first_prop {
+            ctx.push(",");
+        }
         ctx.newline();
         generate_single_prop_for_if(ctx, prop, static_class, static_style);
+        first_prop = false;
     }
 
     // Add scope_id for scoped CSS
@@ -223,10 +255,11 @@ pub(super) fn generate_if_branch_props_object(
     ctx.deindent();
     ctx.newline();
     ctx.push("}");
+    true
 }
 
 /// Check if element has v-bind object spread.
-pub(super) fn has_vbind_spread(el: &ElementNode<'_>) -> bool {
+fn has_vbind_spread(el: &ElementNode<'_>) -> bool {
     el.props.iter().any(|p| is_vbind_spread_prop(p))
 }
 
@@ -239,7 +272,7 @@ pub(super) fn is_vbind_spread_prop(prop: &PropNode<'_>) -> bool {
 }
 
 /// Check if element has v-on object spread (`v-on="obj"`).
-pub(super) fn has_von_spread(el: &ElementNode<'_>) -> bool {
+fn has_von_spread(el: &ElementNode<'_>) -> bool {
     el.props.iter().any(|p| is_von_spread_prop(p))
 }
 
@@ -16,8 +16,6 @@ use vize_carton::ToCompactString;
 
 /// Generate if node.
 pub fn generate_if(ctx: &mut CodegenContext, if_node: &IfNode<'_>) {
-    ctx.use_helper(RuntimeHelper::OpenBlock);
-
     // Vue always imports createCommentVNode for v-if nodes
     ctx.use_helper(RuntimeHelper::CreateComment);
 
@@ -274,6 +274,7 @@ impl<'a> TransformContext<'a> {
                     .iter()
                     .map(|name| CompactString::new(name.as_str()))
                     .collect(),
+                component: None,
             },
             start,
             end,
@@ -120,6 +120,11 @@ fn process_element_props<'a>(ctx: &mut TransformContext<'a>, el: &mut Box<'a, El
     let allocator = ctx.allocator;
     let is_component = el.tag_type == ElementType::Component;
 
+    // v-cloak is a styling hook for in-DOM templates with no effect on compiled
+    // output. Drop it so the element is not treated as dynamic.
+    el.props
+        .retain(|prop| !matches!(prop, PropNode::Directive(dir) if dir.name == "cloak"));
+
     // Process directive expressions with _ctx prefix if needed
     if ctx.options.prefix_identifiers || ctx.options.is_ts {
         process_directive_expressions(ctx, el);
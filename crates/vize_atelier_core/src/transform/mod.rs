@@ -122,12 +122,14 @@ impl<'a> ParentNode<'a> {
 }
 
 /// Transform the root AST node
+///
+/// Returns the errors reported while transforming.
 pub fn transform<'a>(
     allocator: &'a Bump,
     root: &mut RootNode<'a>,
     options: TransformOptions,
     analysis: Option<&'a Croquis>,
-) {
+) -> std::vec::Vec<CompilerError> {
     let source = root.source.clone();
     let mut ctx = if let Some(analysis) = analysis {
         TransformContext::with_analysis(allocator, source, options, analysis)
@@ -155,8 +157,11 @@ pub fn transform<'a>(
         create_root_codegen(&mut ctx, root)
     );
 
-    // Update root with context results
-    for helper in ctx.helpers.into_iter() {
+    // Update root with context results.
+    // Helpers are collected in a hash set, so sort them to keep output independent of hash order.
+    let mut helpers: std::vec::Vec<RuntimeHelper> = ctx.helpers.into_iter().collect();
+    helpers.sort();
+    for helper in helpers {
         root.helpers.push(helper);
     }
     for component in ctx.components.into_iter() {
@@ -171,6 +176,8 @@ pub fn transform<'a>(
     }
     root.temps = ctx.temps;
     root.transformed = true;
+
+    ctx.errors
 }
 
 /// Create codegen node for root
@@ -314,6 +321,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_v_else_branch_validation() {
+        use crate::ErrorCode::VElseNoAdjacentIf;
+
+        fn transform_errors(source: &str) -> std::vec::Vec<crate::ErrorCode> {
+            let allocator = Bump::new();
+            let (mut root, errors) = parse(&allocator, source);
+            assert!(errors.is_empty(), "Parse errors: {:?}", errors);
+            transform(&allocator, &mut root, TransformOptions::default(), None)
+                .into_iter()
+                .map(|e| e.code)
+                .collect()
+        }
+
+        // Whitespace and comments between branches are fine
+        assert!(transform_errors(
+            "<div v-if=\"a\">a</div>\n  <!-- note -->\n  <div v-else-if=\"b\">b</div> <div v-else>c</div>"
+        )
+        .is_empty());
+
+        // No preceding v-if
+        assert_eq!(transform_errors("<div v-else>c</div>"), [VElseNoAdjacentIf]);
+        // Text or another element breaks the chain
+        assert_eq!(
+            transform_errors("<div v-if=\"a\">a</div>text<div v-else>c</div>"),
+            [VElseNoAdjacentIf]
+        );
+        assert_eq!(
+            transform_errors("<div v-if=\"a\">a</div><span /><div v-else-if=\"b\">b</div>"),
+            [VElseNoAdjacentIf]
+        );
+        // Nothing may follow a v-else
+        assert_eq!(
+            transform_errors("<div v-if=\"a\">a</div><div v-else>b</div><div v-else>c</div>"),
+            [VElseNoAdjacentIf]
+        );
+    }
+
+    #[test]
+    fn test_v_else_error_points_at_element() {
+        let allocator = Bump::new();
+        let source = "<p>x</p><div v-else>c</div>";
+        let (mut root, _) = parse(&allocator, source);
+        let errors = transform(&allocator, &mut root, TransformOptions::default(), None);
+
+        assert_eq!(errors.len(), 1);
+        let loc = errors[0].loc.as_ref().expect("error location");
+        assert_eq!(loc.start.offset, 8);
+    }
+
     #[test]
     fn test_v_for_creates_for_node() {
         let allocator = Bump::new();
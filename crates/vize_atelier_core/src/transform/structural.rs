@@ -225,16 +225,28 @@ pub fn transform_v_if<'a>(
         // Find previous v-if node and add branch to it
         let child_index = ctx.child_index;
 
-        // First, find the if node index
+        // First, find the if node index. Comments and whitespace-only text may sit
+        // between branches; any other node breaks the chain. An if node whose last
+        // branch is already a v-else can't take more branches either.
+        let mut current_loc = None;
         let found_if_idx = if let Some(parent) = &ctx.parent {
             let children = parent.children_mut();
+            if let Some(TemplateChildNode::Element(el)) = children.get(child_index) {
+                current_loc = Some(el.loc.clone());
+            }
             let mut found = None;
 
             // Look backwards for v-if node
             for j in (0..child_index).rev() {
                 match &children[j] {
-                    TemplateChildNode::If(_) => {
-                        found = Some(j);
+                    TemplateChildNode::If(if_node) => {
+                        let is_closed = if_node
+                            .branches
+                            .last()
+                            .is_some_and(|branch| branch.condition.is_none());
+                        if !is_closed {
+                            found = Some(j);
+                        }
                         break;
                     }
                     TemplateChildNode::Comment(_) => continue,
@@ -376,7 +388,7 @@ pub fn transform_v_if<'a>(
             // Remove the placeholder we left
             ctx.remove_node();
         } else {
-            ctx.on_error(ErrorCode::VElseNoAdjacentIf, None);
+            ctx.on_error(ErrorCode::VElseNoAdjacentIf, current_loc);
         }
 
         None
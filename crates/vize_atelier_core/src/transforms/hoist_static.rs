@@ -47,11 +47,6 @@ fn is_static_element(el: &ElementNode<'_>) -> bool {
         if matches!(child, TemplateChildNode::Comment(_)) {
             return false;
         }
-        // Nested elements cannot be fully hoisted yet because create_children_expression
-        // doesn't recursively create VNodeCalls for them - this would cause children to be omitted
-        if matches!(child, TemplateChildNode::Element(_)) {
-            return false;
-        }
         if !is_static_node(child) {
             return false;
         }
@@ -101,10 +96,12 @@ fn get_element_static_type(el: &ElementNode<'_>) -> StaticType {
             TemplateChildNode::Interpolation(_) => {
                 has_dynamic_text = true;
             }
-            // Nested elements cannot be fully hoisted yet because create_children_expression
-            // doesn't recursively create VNodeCalls for them - this would cause children to be omitted
-            TemplateChildNode::Element(_) => {
-                return StaticType::NotStatic;
+            // Nested elements are hoisted along with their parent only when the
+            // whole subtree is static
+            TemplateChildNode::Element(child_el) => {
+                if get_element_static_type(child_el) != StaticType::FullyStatic {
+                    return StaticType::NotStatic;
+                }
             }
             TemplateChildNode::If(_) | TemplateChildNode::For(_) => {
                 return StaticType::NotStatic;
@@ -166,17 +163,14 @@ fn hoist_static_inner<'a>(
                         if has_static_props(el) {
                             hoist_element_props(ctx, el, allocator);
                         }
+                        hoist_static_inner(ctx, &mut el.children, false);
                     }
                 } else {
                     // Non-root static elements can be fully hoisted
                     if let TemplateChildNode::Element(el) = &children[i] {
-                        let scope_id = ctx.options.scope_id.clone();
-                        let vnode_call =
-                            create_vnode_call_from_element(allocator, el, scope_id.as_ref());
-                        let hoist_index = ctx.hoist(vnode_call);
+                        let hoist_index = hoist_static_element(ctx, el);
                         // Replace with hoisted reference
                         children[i] = TemplateChildNode::Hoisted(hoist_index);
-                        ctx.helper(RuntimeHelper::CreateElementVNode);
                     }
                 }
             }
@@ -192,6 +186,11 @@ fn hoist_static_inner<'a>(
                 // Cannot hoist, but check children recursively (not as root)
                 match &mut children[i] {
                     TemplateChildNode::Element(el) => {
+                        // Dynamic children are tracked by the block, so a plain
+                        // element's static props can still be hoisted
+                        if el.tag_type == ElementType::Element && has_static_props(el) {
+                            hoist_element_props(ctx, el, allocator);
+                        }
                         hoist_static_inner(ctx, &mut el.children, false);
                     }
                     TemplateChildNode::If(if_node) => {
@@ -217,15 +216,25 @@ fn hoist_static_inner<'a>(
     }
 }
 
+/// Hoist a fully static element and return its hoist index.
+///
+/// Static element children are hoisted first and referenced from the parent,
+/// so a whole static subtree is created once instead of on every render.
+fn hoist_static_element<'a>(ctx: &mut TransformContext<'a>, el: &ElementNode<'a>) -> usize {
+    let vnode_call = create_vnode_call_from_element(ctx, el);
+    ctx.helper(RuntimeHelper::CreateElementVNode);
+    ctx.hoist(vnode_call)
+}
+
 /// Create a VNodeCall from an ElementNode for hoisting
 fn create_vnode_call_from_element<'a>(
-    allocator: &'a Bump,
+    ctx: &mut TransformContext<'a>,
     el: &ElementNode<'a>,
-    scope_id: Option<&vize_carton::String>,
 ) -> JsChildNode<'a> {
+    let allocator = ctx.allocator;
     let tag = VNodeTag::String(el.tag.clone());
-    let props = create_props_expression(allocator, &el.props, scope_id);
-    let children = create_children_expression(allocator, &el.children);
+    let props = create_props_expression(allocator, &el.props, ctx.options.scope_id.as_ref());
+    let children = create_children_expression(ctx, &el.children);
 
     let vnode_call = VNodeCall {
         tag,
@@ -305,9 +314,10 @@ fn create_props_expression<'a>(
 
 /// Create children expression from template children
 fn create_children_expression<'a>(
-    allocator: &'a Bump,
+    ctx: &mut TransformContext<'a>,
     children: &Vec<'a, TemplateChildNode<'a>>,
 ) -> Option<VNodeChildren<'a>> {
+    let allocator = ctx.allocator;
     if children.is_empty() {
         return None;
     }
@@ -345,9 +355,25 @@ fn create_children_expression<'a>(
         )));
     }
 
-    // For complex children with nested elements, return as Simple expression for now
-    // A full implementation would recursively create VNodeCalls
-    None
+    // Mixed children: text becomes a text vnode, static elements are hoisted
+    // on their own and referenced from the parent's children array
+    let mut nodes = Vec::new_in(allocator);
+    for child in children.iter() {
+        match child {
+            TemplateChildNode::Text(text) => {
+                let text_node = TextNode::new(text.content.clone(), text.loc.clone());
+                nodes.push(TemplateChildNode::Text(Box::new_in(text_node, allocator)));
+                ctx.helper(RuntimeHelper::CreateText);
+            }
+            TemplateChildNode::Element(el) => {
+                let hoist_index = hoist_static_element(ctx, el);
+                nodes.push(TemplateChildNode::Hoisted(hoist_index));
+            }
+            _ => {}
+        }
+    }
+
+    Some(VNodeChildren::Multiple(nodes))
 }
 
 /// Check if an element has static props (all attributes, no dynamic bindings)
@@ -8,6 +8,7 @@ use oxc_ast_visit::{
     walk::{walk_assignment_expression, walk_object_property, walk_update_expression},
     Visit,
 };
+use oxc_span::GetSpan;
 use vize_carton::FxHashSet;
 use vize_carton::String;
 
@@ -15,7 +16,7 @@ use vize_croquis::builtins::is_global_allowed;
 
 use crate::transform::TransformContext;
 
-use super::prefix::get_identifier_prefix;
+use super::prefix::{get_identifier_prefix, inline_access, InlineAccess};
 
 /// Visitor to collect identifiers that need prefixing
 pub(crate) struct IdentifierCollector<'a, 'ctx> {
@@ -32,6 +33,8 @@ pub(crate) struct IdentifierCollector<'a, 'ctx> {
     pub(crate) assignment_targets: FxHashSet<usize>,
     /// Whether _unref helper was used
     pub(crate) used_unref: bool,
+    /// Whether _isRef helper was used
+    pub(crate) used_is_ref: bool,
 }
 
 impl<'a, 'ctx> IdentifierCollector<'a, 'ctx> {
@@ -44,30 +47,21 @@ impl<'a, 'ctx> IdentifierCollector<'a, 'ctx> {
             suffix_rewrites: Vec::new(),
             assignment_targets: FxHashSet::default(),
             used_unref: false,
+            used_is_ref: false,
         }
     }
 
-    /// Check if an identifier is a ref that needs .value suffix
-    fn is_ref_binding(&self, name: &str) -> bool {
-        // Skip if in local scope
+    /// How an identifier is accessed in inline mode.
+    fn inline_access(&self, name: &str) -> InlineAccess {
         if self.local_scope.contains(name) {
-            return false;
+            return InlineAccess::Direct;
         }
+        inline_access(name, self.ctx)
+    }
 
-        // Check if this is an inline mode ref binding
-        if self.ctx.options.inline {
-            // Croquis first: use ReactiveKind for precise determination
-            if let Some(kind) = self.ctx.get_reactive_kind(name) {
-                return kind.needs_value_access();
-            }
-            // Fallback: binding_metadata
-            if let Some(bindings) = &self.ctx.options.binding_metadata {
-                if let Some(binding_type) = bindings.bindings.get(name) {
-                    return matches!(binding_type, crate::options::BindingType::SetupRef);
-                }
-            }
-        }
-        false
+    /// Check if an identifier is a ref that needs .value suffix
+    fn is_ref_binding(&self, name: &str) -> bool {
+        self.inline_access(name) == InlineAccess::Value
     }
 
     /// Check if an identifier needs _unref() wrapping.
@@ -79,6 +73,13 @@ impl<'a, 'ctx> IdentifierCollector<'a, 'ctx> {
             return false;
         }
 
+        if self.ctx.options.inline {
+            return matches!(
+                self.inline_access(name),
+                InlineAccess::MaybeRef | InlineAccess::Let
+            );
+        }
+
         // If Croquis has ReactiveKind info, type is known -- no _unref() needed
         if self.ctx.get_reactive_kind(name).is_some() {
             return false;
@@ -214,6 +215,58 @@ impl<'a, 'ctx> IdentifierCollector<'a, 'ctx> {
             self.assignment_targets.insert(ident.span.start as usize);
         }
     }
+
+    /// Return the inline `let` binding written by a simple assignment target.
+    fn let_binding_target<'s>(
+        &self,
+        target: &'s oxc_ast_types::SimpleAssignmentTarget<'_>,
+    ) -> Option<&'s str> {
+        match target {
+            oxc_ast_types::SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)
+                if self.inline_access(ident.name.as_str()) == InlineAccess::Let =>
+            {
+                Some(ident.name.as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// Rewrite a sub-expression on its own, as Vue core does for the
+    /// replicated right-hand side of `let` assignments.
+    fn rewrite_subexpression(&mut self, expr: &oxc_ast_types::Expression<'_>) -> String {
+        let mut nested = IdentifierCollector::new(self.ctx, self.source);
+        nested.local_scope = self.local_scope.clone();
+        nested.visit_expression(expr);
+        self.used_unref |= nested.used_unref;
+        self.used_is_ref |= nested.used_is_ref;
+
+        let span = expr.span();
+        let mut rewrites: Vec<(usize, String, String)> = nested
+            .rewrites
+            .into_iter()
+            .map(|(pos, prefix)| (pos, prefix, String::default()))
+            .collect();
+        rewrites.sort();
+        rewrites.extend(
+            nested
+                .suffix_rewrites
+                .into_iter()
+                .map(|(pos, suffix)| (pos, String::default(), suffix)),
+        );
+        rewrites.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut result = String::new(&self.source[span.start as usize..span.end as usize]);
+        for (pos, prefix, suffix) in rewrites {
+            let pos = pos - span.start as usize;
+            if !suffix.is_empty() {
+                result.insert_str(pos, &suffix);
+            }
+            if !prefix.is_empty() {
+                result.insert_str(pos, &prefix);
+            }
+        }
+        result
+    }
 }
 
 impl<'a, 'ctx> Visit<'_> for IdentifierCollector<'a, 'ctx> {
@@ -325,11 +378,57 @@ impl<'a, 'ctx> Visit<'_> for IdentifierCollector<'a, 'ctx> {
     }
 
     fn visit_assignment_expression(&mut self, expr: &oxc_ast_types::AssignmentExpression<'_>) {
+        if let Some(name) = expr
+            .left
+            .as_simple_assignment_target()
+            .and_then(|target| self.let_binding_target(target))
+        {
+            // x = y --> _isRef(x) ? x.value = y : x = y
+            let right = self.rewrite_subexpression(&expr.right);
+            let operator = expr.operator.as_str();
+            let mut prefix =
+                String::with_capacity(2 * name.len() + operator.len() + right.len() + 24);
+            prefix.push_str("_isRef(");
+            prefix.push_str(name);
+            prefix.push_str(") ? ");
+            prefix.push_str(name);
+            prefix.push_str(".value ");
+            prefix.push_str(operator);
+            prefix.push(' ');
+            prefix.push_str(&right);
+            prefix.push_str(" : ");
+            self.rewrites.insert((expr.span.start as usize, prefix));
+            self.used_is_ref = true;
+            self.visit_expression(&expr.right);
+            return;
+        }
+
         self.collect_assignment_targets(&expr.left);
         walk_assignment_expression(self, expr);
     }
 
     fn visit_update_expression(&mut self, expr: &oxc_ast_types::UpdateExpression<'_>) {
+        if let Some(name) = self.let_binding_target(&expr.argument) {
+            // x++ --> _isRef(x) ? x.value++ : x++
+            let operator = expr.operator.as_str();
+            let mut prefix = String::with_capacity(2 * name.len() + operator.len() + 20);
+            prefix.push_str("_isRef(");
+            prefix.push_str(name);
+            prefix.push_str(") ? ");
+            if expr.prefix {
+                prefix.push_str(operator);
+            }
+            prefix.push_str(name);
+            prefix.push_str(".value");
+            if !expr.prefix {
+                prefix.push_str(operator);
+            }
+            prefix.push_str(" : ");
+            self.rewrites.insert((expr.span.start as usize, prefix));
+            self.used_is_ref = true;
+            return;
+        }
+
         self.collect_simple_assignment_targets(&expr.argument);
         walk_update_expression(self, expr);
     }
@@ -45,6 +45,9 @@ pub fn process_inline_handler<'a>(
                     if result.used_unref {
                         ctx.helper(crate::ast::RuntimeHelper::Unref);
                     }
+                    if result.used_is_ref {
+                        ctx.helper(crate::ast::RuntimeHelper::IsRef);
+                    }
                     return ExpressionNode::Simple(Box::new_in(
                         SimpleExpressionNode {
                             content: String::new(&result.code),
@@ -119,6 +122,9 @@ pub fn process_inline_handler<'a>(
                 if result.used_unref {
                     ctx.helper(crate::ast::RuntimeHelper::Unref);
                 }
+                if result.used_is_ref {
+                    ctx.helper(crate::ast::RuntimeHelper::IsRef);
+                }
                 result.code
             } else if ctx.options.is_ts {
                 // Strip TypeScript type annotations even without prefix_identifiers
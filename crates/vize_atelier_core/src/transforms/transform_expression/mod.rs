@@ -60,6 +60,9 @@ pub fn process_expression<'a>(
                 if result.used_unref {
                     ctx.helper(crate::ast::RuntimeHelper::Unref);
                 }
+                if result.used_is_ref {
+                    ctx.helper(crate::ast::RuntimeHelper::IsRef);
+                }
                 result.code
             } else if ctx.options.is_ts {
                 // Only strip TypeScript, no prefixing
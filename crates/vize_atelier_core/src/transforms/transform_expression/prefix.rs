@@ -63,21 +63,55 @@ pub(crate) fn get_identifier_prefix(
     Some("_ctx.")
 }
 
+/// How a setup binding is accessed from an inline-mode template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InlineAccess {
+    /// Used as-is (`SetupConst`, `SetupReactiveConst`, props, ...)
+    Direct,
+    /// Known ref: `x.value`
+    Value,
+    /// Const that may be a ref: `_unref(x)`, writes assume a ref (`x.value`)
+    MaybeRef,
+    /// Reassignable `let`: `_unref(x)`, writes go through `_isRef(x)`
+    Let,
+}
+
+/// Determine how an identifier is accessed in inline mode.
+///
+/// Binding metadata is authoritative; Croquis only refines bindings whose
+/// kind the script compiler couldn't pin down (`SetupMaybeRef`) or names
+/// without metadata.
+pub(crate) fn inline_access(name: &str, ctx: &TransformContext<'_>) -> InlineAccess {
+    use crate::options::BindingType;
+
+    if !ctx.options.inline || ctx.is_in_scope(name) {
+        return InlineAccess::Direct;
+    }
+
+    let from_kind = |maybe_ref: InlineAccess| match ctx.get_reactive_kind(name) {
+        Some(kind) if kind.needs_value_access() => InlineAccess::Value,
+        Some(_) => InlineAccess::Direct,
+        None => maybe_ref,
+    };
+
+    match ctx
+        .options
+        .binding_metadata
+        .as_ref()
+        .and_then(|bindings| bindings.bindings.get(name))
+    {
+        Some(BindingType::SetupRef) => InlineAccess::Value,
+        Some(BindingType::SetupMaybeRef) => from_kind(InlineAccess::MaybeRef),
+        // Vue core always unwraps `let`: it may be reassigned to a non-ref
+        Some(BindingType::SetupLet) => InlineAccess::Let,
+        Some(_) => InlineAccess::Direct,
+        None => from_kind(InlineAccess::Direct),
+    }
+}
+
 /// Check if a simple identifier is a ref binding in inline mode
 pub(crate) fn is_ref_binding_simple(name: &str, ctx: &TransformContext<'_>) -> bool {
-    if ctx.options.inline {
-        // Croquis first: use ReactiveKind for precise determination
-        if let Some(kind) = ctx.get_reactive_kind(name) {
-            return kind.needs_value_access();
-        }
-        // Fallback: binding_metadata
-        if let Some(bindings) = &ctx.options.binding_metadata {
-            if let Some(binding_type) = bindings.bindings.get(name) {
-                return matches!(binding_type, crate::options::BindingType::SetupRef);
-            }
-        }
-    }
-    false
+    inline_access(name, ctx) == InlineAccess::Value
 }
 
 /// Check if string is a simple identifier
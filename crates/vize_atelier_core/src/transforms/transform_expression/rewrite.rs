@@ -21,6 +21,7 @@ use super::{
 pub(crate) struct RewriteResult {
     pub(crate) code: String,
     pub(crate) used_unref: bool,
+    pub(crate) used_is_ref: bool,
 }
 
 /// Rewrite an expression string, prefixing identifiers with `_ctx.` where needed
@@ -55,6 +56,7 @@ pub(crate) fn rewrite_expression(
             collector.visit_expression(&expr);
 
             let used_unref = collector.used_unref;
+            let used_is_ref = collector.used_is_ref;
 
             // Combine prefix rewrites (from HashSet) with suffix rewrites
             // Each rewrite is (position, prefix, suffix)
@@ -64,12 +66,16 @@ pub(crate) fn rewrite_expression(
                 .map(|(pos, prefix)| (pos, prefix, String::default()))
                 .collect();
 
+            let prefix_count = all_rewrites.len();
+
             // Add suffix rewrites (suffixes come after the identifier)
             for (pos, suffix) in collector.suffix_rewrites {
                 all_rewrites.push((pos, String::default(), suffix));
             }
 
-            // Sort by position descending so we can replace from end to start
+            // Sort by position descending so we can replace from end to start.
+            // Prefix rewrites come from a hash set, so order them first to keep ties stable.
+            all_rewrites[..prefix_count].sort();
             all_rewrites.sort_by(|a, b| b.0.cmp(&a.0));
 
             // Apply rewrites
@@ -92,6 +98,7 @@ pub(crate) fn rewrite_expression(
             RewriteResult {
                 code: result,
                 used_unref,
+                used_is_ref,
             }
         }
         Err(_) => {
@@ -106,6 +113,7 @@ pub(crate) fn rewrite_expression(
                 collector.visit_program(&parse_result2.program);
 
                 let used_unref = collector.used_unref;
+                let used_is_ref = collector.used_is_ref;
 
                 let mut all_rewrites: Vec<(usize, String, String)> = collector
                     .rewrites
@@ -135,6 +143,7 @@ pub(crate) fn rewrite_expression(
                 return RewriteResult {
                     code: result,
                     used_unref,
+                    used_is_ref,
                 };
             }
 
@@ -160,6 +169,7 @@ pub(crate) fn rewrite_expression(
             RewriteResult {
                 code,
                 used_unref: false,
+                used_is_ref: false,
             }
         }
     }
@@ -52,13 +52,16 @@ pub fn compile_template_with_options<'a>(
     options: DomCompilerOptions,
 ) -> (RootNode<'a>, Vec<CompilerError>, CodegenResult) {
     // Create parser options with DOM-specific settings
+    let defaults = ParserOptions::default();
     let parser_opts = ParserOptions {
         is_void_tag: vize_carton::is_void_tag,
         is_native_tag: Some(vize_carton::is_native_tag),
+        is_custom_element: options.is_custom_element,
         is_pre_tag: |tag| tag == "pre",
         get_namespace,
         comments: options.comments,
-        ..ParserOptions::default()
+        delimiters: options.delimiters.clone().unwrap_or(defaults.delimiters),
+        ..defaults
     };
 
     // Parse
@@ -72,6 +75,7 @@ pub fn compile_template_with_options<'a>(
             code: String::default(),
             preamble: String::default(),
             map: None,
+            used_helpers: Vec::new(),
         };
         return (root, errors.to_vec(), codegen_result);
     }
@@ -91,13 +95,13 @@ pub fn compile_template_with_options<'a>(
     };
     // Allocate Croquis in the arena so it shares the allocator lifetime
     let analysis: Option<&Croquis> = options.croquis.map(|c| &*allocator.alloc(*c));
-    profile!(
+    let errors = profile!(
         "atelier.dom.template.transform",
         do_transform(allocator, &mut root, transform_opts, analysis)
     );
 
     // Codegen
-    let codegen_opts = CodegenOptions {
+    let mut codegen_opts = CodegenOptions {
         mode: options.mode,
         source_map: options.source_map,
         scope_id: options.scope_id.clone(),
@@ -108,12 +112,18 @@ pub fn compile_template_with_options<'a>(
         binding_metadata: options.binding_metadata,
         ..Default::default()
     };
+    if let Some(name) = options.runtime_module_name {
+        codegen_opts.runtime_module_name = name;
+    }
+    if let Some(name) = options.runtime_global_name {
+        codegen_opts.runtime_global_name = name;
+    }
     let codegen_result = profile!(
         "atelier.dom.template.codegen",
         generate(&root, codegen_opts)
     );
 
-    (root, errors.to_vec(), codegen_result)
+    (root, errors, codegen_result)
 }
 
 /// Get the namespace for an element based on its parent
@@ -194,6 +204,67 @@ mod tests {
         assert!(!result.code.is_empty());
     }
 
+    #[test]
+    fn test_custom_element_keeps_attribute_casing() {
+        let allocator = Bump::new();
+        let opts = DomCompilerOptions {
+            mode: CodegenMode::Module,
+            is_custom_element: Some(|tag| tag.starts_with("my-")),
+            ..Default::default()
+        };
+        let template = r#"<my-el data-Foo="x" aria-Label="y" someProp="true" disabled></my-el>"#;
+        let (_, errors, result) = compile_template_with_options(&allocator, template, opts);
+
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+        let full = full_output(&result.preamble, &result.code);
+        assert!(!full.contains("resolveComponent"), "{full}");
+        assert!(full.contains("\"my-el\""), "{full}");
+        assert!(full.contains("\"data-Foo\": \"x\""), "{full}");
+        assert!(full.contains("\"aria-Label\": \"y\""), "{full}");
+        assert!(full.contains("someProp: \"true\""), "{full}");
+        assert!(full.contains("disabled: \"\""), "{full}");
+    }
+
+    #[test]
+    fn test_custom_delimiters() {
+        let allocator = Bump::new();
+        let opts = DomCompilerOptions {
+            delimiters: Some(("${".into(), "}".into())),
+            ..Default::default()
+        };
+        let template = "<div>${ { a: msg }.a } {{ raw }}</div>";
+        let (_, errors, result) = compile_template_with_options(&allocator, template, opts);
+
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+        assert!(
+            result.code.contains("_toDisplayString({ a: msg }.a)"),
+            "{}",
+            result.code
+        );
+        assert!(result.code.contains(" {{ raw }}"), "{}", result.code);
+    }
+
+    #[test]
+    fn test_compile_with_custom_runtime_names() {
+        let allocator = Bump::new();
+        let opts = DomCompilerOptions {
+            mode: CodegenMode::Module,
+            runtime_module_name: Some("@vue/runtime-dom".into()),
+            ..Default::default()
+        };
+        let (_, _, result) = compile_template_with_options(&allocator, "<div>hi</div>", opts);
+        assert!(result.preamble.contains("} from \"@vue/runtime-dom\""));
+
+        let allocator = Bump::new();
+        let opts = DomCompilerOptions {
+            mode: CodegenMode::Function,
+            runtime_global_name: Some("MyVue".into()),
+            ..Default::default()
+        };
+        let (_, _, result) = compile_template_with_options(&allocator, "<div>hi</div>", opts);
+        assert!(result.preamble.contains("} = MyVue"));
+    }
+
     #[test]
     fn test_event_handler_setup_ref_value() {
         use vize_atelier_core::options::BindingType;
@@ -229,6 +300,69 @@ mod tests {
         insta::assert_snapshot!(full.as_str());
     }
 
+    #[test]
+    fn test_inline_value_access_per_binding_type() {
+        use vize_atelier_core::options::{BindingMetadata, BindingType};
+        use vize_carton::FxHashMap;
+
+        let allocator = Bump::new();
+        let mut bindings = FxHashMap::default();
+        bindings.insert("counter".into(), BindingType::SetupRef);
+        bindings.insert("maybe".into(), BindingType::SetupMaybeRef);
+        bindings.insert("state".into(), BindingType::SetupReactiveConst);
+        bindings.insert("label".into(), BindingType::SetupLet);
+        bindings.insert("total".into(), BindingType::SetupLet);
+        bindings.insert("format".into(), BindingType::SetupConst);
+
+        let opts = DomCompilerOptions {
+            mode: CodegenMode::Module,
+            prefix_identifiers: true,
+            inline: true,
+            binding_metadata: Some(BindingMetadata {
+                bindings,
+                props_aliases: FxHashMap::default(),
+                is_script_setup: true,
+            }),
+            ..Default::default()
+        };
+        let template = r#"<div>
+  <p>{{ counter }} {{ maybe }} {{ state.n }} {{ label }} {{ format(total) }}</p>
+  <button @click="counter = 1; maybe = 2; state.n = 3">a</button>
+  <button @click="label = state.n + counter">b</button>
+  <button @click="total++">c</button>
+</div>"#;
+        let (_, errors, result) = compile_template_with_options(&allocator, template, opts);
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+        let full = full_output(&result.preamble, &result.code);
+
+        // Reads
+        assert!(full.contains("_toDisplayString(counter.value)"), "{full}");
+        assert!(full.contains("_toDisplayString(_unref(maybe))"), "{full}");
+        assert!(full.contains("_toDisplayString(state.n)"), "{full}");
+        assert!(full.contains("_toDisplayString(_unref(label))"), "{full}");
+        assert!(
+            full.contains("_toDisplayString(format(_unref(total)))"),
+            "{full}"
+        );
+        assert!(!full.contains("state.value"), "{full}");
+
+        // Writes
+        assert!(full.contains("counter.value = 1"), "{full}");
+        assert!(full.contains("maybe.value = 2"), "{full}");
+        assert!(full.contains("state.n = 3"), "{full}");
+        assert!(
+            full.contains(
+                "_isRef(label) ? label.value = state.n + counter.value : label = state.n + counter.value"
+            ),
+            "{full}"
+        );
+        assert!(
+            full.contains("_isRef(total) ? total.value++ : total++"),
+            "{full}"
+        );
+        assert!(full.contains("isRef as _isRef"), "{full}");
+    }
+
     #[test]
     fn test_inline_ref_class_binding_keeps_class_patch_flag() {
         use vize_atelier_core::options::{BindingMetadata, BindingType};
@@ -53,6 +53,24 @@ pub struct DomCompilerOptions {
     #[serde(default)]
     pub is_ts: bool,
 
+    /// Module the generated code imports runtime helpers from (default: `vue`)
+    #[serde(default)]
+    pub runtime_module_name: Option<String>,
+
+    /// Global the function-mode preamble destructures helpers from (default: `Vue`)
+    #[serde(default)]
+    pub runtime_global_name: Option<String>,
+
+    /// Custom interpolation delimiters (default: `{{` and `}}`)
+    #[serde(default)]
+    pub delimiters: Option<(String, String)>,
+
+    /// Check if a tag is a custom element (e.g. a web component).
+    /// Custom elements are rendered as plain elements with their attributes
+    /// passed through verbatim instead of being resolved as components.
+    #[serde(skip)]
+    pub is_custom_element: Option<fn(&str) -> bool>,
+
     /// Semantic analysis data from Croquis (optional, enhances transforms)
     #[serde(skip)]
     pub croquis: Option<Box<Croquis>>,
@@ -72,6 +90,10 @@ impl Clone for DomCompilerOptions {
             inline: self.inline,
             binding_metadata: self.binding_metadata.clone(),
             is_ts: self.is_ts,
+            runtime_module_name: self.runtime_module_name.clone(),
+            runtime_global_name: self.runtime_global_name.clone(),
+            delimiters: self.delimiters.clone(),
+            is_custom_element: self.is_custom_element,
             // Croquis is not cloneable; it will be consumed when passed to the compiler
             croquis: None,
         }
@@ -92,6 +114,10 @@ impl Default for DomCompilerOptions {
             inline: false,
             binding_metadata: None,
             is_ts: false,
+            runtime_module_name: None,
+            runtime_global_name: None,
+            delimiters: None,
+            is_custom_element: None,
             croquis: None,
         }
     }
@@ -29,15 +29,17 @@ pub(super) fn croquis_to_legacy_bindings(
 /// declarations from the normal script are accessible in the template.
 /// Uses OXC parser for accurate import extraction (handles `import { Form as PForm }`,
 /// default imports, namespace imports, re-exports, etc.).
-pub(super) fn register_normal_script_bindings(content: &str, bindings: &mut BindingMetadata) {
+pub(super) fn register_normal_script_bindings(
+    content: &str,
+    lang: Option<&str>,
+    bindings: &mut BindingMetadata,
+) {
+    use crate::script::parse_script_source;
     use oxc_allocator::Allocator;
     use oxc_ast::ast::{Declaration, ImportDeclarationSpecifier, Statement};
-    use oxc_parser::Parser;
-    use oxc_span::SourceType;
 
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
-    let ret = Parser::new(&allocator, content, source_type).parse();
+    let ret = parse_script_source(&allocator, content, lang);
 
     if ret.panicked {
         return;
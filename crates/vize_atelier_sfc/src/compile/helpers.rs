@@ -1,6 +1,10 @@
 //! Helper functions for SFC compilation.
 
 use vize_carton::{String, ToCompactString};
+
+use crate::compile_script::typescript::lower_to_target;
+use crate::types::{SfcCompileOptions, SfcError};
+
 /// Generate scope ID from filename
 pub(super) fn generate_scope_id(filename: &str) -> String {
     use std::hash::{Hash, Hasher};
@@ -24,3 +28,172 @@ pub(super) fn extract_component_name(filename: &str) -> String {
         .unwrap_or("anonymous")
         .to_compact_string()
 }
+
+/// Attach dev-only runtime metadata (devtools info, HMR) to the component module.
+///
+/// The metadata is assigned on the `_sfc_main` binding. Output that exports a
+/// literal (script setup, or its `Object.assign(__default__, …)` merge with a
+/// normal `<script>`) is rebound to `_sfc_main` first.
+pub(super) fn attach_runtime_meta(
+    code: &mut String,
+    filename: &str,
+    component_name: &str,
+    options: &SfcCompileOptions,
+) {
+    if options.script.dev {
+        attach_dev_info(code, filename, component_name);
+    }
+    if options.hmr {
+        let hmr_id = options
+            .scope_id
+            .clone()
+            .unwrap_or_else(|| generate_scope_id(filename));
+        attach_hmr(code, &hmr_id, options.hmr_rerender_only);
+    }
+}
+
+/// Lower the component module to the configured script target.
+///
+/// On failure the code is kept as is and the error is reported as a warning.
+pub(super) fn lower_to_script_target(
+    code: &mut String,
+    is_ts: bool,
+    lang: Option<&str>,
+    options: &SfcCompileOptions,
+    warnings: &mut Vec<SfcError>,
+) {
+    let Some(target) = options.script.target.as_deref() else {
+        return;
+    };
+    match lower_to_target(code, target, is_ts, lang) {
+        Ok(lowered) => *code = lowered,
+        Err(message) => warnings.push(SfcError {
+            message,
+            code: None,
+            loc: None,
+        }),
+    }
+}
+
+const EXPORT_MAIN: &str = "export default _sfc_main";
+
+/// Make sure the component is exported through a `_sfc_main` binding.
+///
+/// Script setup output exports an object literal directly, so it is rebound to
+/// `_sfc_main` and re-exported at the end. Returns the offset of the
+/// `export default _sfc_main` statement and whether a rebind happened, or `None`
+/// for output without a default export (template-only render modules).
+fn bind_sfc_main_export(code: &mut String) -> Option<(usize, bool)> {
+    const EXPORT_DEFAULT: &str = "export default ";
+
+    if let Some(pos) = code.rfind(EXPORT_MAIN) {
+        return Some((pos, false));
+    }
+
+    let pos = if code.starts_with(EXPORT_DEFAULT) {
+        0
+    } else {
+        code.rfind("\nexport default ")? + 1
+    };
+    code.replace_range(pos..pos + EXPORT_DEFAULT.len(), "const _sfc_main = ");
+    if !code.ends_with('\n') {
+        code.push('\n');
+    }
+    let export_pos = code.len();
+    code.push_str(EXPORT_MAIN);
+    code.push('\n');
+    Some((export_pos, true))
+}
+
+/// Attach the scoped style id (`__scopeId`) to the exported component.
+///
+/// The runtime copies it onto the component's rendered elements, including the
+/// root of child components, so scoped selectors keep matching there.
+/// Render-only modules without a default export are left to the bundler.
+pub(super) fn attach_scope_id(code: &mut String, scope_id: &str) {
+    let Some((pos, _)) = bind_sfc_main_export(code) else {
+        return;
+    };
+
+    let mut assignment = String::with_capacity(scope_id.len() + 32);
+    assignment.push_str("_sfc_main.__scopeId = \"data-v-");
+    assignment.push_str(scope_id);
+    assignment.push_str("\"\n");
+    code.insert_str(pos, &assignment);
+}
+
+/// Attach devtools metadata (`__name` / `__file`) to the exported component.
+///
+/// `__name` is only assigned when a normal `<script>` is bound to `_sfc_main`
+/// directly. Script setup output declares `__name` in its component literal,
+/// including when that literal is merged into the normal script's
+/// `__default__`, so only `__file` is added there.
+fn attach_dev_info(code: &mut String, filename: &str, component_name: &str) {
+    let Some((pos, rebound)) = bind_sfc_main_export(code) else {
+        return;
+    };
+
+    let mut info = String::default();
+    if !rebound {
+        info.push_str("_sfc_main.__name = ");
+        push_js_string(&mut info, component_name);
+        info.push('\n');
+    }
+    info.push_str("_sfc_main.__file = ");
+    push_js_string(&mut info, filename);
+    info.push('\n');
+    code.insert_str(pos, &info);
+}
+
+/// Attach HMR boilerplate (`__hmrId` record + `import.meta.hot.accept`).
+///
+/// Mirrors the output of `@vitejs/plugin-vue`: when `rerender_only` is set the
+/// module exports `_rerender_only` so the accept hook calls
+/// `__VUE_HMR_RUNTIME__.rerender` instead of a full `reload`.
+fn attach_hmr(code: &mut String, hmr_id: &str, rerender_only: bool) {
+    let Some((pos, _)) = bind_sfc_main_export(code) else {
+        return;
+    };
+
+    let mut record = String::default();
+    record.push_str("_sfc_main.__hmrId = ");
+    push_js_string(&mut record, hmr_id);
+    record.push('\n');
+    record.push_str(
+        "typeof __VUE_HMR_RUNTIME__ !== 'undefined' && \
+         __VUE_HMR_RUNTIME__.createRecord(_sfc_main.__hmrId, _sfc_main)\n",
+    );
+    code.insert_str(pos, &record);
+
+    if rerender_only {
+        code.push_str("export const _rerender_only = true\n");
+    }
+    code.push_str(
+        "if (import.meta.hot) {\n\
+         \x20 import.meta.hot.accept(mod => {\n\
+         \x20   if (!mod) return\n\
+         \x20   const { default: updated, _rerender_only } = mod\n\
+         \x20   if (_rerender_only) {\n\
+         \x20     __VUE_HMR_RUNTIME__.rerender(updated.__hmrId, updated.render)\n\
+         \x20   } else {\n\
+         \x20     __VUE_HMR_RUNTIME__.reload(updated.__hmrId, updated)\n\
+         \x20   }\n\
+         \x20 })\n\
+         }\n",
+    );
+}
+
+/// Push a double-quoted JavaScript string literal.
+fn push_js_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -21,7 +21,10 @@ use crate::script::ScriptCompileContext;
 use crate::types::{BindingType, SfcCompileOptions, SfcCompileResult, SfcDescriptor, SfcError};
 
 use self::bindings::{croquis_to_legacy_bindings, register_normal_script_bindings};
-use self::helpers::{extract_component_name, generate_scope_id};
+use self::helpers::{
+    attach_runtime_meta, attach_scope_id, extract_component_name, generate_scope_id,
+    lower_to_script_target,
+};
 use self::normal_script::extract_normal_script_content;
 use self::styles::compile_styles;
 
@@ -72,24 +75,20 @@ pub fn compile_sfc(
                 .map(|s| s.attrs.contains_key("vapor"))
                 .unwrap_or(false));
 
-    // source_has_ts: whether source uses TypeScript (detected from lang="ts")
+    // descriptor.is_typescript: whether source uses TypeScript (detected from lang="ts")
     // Used for: parsing source as TS, preserving TS declarations, resolving type references
-    let source_has_ts = descriptor
-        .script_setup
-        .as_ref()
-        .and_then(|s| s.lang.as_ref())
-        .is_some_and(|l| l == "ts" || l == "tsx")
-        || descriptor
-            .script
-            .as_ref()
-            .and_then(|s| s.lang.as_ref())
-            .is_some_and(|l| l == "ts" || l == "tsx");
     // is_ts controls output format:
     // - true: output TypeScript (add `: any` annotations, defineComponent wrapper)
     // - false: output JavaScript (no type annotations)
     // Auto-detected from source lang, or set by explicit options.
     // When true, TypeScript is preserved in output (downstream tools like Vite strip it via .ts suffix).
-    let is_ts = options.script.is_ts || options.template.is_ts || source_has_ts;
+    let is_ts = options.script.is_ts || options.template.is_ts || descriptor.is_typescript;
+    // `<script>` and `<script setup>` share a lang; `jsx`/`tsx` output keeps its JSX
+    let script_lang = descriptor
+        .script_setup
+        .as_ref()
+        .or(descriptor.script.as_ref())
+        .and_then(|script| script.lang.as_deref());
 
     // Extract component name from filename
     let component_name = extract_component_name(filename);
@@ -155,6 +154,12 @@ pub fn compile_sfc(
             css = Some(all_css);
         }
 
+        if has_scoped {
+            attach_scope_id(&mut code, &scope_id);
+        }
+        attach_runtime_meta(&mut code, filename, &component_name, &options);
+        lower_to_script_target(&mut code, is_ts, script_lang, &options, &mut warnings);
+
         return Ok(SfcCompileResult {
             code,
             css,
@@ -170,10 +175,7 @@ pub fn compile_sfc(
         let script = descriptor.script.as_ref().unwrap();
 
         // Check if source script is TypeScript
-        let source_is_ts = script
-            .lang
-            .as_ref()
-            .is_some_and(|l| l == "ts" || l == "tsx");
+        let source_is_ts = script.is_typescript();
 
         // Rewrite `export default` to `const _sfc_main = ...`
         // Parse as TypeScript if source is TypeScript
@@ -186,7 +188,10 @@ pub fn compile_sfc(
         let final_script = if source_is_ts && !is_ts {
             profile!(
                 "atelier.sfc.normal_script.ts_to_js",
-                crate::compile_script::typescript::transform_typescript_to_js(&rewritten_script)
+                crate::compile_script::typescript::transform_typescript_to_js(
+                    &rewritten_script,
+                    script.lang.as_deref()
+                )
             )
         } else {
             rewritten_script
@@ -269,6 +274,12 @@ pub fn compile_sfc(
             css = Some(all_css);
         }
 
+        if has_scoped {
+            attach_scope_id(&mut code, &scope_id);
+        }
+        attach_runtime_meta(&mut code, filename, &component_name, &options);
+        lower_to_script_target(&mut code, is_ts, script_lang, &options, &mut warnings);
+
         return Ok(SfcCompileResult {
             code,
             css,
@@ -300,10 +311,7 @@ pub fn compile_sfc(
     let normal_script_content = if has_script {
         let script = descriptor.script.as_ref().unwrap();
         // Check if source is TypeScript
-        let source_is_ts = script
-            .lang
-            .as_ref()
-            .is_some_and(|l| l == "ts" || l == "tsx");
+        let source_is_ts = script.is_typescript();
         Some(profile!(
             "atelier.sfc.normal_script.extract",
             extract_normal_script_content(&script.content, source_is_ts, is_ts)
@@ -315,7 +323,10 @@ pub fn compile_sfc(
     // 1. Croquis parser: rich analysis with ReactivityTracker
     let croquis = profile!(
         "atelier.sfc.script_setup.croquis",
-        crate::script::analyze_script_setup_to_summary(&script_setup.content)
+        crate::script::analyze_script_setup_to_summary(
+            &script_setup.content,
+            script_setup.lang.as_deref()
+        )
     );
     let mut script_bindings = croquis_to_legacy_bindings(&croquis.bindings);
 
@@ -323,7 +334,7 @@ pub fn compile_sfc(
     //    (Croquis doesn't resolve type references like `defineProps<Props>()`)
     let mut ctx = profile!(
         "atelier.sfc.script_context.new",
-        ScriptCompileContext::new(&script_setup.content)
+        ScriptCompileContext::new(&script_setup.content).with_lang(script_setup.lang.as_deref())
     );
 
     // Merge type definitions from normal <script> block so that
@@ -332,7 +343,7 @@ pub fn compile_sfc(
         let script = descriptor.script.as_ref().unwrap();
         profile!(
             "atelier.sfc.script_context.collect_normal_types",
-            ctx.collect_types_from(&script.content)
+            ctx.collect_types_from(&script.content, script.lang.as_deref())
         );
     }
     profile!(
@@ -383,7 +394,11 @@ pub fn compile_sfc(
         let script = descriptor.script.as_ref().unwrap();
         profile!(
             "atelier.sfc.normal_script.register_bindings",
-            register_normal_script_bindings(&script.content, &mut script_bindings)
+            register_normal_script_bindings(
+                &script.content,
+                script.lang.as_deref(),
+                &mut script_bindings
+            )
         );
     }
 
@@ -484,10 +499,14 @@ pub fn compile_sfc(
     // 3. Hoisted literal consts (module-level)
     // 4. export default { __name, props?, emits?, setup(__props) { ... return (_ctx, _cache) => { ... } } }
     // Detect if the source script setup uses TypeScript
-    let source_is_ts = script_setup
-        .lang
+    let source_is_ts = script_setup.is_typescript();
+    // Helper imports come from the same runtime module as the template's
+    let runtime_module = options
+        .template
+        .compiler_options
         .as_ref()
-        .is_some_and(|l| l == "ts" || l == "tsx");
+        .and_then(|opts| opts.runtime_module_name.as_deref())
+        .unwrap_or("vue");
 
     let script_result = profile!(
         "atelier.sfc.script_setup.inline_compile",
@@ -510,6 +529,7 @@ pub fn compile_sfc(
             normal_script_content.as_deref(),
             &descriptor.css_vars,
             &scope_id,
+            runtime_module,
         )
     )?;
 
@@ -526,6 +546,12 @@ pub fn compile_sfc(
         css = Some(all_css);
     }
 
+    if has_scoped {
+        attach_scope_id(&mut code, &scope_id);
+    }
+    attach_runtime_meta(&mut code, filename, &component_name, &options);
+    lower_to_script_target(&mut code, is_ts, script_lang, &options, &mut warnings);
+
     Ok(SfcCompileResult {
         code,
         css,
@@ -38,13 +38,24 @@ pub(super) fn extract_normal_script_content(
     );
 
     if !ret.errors.is_empty() {
-        // If parsing fails, return original content minus any obvious export default
-        return content
-            .lines()
-            .filter(|line| !line.trim().starts_with("export default"))
-            .collect::<Vec<_>>()
-            .join("\n")
-            .into();
+        // If parsing fails, still rewrite an obvious export default so its
+        // options are merged into the setup component
+        let mut fallback = String::with_capacity(content.len());
+        for (index, line) in content.lines().enumerate() {
+            if index > 0 {
+                fallback.push('\n');
+            }
+            let trimmed = line.trim_start();
+            match trimmed.strip_prefix("export default") {
+                Some(rest) if rest.starts_with(char::is_whitespace) => {
+                    fallback.push_str(&line[..line.len() - trimmed.len()]);
+                    fallback.push_str("const __default__ =");
+                    fallback.push_str(rest);
+                }
+                _ => fallback.push_str(line),
+            }
+        }
+        return fallback;
     }
 
     let program = ret.program;
@@ -108,10 +119,14 @@ pub(super) fn extract_normal_script_content(
         output.push_str(&content[last_end..]);
     }
 
-    let extracted = output.trim().to_compact_string();
+    let mut extracted = output.trim().to_compact_string();
 
     // If source is TypeScript and we need JavaScript output, transpile
     if source_is_ts && !output_is_ts {
+        // `only_remove_type_imports` below keeps `import { type Foo } from './types'`
+        // as a side-effect import; drop type-only imports up front.
+        extracted = crate::compile_script::strip_type_only_imports(&extracted);
+
         // Re-parse the extracted content
         let allocator2 = Allocator::default();
         let ret2 = profile!(
@@ -18,6 +18,43 @@ fn fixtures_path() -> PathBuf {
         .join("imported_types")
 }
 
+#[test]
+fn test_compile_sfc_output_is_deterministic() {
+    let source = r#"<script setup>
+const { zeta = 1, alpha = 2, mid = 'm' } = defineProps(['zeta', 'alpha', 'mid', 'other'])
+const emit = defineEmits(['change', 'update'])
+</script>
+
+<template>
+  <Child :zeta="zeta" :alpha="alpha" :mid="mid" v-model="other" v-focus v-show="alpha" @change="emit('change')">
+    <p v-if="zeta" v-tooltip:top.lazy="mid">{{ alpha }}</p>
+  </Child>
+</template>"#;
+
+    let compile = || {
+        let descriptor =
+            parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+        compile_sfc(&descriptor, SfcCompileOptions::default())
+            .expect("Failed to compile SFC")
+            .code
+    };
+
+    let expected = compile();
+    for _ in 0..100 {
+        assert_eq!(compile(), expected);
+    }
+
+    // mergeDefaults keeps the destructure's declaration order
+    let zeta = expected.find("zeta: 1").expect("zeta default");
+    let alpha = expected.find("alpha: 2").expect("alpha default");
+    let mid = expected.find("mid: 'm'").expect("mid default");
+    assert!(expected.contains("_mergeDefaults("));
+    assert!(
+        zeta < alpha && alpha < mid,
+        "defaults reordered:\n{expected}"
+    );
+}
+
 #[test]
 fn test_generate_scope_id() {
     let id = helpers::generate_scope_id("src/App.vue");
@@ -358,6 +395,23 @@ export default {
     insta::assert_snapshot!(result.as_str());
 }
 
+#[test]
+fn test_extract_normal_script_content_strips_type_imports_for_js() {
+    let input = r#"import type { NuxtRoute } from "@typed-router";
+import { type TabConfig } from "./tab-types";
+import Button, { type ButtonProps } from "./Button.vue";
+
+export default {
+  name: 'Tab'
+}
+"#;
+    let result = normal_script::extract_normal_script_content(input, true, false);
+    assert!(!result.contains("@typed-router"), "{result}");
+    assert!(!result.contains("./tab-types"), "{result}");
+    assert!(!result.contains("ButtonProps"), "{result}");
+    assert!(result.contains("./Button.vue"), "{result}");
+}
+
 #[test]
 fn test_compile_both_script_blocks() {
     let source = r#"<script lang="ts">
@@ -643,6 +697,66 @@ import { NuxtLayout, NuxtPage } from "#components"
     insta::assert_snapshot!(result.code.as_str());
 }
 
+#[test]
+fn test_script_setup_sfc_ssr_renders_async_component_binding() {
+    let source = r#"<script setup lang="ts">
+import { defineAsyncComponent } from 'vue'
+const AsyncComp = defineAsyncComponent(() => import('./AsyncComp.vue'))
+</script>
+
+<template>
+  <AsyncComp />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            is_ts: true,
+            ..Default::default()
+        },
+        template: TemplateCompileOptions {
+            is_ts: true,
+            ssr: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result
+        .code
+        .contains("_push(_ssrRenderComponent($setup.AsyncComp, null, null, _parent))"));
+    assert!(result
+        .code
+        .contains("defineAsyncComponent(() => import('./AsyncComp.vue'))"));
+    assert!(!result.code.contains("_resolveComponent"));
+}
+
+#[test]
+fn test_inline_template_use_template_ref_binding_is_ref() {
+    let source = r#"<script setup lang="ts">
+import { useTemplateRef } from 'vue'
+const inputEl = useTemplateRef<HTMLInputElement>('input')
+</script>
+
+<template>
+  <input ref="input" :data-ready="!!inputEl" />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result =
+        compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+
+    let bindings = result.bindings.as_ref().expect("bindings");
+    assert!(matches!(
+        bindings.bindings.get("inputEl"),
+        Some(BindingType::SetupRef)
+    ));
+    assert!(result.code.contains("ref: \"input\""), "{}", result.code);
+    assert!(result.code.contains("!!inputEl.value"), "{}", result.code);
+    assert!(!result.code.contains("_unref(inputEl)"), "{}", result.code);
+}
+
 #[test]
 fn test_normal_script_sfc_ssr_attaches_ssr_render() {
     let source = r#"<script lang="ts">
@@ -801,3 +915,461 @@ export default {
 
     insta::assert_snapshot!(result.code.as_str());
 }
+
+#[test]
+fn test_dev_injects_file_for_script_setup() {
+    let source = r#"<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("src/components/Foo.vue".to_compact_string()),
+            dev: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result.code.contains("const _sfc_main = {"));
+    assert!(result.code.contains("__name: 'Foo'"));
+    assert!(result
+        .code
+        .ends_with("_sfc_main.__file = \"src/components/Foo.vue\"\nexport default _sfc_main\n"));
+    assert_eq!(result.code.matches("export default").count(), 1);
+}
+
+#[test]
+fn test_dev_injects_name_and_file_for_normal_script() {
+    let source = r#"<script>
+export default { data: () => ({ n: 1 }) }
+</script>
+
+<template>
+  <div>{{ n }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("Bar.vue".to_compact_string()),
+            dev: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result.code.contains(
+        "_sfc_main.__name = \"Bar\"\n_sfc_main.__file = \"Bar.vue\"\nexport default _sfc_main"
+    ));
+}
+
+#[test]
+fn test_dev_injects_file_only_for_merged_normal_script() {
+    let source = r#"<script>
+export default { inheritAttrs: false }
+</script>
+
+<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("Baz.vue".to_compact_string()),
+            dev: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result
+        .code
+        .contains("const _sfc_main = /*@__PURE__*/Object.assign(__default__, "));
+    assert!(result.code.contains("__name: 'Baz'"));
+    assert!(!result.code.contains("_sfc_main.__name"));
+    assert!(result
+        .code
+        .ends_with("_sfc_main.__file = \"Baz.vue\"\nexport default _sfc_main\n"));
+}
+
+#[test]
+fn test_dev_info_skipped_in_production() {
+    let source = r#"<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("Foo.vue".to_compact_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(!result.code.contains("__file"));
+    assert!(!result.code.contains("_sfc_main"));
+}
+
+#[test]
+fn test_hmr_registers_record_and_accept_hook() {
+    let source = r#"<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div>{{ msg }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("Foo.vue".to_compact_string()),
+            dev: true,
+            ..Default::default()
+        },
+        scope_id: Some("abcd1234".to_compact_string()),
+        hmr: true,
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result.code.contains("_sfc_main.__hmrId = \"abcd1234\"\n"));
+    assert!(result
+        .code
+        .contains("__VUE_HMR_RUNTIME__.createRecord(_sfc_main.__hmrId, _sfc_main)"));
+    assert!(result.code.contains("import.meta.hot.accept(mod => {"));
+    assert!(result
+        .code
+        .contains("__VUE_HMR_RUNTIME__.reload(updated.__hmrId, updated)"));
+    assert!(!result.code.contains("export const _rerender_only"));
+    assert_eq!(result.code.matches("export default").count(), 1);
+    let record = result.code.find("__hmrId = ").unwrap();
+    let export = result.code.find("export default _sfc_main").unwrap();
+    assert!(record < export);
+}
+
+#[test]
+fn test_hmr_rerender_only_exports_flag() {
+    let source = r#"<script>
+export default { data: () => ({ n: 1 }) }
+</script>
+
+<template>
+  <div>{{ n }}</div>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        script: ScriptCompileOptions {
+            id: Some("Bar.vue".to_compact_string()),
+            ..Default::default()
+        },
+        hmr: true,
+        hmr_rerender_only: true,
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    let mut hmr_record = "_sfc_main.__hmrId = \"".to_compact_string();
+    hmr_record.push_str(&helpers::generate_scope_id("Bar.vue"));
+    hmr_record.push('"');
+    assert!(result.code.contains(hmr_record.as_str()));
+    assert!(result.code.contains("export const _rerender_only = true\n"));
+    assert!(result
+        .code
+        .contains("__VUE_HMR_RUNTIME__.rerender(updated.__hmrId, updated.render)"));
+}
+
+#[test]
+fn test_scoped_style_attaches_scope_id() {
+    let source = r#"<script setup>
+const msg = 'hi'
+</script>
+
+<template>
+  <div class="text">{{ msg }}</div>
+</template>
+
+<style scoped>
+.text { color: red; }
+</style>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        scope_id: Some("abcd1234".to_compact_string()),
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result
+        .code
+        .ends_with("_sfc_main.__scopeId = \"data-v-abcd1234\"\nexport default _sfc_main\n"));
+    assert!(result.css.unwrap().contains("[data-v-abcd1234]"));
+    assert_eq!(result.code.matches("export default").count(), 1);
+}
+
+#[test]
+fn test_unscoped_style_skips_scope_id() {
+    let source = r#"<script>
+export default { data: () => ({ n: 1 }) }
+</script>
+
+<template>
+  <div>{{ n }}</div>
+</template>
+
+<style>
+div { color: red; }
+</style>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result =
+        compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+
+    assert!(!result.code.contains("__scopeId"));
+}
+
+#[test]
+fn test_runtime_module_name_sets_helper_import_source() {
+    let source = r#"<script setup lang="ts">
+import { ref } from 'vue';
+
+const count = ref(0);
+</script>
+
+<template>
+  <button @click="count++">{{ count }}</button>
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let opts = SfcCompileOptions {
+        template: TemplateCompileOptions {
+            compiler_options: Some(vize_atelier_dom::DomCompilerOptions {
+                runtime_module_name: Some("@vue/runtime-dom".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = compile_sfc(&descriptor, opts).expect("Failed to compile SFC");
+
+    assert!(result
+        .code
+        .contains("import { defineComponent as _defineComponent } from '@vue/runtime-dom'"));
+    assert!(result
+        .code
+        .contains(" as _toDisplayString } from \"@vue/runtime-dom\""));
+    // User imports keep their original source
+    assert!(result.code.contains("import { ref } from 'vue'"));
+    assert!(!result.code.contains(" as _toDisplayString } from 'vue'"));
+    assert!(!result.code.contains(" as _toDisplayString } from \"vue\""));
+}
+
+#[test]
+fn test_script_target_lowers_js_output() {
+    let source = r#"<script setup lang="ts">
+const user: { name?: string } | undefined = undefined
+const name = user?.name ?? 'anonymous'
+</script>
+
+<template>
+  <div>{{ name }}</div>
+</template>"#;
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let compile = |target: &str| {
+        let opts = SfcCompileOptions {
+            script: ScriptCompileOptions {
+                target: Some(target.to_compact_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        compile_sfc(&descriptor, opts).expect("Failed to compile SFC")
+    };
+
+    let modern = compile("esnext");
+    assert!(modern.code.contains("user?.name ?? 'anonymous'"));
+
+    let lowered = compile("es2019");
+    assert!(lowered.warnings.is_empty());
+    assert!(!lowered.code.contains("?."));
+    assert!(!lowered.code.contains("??"));
+    // Lowered output is plain JavaScript
+    assert!(!lowered.code.contains("name?: string"));
+
+    let invalid = compile("es1999");
+    assert_eq!(invalid.code, modern.code);
+    assert_eq!(invalid.warnings.len(), 1);
+}
+
+#[test]
+fn test_normal_script_and_script_setup_imports_are_merged() {
+    let source = r#"<script>
+import { ref } from 'vue'
+import { helper } from './helper'
+export const shared = ref(0)
+export default { name: 'Foo', inheritAttrs: false }
+</script>
+
+<script setup>
+import { ref } from 'vue'
+import Child from './Child.vue'
+const count = ref(helper())
+</script>
+
+<template>
+  <Child :count="count" />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result =
+        compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+    let code = result.code.as_str();
+
+    assert!(result.errors.is_empty());
+    assert_eq!(code.matches("import { ref } from 'vue'").count(), 1);
+    assert!(code.contains("import { helper } from './helper'"));
+    assert!(code.contains("import Child from './Child.vue'"));
+
+    // The plain script body comes before the setup-produced component,
+    // with its default export merged into it
+    let shared = code.find("export const shared = ref(0)").unwrap();
+    let default = code
+        .find("const __default__ = { name: 'Foo', inheritAttrs: false }")
+        .unwrap();
+    let component = code.find("Object.assign(__default__, {").unwrap();
+    assert!(shared < component && default < component);
+    assert!(code.contains("setup(__props)"));
+}
+
+#[test]
+fn test_normal_script_options_merge_with_define_options() {
+    let source = r#"<script>
+import X from './X.vue'
+export default {
+  name: 'Foo',
+  components: { X },
+  directives: { focus: {} },
+}
+</script>
+
+<script setup>
+defineOptions({ inheritAttrs: false })
+const msg = 'hi'
+</script>
+
+<template>
+  <X :msg="msg" />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result =
+        compile_sfc(&descriptor, SfcCompileOptions::default()).expect("Failed to compile SFC");
+    let code = result.code.as_str();
+
+    assert!(result.errors.is_empty());
+    assert!(code.contains("components: { X }"));
+    assert!(code.contains("directives: { focus: {} }"));
+    // Both the plain script options and defineOptions are merged into the
+    // setup component instead of one replacing the other
+    assert!(code.contains("Object.assign(__default__, { inheritAttrs: false }, {"));
+    assert!(code.contains("setup(__props)"));
+}
+
+#[test]
+fn test_extract_normal_script_content_rewrites_default_on_parse_error() {
+    let input = "const broken = (\nexport default { name: 'Foo' }";
+    let result = normal_script::extract_normal_script_content(input, false, false);
+    assert!(result.contains("const __default__ = { name: 'Foo' }"));
+    assert!(!result.contains("export default"));
+}
+
+#[test]
+fn test_tsx_script_setup_keeps_jsx() {
+    let source = r#"<script setup lang="tsx">
+const msg: string = 'hi'
+const Greeting = () => <span class="greeting">{msg}</span>
+</script>
+
+<template>
+  <Greeting />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result = compile_sfc(&descriptor, SfcCompileOptions::default()).expect("compile");
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(
+        result
+            .code
+            .contains(r#"<span class="greeting">{msg}</span>"#),
+        "{}",
+        result.code
+    );
+    assert!(
+        !result.code.contains("_resolveComponent"),
+        "{}",
+        result.code
+    );
+    assert!(
+        result.code.contains("_createBlock(Greeting)"),
+        "{}",
+        result.code
+    );
+}
+
+#[test]
+fn test_jsx_script_setup_keeps_jsx() {
+    let source = r#"<script setup lang="jsx">
+import { ref } from 'vue'
+const count = ref(0)
+const Counter = () => <button onClick={() => count.value++}>{count.value}</button>
+</script>
+
+<template>
+  <Counter />
+</template>"#;
+
+    let descriptor = parse_sfc(source, SfcParseOptions::default()).expect("Failed to parse SFC");
+    let result = compile_sfc(&descriptor, SfcCompileOptions::default()).expect("compile");
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert!(
+        result
+            .code
+            .contains("<button onClick={() => count.value++}>{count.value}</button>"),
+        "{}",
+        result.code
+    );
+    assert!(
+        !result.code.contains("_resolveComponent"),
+        "{}",
+        result.code
+    );
+    assert!(
+        result.code.contains("_createBlock(Counter)"),
+        "{}",
+        result.code
+    );
+}
@@ -20,7 +20,9 @@ use self::typescript::transform_typescript_to_js;
 
 // Re-export commonly used items
 pub use self::function_mode::compile_script_setup as compile_script_setup_function_mode;
-pub use self::import_utils::{extract_import_identifiers, process_import_for_types};
+pub use self::import_utils::{
+    extract_import_identifiers, process_import_for_types, strip_type_only_imports,
+};
 pub use self::inline::compile_script_setup_inline;
 pub(crate) use self::inline::compile_script_setup_inline_with_context;
 pub use self::macros::{
@@ -83,7 +85,7 @@ pub fn compile_script(
         let final_code = if is_ts {
             code
         } else {
-            transform_typescript_to_js(&code)
+            transform_typescript_to_js(&code, script.lang.as_deref())
         };
         Ok(ScriptCompileResult {
             code: final_code,
@@ -39,20 +39,21 @@ pub fn compile_script_setup(
     // Check if we have props destructure
     let has_props_destructure = ctx.macros.props_destructure.is_some();
 
-    let (imports, setup_lines, _) = extract_script_sections(content, is_ts).unwrap_or_else(|| {
-        let setup_lines = content
-            .lines()
-            .filter_map(|line| {
-                let trimmed = line.trim();
-                if trimmed.is_empty() {
-                    None
-                } else {
-                    Some(line.to_compact_string())
-                }
-            })
-            .collect();
-        (Vec::new(), setup_lines, Vec::new())
-    });
+    let (imports, setup_lines, _) = extract_script_sections(content, is_ts, ctx.lang.as_deref())
+        .unwrap_or_else(|| {
+            let setup_lines = content
+                .lines()
+                .filter_map(|line| {
+                    let trimmed = line.trim();
+                    if trimmed.is_empty() {
+                        None
+                    } else {
+                        Some(line.to_compact_string())
+                    }
+                })
+                .collect();
+            (Vec::new(), setup_lines, Vec::new())
+        });
 
     // Check if we need PropType import (type-based defineProps in non-vapor TS mode)
     let needs_prop_type = is_ts
@@ -88,8 +89,12 @@ pub fn compile_script_setup(
         output.extend_from_slice(b"import { mergeDefaults as _mergeDefaults } from 'vue'\n");
     }
 
-    // Add useSlots import if defineSlots was used
-    let has_define_slots = ctx.macros.define_slots.is_some();
+    // Add useSlots import if defineSlots result is bound (a bare call is just removed)
+    let has_define_slots = ctx
+        .macros
+        .define_slots
+        .as_ref()
+        .is_some_and(|call| call.binding_name.is_some());
     if has_define_slots {
         output.extend_from_slice(b"import { useSlots as _useSlots } from 'vue'\n");
     }
@@ -223,7 +228,7 @@ pub fn compile_script_setup(
     }
 
     let transformed_setup: String = if let Some(ref destructure) = ctx.macros.props_destructure {
-        let result = transform_destructured_props(&setup_code, destructure);
+        let result = transform_destructured_props(&setup_code, destructure, ctx.lang.as_deref());
         #[cfg(debug_assertions)]
         eprintln!("[DEBUG] Setup code after transform:\n{}", result);
         result
@@ -293,7 +298,7 @@ pub fn compile_script_setup(
     let final_code: String = if is_ts {
         output_str.into()
     } else {
-        transform_typescript_to_js(&output_str)
+        transform_typescript_to_js(&output_str, ctx.lang.as_deref())
     };
 
     Ok(ScriptCompileResult {
@@ -355,7 +360,7 @@ fn emit_props_definition(
             output.extend_from_slice(b", {\n");
 
             // Add defaults
-            for (key, binding) in &destructure.bindings {
+            for (key, binding) in destructure.bindings.iter() {
                 if let Some(ref default_val) = binding.default {
                     output.extend_from_slice(b"  ");
                     output.extend_from_slice(key.as_bytes());
@@ -68,7 +68,7 @@ pub(crate) fn is_reserved_word(name: &str) -> bool {
 
 /// Collect runtime identifier references from setup code after stripping TypeScript syntax.
 pub(crate) fn collect_runtime_identifier_references(code: &str) -> FxHashSet<String> {
-    let runtime_code = transform_typescript_to_js(code);
+    let runtime_code = transform_typescript_to_js(code, None);
 
     let allocator = Allocator::default();
     let parser = Parser::new(&allocator, &runtime_code, SourceType::default());
@@ -15,8 +15,14 @@ use super::super::import_utils::process_import_for_types;
 /// Deduplicate imports by removing duplicate specifiers from the same source.
 /// This avoids "Identifier has already been declared" errors.
 pub fn dedupe_imports(imports: &[String], is_ts: bool) -> Vec<String> {
+    dedupe_imports_after(imports, is_ts, "")
+}
+
+/// Like [`dedupe_imports`], but also drops specifiers that `preceding` code
+/// (the normal `<script>` block) already imports from the same source.
+pub fn dedupe_imports_after(imports: &[String], is_ts: bool, preceding: &str) -> Vec<String> {
     let mut result: Vec<String> = Vec::new();
-    let mut seen_specifiers: FxHashSet<String> = FxHashSet::default();
+    let mut seen_specifiers = collect_import_keys(preceding);
 
     for import in imports {
         let processed = if is_ts {
@@ -194,3 +200,36 @@ pub fn dedupe_imports(imports: &[String], is_ts: bool) -> Vec<String> {
 
     result
 }
+
+/// Collect the `source::local` keys of every import declaration in `code`.
+fn collect_import_keys(code: &str) -> FxHashSet<String> {
+    let mut keys = FxHashSet::default();
+    if code.is_empty() {
+        return keys;
+    }
+
+    let allocator = Allocator::default();
+    let parse_result = Parser::new(&allocator, code, SourceType::ts()).parse();
+    for stmt in &parse_result.program.body {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            continue;
+        };
+        let source = decl.source.value.as_str();
+        let Some(specifiers) = &decl.specifiers else {
+            let mut key = String::with_capacity(source.len() + 13);
+            key.push_str(source);
+            key.push_str("::side-effect");
+            keys.insert(key);
+            continue;
+        };
+        for spec in specifiers {
+            let local = spec.local().name.as_str();
+            let mut key = String::with_capacity(source.len() + local.len() + 2);
+            key.push_str(source);
+            key.push_str("::");
+            key.push_str(local);
+            keys.insert(key);
+        }
+    }
+    keys
+}
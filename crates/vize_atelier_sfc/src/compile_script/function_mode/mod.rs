@@ -13,4 +13,4 @@ pub(crate) mod imports;
 
 pub use compiler::compile_script_setup;
 pub use helpers::contains_top_level_await;
-pub use imports::dedupe_imports;
+pub use imports::{dedupe_imports, dedupe_imports_after};
@@ -121,6 +121,47 @@ pub fn process_import_for_types(import: &str) -> Option<vize_carton::String> {
     Some(result)
 }
 
+/// Remove type-only imports from a TypeScript module that is emitted as JavaScript.
+///
+/// Drops `import type` declarations and inline `type` specifiers, and removes
+/// imports that end up without any specifiers, so no import of a types-only
+/// module survives as a side-effect import. Returns the code unchanged if it
+/// doesn't parse.
+pub fn strip_type_only_imports(code: &str) -> vize_carton::String {
+    let allocator = Allocator::default();
+    let result = Parser::new(&allocator, code, SourceType::ts()).parse();
+    if !result.errors.is_empty() {
+        return code.to_compact_string();
+    }
+
+    let mut output = vize_carton::String::with_capacity(code.len());
+    let mut last_end = 0;
+    for stmt in &result.program.body {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            continue;
+        };
+        let has_type_specifier = decl.specifiers.as_ref().is_some_and(|specifiers| {
+            specifiers.iter().any(|spec| match spec {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => s.import_kind.is_type(),
+                _ => false,
+            })
+        });
+        if !decl.import_kind.is_type() && !has_type_specifier {
+            continue;
+        }
+
+        let start = decl.span.start as usize;
+        let end = decl.span.end as usize;
+        output.push_str(&code[last_end..start]);
+        if let Some(value_import) = process_import_for_types(&code[start..end]) {
+            output.push_str(value_import.trim_end());
+        }
+        last_end = end;
+    }
+    output.push_str(&code[last_end..]);
+    output
+}
+
 /// Extract all identifiers from an import statement (including default imports)
 pub fn extract_import_identifiers(import: &str) -> Vec<vize_carton::String> {
     let import = import.trim();
@@ -167,7 +208,7 @@ pub fn extract_import_identifiers(import: &str) -> Vec<vize_carton::String> {
 
 #[cfg(test)]
 mod tests {
-    use super::process_import_for_types;
+    use super::{process_import_for_types, strip_type_only_imports};
 
     #[test]
     fn test_default_import_with_type_named_import() {
@@ -211,4 +252,24 @@ mod tests {
         let result = process_import_for_types(input);
         assert!(result.is_some(), "Normal import should be returned as-is");
     }
+
+    #[test]
+    fn test_strip_type_only_imports() {
+        let input = r#"import type { Foo } from './types'
+import { type Bar } from './bar-types'
+import Baz, { type Qux, useQux } from 'qux'
+import { ref } from 'vue'
+const count = ref<Foo>(0)
+"#;
+        let output = strip_type_only_imports(input);
+        assert_eq!(
+            output.as_str(),
+            r#"
+
+import Baz, { useQux } from 'qux'
+import { ref } from 'vue'
+const count = ref<Foo>(0)
+"#
+        );
+    }
 }
@@ -7,14 +7,13 @@ use std::borrow::Cow;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Expression, Statement};
-use oxc_parser::Parser;
-use oxc_span::{GetSpan, SourceType};
+use oxc_span::GetSpan;
 use vize_carton::{profile, String, ToCompactString};
 
-use crate::script::{transform_destructured_props, ScriptCompileContext};
+use crate::script::{parse_script_source, transform_destructured_props, ScriptCompileContext};
 use crate::types::SfcError;
 
-use super::super::function_mode::{contains_top_level_await, dedupe_imports};
+use super::super::function_mode::{contains_top_level_await, dedupe_imports_after};
 use super::super::macros::{
     is_macro_call_line, is_multiline_macro_start, is_paren_macro_start, is_props_destructure_line,
 };
@@ -56,6 +55,7 @@ pub fn compile_script_setup_inline(
         normal_script_content,
         css_vars,
         scope_id,
+        "vue",
     )
 }
 
@@ -71,12 +71,13 @@ pub(crate) fn compile_script_setup_inline_with_context(
     normal_script_content: Option<&str>,
     css_vars: &[Cow<'_, str>],
     scope_id: &str,
+    runtime_module: &str,
 ) -> Result<ScriptCompileResult, SfcError> {
     // Extract user imports and setup lines from script content once; await detection
     // and output assembly share the same split.
     let (user_imports, setup_lines, ts_declarations) = profile!(
         "atelier.script_inline.parse_sections",
-        parse_script_content(content, is_ts)
+        parse_script_content(content, is_ts, ctx.lang.as_deref())
     );
     let setup_code: String = setup_lines.join("\n").into();
 
@@ -111,8 +112,12 @@ pub(crate) fn compile_script_setup_inline_with_context(
     // Check if defineModel was used
     let has_define_model = !ctx.macros.define_models.is_empty();
 
-    // Check if defineSlots was used
-    let has_define_slots = ctx.macros.define_slots.is_some();
+    // Check if defineSlots result is bound (a bare call is just removed)
+    let has_define_slots = ctx
+        .macros
+        .define_slots
+        .as_ref()
+        .is_some_and(|call| call.binding_name.is_some());
     let needs_vapor_setup_context = is_vapor && !template.render_fn.is_empty();
     let vapor_render_alias = needs_vapor_setup_context
         .then(|| build_vapor_render_alias(content, normal_script_content, template.render_fn));
@@ -122,21 +127,29 @@ pub(crate) fn compile_script_setup_inline_with_context(
     if is_async {
         if is_vapor {
             if needs_vapor_setup_context {
-                output.extend_from_slice(
-                    b"import { withAsyncContext as _withAsyncContext, defineVaporComponent as _defineVaporComponent, getCurrentInstance as _getCurrentInstance, proxyRefs as _proxyRefs } from 'vue'\n",
+                push_runtime_import(
+                    &mut output,
+                    "withAsyncContext as _withAsyncContext, defineVaporComponent as _defineVaporComponent, getCurrentInstance as _getCurrentInstance, proxyRefs as _proxyRefs",
+                    runtime_module,
                 );
             } else {
-                output.extend_from_slice(
-                    b"import { withAsyncContext as _withAsyncContext, defineVaporComponent as _defineVaporComponent } from 'vue'\n",
+                push_runtime_import(
+                    &mut output,
+                    "withAsyncContext as _withAsyncContext, defineVaporComponent as _defineVaporComponent",
+                    runtime_module,
                 );
             }
         } else if is_ts {
-            output.extend_from_slice(
-                b"import { withAsyncContext as _withAsyncContext, defineComponent as _defineComponent } from 'vue'\n",
+            push_runtime_import(
+                &mut output,
+                "withAsyncContext as _withAsyncContext, defineComponent as _defineComponent",
+                runtime_module,
             );
         } else {
-            output.extend_from_slice(
-                b"import { withAsyncContext as _withAsyncContext } from 'vue'\n",
+            push_runtime_import(
+                &mut output,
+                "withAsyncContext as _withAsyncContext",
+                runtime_module,
             );
         }
     }
@@ -161,9 +174,19 @@ pub(crate) fn compile_script_setup_inline_with_context(
         needs_vapor_setup_context,
         vapor_render_alias,
         is_async,
+        runtime_module,
     )
 }
 
+/// Emit a compiler helper import from the runtime module.
+fn push_runtime_import(output: &mut vize_carton::Vec<u8>, specifiers: &str, runtime_module: &str) {
+    output.extend_from_slice(b"import { ");
+    output.extend_from_slice(specifiers.as_bytes());
+    output.extend_from_slice(b" } from '");
+    output.extend_from_slice(runtime_module.as_bytes());
+    output.extend_from_slice(b"'\n");
+}
+
 fn build_script_setup_context(
     content: &str,
     normal_script_content: Option<&str>,
@@ -180,7 +203,7 @@ fn build_script_setup_context(
         if !normal_src.is_empty() {
             profile!(
                 "atelier.script_inline.collect_normal_types",
-                ctx.collect_types_from(normal_src)
+                ctx.collect_types_from(normal_src, None)
             );
         }
     }
@@ -223,27 +246,34 @@ fn compile_script_setup_inline_body(
     needs_vapor_setup_context: bool,
     vapor_render_alias: Option<String>,
     is_async: bool,
+    runtime_module: &str,
 ) -> Result<ScriptCompileResult, SfcError> {
     // mergeDefaults import comes first if needed
     if needs_merge_defaults {
-        output.extend_from_slice(b"import { mergeDefaults as _mergeDefaults } from 'vue'\n");
+        push_runtime_import(
+            &mut output,
+            "mergeDefaults as _mergeDefaults",
+            runtime_module,
+        );
     }
 
     // useSlots import if defineSlots was used
     if has_define_slots {
-        output.extend_from_slice(b"import { useSlots as _useSlots } from 'vue'\n");
+        push_runtime_import(&mut output, "useSlots as _useSlots", runtime_module);
     }
 
     // useModel import if defineModel was used
     if has_define_model {
-        output.extend_from_slice(b"import { useModel as _useModel } from 'vue'\n");
+        push_runtime_import(&mut output, "useModel as _useModel", runtime_module);
     }
 
     // useCssVars import if style has v-bind()
     let has_css_vars = !css_vars.is_empty();
     if has_css_vars {
-        output.extend_from_slice(
-            b"import { useCssVars as _useCssVars, unref as _unref } from 'vue'\n",
+        push_runtime_import(
+            &mut output,
+            "useCssVars as _useCssVars, unref as _unref",
+            runtime_module,
         );
     }
 
@@ -254,16 +284,24 @@ fn compile_script_setup_inline_body(
     // Component helper import (skip if already emitted with withAsyncContext)
     if is_vapor && !is_async {
         if needs_vapor_setup_context {
-            output.extend_from_slice(
-                b"import { defineVaporComponent as _defineVaporComponent, getCurrentInstance as _getCurrentInstance, proxyRefs as _proxyRefs } from 'vue'\n",
+            push_runtime_import(
+                &mut output,
+                "defineVaporComponent as _defineVaporComponent, getCurrentInstance as _getCurrentInstance, proxyRefs as _proxyRefs",
+                runtime_module,
             );
         } else {
-            output.extend_from_slice(
-                b"import { defineVaporComponent as _defineVaporComponent } from 'vue'\n",
+            push_runtime_import(
+                &mut output,
+                "defineVaporComponent as _defineVaporComponent",
+                runtime_module,
             );
         }
     } else if is_ts && !is_async {
-        output.extend_from_slice(b"import { defineComponent as _defineComponent } from 'vue'\n");
+        push_runtime_import(
+            &mut output,
+            "defineComponent as _defineComponent",
+            runtime_module,
+        );
     }
 
     // Template imports (Vue helpers)
@@ -290,10 +328,15 @@ fn compile_script_setup_inline_body(
         }
     }
 
-    // User imports (after hoisted consts) - deduplicate to avoid "already declared" errors
+    // User imports (after hoisted consts) - deduplicate to avoid "already declared" errors,
+    // including specifiers the normal <script> block already imports
     let deduped_imports = profile!(
         "atelier.script_inline.dedupe_imports",
-        dedupe_imports(&user_imports, is_ts)
+        dedupe_imports_after(
+            &user_imports,
+            is_ts,
+            preserved_normal_script.as_deref().unwrap_or_default()
+        )
     );
     for import in &deduped_imports {
         output.extend_from_slice(import.as_bytes());
@@ -347,7 +390,7 @@ fn compile_script_setup_inline_body(
     let transformed_setup: String = if let Some(ref destructure) = ctx.macros.props_destructure {
         profile!(
             "atelier.script_inline.transform_props_destructure",
-            transform_destructured_props(&setup_code, destructure)
+            transform_destructured_props(&setup_code, destructure, ctx.lang.as_deref())
         )
     } else {
         setup_code
@@ -380,8 +423,10 @@ fn compile_script_setup_inline_body(
         .is_some_and(|emits| emits.binding_name.is_some());
     let has_expose = ctx.macros.define_expose.is_some();
 
-    if has_options {
-        // Use Object.assign for defineOptions
+    if has_options || has_default_export {
+        // Merge the normal script's default export (rewritten to __default__) and
+        // defineOptions into the setup component, in that order, so options such as
+        // `name` or `components` declared in either place survive
         if is_vapor {
             output.extend_from_slice(
                 b"export default /*@__PURE__*/_defineVaporComponent(Object.assign(",
@@ -389,19 +434,14 @@ fn compile_script_setup_inline_body(
         } else {
             output.extend_from_slice(b"export default /*@__PURE__*/Object.assign(");
         }
-        let options_args = ctx.macros.define_options.as_ref().unwrap().args.trim();
-        output.extend_from_slice(options_args.as_bytes());
-        output.extend_from_slice(b", {\n");
-    } else if has_default_export {
-        // Normal script has export default that was rewritten to __default__
-        // Use Object.assign to merge with setup component
-        if is_vapor {
-            output.extend_from_slice(
-                b"export default /*@__PURE__*/_defineVaporComponent(Object.assign(__default__, {\n",
-            );
-        } else {
-            output.extend_from_slice(b"export default /*@__PURE__*/Object.assign(__default__, {\n");
+        if has_default_export {
+            output.extend_from_slice(b"__default__, ");
         }
+        if let Some(options) = ctx.macros.define_options.as_ref() {
+            output.extend_from_slice(options.args.trim().as_bytes());
+            output.extend_from_slice(b", ");
+        }
+        output.extend_from_slice(b"{\n");
     } else if is_vapor {
         output.extend_from_slice(b"export default /*@__PURE__*/_defineVaporComponent({\n");
     } else if is_ts {
@@ -531,7 +571,7 @@ fn compile_script_setup_inline_body(
     if is_async {
         let transformed_async = profile!(
             "atelier.script_inline.transform_await",
-            transform_await_expressions(&setup_body_lines, source_is_ts)
+            transform_await_expressions(&setup_body_lines, ctx.lang.as_deref())
         );
         for line in &transformed_async {
             output.extend_from_slice(line.as_bytes());
@@ -615,7 +655,7 @@ fn compile_script_setup_inline_body(
         // Source is TypeScript but output should be JavaScript - transform to strip TS syntax
         profile!(
             "atelier.script_inline.ts_to_js",
-            transform_typescript_to_js(&output_str)
+            transform_typescript_to_js(&output_str, ctx.lang.as_deref())
         )
     };
 
@@ -785,7 +825,8 @@ fn append_usize(target: &mut String, value: usize) {
 fn collect_setup_bindings(ctx: &ScriptCompileContext) -> Vec<&str> {
     use crate::types::BindingType;
 
-    ctx.bindings
+    let mut bindings: Vec<&str> = ctx
+        .bindings
         .bindings
         .iter()
         .filter(|(_, bt)| {
@@ -800,14 +841,21 @@ fn collect_setup_bindings(ctx: &ScriptCompileContext) -> Vec<&str> {
             )
         })
         .map(|(name, _)| name.as_str())
-        .collect()
+        .collect();
+    // Binding metadata is hash-backed; sort so the emitted object is stable.
+    bindings.sort_unstable();
+    bindings
 }
 
 /// Parse script content to extract imports, setup lines, and TypeScript declarations.
 ///
 /// Returns a tuple of (user_imports, setup_lines, ts_declarations).
-fn parse_script_content(content: &str, is_ts: bool) -> (Vec<String>, Vec<String>, Vec<String>) {
-    if let Some(sections) = extract_script_sections(content, is_ts) {
+fn parse_script_content(
+    content: &str,
+    is_ts: bool,
+    lang: Option<&str>,
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    if let Some(sections) = extract_script_sections(content, is_ts, lang) {
         return sections;
     }
 
@@ -1691,7 +1739,7 @@ fn separate_hoisted_consts(
 /// Handles two patterns:
 /// 1. `const x = await expr` → `const x = (\n  ([__temp,__restore] = _withAsyncContext(() => expr)),\n  __temp = await __temp,\n  __restore(),\n  __temp\n)`
 /// 2. `await expr` (statement) → `;(\n  ([__temp,__restore] = _withAsyncContext(() => expr)),\n  await __temp,\n  __restore()\n)`
-fn transform_await_expressions(lines: &[String], is_ts: bool) -> Vec<String> {
+fn transform_await_expressions(lines: &[String], lang: Option<&str>) -> Vec<String> {
     let mut source = String::default();
     for (idx, line) in lines.iter().enumerate() {
         if idx > 0 {
@@ -1700,7 +1748,7 @@ fn transform_await_expressions(lines: &[String], is_ts: bool) -> Vec<String> {
         source.push_str(line);
     }
 
-    transform_await_source(&source, is_ts)
+    transform_await_source(&source, lang)
         .lines()
         .map(|line| line.to_compact_string())
         .collect()
@@ -1709,7 +1757,7 @@ fn transform_await_expressions(lines: &[String], is_ts: bool) -> Vec<String> {
 const AWAIT_WRAP_PREFIX: &str = "async function __vize_async_setup__() {\n";
 const AWAIT_WRAP_SUFFIX: &str = "\n}";
 
-fn transform_await_source(source: &str, is_ts: bool) -> String {
+fn transform_await_source(source: &str, lang: Option<&str>) -> String {
     if source.trim().is_empty() {
         return source.to_compact_string();
     }
@@ -1721,8 +1769,7 @@ fn transform_await_source(source: &str, is_ts: bool) -> String {
     wrapped.push_str(AWAIT_WRAP_SUFFIX);
 
     let allocator = Allocator::default();
-    let source_type = SourceType::default().with_typescript(is_ts);
-    let parse_result = Parser::new(&allocator, &wrapped, source_type).parse();
+    let parse_result = parse_script_source(&allocator, &wrapped, lang);
     if !parse_result.errors.is_empty() {
         return source.to_compact_string();
     }
@@ -393,6 +393,22 @@ const hasDefault = !!slots.default
         insta::assert_snapshot!(output.as_str());
     }
 
+    #[test]
+    fn test_define_slots_without_binding_is_removed_ts() {
+        let content = r#"
+defineSlots<{
+  default(props: { msg: string }): any
+}>()
+
+const msg = 'hello'
+"#;
+        let output = compile_setup_ts(content);
+        assert!(!output.contains("defineSlots"), "{output}");
+        assert!(!output.contains("useSlots"), "{output}");
+        assert!(!output.contains("msg: string"), "{output}");
+        assert!(output.contains("const msg = "), "{output}");
+    }
+
     #[test]
     fn test_multiline_conditional_type_ts() {
         // Multi-line conditional type with ? and : continuation markers
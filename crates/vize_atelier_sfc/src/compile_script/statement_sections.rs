@@ -5,12 +5,13 @@
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Declaration, Expression, Statement};
-use oxc_parser::Parser;
-use oxc_span::{GetSpan, SourceType};
+use oxc_span::GetSpan;
 
 use vize_carton::{String, ToCompactString};
 use vize_croquis::macros::is_builtin_macro;
 
+use crate::script::parse_script_source;
+
 enum StatementBucket {
     Import,
     TypeDeclaration,
@@ -21,10 +22,10 @@ enum StatementBucket {
 pub(crate) fn extract_script_sections(
     content: &str,
     is_ts: bool,
+    lang: Option<&str>,
 ) -> Option<(Vec<String>, Vec<String>, Vec<String>)> {
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
-    let ret = Parser::new(&allocator, content, source_type).parse();
+    let ret = parse_script_source(&allocator, content, lang);
 
     if ret.panicked {
         return None;
@@ -194,7 +195,7 @@ const count = 1
 "#;
 
         let (_, setup_lines, ts_declarations) =
-            extract_script_sections(content, true).expect("sections should parse");
+            extract_script_sections(content, true, Some("ts")).expect("sections should parse");
 
         insta::assert_debug_snapshot!((&setup_lines, &ts_declarations));
     }
@@ -211,7 +212,7 @@ const store = useStore<RootState>()
 "#;
 
         let (_, _, ts_declarations) =
-            extract_script_sections(content, true).expect("sections should parse");
+            extract_script_sections(content, true, Some("ts")).expect("sections should parse");
 
         assert_eq!(ts_declarations.len(), 1);
         assert_eq!(
@@ -348,6 +348,24 @@ const itemCount = computed(() => items.length)
         insta::assert_snapshot!(result.code.as_str());
     }
 
+    #[test]
+    fn test_props_destructure_defaults_keep_declaration_order() {
+        let content = r#"
+const { zeta = 1, alpha = 2, mid = 3 } = defineProps<{
+  zeta?: number
+  alpha?: number
+  mid?: number
+}>()
+"#;
+        let result = compile_script_setup(content, "Test", false, false, None).unwrap();
+        let code = result.code.as_str();
+
+        let zeta = code.find("zeta: 1").expect("zeta default");
+        let alpha = code.find("alpha: 2").expect("alpha default");
+        let mid = code.find("mid: 3").expect("mid default");
+        assert!(zeta < alpha && alpha < mid, "defaults reordered:\n{code}");
+    }
+
     #[test]
     fn test_extract_prop_types() {
         let type_args = r#"{
@@ -483,7 +501,7 @@ defineExpose()
     return x.toString();
 }
 const foo: string = "bar";"#;
-        let result = transform_typescript_to_js(ts_code);
+        let result = transform_typescript_to_js(ts_code, Some("ts"));
         insta::assert_snapshot!(result.as_str());
     }
 
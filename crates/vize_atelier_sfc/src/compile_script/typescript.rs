@@ -7,15 +7,22 @@ use oxc_codegen::Codegen;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_transformer::{TransformOptions, Transformer, TypeScriptOptions};
-use vize_carton::{profile, String, ToCompactString};
+use oxc_transformer::{JsxOptions, TransformOptions, Transformer, TypeScriptOptions};
+use vize_carton::{cstr, profile, String, ToCompactString};
+
+use super::import_utils::strip_type_only_imports;
+use crate::script::parse_script_source;
+use vize_croquis::script_parser::script_source_type;
 
 /// Transform TypeScript code to JavaScript using OXC
-pub fn transform_typescript_to_js(code: &str) -> String {
+///
+/// `lang` is the script block's `lang` attribute.
+pub fn transform_typescript_to_js(code: &str, lang: Option<&str>) -> String {
     let allocator = Allocator::default();
-    let source_type = SourceType::ts();
-    let parser = Parser::new(&allocator, code, source_type);
-    let parse_result = profile!("atelier.script.ts.parse", parser.parse());
+    let parse_result = profile!(
+        "atelier.script.ts.parse",
+        parse_script_source(&allocator, code, lang)
+    );
 
     if !parse_result.errors.is_empty() {
         // If parsing fails, return original code
@@ -33,19 +40,22 @@ pub fn transform_typescript_to_js(code: &str) -> String {
     );
 
     if !semantic_ret.errors.is_empty() {
-        // If semantic analysis fails, return original code
-        return code.to_compact_string();
+        // If semantic analysis fails, return original code (minus type-only imports,
+        // which would fail to resolve at runtime)
+        return strip_type_only_imports(code);
     }
 
     let scoping = semantic_ret.semantic.into_scoping();
 
     // Transform TypeScript to JavaScript
     // Strip all TypeScript syntax including type parameters (generics)
+    // JSX is left as-is for a Vue JSX plugin downstream
     let transform_options = TransformOptions {
         typescript: TypeScriptOptions {
             only_remove_type_imports: false,
             ..Default::default()
         },
+        jsx: JsxOptions::disable(),
         ..Default::default()
     };
     let ret = profile!(
@@ -55,8 +65,8 @@ pub fn transform_typescript_to_js(code: &str) -> String {
     );
 
     if !ret.errors.is_empty() {
-        // If transformation fails, return original code
-        return code.to_compact_string();
+        // If transformation fails, return original code (minus type-only imports)
+        return strip_type_only_imports(code);
     }
 
     // Generate JavaScript code
@@ -66,3 +76,65 @@ pub fn transform_typescript_to_js(code: &str) -> String {
         .replace('\t', "  ")
         .into()
 }
+
+/// Lower script syntax to an ECMAScript target such as `es2015`.
+///
+/// TypeScript input (`is_ts`) comes out as JavaScript, and JSX from
+/// `lang="jsx"`/`"tsx"` scripts is kept. `esnext` returns the code unchanged.
+/// Errors carry a message for an unknown target or for code OXC could not
+/// parse or transform.
+pub fn lower_to_target(
+    code: &str,
+    target: &str,
+    is_ts: bool,
+    lang: Option<&str>,
+) -> Result<String, String> {
+    if target.eq_ignore_ascii_case("esnext") {
+        return Ok(code.to_compact_string());
+    }
+    let mut transform_options = TransformOptions::from_target(target)
+        .map_err(|e| cstr!("Invalid script target `{target}`: {e}"))?;
+    transform_options.jsx = JsxOptions::disable();
+
+    let allocator = Allocator::default();
+    let source_type = if is_ts {
+        SourceType::ts()
+    } else {
+        SourceType::mjs()
+    }
+    .with_jsx(script_source_type(lang).is_jsx());
+    let parse_result = profile!(
+        "atelier.script.lower.parse",
+        Parser::new(&allocator, code, source_type).parse()
+    );
+    if let Some(error) = parse_result.errors.first() {
+        return Err(cstr!("Failed to parse script for `{target}`: {error}"));
+    }
+    let mut program = parse_result.program;
+
+    let semantic_ret = profile!(
+        "atelier.script.lower.semantic",
+        SemanticBuilder::new()
+            .with_excess_capacity(2.0)
+            .build(&program)
+    );
+    if let Some(error) = semantic_ret.errors.first() {
+        return Err(cstr!("Failed to analyze script for `{target}`: {error}"));
+    }
+    let scoping = semantic_ret.semantic.into_scoping();
+
+    let ret = profile!(
+        "atelier.script.lower.transform",
+        Transformer::new(&allocator, std::path::Path::new(""), &transform_options)
+            .build_with_scoping(scoping, &mut program)
+    );
+    if let Some(error) = ret.errors.first() {
+        return Err(cstr!("Failed to lower script to `{target}`: {error}"));
+    }
+
+    let output = profile!(
+        "atelier.script.lower.codegen",
+        Codegen::new().build(&program)
+    );
+    Ok(output.code.replace('\t', "  ").into())
+}
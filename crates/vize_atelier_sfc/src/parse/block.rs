@@ -1,6 +1,6 @@
 use memchr::memchr;
 use std::borrow::Cow;
-use vize_carton::FxHashMap;
+use vize_carton::OrderedMap;
 
 // Static closing tags for fast comparison (avoid format!)
 const CLOSING_SCRIPT: &[u8] = b"</script>";
@@ -43,14 +43,14 @@ pub(super) fn parse_block_fast<'a>(
     start: usize,
     start_line: usize,
 ) -> Option<(
-    &'a [u8],                              // tag name as bytes
-    FxHashMap<Cow<'a, str>, Cow<'a, str>>, // attrs with borrowed strings
-    Cow<'a, str>,                          // content as borrowed string
-    usize,                                 // content start
-    usize,                                 // content end
-    usize,                                 // end position
-    usize,                                 // end line
-    usize,                                 // end column
+    &'a [u8],                               // tag name as bytes
+    OrderedMap<Cow<'a, str>, Cow<'a, str>>, // attrs with borrowed strings
+    Cow<'a, str>,                           // content as borrowed string
+    usize,                                  // content start
+    usize,                                  // content end
+    usize,                                  // end position
+    usize,                                  // end line
+    usize,                                  // end column
 )> {
     let len = bytes.len();
 
@@ -73,7 +73,7 @@ pub(super) fn parse_block_fast<'a>(
     let tag_name = &source.as_bytes()[tag_start..pos];
 
     // Parse attributes with zero-copy
-    let mut attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>> = FxHashMap::default();
+    let mut attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>> = OrderedMap::new();
 
     while pos < len && bytes[pos] != b'>' {
         // Skip whitespace
@@ -493,10 +493,10 @@ fn find_custom_block_end<'a>(
     mut pos: usize,
     content_start: usize,
     start_line: usize,
-    attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>>,
 ) -> Option<(
     &'a [u8],
-    FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    OrderedMap<Cow<'a, str>, Cow<'a, str>>,
     Cow<'a, str>,
     usize,
     usize,
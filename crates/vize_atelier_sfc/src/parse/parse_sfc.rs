@@ -200,5 +200,11 @@ pub fn parse_sfc<'a>(
         }
     }
 
+    descriptor.is_typescript = descriptor
+        .script
+        .iter()
+        .chain(&descriptor.script_setup)
+        .any(SfcScriptBlock::is_typescript);
+
     Ok(descriptor)
 }
@@ -29,6 +29,27 @@ fn test_parse_with_lang_attr() {
     assert_eq!(script.lang.as_deref(), Some("ts"));
 }
 
+#[test]
+fn test_typescript_detection() {
+    let source = r#"<script>export default {}</script>
+<script setup lang="tsx">const x = 1</script>
+<template><div /></template>
+<style lang="scss">.a {}</style>"#;
+    let result = parse_sfc(source, Default::default()).unwrap();
+
+    assert!(result.is_typescript);
+    assert_eq!(result.script.as_ref().unwrap().effective_lang(), "js");
+    assert_eq!(
+        result.script_setup.as_ref().unwrap().effective_lang(),
+        "tsx"
+    );
+    assert_eq!(result.template.as_ref().unwrap().effective_lang(), "html");
+    assert_eq!(result.styles[0].effective_lang(), "scss");
+
+    let result = parse_sfc("<script setup>const x = 1</script>", Default::default()).unwrap();
+    assert!(!result.is_typescript);
+}
+
 #[test]
 fn test_parse_multiple_styles() {
     let source = r#"
@@ -56,6 +77,19 @@ fn test_parse_custom_block() {
     assert_eq!(result.custom_blocks[0].block_type, "i18n");
 }
 
+#[test]
+fn test_parse_custom_block_attrs_keep_source_order() {
+    let source = r#"
+<i18n locale="en" lang="yaml" global>hello: Hello</i18n>
+"#;
+    let result = parse_sfc(source, Default::default()).unwrap();
+
+    let attrs = &result.custom_blocks[0].attrs;
+    let keys: Vec<&str> = attrs.keys().map(|k| k.as_ref()).collect();
+    assert_eq!(keys, ["locale", "lang", "global"]);
+    assert_eq!(attrs.get("lang").map(|v| v.as_ref()), Some("yaml"));
+}
+
 #[test]
 fn test_parse_script_setup() {
     let source = r#"
@@ -40,6 +40,7 @@ pub use utils::{
     get_escaped_prop_name, is_compiler_macro_line, is_valid_identifier, MacroCall,
     ScriptSetupMacros,
 };
+pub(crate) use vize_croquis::script_parser::parse_script_source;
 
 // Re-export constants
 pub use define_emits::DEFINE_EMITS;
@@ -90,9 +91,9 @@ pub fn analyze_script_setup_fast(content: &str) -> ScriptParseResult {
 /// Analyze script setup and return a croquis Croquis.
 ///
 /// This uses vize_croquis for the core analysis and converts
-/// the result to the shared Croquis format.
-pub fn analyze_script_setup_to_summary(content: &str) -> CroquisSummary {
-    let result = vize_croquis::script_parser::parse_script_setup(content);
+/// the result to the shared Croquis format. `lang` is the block's `lang` attribute.
+pub fn analyze_script_setup_to_summary(content: &str, lang: Option<&str>) -> CroquisSummary {
+    let result = vize_croquis::script_parser::parse_script_setup_with_lang(content, None, lang);
 
     let mut summary = CroquisSummary::new();
 
@@ -11,22 +11,20 @@ use oxc_ast::ast::{
     ArrayExpression, ArrayExpressionElement, ExportDefaultDeclarationKind, Expression,
     ObjectExpression, ObjectPropertyKind, PropertyKey, Statement,
 };
-use oxc_parser::Parser;
-use oxc_span::SourceType;
 
 use vize_carton::{CompactString, ToCompactString};
 
+use super::parse_script_source;
 use crate::types::{BindingMetadata, BindingType};
 
 /// Analyze bindings in normal `<script>` block
 ///
 /// This analyzes the default export object to extract binding information
 /// from props, inject, computed, methods, setup, and data options.
-pub fn analyze_script_bindings(source: &str) -> BindingMetadata {
+/// `lang` is the block's `lang` attribute.
+pub fn analyze_script_bindings(source: &str, lang: Option<&str>) -> BindingMetadata {
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
-
-    let ret = Parser::new(&allocator, source, source_type).parse();
+    let ret = parse_script_source(&allocator, source, lang);
 
     if ret.panicked {
         return BindingMetadata::default();
@@ -212,7 +210,7 @@ mod tests {
                 props: ['foo', 'bar']
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(bindings.bindings.get("foo"), Some(&BindingType::Props));
         assert_eq!(bindings.bindings.get("bar"), Some(&BindingType::Props));
     }
@@ -227,7 +225,7 @@ mod tests {
                 }
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(bindings.bindings.get("foo"), Some(&BindingType::Props));
         assert_eq!(bindings.bindings.get("bar"), Some(&BindingType::Props));
     }
@@ -239,7 +237,7 @@ mod tests {
                 inject: ['service', 'store']
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(
             bindings.bindings.get("service"),
             Some(&BindingType::Options)
@@ -257,7 +255,7 @@ mod tests {
                 }
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(
             bindings.bindings.get("doubled"),
             Some(&BindingType::Options)
@@ -275,7 +273,7 @@ mod tests {
                 }
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(
             bindings.bindings.get("handleClick"),
             Some(&BindingType::Options)
@@ -298,7 +296,7 @@ mod tests {
                 }
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(bindings.bindings.get("count"), Some(&BindingType::Data));
         assert_eq!(bindings.bindings.get("name"), Some(&BindingType::Data));
     }
@@ -315,7 +313,7 @@ mod tests {
                 }
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert_eq!(
             bindings.bindings.get("count"),
             Some(&BindingType::SetupMaybeRef)
@@ -333,7 +331,7 @@ mod tests {
                 props: ['foo']
             }
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert!(!bindings.is_script_setup);
     }
 
@@ -342,7 +340,7 @@ mod tests {
         let source = r#"
             export default {}
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert!(bindings.bindings.is_empty());
     }
 
@@ -351,7 +349,7 @@ mod tests {
         let source = r#"
             const foo = 'bar'
         "#;
-        let bindings = analyze_script_bindings(source);
+        let bindings = analyze_script_bindings(source, None);
         assert!(bindings.bindings.is_empty());
     }
 }
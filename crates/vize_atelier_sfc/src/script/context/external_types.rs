@@ -97,7 +97,8 @@ impl ScriptCompileContext {
                 continue;
             }
 
-            self.collect_types_from(&content);
+            let lang = resolved_path.extension().and_then(|ext| ext.to_str());
+            self.collect_types_from(&content, lang);
             self.collect_imported_types_recursive(&content, &resolved_path, visited);
         }
     }
@@ -113,12 +114,12 @@ impl ScriptCompileContext {
         };
 
         if let Some(ref script) = descriptor.script {
-            self.collect_types_from(&script.content);
+            self.collect_types_from(&script.content, script.lang.as_deref());
             self.collect_imported_types_recursive(&script.content, path, visited);
         }
 
         if let Some(ref script_setup) = descriptor.script_setup {
-            self.collect_types_from(&script_setup.content);
+            self.collect_types_from(&script_setup.content, script_setup.lang.as_deref());
             self.collect_imported_types_recursive(&script_setup.content, path, visited);
         }
     }
@@ -21,6 +21,9 @@ pub struct ScriptCompileContext {
     /// Source content
     pub source: String,
 
+    /// `lang` attribute of the script setup block
+    pub lang: Option<String>,
+
     /// Binding metadata
     pub bindings: BindingMetadata,
 
@@ -69,6 +72,7 @@ impl ScriptCompileContext {
     pub fn new(source: &str) -> Self {
         Self {
             source: source.to_compact_string(),
+            lang: None,
             bindings: BindingMetadata::default(),
             macros: ScriptSetupMacros::default(),
             has_define_props_call: false,
@@ -85,6 +89,14 @@ impl ScriptCompileContext {
         }
     }
 
+    /// Set the `lang` attribute of the script setup block.
+    ///
+    /// `lang="jsx"`/`"tsx"` blocks are parsed with JSX enabled.
+    pub fn with_lang(mut self, lang: Option<&str>) -> Self {
+        self.lang = lang.map(String::from);
+        self
+    }
+
     /// Analyze script setup and extract bindings
     pub fn analyze(&mut self) {
         // Temporarily take ownership of source to avoid borrow conflicts
@@ -5,8 +5,7 @@
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Argument, BindingPattern, Expression, Statement, VariableDeclarationKind};
-use oxc_parser::Parser;
-use oxc_span::{GetSpan, SourceType};
+use oxc_span::GetSpan;
 
 use vize_carton::{profile, String, ToCompactString};
 
@@ -19,17 +18,16 @@ use super::helpers::{
     infer_binding_type, is_call_of, is_import_type_only,
 };
 use super::ScriptCompileContext;
-use crate::script::build_interface_type_source;
+use crate::script::{build_interface_type_source, parse_script_source};
 
 impl ScriptCompileContext {
     /// Parse the source with OXC and extract information
     pub(super) fn parse_with_oxc(&mut self, source: &str) {
         let allocator = Allocator::default();
-        let source_type = SourceType::from_path("script.ts").unwrap_or_default();
 
         let ret = profile!(
             "atelier.script.context.oxc_parse",
-            Parser::new(&allocator, source, source_type).parse()
+            parse_script_source(&allocator, source, self.lang.as_deref())
         );
 
         if ret.panicked {
@@ -107,12 +105,12 @@ impl ScriptCompileContext {
     /// Collect type definitions (interfaces and type aliases) from additional source.
     /// Used to merge types from the normal `<script>` block into the context
     /// so that `defineProps<TypeRef>()` can resolve type references across blocks.
-    pub fn collect_types_from(&mut self, source: &str) {
+    /// `lang` is the `lang` attribute (or file extension) of that source.
+    pub fn collect_types_from(&mut self, source: &str, lang: Option<&str>) {
         let allocator = Allocator::default();
-        let source_type = SourceType::from_path("script.ts").unwrap_or_default();
         let ret = profile!(
             "atelier.script.context.collect_types_parse",
-            Parser::new(&allocator, source, source_type).parse()
+            parse_script_source(&allocator, source, lang)
         );
         if ret.panicked {
             return;
@@ -12,7 +12,7 @@ mod process;
 mod tests;
 mod transform;
 
-use vize_carton::{FxHashMap, String};
+use vize_carton::{OrderedMap, String};
 
 /// Props destructure binding info
 #[derive(Debug, Clone)]
@@ -26,8 +26,8 @@ pub struct PropsDestructureBinding {
 /// Props destructure bindings data
 #[derive(Debug, Clone, Default)]
 pub struct PropsDestructuredBindings {
-    /// Map of prop key -> binding info
-    pub bindings: FxHashMap<String, PropsDestructureBinding>,
+    /// Prop key -> binding info, in declaration order
+    pub bindings: OrderedMap<String, PropsDestructureBinding>,
     /// Rest spread identifier (if any)
     pub rest_id: Option<String>,
 }
@@ -32,7 +32,7 @@ mod tests {
     fn test_transform_simple() {
         let bindings = make_bindings(&["msg"]);
         let source = "console.log(msg)";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -42,7 +42,7 @@ mod tests {
 
         // msg is shadowed by the arrow function parameter
         let source = "const fn = (msg) => console.log(msg)";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -52,7 +52,7 @@ mod tests {
 
         // count inside computed arrow function should be rewritten
         let source = "const double = computed(() => count * 2)";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         assert_eq!(result, "const double = computed(() => __props.count * 2)");
     }
 
@@ -61,7 +61,7 @@ mod tests {
         let bindings = make_bindings(&["foo", "bar"]);
 
         let source = "const result = foo + bar";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -75,7 +75,7 @@ mod tests {
         let source = r#"function double() {
     return count * 2
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -88,7 +88,7 @@ mod tests {
     const inner = () => msg
     return inner()
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -100,7 +100,7 @@ mod tests {
         let source = r#"const obj = {
     getCount: function() { return count }
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -111,7 +111,7 @@ mod tests {
         let source = r#"watch(() => count, (newVal) => {
     console.log(newVal)
 })"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -123,7 +123,7 @@ mod tests {
         let source = r#"for (const item of items) {
     console.log(item)
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -135,7 +135,7 @@ mod tests {
         let source = r#"for (const key in obj) {
     console.log(key)
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -149,7 +149,7 @@ mod tests {
 } catch (error) {
     console.log(error)
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -162,7 +162,7 @@ mod tests {
     const doubled = count * 2
     console.log(doubled)
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -175,7 +175,7 @@ mod tests {
     const count = 10
     console.log(count)
 }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -184,7 +184,7 @@ mod tests {
         let bindings = make_bindings(&["name"]);
 
         let source = "const greeting = `Hello, ${name}!`";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -193,7 +193,7 @@ mod tests {
         let bindings = make_bindings(&["show"]);
 
         let source = "const display = show ? 'visible' : 'hidden'";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -202,7 +202,7 @@ mod tests {
         let bindings = make_bindings(&["enabled", "active"]);
 
         let source = "const isOn = enabled && active";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -211,7 +211,7 @@ mod tests {
         let bindings = make_bindings(&["foo"]);
 
         let source = "const obj = { foo }";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -220,7 +220,7 @@ mod tests {
         let bindings = make_bindings(&["items"]);
 
         let source = "const first = items[0]";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -229,7 +229,7 @@ mod tests {
         let bindings = make_bindings(&["user"]);
 
         let source = "const name = user.name";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -238,7 +238,7 @@ mod tests {
         let bindings = make_bindings(&["data"]);
 
         let source = "const value = data.nested.value";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -247,7 +247,7 @@ mod tests {
         let bindings = make_bindings(&["count"]);
 
         let source = "doSomething(count, 'test')";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -258,7 +258,7 @@ mod tests {
         let source = r#"console.log(msg)
 const double = count * 2
 return { msg, count }"#;
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -268,7 +268,7 @@ return { msg, count }"#;
 
         // msg as property key should NOT be rewritten
         let source = "const obj = { msg: 'hello' }";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -278,7 +278,7 @@ return { msg, count }"#;
 
         // .name should NOT be rewritten (it's property access, not reference)
         let source = "const userName = user.name";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -295,7 +295,7 @@ return { msg, count }"#;
         );
 
         let source = "console.log(msg)";
-        let result = transform_destructured_props(source, &bindings);
+        let result = transform_destructured_props(source, &bindings, None);
         insta::assert_snapshot!(result.as_str());
     }
 
@@ -313,7 +313,7 @@ return { msg, count }"#;
         fn test_basic_usage() {
             let bindings = make_bindings(&["foo"]);
             let source = "console.log(foo)";
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -324,7 +324,7 @@ return { msg, count }"#;
     console.log(foo)
     console.log(bar)
 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -333,7 +333,7 @@ return { msg, count }"#;
             let bindings = make_bindings(&["foo"]);
             let source = r#"const bar = 'fish', hello = 'world'
 console.log(foo)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -346,7 +346,7 @@ console.log(foo)"#;
     }
 }
 console.log(value)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -369,7 +369,7 @@ console.log(value)"#;
             );
             let source = r#"let a = x
 let b = y"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -378,7 +378,7 @@ let b = y"#;
             let bindings = make_bindings(&["count"]);
             let source = r#"const double = computed(() => count * 2)
 const triple = computed(function() { return count * 3 })"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -388,7 +388,7 @@ const triple = computed(function() { return count * 3 })"#;
             let source = r#"watch(() => count, (newVal, oldVal) => {
     console.log('changed from', oldVal, 'to', newVal)
 })"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -396,7 +396,7 @@ const triple = computed(function() { return count * 3 })"#;
         fn test_template_literal() {
             let bindings = make_bindings(&["name", "age"]);
             let source = r#"const greeting = `Hello ${name}, you are ${age} years old`"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -404,7 +404,7 @@ const triple = computed(function() { return count * 3 })"#;
         fn test_object_shorthand_props() {
             let bindings = make_bindings(&["foo", "bar"]);
             let source = r#"const obj = { foo, bar, baz: 123 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -418,7 +418,7 @@ const triple = computed(function() { return count * 3 })"#;
     })
     return result
 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -434,7 +434,7 @@ for (const item of items) {
 for (const index in items) {
     console.log(index)
 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -448,7 +448,7 @@ for (const index in items) {
 } finally {
     console.log(error)
 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -457,7 +457,7 @@ for (const index in items) {
             let bindings = make_bindings(&["show", "msg"]);
             let source = r#"const display = show ? msg : 'hidden'
 const result = show && msg || 'default'"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -467,7 +467,7 @@ const result = show && msg || 'default'"#;
             let source = r#"const name = user.profile.name
 const email = user?.contact?.email
 const id = user['id']"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -477,7 +477,7 @@ const id = user['id']"#;
             let source = r#"const filtered = items.filter(x => x > 0)
 const mapped = items.map(x => x * 2)
 const reduced = items.reduce((acc, x) => acc + x, 0)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -486,7 +486,7 @@ const reduced = items.reduce((acc, x) => acc + x, 0)"#;
             let bindings = make_bindings(&["data"]);
             let source = r#"const fn = ({ x, y }) => x + y
 console.log(data)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -503,7 +503,7 @@ console.log(data)"#;
     default:
         console.log(value)
 }"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -516,7 +516,7 @@ console.log(data)"#;
 do {
     console.log(count)
 } while (count > 0)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -540,7 +540,7 @@ do {
 })
 const rebloggedBy = computed(() => props.status.reblog ? props.status.account : null)
 console.log(actions)"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
 
@@ -560,7 +560,7 @@ console.log(actions)"#;
 
             let source = r#"console.log(rest)
 const b = rest"#;
-            let result = transform_destructured_props(source, &bindings);
+            let result = transform_destructured_props(source, &bindings, None);
             insta::assert_snapshot!(result);
         }
     }
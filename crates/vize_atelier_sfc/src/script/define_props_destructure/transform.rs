@@ -5,20 +5,21 @@
 //! with a text-based fallback.
 
 use oxc_allocator::Allocator;
-use oxc_parser::Parser;
-use oxc_span::SourceType;
 use vize_carton::FxHashMap;
 
 use super::collector::collect_identifier_rewrites;
 use super::helpers::{transform_props_text_based, PROPS_REST_SENTINEL};
 use super::PropsDestructuredBindings;
+use crate::script::parse_script_source;
 use vize_carton::{profile, String, ToCompactString};
 
 /// Transform destructured props references in source code.
 /// Rewrites `foo` to `__props.foo` for destructured props.
+/// `lang` is the script block's `lang` attribute.
 pub fn transform_destructured_props(
     source: &str,
     destructured: &PropsDestructuredBindings,
+    lang: Option<&str>,
 ) -> String {
     if destructured.is_empty() {
         return source.to_compact_string();
@@ -39,10 +40,9 @@ pub fn transform_destructured_props(
 
     // Try AST-based transformation first
     let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
     let ret = profile!(
         "atelier.props_destructure.parse",
-        Parser::new(&allocator, source, source_type).parse()
+        parse_script_source(&allocator, source, lang)
     );
 
     if !ret.panicked {
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use vize_carton::{FxHashMap, String};
+use vize_carton::{OrderedMap, String};
 
 // Re-export from vize_relief to avoid duplication
 pub use vize_atelier_core::options::{BindingMetadata, BindingType};
@@ -48,6 +48,10 @@ pub struct SfcDescriptor<'a> {
     /// Whether the component should inherit attrs
     #[serde(default)]
     pub should_force_reload: bool,
+
+    /// Whether any script block is TypeScript (`lang="ts"` or `lang="tsx"`)
+    #[serde(default)]
+    pub is_typescript: bool,
 }
 
 impl<'a> Default for SfcDescriptor<'a> {
@@ -63,6 +67,7 @@ impl<'a> Default for SfcDescriptor<'a> {
             css_vars: Vec::new(),
             slotted: false,
             should_force_reload: false,
+            is_typescript: false,
         }
     }
 }
@@ -89,6 +94,7 @@ impl<'a> SfcDescriptor<'a> {
                 .collect(),
             slotted: self.slotted,
             should_force_reload: self.should_force_reload,
+            is_typescript: self.is_typescript,
         }
     }
 
@@ -156,10 +162,15 @@ pub struct SfcTemplateBlock<'a> {
 
     /// Additional attributes
     #[serde(default)]
-    pub attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    pub attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>>,
 }
 
 impl<'a> SfcTemplateBlock<'a> {
+    /// Template language, defaulting to `html` when `lang` is absent.
+    pub fn effective_lang(&self) -> &str {
+        self.lang.as_deref().unwrap_or("html")
+    }
+
     /// Convert to owned version
     pub fn into_owned(self) -> SfcTemplateBlock<'static> {
         SfcTemplateBlock {
@@ -201,7 +212,7 @@ pub struct SfcScriptBlock<'a> {
 
     /// Additional attributes
     #[serde(default)]
-    pub attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    pub attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>>,
 
     /// Binding metadata (filled after analysis)
     #[serde(default)]
@@ -209,6 +220,16 @@ pub struct SfcScriptBlock<'a> {
 }
 
 impl<'a> SfcScriptBlock<'a> {
+    /// Script language, defaulting to `js` when `lang` is absent.
+    pub fn effective_lang(&self) -> &str {
+        self.lang.as_deref().unwrap_or("js")
+    }
+
+    /// Whether the block is TypeScript (`lang="ts"` or `lang="tsx"`).
+    pub fn is_typescript(&self) -> bool {
+        matches!(self.effective_lang(), "ts" | "tsx")
+    }
+
     /// Convert to owned version
     pub fn into_owned(self) -> SfcScriptBlock<'static> {
         SfcScriptBlock {
@@ -256,10 +277,15 @@ pub struct SfcStyleBlock<'a> {
 
     /// Additional attributes
     #[serde(default)]
-    pub attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    pub attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>>,
 }
 
 impl<'a> SfcStyleBlock<'a> {
+    /// Style language, defaulting to `css` when `lang` is absent.
+    pub fn effective_lang(&self) -> &str {
+        self.lang.as_deref().unwrap_or("css")
+    }
+
     /// Convert to owned version
     pub fn into_owned(self) -> SfcStyleBlock<'static> {
         SfcStyleBlock {
@@ -295,7 +321,7 @@ pub struct SfcCustomBlock<'a> {
 
     /// Additional attributes
     #[serde(default)]
-    pub attrs: FxHashMap<Cow<'a, str>, Cow<'a, str>>,
+    pub attrs: OrderedMap<Cow<'a, str>, Cow<'a, str>>,
 }
 
 impl<'a> SfcCustomBlock<'a> {
@@ -397,6 +423,14 @@ pub struct SfcCompileOptions {
     /// When provided, this scope ID is used instead of generating one from the filename.
     /// This ensures consistency with the JS-side scope ID generation (SHA-256).
     pub scope_id: Option<String>,
+
+    /// Emit HMR boilerplate (`__hmrId` record and `import.meta.hot.accept` hook).
+    /// The HMR id is the scope ID when provided, otherwise derived from the filename.
+    pub hmr: bool,
+
+    /// Only the template changed since the last compile, so the accept hook can
+    /// `rerender` instead of `reload`.
+    pub hmr_rerender_only: bool,
 }
 
 /// Script compile options
@@ -419,6 +453,16 @@ pub struct ScriptCompileOptions {
 
     /// Define model options
     pub define_model: bool,
+
+    /// Development mode: attach `__file` to the exported component for better
+    /// devtools display, plus `__name` for components without `<script setup>`
+    /// (whose output declares it already)
+    pub dev: bool,
+
+    /// ECMAScript target for the output (e.g. `es2015`). Newer syntax is
+    /// lowered and TypeScript is stripped; `None` or `esnext` keeps the output
+    /// as written.
+    pub target: Option<String>,
 }
 
 /// Props destructure mode
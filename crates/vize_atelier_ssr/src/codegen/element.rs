@@ -1,6 +1,8 @@
 //! Element, component, and slot processing for SSR code generation.
 
-use vize_atelier_core::ast::{ElementNode, ElementType, RuntimeHelper};
+use vize_atelier_core::ast::{
+    ElementNode, ElementType, ExpressionNode, PropNode, RuntimeHelper, TemplateChildNode,
+};
 use vize_carton::{String, ToCompactString};
 
 use super::{helpers::escape_html_attr, SsrCodegenContext};
@@ -13,6 +15,12 @@ impl<'a> SsrCodegenContext<'a> {
             ElementType::Element => {
                 self.process_plain_element(el);
             }
+            ElementType::Component if is_suspense_tag(&el.tag) => {
+                self.process_suspense(el);
+            }
+            ElementType::Component if is_teleport_tag(&el.tag) => {
+                self.process_teleport(el);
+            }
             ElementType::Component => {
                 self.process_component(el, disable_nested_fragments);
             }
@@ -62,10 +70,10 @@ impl<'a> SsrCodegenContext<'a> {
 
     /// Process element attributes
     fn process_element_attrs(&mut self, el: &ElementNode) {
-        use vize_atelier_core::ast::PropNode;
-
         for prop in &el.props {
             match prop {
+                // `key` and `ref` only matter to the client vnode tree
+                PropNode::Attribute(attr) if is_vnode_only_attr(&attr.name) => {}
                 PropNode::Attribute(attr) => {
                     self.push_string_part_static(" ");
                     self.push_string_part_static(&attr.name);
@@ -121,8 +129,6 @@ impl<'a> SsrCodegenContext<'a> {
         _el: &ElementNode,
         dir: &vize_atelier_core::ast::DirectiveNode,
     ) {
-        use vize_atelier_core::ast::ExpressionNode;
-
         // Get the argument (attribute name)
         let arg_name = match &dir.arg {
             Some(ExpressionNode::Simple(simple)) if simple.is_static => {
@@ -142,6 +148,7 @@ impl<'a> SsrCodegenContext<'a> {
         };
 
         match arg_name.as_deref() {
+            Some(name) if is_vnode_only_attr(name) => {}
             Some("class") => {
                 self.use_ssr_helper(RuntimeHelper::SsrRenderClass);
                 self.push_string_part_dynamic(&cstr!("_ssrRenderClass({exp})"));
@@ -170,8 +177,6 @@ impl<'a> SsrCodegenContext<'a> {
         el: &ElementNode,
         dir: &vize_atelier_core::ast::DirectiveNode,
     ) {
-        use vize_atelier_core::ast::ExpressionNode;
-
         let exp = match &dir.exp {
             Some(ExpressionNode::Simple(simple)) => simple.content.as_str(),
             _ => return,
@@ -225,8 +230,6 @@ impl<'a> SsrCodegenContext<'a> {
         _el: &ElementNode,
         dir: &vize_atelier_core::ast::DirectiveNode,
     ) {
-        use vize_atelier_core::ast::ExpressionNode;
-
         let exp = match &dir.exp {
             Some(ExpressionNode::Simple(simple)) => simple.content.as_str(),
             _ => return,
@@ -254,8 +257,6 @@ impl<'a> SsrCodegenContext<'a> {
 
     /// Get an attribute value from an element
     pub(crate) fn get_element_attr_value(&self, el: &ElementNode, name: &str) -> Option<String> {
-        use vize_atelier_core::ast::PropNode;
-
         for prop in &el.props {
             if let PropNode::Attribute(attr) = prop {
                 if attr.name == name {
@@ -267,6 +268,10 @@ impl<'a> SsrCodegenContext<'a> {
     }
 
     /// Process a component
+    ///
+    /// Async components (`defineAsyncComponent`) need no special handling:
+    /// `ssrRenderComponent` returns a promise for them and `_push` buffers it
+    /// until the server renderer resolves it.
     fn process_component(&mut self, el: &ElementNode, _disable_nested_fragments: bool) {
         self.flush_push();
         self.use_ssr_helper(RuntimeHelper::SsrRenderComponent);
@@ -336,6 +341,124 @@ impl<'a> SsrCodegenContext<'a> {
         self.push("))\n");
     }
 
+    /// Process a `<Suspense>` boundary
+    ///
+    /// Each slot becomes a render callback. `ssrRenderSuspense` renders the
+    /// default content on the server; the fallback is left for the client.
+    fn process_suspense(&mut self, el: &ElementNode) {
+        self.flush_push();
+        self.use_ssr_helper(RuntimeHelper::SsrRenderSuspense);
+
+        self.push_indent();
+        self.push("_ssrRenderSuspense(_push, {\n");
+        self.indent_level += 1;
+
+        let mut slots = std::vec::Vec::new();
+        for child in el.children.iter() {
+            if let TemplateChildNode::Element(template) = child {
+                if let Some(name) = slot_template_name(template) {
+                    slots.push((name, template.children.as_slice()));
+                }
+            }
+        }
+        if slots.is_empty() && !el.children.is_empty() {
+            slots.push(("default".to_compact_string(), el.children.as_slice()));
+        }
+
+        for (name, children) in slots {
+            self.push_indent();
+            self.push(&name);
+            self.push(": () => {\n");
+            self.indent_level += 1;
+
+            let old_parts = std::mem::take(&mut self.current_template_parts);
+            self.process_children(children, false, false, false);
+            self.flush_push();
+            self.current_template_parts = old_parts;
+
+            self.indent_level -= 1;
+            self.push_indent();
+            self.push("},\n");
+        }
+
+        self.push_indent();
+        self.push("_: 1 /* STABLE */\n");
+        self.indent_level -= 1;
+        self.push_indent();
+        self.push("})\n");
+    }
+
+    /// Process a `<Teleport>`
+    ///
+    /// The content is rendered by a callback that `ssrRenderTeleport` pushes
+    /// into the teleport buffer for `to`, or inline when `disabled` is truthy.
+    /// Like `@vue/compiler-ssr`, a teleport without a target renders nothing.
+    fn process_teleport(&mut self, el: &ElementNode) {
+        let mut target = None;
+        let mut disabled = None;
+        for prop in el.props.iter() {
+            match prop {
+                PropNode::Attribute(attr) if attr.name == "to" => {
+                    target = attr
+                        .value
+                        .as_ref()
+                        .map(|value| TeleportProp::Static(value.content.as_str()));
+                }
+                PropNode::Attribute(attr) if attr.name == "disabled" => {
+                    disabled = Some(TeleportProp::Static("true"));
+                }
+                PropNode::Directive(dir) if dir.name == "bind" => {
+                    let exp = match (&dir.arg, &dir.exp) {
+                        (Some(ExpressionNode::Simple(arg)), Some(exp)) if arg.is_static => {
+                            Some((arg.content.as_str(), exp))
+                        }
+                        _ => None,
+                    };
+                    match exp {
+                        Some(("to", exp)) => target = Some(TeleportProp::Dynamic(exp)),
+                        Some(("disabled", exp)) => disabled = Some(TeleportProp::Dynamic(exp)),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        let Some(target) = target else {
+            return;
+        };
+
+        self.flush_push();
+        self.use_ssr_helper(RuntimeHelper::SsrRenderTeleport);
+
+        self.push_indent();
+        self.push("_ssrRenderTeleport(_push, (_push) => {\n");
+        self.indent_level += 1;
+
+        let old_parts = std::mem::take(&mut self.current_template_parts);
+        self.process_children(&el.children, false, false, false);
+        self.flush_push();
+        self.current_template_parts = old_parts;
+
+        self.indent_level -= 1;
+        self.push_indent();
+        self.push("}, ");
+        match target {
+            TeleportProp::Static(to) => {
+                self.push("\"");
+                self.push(&to.replace('\\', "\\\\").replace('"', "\\\""));
+                self.push("\"");
+            }
+            TeleportProp::Dynamic(exp) => self.push_expression(exp),
+        }
+        self.push(", ");
+        match disabled {
+            Some(TeleportProp::Static(value)) => self.push(value),
+            Some(TeleportProp::Dynamic(exp)) => self.push_expression(exp),
+            None => self.push("false"),
+        }
+        self.push(", _parent)\n");
+    }
+
     /// Process a slot outlet (<slot>)
     fn process_slot_outlet(&mut self, el: &ElementNode) {
         self.flush_push();
@@ -382,8 +505,6 @@ impl<'a> SsrCodegenContext<'a> {
 
     /// Get the name of a slot
     fn get_slot_name(&self, el: &ElementNode) -> String {
-        use vize_atelier_core::ast::{ExpressionNode, PropNode};
-
         for prop in &el.props {
             if let PropNode::Directive(dir) = prop {
                 if dir.name == "bind" {
@@ -406,3 +527,38 @@ impl<'a> SsrCodegenContext<'a> {
         "default".to_compact_string()
     }
 }
+
+/// Whether the tag is the built-in `<Suspense>` component
+fn is_suspense_tag(tag: &str) -> bool {
+    tag == "Suspense" || tag == "suspense"
+}
+
+/// Whether the tag is the built-in `<Teleport>` component
+fn is_teleport_tag(tag: &str) -> bool {
+    tag == "Teleport" || tag == "teleport"
+}
+
+/// Whether the attribute is a vnode-only prop that is never rendered to HTML
+fn is_vnode_only_attr(name: &str) -> bool {
+    name == "key" || name == "ref"
+}
+
+/// A `<Teleport>` prop value: attribute text or bound expression
+enum TeleportProp<'n, 'a> {
+    Static(&'n str),
+    Dynamic(&'n ExpressionNode<'a>),
+}
+
+/// Static slot name of a `<template v-slot:name>` / `<template #name>` element
+fn slot_template_name(el: &ElementNode) -> Option<String> {
+    if el.tag != "template" {
+        return None;
+    }
+    el.props.iter().find_map(|prop| match prop {
+        PropNode::Directive(dir) if dir.name == "slot" => Some(match &dir.arg {
+            Some(ExpressionNode::Simple(arg)) if arg.is_static => arg.content.to_compact_string(),
+            _ => "default".to_compact_string(),
+        }),
+        _ => None,
+    })
+}
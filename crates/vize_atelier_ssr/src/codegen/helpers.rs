@@ -1,7 +1,8 @@
 //! HTML escaping utilities and child/control-flow processing for SSR codegen.
 
 use vize_atelier_core::ast::{
-    CommentNode, ForNode, IfNode, InterpolationNode, RuntimeHelper, TemplateChildNode, TextNode,
+    CommentNode, ElementType, ForNode, IfBranchNode, IfNode, InterpolationNode, RuntimeHelper,
+    TemplateChildNode, TextNode,
 };
 
 use super::SsrCodegenContext;
@@ -129,8 +130,10 @@ impl<'a> SsrCodegenContext<'a> {
 
             self.indent_level += 1;
 
-            // Check if branch needs fragment
-            let needs_fragment = !disable_nested_fragments && branch.children.len() > 1;
+            // Match the client's anchor strategy: anything other than a single
+            // element (or a lone v-for, which brings its own markers) renders as a
+            // fragment, including empty `<template v-if>` branches.
+            let needs_fragment = !disable_nested_fragments && branch_needs_fragment(branch);
 
             self.process_children(
                 &branch.children,
@@ -143,8 +146,8 @@ impl<'a> SsrCodegenContext<'a> {
             self.indent_level -= 1;
         }
 
-        // If no else branch, emit empty comment
-        if if_node.branches.iter().all(|b| b.condition.is_some()) {
+        // If no else branch, emit the empty comment the client hydrates against
+        if !disable_comment && if_node.branches.iter().all(|b| b.condition.is_some()) {
             self.push_indent();
             self.push("} else {\n");
             self.indent_level += 1;
@@ -193,8 +196,9 @@ impl<'a> SsrCodegenContext<'a> {
         self.push(") => {\n");
         self.indent_level += 1;
 
-        // Process for body
-        let needs_fragment = !disable_nested_fragments && for_node.children.len() > 1;
+        // Process for body; each item is wrapped in its own fragment
+        // unless it renders exactly one element
+        let needs_fragment = !disable_nested_fragments && for_item_needs_fragment(for_node);
         self.process_children(&for_node.children, needs_fragment, true, false);
         self.flush_push();
 
@@ -236,6 +240,33 @@ impl<'a> SsrCodegenContext<'a> {
     }
 }
 
+/// Whether a v-if branch renders as a fragment (`<!--[-->...<!--]-->`).
+fn branch_needs_fragment(branch: &IfBranchNode) -> bool {
+    let children = match branch.children.first() {
+        Some(TemplateChildNode::Element(el))
+            if branch.is_template_if && el.tag_type == ElementType::Template =>
+        {
+            &el.children[..]
+        }
+        _ => &branch.children[..],
+    };
+    !matches!(
+        children,
+        [TemplateChildNode::Element(_)] | [TemplateChildNode::For(_)]
+    )
+}
+
+/// Whether each v-for item renders as a fragment (`<!--[-->...<!--]-->`).
+fn for_item_needs_fragment(for_node: &ForNode) -> bool {
+    let children = match &for_node.children[..] {
+        [TemplateChildNode::Element(el)] if el.tag_type == ElementType::Template => {
+            &el.children[..]
+        }
+        children => children,
+    };
+    !matches!(children, [TemplateChildNode::Element(_)])
+}
+
 /// Escape HTML special characters
 pub(crate) fn escape_html(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
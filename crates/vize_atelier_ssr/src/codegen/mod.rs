@@ -8,7 +8,7 @@ pub(crate) mod helpers;
 
 use crate::options::SsrCompilerOptions;
 use vize_atelier_core::ast::{RootNode, RuntimeHelper, TemplateChildNode};
-use vize_carton::{cstr, Bump, FxHashSet, String, ToCompactString};
+use vize_carton::{Bump, FxHashSet, String, ToCompactString};
 
 /// SSR codegen result
 #[derive(Debug, Default)]
@@ -17,6 +17,8 @@ pub struct SsrCodegenResult {
     pub code: String,
     /// Import preamble
     pub preamble: String,
+    /// Names of the `vue/server-renderer` helpers used by the code, sorted
+    pub helpers: Vec<String>,
 }
 
 /// A part of a template literal
@@ -104,12 +106,15 @@ impl<'a> SsrCodegenContext<'a> {
         self.push("}\n");
 
         // Build preamble with imports
-        let preamble = self.build_preamble();
+        let ssr_helpers = sorted_helpers(&self.ssr_helpers);
+        let core_helpers = sorted_helpers(&self.core_helpers);
+        let preamble = build_preamble(&core_helpers, &ssr_helpers);
 
         SsrCodegenResult {
             // SAFETY: We only push valid UTF-8 strings
             code: unsafe { String::from_utf8_unchecked(self.code) },
             preamble,
+            helpers: ssr_helpers.iter().map(|h| h.name().into()).collect(),
         }
     }
 
@@ -187,37 +192,41 @@ impl<'a> SsrCodegenContext<'a> {
             self.code.extend_from_slice(b"  ");
         }
     }
+}
 
-    /// Build the preamble with imports
-    fn build_preamble(&self) -> String {
-        let mut preamble = String::default();
+/// Collect helpers in a stable order for output.
+fn sorted_helpers(helpers: &FxHashSet<RuntimeHelper>) -> Vec<RuntimeHelper> {
+    let mut helpers: Vec<_> = helpers.iter().copied().collect();
+    helpers.sort();
+    helpers
+}
 
-        // SSR helpers from @vue/server-renderer
-        if !self.ssr_helpers.is_empty() {
-            preamble.push_str("import { ");
-            let helpers: Vec<_> = self
-                .ssr_helpers
-                .iter()
-                .map(|h| cstr!("{} as _{}", h.name(), h.name()))
-                .collect();
-            preamble.push_str(&helpers.join(", "));
-            preamble.push_str(" } from \"@vue/server-renderer\"\n");
-        }
+/// Build the preamble importing core helpers from `vue` and SSR helpers from
+/// `vue/server-renderer`.
+fn build_preamble(core_helpers: &[RuntimeHelper], ssr_helpers: &[RuntimeHelper]) -> String {
+    let mut preamble = String::default();
+    push_import(&mut preamble, core_helpers, "vue");
+    push_import(&mut preamble, ssr_helpers, "vue/server-renderer");
+    preamble
+}
 
-        // Core helpers from vue
-        if !self.core_helpers.is_empty() {
-            preamble.push_str("import { ");
-            let helpers: Vec<_> = self
-                .core_helpers
-                .iter()
-                .map(|h| cstr!("{} as _{}", h.name(), h.name()))
-                .collect();
-            preamble.push_str(&helpers.join(", "));
-            preamble.push_str(" } from \"vue\"\n");
-        }
+fn push_import(preamble: &mut String, helpers: &[RuntimeHelper], module: &str) {
+    if helpers.is_empty() {
+        return;
+    }
 
-        preamble
+    preamble.push_str("import { ");
+    for (i, h) in helpers.iter().enumerate() {
+        if i > 0 {
+            preamble.push_str(", ");
+        }
+        preamble.push_str(h.name());
+        preamble.push_str(" as _");
+        preamble.push_str(h.name());
     }
+    preamble.push_str(" } from \"");
+    preamble.push_str(module);
+    preamble.push_str("\"\n");
 }
 
 #[cfg(test)]
@@ -270,5 +279,6 @@ mod tests {
         let result = SsrCodegenResult::default();
         assert!(result.code.is_empty());
         assert!(result.preamble.is_empty());
+        assert!(result.helpers.is_empty());
     }
 }
@@ -39,7 +39,7 @@ use vize_atelier_core::{
     parser::parse_with_options,
     transform::transform as do_transform,
 };
-use vize_carton::{profile, Bump, String};
+use vize_carton::{profile, Bump};
 
 /// Compile a Vue template for SSR with default options
 pub fn compile_ssr<'a>(
@@ -74,11 +74,7 @@ pub fn compile_ssr_with_options<'a>(
     );
 
     if !errors.is_empty() {
-        let codegen_result = SsrCodegenResult {
-            code: String::default(),
-            preamble: String::default(),
-        };
-        return (root, errors.to_vec(), codegen_result);
+        return (root, errors.to_vec(), SsrCodegenResult::default());
     }
 
     // Transform with SSR-specific settings
@@ -154,4 +150,93 @@ mod tests {
         assert!(errors.is_empty());
         insta::assert_snapshot!(result.code.as_str());
     }
+
+    #[test]
+    fn test_static_text_is_not_interpolated() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, "<div>hello</div>");
+
+        assert!(errors.is_empty());
+        assert!(result.code.contains("_push(`<div>hello</div>`)"));
+        assert!(!result.code.contains("_ssrInterpolate"));
+        assert!(!result.preamble.contains("ssrInterpolate"));
+    }
+
+    #[test]
+    fn test_used_helpers_and_preamble() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, "<div>{{ msg }}</div><Foo />");
+
+        assert!(errors.is_empty());
+        assert_eq!(result.helpers, ["ssrInterpolate", "ssrRenderComponent"]);
+        assert_eq!(
+            result.preamble,
+            "import { resolveComponent as _resolveComponent } from \"vue\"\n\
+             import { ssrInterpolate as _ssrInterpolate, \
+             ssrRenderComponent as _ssrRenderComponent } from \"vue/server-renderer\"\n"
+        );
+    }
+
+    #[test]
+    fn test_teleport() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(
+            &allocator,
+            r##"<Teleport to="#modal" :disabled="inline"><p>{{ msg }}</p></Teleport>"##,
+        );
+
+        assert!(errors.is_empty());
+        assert_eq!(result.helpers, ["ssrInterpolate", "ssrRenderTeleport"]);
+        assert!(result
+            .code
+            .contains("_ssrRenderTeleport(_push, (_push) => {"));
+        assert!(result
+            .code
+            .contains("_push(`<p>${_ssrInterpolate(_ctx.msg)}</p>`)"));
+        assert!(result.code.contains("}, \"#modal\", _ctx.inline, _parent)"));
+        assert!(!result.code.contains("_ssrRenderComponent"));
+    }
+
+    #[test]
+    fn test_teleport_static_disabled_and_missing_target() {
+        let allocator = Bump::new();
+        let (_, _, result) =
+            compile_ssr(&allocator, r#"<Teleport to="body" disabled>x</Teleport>"#);
+        assert!(result.code.contains("}, \"body\", true, _parent)"));
+
+        let allocator = Bump::new();
+        let (_, _, result) = compile_ssr(&allocator, "<Teleport>x</Teleport>");
+        assert!(!result.code.contains("_ssrRenderTeleport"));
+        assert!(result.helpers.is_empty());
+    }
+
+    #[test]
+    fn test_v_for_uses_ssr_render_list() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(
+            &allocator,
+            r#"<ul><li v-for="item in items" :key="item.id" ref="row">{{ item.name }}</li></ul>"#,
+        );
+
+        assert!(errors.is_empty());
+        assert!(result.helpers.iter().any(|h| h == "ssrRenderList"));
+        assert!(result
+            .code
+            .contains("_ssrRenderList(_ctx.items, (item) => {"));
+        assert!(result
+            .code
+            .contains("_push(`<li>${_ssrInterpolate(item.name)}</li>`)"));
+        assert!(!result.code.contains("_renderList"));
+        assert!(!result.code.contains("key"));
+        assert!(!result.code.contains("ref=") && !result.code.contains("row"));
+
+        // Multi-root items are each wrapped in their own fragment
+        let allocator = Bump::new();
+        let (_, _, result) = compile_ssr(
+            &allocator,
+            r#"<template v-for="i in items" :key="i.id"><dt>{{ i.k }}</dt><dd/></template>"#,
+        );
+        assert!(result.code.contains("_push(`<!--[--><dt>"));
+        assert!(result.code.contains("<dd></dd><!--]-->`)"));
+    }
 }
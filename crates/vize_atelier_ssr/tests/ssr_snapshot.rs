@@ -156,7 +156,8 @@ mod element {
 // =============================================================================
 
 mod v_if {
-    use super::compile_full;
+    use super::{compile_full, get_compiled_string};
+    use vize_carton::Bump;
 
     #[test]
     fn basic_v_if() {
@@ -188,6 +189,44 @@ mod v_if {
     fn v_if_with_text() {
         insta::assert_snapshot!(compile_full(r#"<div v-if="foo">{{ msg }}</div>"#));
     }
+
+    /// Number of `v-if` comment placeholders the client render function creates.
+    fn dom_v_if_anchor_count(src: &str) -> usize {
+        use vize_atelier_core::{generate, parse, transform, CodegenOptions, TransformOptions};
+
+        let allocator = Bump::new();
+        let (mut root, errors) = parse(&allocator, src);
+        assert!(errors.is_empty(), "Parse errors: {:?}", errors);
+        transform(&allocator, &mut root, TransformOptions::default(), None);
+        generate(&root, CodegenOptions::default())
+            .code
+            .matches(r#"_createCommentVNode("v-if", true)"#)
+            .count()
+    }
+
+    #[test]
+    fn v_if_without_else_anchor_count_matches_dom() {
+        let src =
+            r#"<div><p v-if="a">a</p><span>x</span><p v-if="b">b</p><p v-else-if="c">c</p></div>"#;
+        let ssr = get_compiled_string(src);
+
+        assert_eq!(ssr.matches("<!---->").count(), 2);
+        assert_eq!(ssr.matches("<!---->").count(), dom_v_if_anchor_count(src));
+    }
+
+    #[test]
+    fn v_if_on_template_with_text_renders_fragment() {
+        let ssr = compile_full(r#"<template v-if="foo">hello</template>"#);
+
+        assert!(ssr.contains("_push(`<!--[-->hello<!--]-->`)"), "{}", ssr);
+    }
+
+    #[test]
+    fn v_if_on_empty_template_renders_fragment() {
+        let ssr = compile_full(r#"<template v-if="foo"></template>"#);
+
+        assert!(ssr.contains("_push(`<!--[--><!--]-->`)"), "{}", ssr);
+    }
 }
 
 // =============================================================================
@@ -310,6 +349,48 @@ mod component {
     }
 }
 
+// =============================================================================
+// Suspense Tests
+// =============================================================================
+
+mod suspense {
+    use super::compile_full;
+    use vize_atelier_ssr::compile_ssr;
+    use vize_carton::Bump;
+
+    #[test]
+    fn suspense_implicit_default() {
+        let code = compile_full(r#"<Suspense><Foo /></Suspense>"#);
+        assert!(code.contains("_ssrRenderSuspense(_push, {\n    default: () => {\n"));
+        assert!(code.contains("_ssrRenderComponent(_resolveComponent(\"Foo\")"));
+        assert!(code.contains("_: 1 /* STABLE */"));
+        assert!(!code.contains("_resolveComponent(\"Suspense\")"));
+    }
+
+    #[test]
+    fn suspense_explicit_slots() {
+        let code = compile_full(
+            r#"<Suspense><template #default><Foo /></template><template #fallback>loading...</template></Suspense>"#,
+        );
+        let default = code.find("default: () => {").expect("default slot");
+        let fallback = code.find("fallback: () => {").expect("fallback slot");
+        assert!(default < fallback);
+        assert!(code.contains("_push(`loading...`)"));
+        assert!(!code.contains("<template"));
+    }
+
+    #[test]
+    fn suspense_imports_helper() {
+        let allocator = Bump::new();
+        let (_, errors, result) = compile_ssr(&allocator, "<Suspense><div>ok</div></Suspense>");
+        assert!(errors.is_empty());
+        assert!(result
+            .preamble
+            .contains("ssrRenderSuspense as _ssrRenderSuspense"));
+        assert!(result.code.contains("_push(`<div>ok</div>`)"));
+    }
+}
+
 // =============================================================================
 // Slot Tests
 // =============================================================================
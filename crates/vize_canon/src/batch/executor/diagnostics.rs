@@ -294,6 +294,53 @@ mod tests {
         assert_eq!(index.offset_to_line_col(content, 1), Some((0, 1)));
     }
 
+    #[test]
+    fn maps_unused_ts_expect_error_to_directive_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let vue_path = temp_dir.path().join("App.vue");
+        let vue_content = r#"<script setup lang="ts">
+// @ts-expect-error
+import legacy from 'legacy-lib'
+const count = 1
+</script>
+"#;
+        std::fs::write(&vue_path, vue_content).unwrap();
+
+        let mut project = VirtualProject::new(temp_dir.path()).unwrap();
+        project.register_vue_file(&vue_path, vue_content).unwrap();
+        let virtual_file = project.find_by_original(&vue_path).unwrap();
+        let directive = virtual_file.content.find("// @ts-expect-error").unwrap();
+        let before = &virtual_file.content[..directive];
+        let line = before.matches('\n').count() as u32;
+        let character = (directive - before.rfind('\n').map_or(0, |index| index + 1)) as u32;
+        let uri = crate::file_uri::path_to_file_uri(&virtual_file.virtual_path);
+
+        let diagnostics = map_batch_diagnostics(
+            vec![(
+                uri,
+                vec![crate::corsa_client::LspDiagnostic {
+                    range: crate::corsa_client::LspRange {
+                        start: crate::corsa_client::LspPosition { line, character },
+                        end: crate::corsa_client::LspPosition {
+                            line,
+                            character: character + 19,
+                        },
+                    },
+                    severity: Some(1),
+                    code: Some(json!(2578)),
+                    source: Some("ts".into()),
+                    message: "Unused '@ts-expect-error' directive.".into(),
+                }],
+            )],
+            &project,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].file, vue_path);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (1, 0));
+        assert_eq!(diagnostics[0].code, Some(2578));
+    }
+
     #[test]
     fn maps_unmapped_diagnostics_snapshot() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,7 +1,11 @@
 //! Import rewriter for transforming .vue imports to .vue.ts.
 //!
 //! This module uses oxc to parse TypeScript/JavaScript files and rewrite
-//! import paths that reference .vue files to .vue.ts.
+//! import paths that reference .vue files to .vue.ts. Aliased `.vue`
+//! imports (tsconfig `compilerOptions.paths`) are resolved to relative paths
+//! so they point at the mirrored virtual file instead of the original SFC.
+
+use std::path::{Component, Path, PathBuf};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Expression, Statement};
@@ -74,13 +78,102 @@ impl ImportSourceMap {
     }
 }
 
+/// Path alias mappings from tsconfig `compilerOptions.paths`.
+#[derive(Debug, Clone, Default)]
+pub struct PathAliases {
+    /// Directory targets are resolved against (`baseUrl`, or the tsconfig directory).
+    base_dir: PathBuf,
+    entries: Vec<PathAlias>,
+}
+
+#[derive(Debug, Clone)]
+struct PathAlias {
+    pattern: String,
+    targets: Vec<String>,
+}
+
+impl PathAliases {
+    /// Create an empty alias table resolving targets against `base_dir`.
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add a `paths` entry, e.g. `@/*` -> `["src/*"]`.
+    pub fn insert<'t>(&mut self, pattern: &str, targets: impl IntoIterator<Item = &'t str>) {
+        let targets: Vec<String> = targets.into_iter().map(String::from).collect();
+        if !targets.is_empty() {
+            self.entries.push(PathAlias {
+                pattern: pattern.into(),
+                targets,
+            });
+        }
+    }
+
+    /// Check if no aliases are configured.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Resolve an aliased specifier to a file path.
+    ///
+    /// Follows TypeScript's matching rules: an exact pattern wins, otherwise
+    /// the wildcard pattern with the longest prefix. Among the targets of the
+    /// matched pattern, the first existing file is used, falling back to the
+    /// first target.
+    pub fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        let mut best: Option<(usize, &str, &PathAlias)> = None;
+        for alias in &self.entries {
+            let (rank, captured) = match alias.pattern.split_once('*') {
+                Some((prefix, suffix)) => {
+                    let captured = specifier
+                        .strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_suffix(suffix));
+                    match captured {
+                        Some(captured) => (prefix.len(), captured),
+                        None => continue,
+                    }
+                }
+                None if alias.pattern == specifier => (usize::MAX, ""),
+                None => continue,
+            };
+            if best.is_none_or(|(best_rank, _, _)| rank > best_rank) {
+                best = Some((rank, captured, alias));
+            }
+        }
+
+        let (_, captured, alias) = best?;
+        let mut candidates = alias.targets.iter().map(|target| {
+            let target = match target.split_once('*') {
+                Some((before, after)) => cstr!("{before}{captured}{after}"),
+                None => target.clone(),
+            };
+            self.base_dir.join(target.as_str())
+        });
+        let first = candidates.next()?;
+        if first.exists() {
+            return Some(first);
+        }
+        Some(candidates.find(|path| path.exists()).unwrap_or(first))
+    }
+}
+
 /// Import rewriter that transforms .vue imports to .vue.ts.
-pub struct ImportRewriter;
+pub struct ImportRewriter {
+    path_aliases: PathAliases,
+}
 
 impl ImportRewriter {
     /// Create a new import rewriter.
     pub fn new() -> Self {
-        Self
+        Self::with_path_aliases(PathAliases::default())
+    }
+
+    /// Create an import rewriter that also resolves aliased `.vue` imports.
+    pub fn with_path_aliases(path_aliases: PathAliases) -> Self {
+        Self { path_aliases }
     }
 
     /// Rewrite imports in the given source code.
@@ -90,6 +183,23 @@ impl ImportRewriter {
         })
     }
 
+    /// Rewrite imports in the source of the file at `path`.
+    ///
+    /// In addition to [`Self::rewrite`], aliased `.vue` imports are replaced
+    /// with `.vue.ts` paths relative to `path`.
+    pub fn rewrite_file(
+        &self,
+        source: &str,
+        source_type: SourceType,
+        path: &Path,
+    ) -> RewriteResult {
+        let importer_dir = path.parent();
+        self.rewrite_with(source, source_type, |specifier| {
+            self.rewrite_module_specifier(specifier)
+                .or_else(|| self.rewrite_aliased_specifier(specifier, importer_dir?))
+        })
+    }
+
     /// Rewrite emitted declaration imports back to `.vue` specifiers.
     pub fn rewrite_declaration_specifiers(
         &self,
@@ -194,6 +304,16 @@ impl ImportRewriter {
         }
     }
 
+    /// Rewrite an aliased .vue import to a relative .vue.ts import.
+    fn rewrite_aliased_specifier(&self, specifier: &str, importer_dir: &Path) -> Option<String> {
+        if !specifier.ends_with(".vue") || self.path_aliases.is_empty() {
+            return None;
+        }
+        let target = self.path_aliases.resolve(specifier)?;
+        let relative = relative_specifier(importer_dir, &target)?;
+        Some(cstr!("{relative}.ts"))
+    }
+
     fn rewrite_declaration_specifier(&self, path: &str) -> Option<String> {
         if path.ends_with(".vue.ts") && (path.starts_with("./") || path.starts_with("../")) {
             return path
@@ -210,6 +330,54 @@ impl Default for ImportRewriter {
     }
 }
 
+/// Build a `./`- or `../`-prefixed specifier pointing from `from_dir` to `to`.
+fn relative_specifier(from_dir: &Path, to: &Path) -> Option<String> {
+    let from = normalize_path(from_dir);
+    let to = normalize_path(to);
+    let from_components: Vec<_> = from.components().collect();
+    let to_components: Vec<_> = to.components().collect();
+    let common = from_components
+        .iter()
+        .zip(&to_components)
+        .take_while(|(left, right)| left == right)
+        .count();
+    if common == 0 || common == to_components.len() {
+        return None;
+    }
+
+    let mut specifier = String::default();
+    for _ in common..from_components.len() {
+        specifier.push_str("../");
+    }
+    if specifier.is_empty() {
+        specifier.push_str("./");
+    }
+    for (index, component) in to_components[common..].iter().enumerate() {
+        if index > 0 {
+            specifier.push('/');
+        }
+        specifier.push_str(component.as_os_str().to_str()?);
+    }
+    Some(specifier)
+}
+
+/// Lexically resolve `.` and `..` components.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
 /// Visitor to collect dynamic imports.
 struct DynamicImportCollector {
     imports: Vec<(u32, u32, String)>,
@@ -239,8 +407,9 @@ impl<'a> Visit<'a> for DynamicImportCollector {
 
 #[cfg(test)]
 mod tests {
-    use super::ImportRewriter;
+    use super::{ImportRewriter, PathAliases};
     use oxc_span::SourceType;
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn test_rewrite_default_import() {
@@ -338,4 +507,60 @@ import { ref } from 'vue';"#;
 
         insta::assert_snapshot!(result.code.as_str());
     }
+
+    fn aliased_rewriter() -> ImportRewriter {
+        let mut aliases = PathAliases::new("/project");
+        aliases.insert("@/*", ["src/*"]);
+        aliases.insert("@components/*", ["src/components/*"]);
+        aliases.insert("~ui", ["src/ui/Index.vue"]);
+        ImportRewriter::with_path_aliases(aliases)
+    }
+
+    #[test]
+    fn test_rewrite_path_alias() {
+        let rewriter = aliased_rewriter();
+        let source = r#"import X from '@/components/X.vue';
+import Y from '@/Y.vue';
+import Ui from '~ui';
+import { helper } from '@/utils/helper';"#;
+        let result = rewriter.rewrite_file(
+            source,
+            SourceType::ts(),
+            Path::new("/project/src/pages/Home.vue"),
+        );
+
+        assert_eq!(
+            result.code,
+            r#"import X from '../components/X.vue.ts';
+import Y from '../Y.vue.ts';
+import Ui from '~ui';
+import { helper } from '@/utils/helper';"#
+        );
+    }
+
+    #[test]
+    fn test_path_alias_prefers_longest_prefix() {
+        let mut aliases = PathAliases::new("/project");
+        aliases.insert("@/*", ["src/*"]);
+        aliases.insert("@/widgets/*", ["lib/widgets/*"]);
+
+        assert_eq!(
+            aliases.resolve("@/widgets/Button.vue"),
+            Some(PathBuf::from("/project/lib/widgets/Button.vue"))
+        );
+        assert_eq!(
+            aliases.resolve("@/App.vue"),
+            Some(PathBuf::from("/project/src/App.vue"))
+        );
+        assert_eq!(aliases.resolve("vue"), None);
+    }
+
+    #[test]
+    fn test_path_alias_ignored_without_importer_path() {
+        let rewriter = aliased_rewriter();
+        let source = r#"import X from '@/components/X.vue';"#;
+        let result = rewriter.rewrite(source, SourceType::ts());
+
+        assert_eq!(result.code, source);
+    }
 }
@@ -15,7 +15,9 @@ mod virtual_ts;
 
 pub use error::{CorsaError, CorsaNotFoundError, CorsaResult, PackageManager};
 pub use executor::CorsaExecutor;
-pub use import_rewriter::{ImportRewriter, ImportSourceMap, OffsetAdjustment, RewriteResult};
+pub use import_rewriter::{
+    ImportRewriter, ImportSourceMap, OffsetAdjustment, PathAliases, RewriteResult,
+};
 pub use source_map::{CompositeSourceMap, SfcBlockRange, SfcSourceMap};
 pub use type_checker::{
     BatchTypeChecker, BatchTypeCheckerOptions, DeclarationEmitOptions, DeclarationEmitResult,
@@ -5,10 +5,12 @@
 //! regular TypeScript sources, ambient declarations, and emitted `.d.ts` files.
 
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use super::error::{CorsaError, CorsaResult};
-use super::import_rewriter::ImportRewriter;
+use super::import_rewriter::{ImportRewriter, PathAliases};
 use super::source_map::{CompositeSourceMap, SfcBlockRange, SfcSourceMap};
+use super::virtual_ts::{VirtualTsGenerator, VirtualTsResult};
 use super::SfcBlockType;
 use crate::virtual_ts::{generate_virtual_ts_with_offsets, VirtualTsOptions};
 use oxc_span::SourceType;
@@ -18,6 +20,10 @@ use vize_atelier_sfc::{parse_sfc, SfcDescriptor, SfcParseOptions};
 use vize_carton::{cstr, profile, Bump, FxHashMap, String as CompactString, ToCompactString};
 use vize_croquis::{Analyzer, AnalyzerOptions, ImportStatementInfo, ReExportInfo, TypeExport};
 
+/// `compilerOptions` of a tsconfig file.
+#[allow(clippy::disallowed_types)]
+type CompilerOptions = Map<std::string::String, Value>;
+
 /// A virtual file in the project.
 #[derive(Debug)]
 pub struct VirtualFile {
@@ -61,8 +67,15 @@ pub struct VirtualProject {
     /// Virtual files keyed by materialized path.
     virtual_files: FxHashMap<PathBuf, VirtualFile>,
 
-    /// Import rewriter for `.vue` specifiers inside TypeScript sources.
-    rewriter: ImportRewriter,
+    /// Block caches of the registered Vue files, keyed by original path.
+    vue_blocks: FxHashMap<PathBuf, VueBlocks>,
+
+    /// Parsed `compilerOptions` of the resolved tsconfig, loaded on first use.
+    compiler_options: OnceLock<CompilerOptions>,
+
+    /// Import rewriter for `.vue` specifiers inside TypeScript sources,
+    /// configured with the tsconfig path aliases on first use.
+    rewriter: OnceLock<ImportRewriter>,
 }
 
 impl VirtualProject {
@@ -82,18 +95,27 @@ impl VirtualProject {
             tsconfig_path: None,
             virtual_ts_options: VirtualTsOptions::default(),
             virtual_files: FxHashMap::default(),
-            rewriter: ImportRewriter::new(),
+            vue_blocks: FxHashMap::default(),
+            compiler_options: OnceLock::new(),
+            rewriter: OnceLock::new(),
         })
     }
 
     /// Set the tsconfig path to extend.
+    ///
+    /// Call this before registering files so their imports are rewritten
+    /// with the path aliases of this tsconfig.
     pub fn set_tsconfig_path(&mut self, tsconfig_path: Option<PathBuf>) {
         self.tsconfig_path = tsconfig_path;
+        self.compiler_options = OnceLock::new();
+        self.rewriter = OnceLock::new();
+        self.vue_blocks.clear();
     }
 
     /// Set the shared virtual TS options.
     pub fn set_virtual_ts_options(&mut self, options: VirtualTsOptions) {
         self.virtual_ts_options = options;
+        self.vue_blocks.clear();
     }
 
     /// Get the project root.
@@ -133,6 +155,9 @@ impl VirtualProject {
     }
 
     /// Register a `.vue` file.
+    ///
+    /// A file registered again keeps its virtual file when no block that
+    /// reaches the virtual TypeScript changed and the blocks did not move.
     pub fn register_vue_file(&mut self, path: &Path, content: &str) -> CorsaResult<()> {
         let descriptor = profile!(
             "canon.sfc.parse",
@@ -146,22 +171,51 @@ impl VirtualProject {
             .map_err(|error| CorsaError::SfcParse(error.message.to_compact_string()))
         )?;
 
+        let virtual_path = virtual_vue_path(&self.project_root, &self.virtual_root, path)?;
+        let ranges = collect_sfc_block_ranges(&descriptor);
+        let previous = self.vue_blocks.remove(path);
+        let blocks = profile!(
+            "canon.vue.block_cache",
+            VirtualTsGenerator::new()
+                .generate_incremental(previous.as_ref().map(|blocks| &blocks.result), &descriptor)
+        );
+        let unchanged = previous.is_some_and(|previous| {
+            previous.ranges == ranges && previous.result.cache.same_output(&blocks.cache)
+        });
+        if unchanged && self.virtual_files.contains_key(&virtual_path) {
+            self.vue_blocks.insert(
+                path.to_path_buf(),
+                VueBlocks {
+                    result: blocks,
+                    ranges,
+                },
+            );
+            return Ok(());
+        }
+
         let effective_options =
             virtual_ts_options_for_descriptor(&self.virtual_ts_options, &descriptor);
         let generated = profile!(
             "canon.vue.virtual_ts",
             generate_vue_virtual_ts(path, content, &descriptor, &effective_options)
         )?;
+        let rewriter = self.rewriter()?;
         let rewritten = profile!(
             "canon.import.rewrite.vue",
-            self.rewriter.rewrite(&generated.code, SourceType::ts())
+            rewriter.rewrite_file(&generated.code, SourceType::ts(), path)
         );
         let source_map = CompositeSourceMap::new_vue(
-            SfcSourceMap::new(generated.mappings, collect_sfc_block_ranges(&descriptor)),
+            SfcSourceMap::new(generated.mappings, ranges.clone()),
             rewritten.source_map,
         );
-        let virtual_path = virtual_vue_path(&self.project_root, &self.virtual_root, path)?;
 
+        self.vue_blocks.insert(
+            path.to_path_buf(),
+            VueBlocks {
+                result: blocks,
+                ranges,
+            },
+        );
         self.virtual_files.insert(
             virtual_path.clone(),
             VirtualFile {
@@ -196,9 +250,10 @@ impl VirtualProject {
         content: &str,
         source_type: SourceType,
     ) -> CorsaResult<()> {
+        let rewriter = self.rewriter()?;
         let rewritten = profile!(
             "canon.import.rewrite.script",
-            self.rewriter.rewrite(content, source_type)
+            rewriter.rewrite_file(content, source_type, path)
         );
         let virtual_path = mirrored_virtual_path(&self.project_root, &self.virtual_root, path)?;
 
@@ -380,7 +435,7 @@ impl VirtualProject {
             );
         }
 
-        let mut compiler_options = self.load_compiler_options(original_tsconfig.as_deref())?;
+        let mut compiler_options = self.compiler_options()?.clone();
         compiler_options.insert("allowImportingTsExtensions".into(), Value::Bool(true));
 
         if let Some(out_dir) = out_dir {
@@ -463,11 +518,35 @@ impl VirtualProject {
         tsconfig.exists().then_some(tsconfig)
     }
 
-    #[allow(clippy::disallowed_types)]
-    fn load_compiler_options(
-        &self,
-        tsconfig_path: Option<&Path>,
-    ) -> CorsaResult<Map<std::string::String, Value>> {
+    /// Get the import rewriter, building it from the tsconfig path aliases.
+    fn rewriter(&self) -> CorsaResult<&ImportRewriter> {
+        if let Some(rewriter) = self.rewriter.get() {
+            return Ok(rewriter);
+        }
+
+        let path_aliases = match self.resolved_tsconfig_path() {
+            Some(tsconfig_path) => {
+                path_aliases_for_tsconfig(&tsconfig_path, self.compiler_options()?)
+            }
+            None => PathAliases::default(),
+        };
+        Ok(self
+            .rewriter
+            .get_or_init(|| ImportRewriter::with_path_aliases(path_aliases)))
+    }
+
+    /// Get the cached `compilerOptions` of the resolved tsconfig.
+    fn compiler_options(&self) -> CorsaResult<&CompilerOptions> {
+        if let Some(compiler_options) = self.compiler_options.get() {
+            return Ok(compiler_options);
+        }
+
+        let compiler_options =
+            self.load_compiler_options(self.resolved_tsconfig_path().as_deref())?;
+        Ok(self.compiler_options.get_or_init(|| compiler_options))
+    }
+
+    fn load_compiler_options(&self, tsconfig_path: Option<&Path>) -> CorsaResult<CompilerOptions> {
         let Some(tsconfig_path) = tsconfig_path else {
             return Ok(Map::new());
         };
@@ -489,6 +568,14 @@ impl VirtualProject {
     }
 }
 
+/// Block cache of a registered Vue file.
+struct VueBlocks {
+    /// Block outputs of the last registration.
+    result: VirtualTsResult,
+    /// Block ranges the virtual file's source map was built with.
+    ranges: Vec<SfcBlockRange>,
+}
+
 struct GeneratedVueFile {
     code: CompactString,
     mappings: Vec<crate::virtual_ts::VizeMapping>,
@@ -702,6 +789,27 @@ fn virtual_ts_options_for_descriptor(
     options
 }
 
+/// Collect `compilerOptions.paths`, resolved against `baseUrl` or the tsconfig directory.
+fn path_aliases_for_tsconfig(
+    tsconfig_path: &Path,
+    compiler_options: &CompilerOptions,
+) -> PathAliases {
+    let config_dir = tsconfig_path.parent().unwrap_or_else(|| Path::new(""));
+    let base_dir = match compiler_options.get("baseUrl").and_then(Value::as_str) {
+        Some(base_url) => config_dir.join(base_url),
+        None => config_dir.to_path_buf(),
+    };
+
+    let mut aliases = PathAliases::new(base_dir);
+    if let Some(paths) = compiler_options.get("paths").and_then(Value::as_object) {
+        for (pattern, targets) in paths {
+            let targets = targets.as_array().into_iter().flatten();
+            aliases.insert(pattern, targets.filter_map(Value::as_str));
+        }
+    }
+    aliases
+}
+
 fn mirrored_virtual_path(
     project_root: &Path,
     virtual_root: &Path,
@@ -913,6 +1021,99 @@ const count = 1
         let _ = fs::remove_dir_all(&case_dir);
     }
 
+    #[test]
+    fn test_register_vue_file_reuses_unchanged_blocks() {
+        let case_dir = unique_case_dir("register-vue-reuse");
+        let _ = fs::remove_dir_all(&case_dir);
+        let src_dir = case_dir.join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+        let vue_path = src_dir.join("App.vue");
+        let vue_content = r#"<script setup lang="ts">
+const count = 1
+</script>
+
+<template>
+  <div>{{ count }}</div>
+</template>
+
+<style>
+.a { color: red; }
+</style>
+"#;
+        fs::write(&vue_path, vue_content).unwrap();
+
+        let mut project = VirtualProject::new(&case_dir).unwrap();
+        project.register_vue_file(&vue_path, vue_content).unwrap();
+
+        // Mark the virtual file so reuse is observable.
+        for file in project.virtual_files.values_mut() {
+            file.content = "// kept".into();
+        }
+
+        // A plain style edit does not reach the virtual TypeScript
+        let style_edit = vue_content.replace("red", "blue");
+        project.register_vue_file(&vue_path, &style_edit).unwrap();
+        let virtual_file = project.find_by_original(&vue_path).unwrap();
+        assert_eq!(virtual_file.content.as_str(), "// kept");
+
+        let template_edit = style_edit.replace("<div>", "<p>").replace("</div>", "</p>");
+        project
+            .register_vue_file(&vue_path, &template_edit)
+            .unwrap();
+        let virtual_file = project.find_by_original(&vue_path).unwrap();
+        assert!(virtual_file.content.contains("count"));
+
+        let _ = fs::remove_dir_all(&case_dir);
+    }
+
+    #[test]
+    fn test_register_vue_file_resolves_path_aliases() {
+        let case_dir = unique_case_dir("path-alias");
+        let _ = fs::remove_dir_all(&case_dir);
+        let pages_dir = case_dir.join("src/pages");
+        let components_dir = case_dir.join("src/components");
+        fs::create_dir_all(&pages_dir).unwrap();
+        fs::create_dir_all(&components_dir).unwrap();
+        fs::write(
+            case_dir.join("tsconfig.json"),
+            r#"{
+  "compilerOptions": {
+    // aliases resolve against baseUrl
+    "baseUrl": ".",
+    "paths": { "@/*": ["./src/*"] },
+  },
+}"#,
+        )
+        .unwrap();
+        fs::write(
+            components_dir.join("Child.vue"),
+            "<template><div /></template>\n",
+        )
+        .unwrap();
+        let vue_path = pages_dir.join("Home.vue");
+        let vue_content = r#"<script setup lang="ts">
+import Child from '@/components/Child.vue'
+</script>
+
+<template>
+  <Child />
+</template>
+"#;
+        fs::write(&vue_path, vue_content).unwrap();
+
+        let mut project = VirtualProject::new(&case_dir).unwrap();
+        let vue_path = project.project_root().join("src/pages/Home.vue");
+        project.register_vue_file(&vue_path, vue_content).unwrap();
+
+        let virtual_file = project.find_by_original(&vue_path).unwrap();
+        assert!(virtual_file
+            .content
+            .contains("from '../components/Child.vue.ts'"));
+        assert!(!virtual_file.content.contains("'@/components/Child.vue'"));
+
+        let _ = fs::remove_dir_all(&case_dir);
+    }
+
     #[test]
     fn test_materialize_writes_tsconfig_and_virtual_files() {
         let case_dir = unique_case_dir("materialize");
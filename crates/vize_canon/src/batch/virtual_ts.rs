@@ -1,17 +1,21 @@
-//! Legacy virtual TypeScript generator used by older batch tests.
+//! Lightweight virtual TypeScript generator with a per-block output cache.
 //!
-//! The production path now uses `crate::virtual_ts`, but a lightweight version
-//! is kept here for the focused unit tests that exercise import rewriting and
-//! baseline batch behavior in isolation.
+//! Type checking generates its code with `crate::virtual_ts`; the virtual
+//! project uses the block cache of this generator to tell whether a Vue file
+//! registered again has to be regenerated at all.
 
 use super::import_rewriter::ImportRewriter;
 use super::source_map::{SfcBlockRange, SfcSourceMap};
 use super::SfcBlockType;
 use crate::virtual_ts::VizeMapping;
-use vize_atelier_sfc::SfcDescriptor;
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use vize_atelier_sfc::{SfcDescriptor, SfcTemplateBlock};
 use vize_carton::append;
 use vize_carton::cstr;
+use vize_carton::OrderedMap;
 use vize_carton::String;
+use vize_carton::ToCompactString;
 use vize_croquis::{Analyzer, AnalyzerOptions, Croquis};
 
 /// Result of virtual TypeScript generation.
@@ -21,6 +25,59 @@ pub struct VirtualTsResult {
     pub code: String,
     /// Source map for position mapping.
     pub source_map: SfcSourceMap,
+    /// Per-block outputs reused by [`VirtualTsGenerator::generate_incremental`].
+    pub cache: VirtualTsBlockCache,
+}
+
+/// Per-block generated output, keyed by a hash of the block content.
+#[derive(Debug, Clone, Default)]
+pub struct VirtualTsBlockCache {
+    script: Option<ScriptBlockOutput>,
+    template: Option<TemplateBlockOutput>,
+    style: Option<StyleBlockOutput>,
+}
+
+impl VirtualTsBlockCache {
+    /// Whether both caches hold the same script and template outputs and
+    /// declare the same CSS modules.
+    ///
+    /// Other style changes are ignored since they do not reach the virtual
+    /// TypeScript.
+    pub fn same_output(&self, other: &Self) -> bool {
+        self.script.as_ref().map(|script| script.hash)
+            == other.script.as_ref().map(|script| script.hash)
+            && self.template.as_ref().map(|template| template.hash)
+                == other.template.as_ref().map(|template| template.hash)
+            && self.style.as_ref().map(|style| &style.modules)
+                == other.style.as_ref().map(|style| &style.modules)
+    }
+}
+
+/// Generated output that only depends on the script block.
+#[derive(Debug, Clone)]
+struct ScriptBlockOutput {
+    hash: u64,
+    imports: String,
+    content: String,
+    component_types: String,
+    /// Script binding names, needed to regenerate the template block.
+    bindings: Vec<String>,
+}
+
+/// Generated output of the template block.
+#[derive(Debug, Clone)]
+struct TemplateBlockOutput {
+    hash: u64,
+    bindings: String,
+}
+
+/// Output of the style blocks.
+#[derive(Debug, Clone)]
+struct StyleBlockOutput {
+    hash: u64,
+    /// CSS module names (`<style module>`), typed as bindings by the
+    /// type-check path.
+    modules: Vec<String>,
 }
 
 /// Vue compiler macros - defined with parameters marked as used.
@@ -60,6 +117,124 @@ impl VirtualTsGenerator {
 
     /// Generate virtual TypeScript from SFC descriptor.
     pub fn generate(&self, descriptor: &SfcDescriptor, analysis: &Croquis) -> VirtualTsResult {
+        let script = self.generate_script_block(descriptor, analysis);
+        let template = descriptor
+            .template
+            .as_ref()
+            .map(|template| self.generate_template_block(&script, template));
+        let style = self.generate_style_block(descriptor);
+        self.assemble(descriptor, script, template, style)
+    }
+
+    /// Generate virtual TypeScript, reusing the block outputs of `prev` whose
+    /// content hash is unchanged.
+    ///
+    /// The script is only re-analyzed when it changed, so a template-only
+    /// edit skips analysis entirely. Block mappings are rebuilt from the
+    /// current descriptor since unchanged blocks may still have moved.
+    pub fn generate_incremental(
+        &self,
+        prev: Option<&VirtualTsResult>,
+        descriptor: &SfcDescriptor,
+    ) -> VirtualTsResult {
+        let cache = prev.map(|prev| &prev.cache);
+
+        let script_hash = script_block_hash(descriptor);
+        let script = match cache.and_then(|cache| cache.script.as_ref()) {
+            Some(script) if script.hash == script_hash => script.clone(),
+            _ => {
+                let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+                if let Some(ref script_setup) = descriptor.script_setup {
+                    analyzer.analyze_script_setup(&script_setup.content);
+                } else if let Some(ref script) = descriptor.script {
+                    analyzer.analyze_script_plain(&script.content);
+                }
+                self.generate_script_block(descriptor, &analyzer.finish())
+            }
+        };
+
+        let template = descriptor.template.as_ref().map(|template| {
+            let hash = template_block_hash(&script, template);
+            match cache.and_then(|cache| cache.template.as_ref()) {
+                Some(cached) if cached.hash == hash => cached.clone(),
+                _ => self.generate_template_block(&script, template),
+            }
+        });
+
+        let style = match cache.and_then(|cache| cache.style.as_ref()) {
+            Some(style) if style.hash == style_block_hash(descriptor) => style.clone(),
+            _ => self.generate_style_block(descriptor),
+        };
+
+        self.assemble(descriptor, script, template, style)
+    }
+
+    /// Generate the outputs that depend on the script block.
+    fn generate_script_block(
+        &self,
+        descriptor: &SfcDescriptor,
+        analysis: &Croquis,
+    ) -> ScriptBlockOutput {
+        let mut imports = String::default();
+        let mut content = String::default();
+        if let Some(ref script_setup) = descriptor.script_setup {
+            self.emit_imports(&mut imports, &script_setup.content);
+            self.emit_script_content_module_scope(&mut content, &script_setup.content);
+        } else if let Some(ref script) = descriptor.script {
+            self.emit_imports(&mut imports, &script.content);
+            self.emit_script_content_module_scope(&mut content, &script.content);
+        }
+
+        let mut component_types = String::default();
+        self.emit_component_types(&mut component_types, analysis);
+
+        ScriptBlockOutput {
+            hash: script_block_hash(descriptor),
+            imports,
+            content,
+            component_types,
+            bindings: analysis
+                .bindings
+                .iter()
+                .map(|(name, _)| name.to_compact_string())
+                .collect(),
+        }
+    }
+
+    /// Generate the template binding references.
+    fn generate_template_block(
+        &self,
+        script: &ScriptBlockOutput,
+        template: &SfcTemplateBlock<'_>,
+    ) -> TemplateBlockOutput {
+        let mut bindings = String::default();
+        self.emit_template_bindings(&mut bindings, &script.bindings);
+        TemplateBlockOutput {
+            hash: template_block_hash(script, template),
+            bindings,
+        }
+    }
+
+    /// Collect the CSS module names of the style blocks.
+    fn generate_style_block(&self, descriptor: &SfcDescriptor) -> StyleBlockOutput {
+        StyleBlockOutput {
+            hash: style_block_hash(descriptor),
+            modules: descriptor
+                .styles
+                .iter()
+                .filter_map(|style| style.module.as_deref().map(String::from))
+                .collect(),
+        }
+    }
+
+    /// Join block outputs into the virtual file and build its source map.
+    fn assemble(
+        &self,
+        descriptor: &SfcDescriptor,
+        script: ScriptBlockOutput,
+        template: Option<TemplateBlockOutput>,
+        style: StyleBlockOutput,
+    ) -> VirtualTsResult {
         let mut code = String::default();
         let mut mappings = Vec::new();
         let mut blocks = Vec::new();
@@ -75,57 +250,49 @@ impl VirtualTsGenerator {
         code.push('\n');
 
         code.push_str("// ========== Imports ==========\n");
-        if let Some(ref script_setup) = descriptor.script_setup {
-            self.emit_imports(&mut code, &script_setup.content);
-        } else if let Some(ref script) = descriptor.script {
-            self.emit_imports(&mut code, &script.content);
-        }
+        code.push_str(&script.imports);
         code.push('\n');
 
         code.push_str("// ========== Script Content ==========\n");
-        if let Some(ref script_setup) = descriptor.script_setup {
-            let start = code.len();
-            self.emit_script_content_module_scope(&mut code, &script_setup.content);
-            let end = code.len();
-            mappings.push(VizeMapping {
-                gen_range: start..end,
-                src_range: script_setup.loc.start
-                    ..script_setup.loc.start + script_setup.content.len(),
-            });
-            blocks.push(SfcBlockRange {
-                start: script_setup.loc.start as u32,
-                end: script_setup.loc.start as u32 + script_setup.content.len() as u32,
-                block_type: SfcBlockType::ScriptSetup,
+        let script_block = descriptor
+            .script_setup
+            .as_ref()
+            .map(|block| (block, SfcBlockType::ScriptSetup))
+            .or_else(|| {
+                descriptor
+                    .script
+                    .as_ref()
+                    .map(|block| (block, SfcBlockType::Script))
             });
-        } else if let Some(ref script) = descriptor.script {
+        if let Some((block, block_type)) = script_block {
             let start = code.len();
-            self.emit_script_content_module_scope(&mut code, &script.content);
+            code.push_str(&script.content);
             let end = code.len();
             mappings.push(VizeMapping {
                 gen_range: start..end,
-                src_range: script.loc.start..script.loc.start + script.content.len(),
+                src_range: block.loc.start..block.loc.start + block.content.len(),
             });
             blocks.push(SfcBlockRange {
-                start: script.loc.start as u32,
-                end: script.loc.start as u32 + script.content.len() as u32,
-                block_type: SfcBlockType::Script,
+                start: block.loc.start as u32,
+                end: block.loc.start as u32 + block.content.len() as u32,
+                block_type,
             });
         }
         code.push('\n');
 
-        if let Some(ref template) = descriptor.template {
+        if let (Some(block), Some(template)) = (descriptor.template.as_ref(), template.as_ref()) {
             code.push_str("// ========== Template Bindings ==========\n");
             code.push_str("(function __template() {\n");
             let start = code.len();
-            self.emit_template_bindings(&mut code, analysis);
+            code.push_str(&template.bindings);
             let end = code.len();
             mappings.push(VizeMapping {
                 gen_range: start..end,
-                src_range: template.loc.start..template.loc.start + template.content.len(),
+                src_range: block.loc.start..block.loc.start + block.content.len(),
             });
             blocks.push(SfcBlockRange {
-                start: template.loc.start as u32,
-                end: template.loc.start as u32 + template.content.len() as u32,
+                start: block.loc.start as u32,
+                end: block.loc.start as u32 + block.content.len() as u32,
                 block_type: SfcBlockType::Template,
             });
             code.push_str("})();\n\n");
@@ -133,13 +300,18 @@ impl VirtualTsGenerator {
 
         code.push_str("// ========== Component Export ==========\n");
         code.push_str("import { DefineComponent } from 'vue';\n\n");
-        self.emit_component_types(&mut code, analysis);
+        code.push_str(&script.component_types);
         code.push_str("declare const __component: DefineComponent<__Props, {}, {}, {}, {}, {}, {}, __Emits>;\n");
         code.push_str("export default __component;\n");
 
         VirtualTsResult {
             code,
             source_map: SfcSourceMap::new(mappings, blocks),
+            cache: VirtualTsBlockCache {
+                script: Some(script),
+                template,
+                style: Some(style),
+            },
         }
     }
 
@@ -173,8 +345,8 @@ impl VirtualTsGenerator {
     }
 
     /// Emit template binding references.
-    fn emit_template_bindings(&self, code: &mut String, analysis: &Croquis) {
-        for (name, _) in analysis.bindings.iter() {
+    fn emit_template_bindings(&self, code: &mut String, bindings: &[String]) {
+        for name in bindings {
             append!(*code, "    void {name};\n");
         }
     }
@@ -228,6 +400,49 @@ impl VirtualTsGenerator {
     }
 }
 
+/// Hash of the script blocks, including attributes such as `lang` and
+/// `generic`.
+fn script_block_hash(descriptor: &SfcDescriptor) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for (block_type, block) in [
+        (SfcBlockType::Script, descriptor.script.as_ref()),
+        (SfcBlockType::ScriptSetup, descriptor.script_setup.as_ref()),
+    ] {
+        if let Some(block) = block {
+            (block_type as u8).hash(&mut hasher);
+            block.content.hash(&mut hasher);
+            hash_attrs(&block.attrs, &mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Hash of the template block combined with the script bindings it references.
+fn template_block_hash(script: &ScriptBlockOutput, template: &SfcTemplateBlock<'_>) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    script.hash.hash(&mut hasher);
+    template.content.hash(&mut hasher);
+    hash_attrs(&template.attrs, &mut hasher);
+    hasher.finish()
+}
+
+/// Hash of the style blocks and their attributes.
+fn style_block_hash(descriptor: &SfcDescriptor) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for style in descriptor.styles.iter() {
+        style.content.hash(&mut hasher);
+        hash_attrs(&style.attrs, &mut hasher);
+    }
+    hasher.finish()
+}
+
+fn hash_attrs(attrs: &OrderedMap<Cow<'_, str>, Cow<'_, str>>, hasher: &mut impl Hasher) {
+    for (name, value) in attrs.iter() {
+        name.hash(hasher);
+        value.hash(hasher);
+    }
+}
+
 impl Default for VirtualTsGenerator {
     fn default() -> Self {
         Self
@@ -237,6 +452,7 @@ impl Default for VirtualTsGenerator {
 #[cfg(test)]
 mod tests {
     use super::VirtualTsGenerator;
+    use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
 
     #[test]
     fn test_generate_from_content() {
@@ -256,4 +472,86 @@ const message = ref('Hello')
         let result = generator.generate_from_content(content).unwrap();
         insta::assert_snapshot!(result.code.as_str());
     }
+
+    #[test]
+    fn test_generate_incremental_reuses_unchanged_blocks() {
+        let generator = VirtualTsGenerator::new();
+        let original = r#"<template>
+  <div>{{ message }}</div>
+</template>
+
+<script setup lang="ts">
+const message = 'Hello'
+</script>
+"#;
+        let template_edit = r#"<template>
+  <p class="title">{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+const message = 'Hello'
+</script>
+"#;
+        let script_edit = r#"<template>
+  <div>{{ message }}</div>
+</template>
+
+<script setup lang="ts">
+const message = 'Hello'
+const count = 1
+</script>
+"#;
+
+        let descriptor = parse_sfc(original, SfcParseOptions::default()).unwrap();
+        let mut prev = generator.generate_incremental(None, &descriptor);
+        let full = generator.generate_from_content(original).unwrap();
+        assert_eq!(prev.code, full.code);
+
+        // Mark the cached script output so reuse is observable.
+        prev.cache.script.as_mut().unwrap().content = "// cached script\n".into();
+
+        let descriptor = parse_sfc(template_edit, SfcParseOptions::default()).unwrap();
+        let result = generator.generate_incremental(Some(&prev), &descriptor);
+        assert!(result.code.contains("// cached script"));
+        let script_start = template_edit.find("const message").unwrap() as u32;
+        assert!(result
+            .source_map
+            .get_virtual_offset(script_start, super::SfcBlockType::ScriptSetup)
+            .is_some());
+
+        let descriptor = parse_sfc(script_edit, SfcParseOptions::default()).unwrap();
+        let result = generator.generate_incremental(Some(&prev), &descriptor);
+        let full = generator.generate_from_content(script_edit).unwrap();
+        assert!(!result.code.contains("// cached script"));
+        assert_eq!(result.code, full.code);
+        assert!(!result.cache.same_output(&prev.cache));
+    }
+
+    #[test]
+    fn test_generate_incremental_style_blocks() {
+        let generator = VirtualTsGenerator::new();
+        let original = r#"<script setup lang="ts">
+const message = 'Hello'
+</script>
+
+<style>
+.a { color: red; }
+</style>
+"#;
+        let style_edit = original.replace("red", "blue");
+        let module_edit = original.replace("<style>", "<style module>");
+
+        let descriptor = parse_sfc(original, SfcParseOptions::default()).unwrap();
+        let prev = generator.generate_incremental(None, &descriptor);
+
+        // Plain style edits keep the output the same
+        let descriptor = parse_sfc(&style_edit, SfcParseOptions::default()).unwrap();
+        let result = generator.generate_incremental(Some(&prev), &descriptor);
+        assert!(result.cache.same_output(&prev.cache));
+
+        // A new CSS module changes what the type-check path declares
+        let descriptor = parse_sfc(&module_edit, SfcParseOptions::default()).unwrap();
+        let result = generator.generate_incremental(Some(&prev), &descriptor);
+        assert!(!result.cache.same_output(&prev.cache));
+    }
 }
@@ -108,9 +108,9 @@ pub use corsa_bridge::{
 pub use batch::{
     BatchTypeChecker, BatchTypeCheckerOptions, CorsaError, CorsaExecutor, CorsaNotFoundError,
     DeclarationEmitOptions, DeclarationEmitResult, DeclarationOutput,
-    Diagnostic as BatchDiagnostic, ImportRewriter, ImportSourceMap, PackageManager, SfcBlockType,
-    TypeCheckResult as BatchTypeCheckResult, TypeChecker as BatchTypeCheckerTrait, VirtualFile,
-    VirtualProject, VirtualTsGenerator,
+    Diagnostic as BatchDiagnostic, ImportRewriter, ImportSourceMap, PackageManager, PathAliases,
+    SfcBlockType, TypeCheckResult as BatchTypeCheckResult, TypeChecker as BatchTypeCheckerTrait,
+    VirtualFile, VirtualProject, VirtualTsGenerator,
 };
 
 #[cfg(feature = "native")]
@@ -91,6 +91,28 @@ impl SfcTypeCheckResult {
     pub fn has_errors(&self) -> bool {
         self.error_count > 0
     }
+
+    /// Set the severity of every diagnostic starting within `start..end`
+    /// and recompute the counts.
+    pub(crate) fn override_severity_in_range(
+        &mut self,
+        start: u32,
+        end: u32,
+        severity: SfcTypeSeverity,
+    ) {
+        self.error_count = 0;
+        self.warning_count = 0;
+        for diagnostic in &mut self.diagnostics {
+            if diagnostic.start >= start && diagnostic.start < end {
+                diagnostic.severity = severity;
+            }
+            match diagnostic.severity {
+                SfcTypeSeverity::Error => self.error_count += 1,
+                SfcTypeSeverity::Warning => self.warning_count += 1,
+                _ => {}
+            }
+        }
+    }
 }
 
 /// Type checking options.
@@ -116,6 +138,9 @@ pub struct SfcTypeCheckOptions {
     pub check_fallthrough_attrs: bool,
     /// Strict mode - report more potential issues
     pub strict: bool,
+    /// Severity for diagnostics originating from `<template>`, overriding the
+    /// checker's own (e.g. downgrade template errors to warnings while migrating)
+    pub template_severity_override: Option<SfcTypeSeverity>,
 }
 
 impl SfcTypeCheckOptions {
@@ -132,6 +157,7 @@ impl SfcTypeCheckOptions {
             check_invalid_exports: true,
             check_fallthrough_attrs: true,
             strict: false,
+            template_severity_override: None,
         }
     }
 
@@ -146,4 +172,10 @@ impl SfcTypeCheckOptions {
         self.include_virtual_ts = true;
         self
     }
+
+    /// Report template diagnostics with the given severity.
+    pub fn with_template_severity(mut self, severity: SfcTypeSeverity) -> Self {
+        self.template_severity_override = Some(severity);
+        self
+    }
 }
@@ -219,6 +219,35 @@ const count = ref(0);
         assert!(has_undefined_error);
     }
 
+    #[test]
+    fn test_type_check_template_severity_override() {
+        let source = r#"<script setup>
+export const exported = 1;
+</script>
+<template>
+    <div>{{ undefinedVar }}</div>
+</template>"#;
+        let options =
+            SfcTypeCheckOptions::new("test.vue").with_template_severity(SfcTypeSeverity::Warning);
+        let result = type_check_sfc(source, &options);
+
+        let severity_of = |code: &str| {
+            result
+                .diagnostics
+                .iter()
+                .find(|d| d.code.as_deref() == Some(code))
+                .map(|d| d.severity)
+        };
+        assert_eq!(
+            severity_of("undefined-binding"),
+            Some(SfcTypeSeverity::Warning)
+        );
+        // Script diagnostics keep their severity
+        assert_eq!(severity_of("invalid-export"), Some(SfcTypeSeverity::Error));
+        assert!(result.has_errors());
+        assert!(result.warning_count >= 1);
+    }
+
     #[test]
     fn test_type_check_defined_binding() {
         let source = r#"<script setup>
@@ -128,6 +128,18 @@ pub fn type_check_sfc(source: &str, options: &SfcTypeCheckOptions) -> SfcTypeChe
         check_fallthrough_attrs(&summary, &mut result, options.strict);
     }
 
+    // Remap template diagnostics to the configured severity
+    if let (Some(severity), Some(template)) = (
+        options.template_severity_override,
+        descriptor.template.as_ref(),
+    ) {
+        result.override_severity_in_range(
+            template.loc.start as u32,
+            template.loc.end as u32,
+            severity,
+        );
+    }
+
     // Generate virtual TypeScript with scope information if requested
     if options.include_virtual_ts {
         result.virtual_ts = Some(generate_virtual_ts_with_scopes(
@@ -46,6 +46,50 @@ pub struct SfcTypeCheckResult {
     pub analysis_time_ms: Option<f64>,
 }
 
+impl SfcTypeCheckResult {
+    /// Merge diagnostics that map to the same original range with the same
+    /// message and code.
+    ///
+    /// A binding used from both the script and the template can surface the same
+    /// error once per virtual file. The first occurrence is kept, related
+    /// information from the duplicates is folded into it, and the more severe
+    /// severity wins. Error and warning counts are recomputed.
+    pub fn dedupe_diagnostics(&mut self) {
+        let mut merged: Vec<SfcDiagnostic> = Vec::with_capacity(self.diagnostics.len());
+        for diagnostic in std::mem::take(&mut self.diagnostics) {
+            let existing = merged.iter_mut().find(|d| {
+                d.start == diagnostic.start
+                    && d.end == diagnostic.end
+                    && d.message == diagnostic.message
+                    && d.code == diagnostic.code
+            });
+            match existing {
+                Some(existing) => {
+                    if diagnostic.severity.rank() < existing.severity.rank() {
+                        existing.severity = diagnostic.severity;
+                    }
+                    for related in diagnostic.related {
+                        if !existing.related.contains(&related) {
+                            existing.related.push(related);
+                        }
+                    }
+                }
+                None => merged.push(diagnostic),
+            }
+        }
+
+        self.error_count = merged
+            .iter()
+            .filter(|d| d.severity == SfcDiagnosticSeverity::Error)
+            .count();
+        self.warning_count = merged
+            .iter()
+            .filter(|d| d.severity == SfcDiagnosticSeverity::Warning)
+            .count();
+        self.diagnostics = merged;
+    }
+}
+
 /// A diagnostic from type checking.
 #[derive(Debug, Clone)]
 pub struct SfcDiagnostic {
@@ -76,8 +120,20 @@ pub enum SfcDiagnosticSeverity {
     Hint,
 }
 
+impl SfcDiagnosticSeverity {
+    /// Sort rank, most severe first.
+    fn rank(self) -> u8 {
+        match self {
+            Self::Error => 0,
+            Self::Warning => 1,
+            Self::Info => 2,
+            Self::Hint => 3,
+        }
+    }
+}
+
 /// Related diagnostic information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SfcRelatedInfo {
     /// Message.
     pub message: String,
@@ -259,6 +315,8 @@ impl TypeCheckService {
             self.bridge.close_virtual_document(&virtual_uri).await?;
         }
 
+        result.dedupe_diagnostics();
+
         result.analysis_time_ms = Some(start_time.elapsed().as_secs_f64() * 1000.0);
         Ok(result)
     }
@@ -319,7 +377,10 @@ fn map_position_to_sfc(
 
 #[cfg(test)]
 mod tests {
-    use super::{SfcDiagnosticSeverity, TypeCheckServiceOptions};
+    use super::{
+        SfcDiagnostic, SfcDiagnosticSeverity, SfcRelatedInfo, SfcTypeCheckResult,
+        TypeCheckServiceOptions,
+    };
 
     #[test]
     fn test_sfc_diagnostic_severity() {
@@ -335,4 +396,59 @@ mod tests {
         assert!(!opts.check_cross_component);
         assert!(!opts.check_template);
     }
+
+    fn diagnostic(
+        severity: SfcDiagnosticSeverity,
+        message: &str,
+        related: Vec<SfcRelatedInfo>,
+    ) -> SfcDiagnostic {
+        SfcDiagnostic {
+            message: message.into(),
+            severity,
+            start: 10,
+            end: 15,
+            code: Some("TS2339".into()),
+            related,
+        }
+    }
+
+    fn related(start: u32) -> SfcRelatedInfo {
+        SfcRelatedInfo {
+            message: "declared here".into(),
+            filename: None,
+            start,
+            end: start + 3,
+        }
+    }
+
+    #[test]
+    fn test_dedupe_diagnostics_merges_related_info() {
+        let mut result = SfcTypeCheckResult {
+            diagnostics: vec![
+                diagnostic(
+                    SfcDiagnosticSeverity::Warning,
+                    "Property 'foo' does not exist",
+                    vec![related(1)],
+                ),
+                diagnostic(
+                    SfcDiagnosticSeverity::Error,
+                    "Property 'foo' does not exist",
+                    vec![related(1), related(40)],
+                ),
+                diagnostic(SfcDiagnosticSeverity::Error, "Other error", Vec::new()),
+            ],
+            error_count: 2,
+            warning_count: 1,
+            ..Default::default()
+        };
+
+        result.dedupe_diagnostics();
+
+        assert_eq!(result.diagnostics.len(), 2);
+        let merged = &result.diagnostics[0];
+        assert_eq!(merged.severity, SfcDiagnosticSeverity::Error);
+        assert_eq!(merged.related, vec![related(1), related(40)]);
+        assert_eq!(result.error_count, 2);
+        assert_eq!(result.warning_count, 0);
+    }
 }
@@ -3,12 +3,12 @@
 //! Contains the public `generate_virtual_ts` and `generate_virtual_ts_with_offsets`
 //! functions that orchestrate the full virtual TypeScript generation pipeline.
 
-use vize_croquis::{BindingType, Croquis, ScopeData, ScopeKind, COMPILER_MACRO_NAMES};
+use vize_croquis::{Croquis, ScopeData, ScopeKind, COMPILER_MACRO_NAMES};
 
 use super::{
     helpers::{
-        generate_template_context, to_safe_identifier, IMPORT_META_AUGMENTATION,
-        VUE_SETUP_COMPILER_MACROS,
+        generate_template_context, include_leading_ts_directives, to_safe_identifier,
+        IMPORT_META_AUGMENTATION, VUE_SETUP_COMPILER_MACROS,
     },
     props::{generate_props_type, generate_props_variables},
     scope::generate_scope_closures,
@@ -113,6 +113,12 @@ pub fn generate_virtual_ts_with_offsets(
         for te in &summary.type_exports {
             module_spans.push((te.start, te.end));
         }
+        // Directive comments travel with the statement they suppress
+        if let Some(script) = script_content {
+            for (start, _) in &mut module_spans {
+                *start = include_leading_ts_directives(script, *start);
+            }
+        }
         module_spans.sort_by_key(|&(start, _)| start);
         module_spans
     });
@@ -311,11 +317,7 @@ pub fn generate_virtual_ts_with_offsets(
                 .bindings
                 .bindings
                 .iter()
-                .filter(|(name, binding_type)| {
-                    summary.reactivity.needs_value_access(name.as_str())
-                        || matches!(binding_type, BindingType::SetupMaybeRef)
-                            && is_use_template_ref_binding(summary, script_content, name.as_str())
-                })
+                .filter(|(name, _)| summary.reactivity.needs_value_access(name.as_str()))
                 .map(|(name, _)| name.as_str())
                 .collect();
 
@@ -631,32 +633,3 @@ fn extract_import_names(import_text: &str) -> Vec<&str> {
 
     names
 }
-
-fn is_use_template_ref_binding(
-    summary: &Croquis,
-    script_content: Option<&str>,
-    name: &str,
-) -> bool {
-    let Some(script) = script_content else {
-        return false;
-    };
-    let Some(&(start, end)) = summary.binding_spans.get(name) else {
-        return false;
-    };
-
-    if summary
-        .import_statements
-        .iter()
-        .any(|import| start >= import.start && end <= import.end)
-    {
-        return false;
-    }
-
-    let tail_end = (end as usize + 256).min(script.len());
-    let tail = &script[end as usize..tail_end];
-    let Some(eq_pos) = tail.find('=') else {
-        return false;
-    };
-    let rhs = tail[eq_pos + 1..].trim_start();
-    rhs.starts_with("useTemplateRef") || rhs.starts_with("(useTemplateRef")
-}
@@ -147,6 +147,41 @@ pub(crate) fn generated_text_range(
     start..start + mapped_text.len()
 }
 
+/// Extend a module-level statement start over the `// @ts-expect-error` or
+/// `// @ts-ignore` comment lines directly above it.
+///
+/// Module-level statements are hoisted out of `__setup()`, so their directive
+/// comments must move with them. Left behind, the directive would apply to
+/// whatever setup code follows, and tsc's "Unused '@ts-expect-error'
+/// directive" would point at the wrong statement.
+pub(crate) fn include_leading_ts_directives(script: &str, start: u32) -> u32 {
+    let Some(before) = script.get(..start as usize) else {
+        return start;
+    };
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    if !before[line_start..].trim().is_empty() {
+        return start;
+    }
+
+    let mut extended = start;
+    let mut cursor = line_start;
+    while cursor > 0 {
+        let prev_end = cursor - 1;
+        let prev_start = script[..prev_end].rfind('\n').map_or(0, |index| index + 1);
+        let Some(comment) = script[prev_start..prev_end].trim().strip_prefix("//") else {
+            break;
+        };
+        let comment = comment.trim_start_matches('/').trim_start();
+        if !comment.starts_with("@ts-expect-error") && !comment.starts_with("@ts-ignore") {
+            break;
+        }
+        extended = prev_start as u32;
+        cursor = prev_start;
+    }
+
+    extended
+}
+
 /// Strip TypeScript `as Type` assertion from a v-for source expression.
 /// Returns (source_expression, Option<type_annotation>).
 /// e.g., "(expr) as OptionSponsor[]" -> ("(expr)", Some("OptionSponsor[]"))
@@ -236,6 +236,39 @@ const items = ['a', 'b']
         assert_virtual_ts_snapshot("virtual_ts_scoped_slot_expressions", output.code.as_str());
     }
 
+    #[test]
+    fn test_slot_props_typed_from_component_slots() {
+        use vize_croquis::{Analyzer, AnalyzerOptions};
+
+        let script = r#"import MyList from './MyList.vue'
+const name = 'footer'
+"#;
+        let template = r#"<div>
+  <MyList v-slot="{ item }">{{ item }}</MyList>
+  <MyList>
+    <template #[name]="{ row }">{{ row }}</template>
+  </MyList>
+</div>"#;
+
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, template);
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(script);
+        analyzer.analyze_template(&root);
+        let summary = analyzer.finish();
+
+        let output = generate_virtual_ts(&summary, Some(script), Some(&root), 0);
+        let code = output.code.as_str();
+
+        assert!(code.contains("type __VizeSlotProps<C, N extends PropertyKey>"));
+        assert!(code.contains(
+            "void function _slot_default({ item }: __VizeSlotProps<typeof MyList, 'default'>) {"
+        ));
+        // Dynamic slot names cannot be looked up statically
+        assert!(code.contains("({ row }: any) {"));
+    }
+
     #[test]
     fn test_multiple_event_handlers() {
         use vize_croquis::{Analyzer, AnalyzerOptions};
@@ -330,6 +363,49 @@ const inputRef = useTemplateRef<HTMLInputElement>('input')
         assert_eq!(&output.code[mapping.gen_range.clone()], expression);
     }
 
+    #[test]
+    fn test_ts_directive_comments_move_with_module_statements() {
+        use vize_croquis::{Analyzer, AnalyzerOptions};
+
+        let script = r#"const before = 1
+// @ts-expect-error untyped module
+import legacy from 'legacy-lib'
+// a regular comment
+import { ref } from 'vue'
+// @ts-expect-error
+const count: number = 'x'
+"#;
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(script);
+        let summary = analyzer.finish();
+
+        let output = generate_virtual_ts(&summary, Some(script), None, 0);
+        let code = output.code.as_str();
+        let setup_start = code.find("function __setup").unwrap();
+
+        // The directive is emitted at module scope, right above its import
+        let hoisted = "// @ts-expect-error untyped module\nimport legacy from 'legacy-lib'\n";
+        let hoisted_at = code
+            .find(hoisted)
+            .expect("directive should precede its import");
+        assert!(hoisted_at < setup_start);
+        assert_eq!(code.matches("@ts-expect-error untyped module").count(), 1);
+
+        // Plain comments and directives on setup code stay where they were
+        assert!(code[setup_start..].contains("  // a regular comment\n"));
+        assert!(code[setup_start..].contains("  // @ts-expect-error\n  const count"));
+
+        // The hoisted directive maps back to its original line
+        let directive_start = script.find("// @ts-expect-error untyped").unwrap();
+        let mapping = output
+            .mappings
+            .iter()
+            .find(|mapping| mapping.src_range.start == directive_start)
+            .expect("should map the directive comment");
+        assert_eq!(mapping.gen_range.start, hoisted_at);
+    }
+
     #[test]
     fn test_template_shadow_bindings_only_unwrap_vue_refs() {
         use vize_croquis::{Analyzer, AnalyzerOptions};
@@ -11,7 +11,7 @@ use vize_carton::String;
 
 use vize_croquis::{
     analysis::ComponentUsage, naming::to_pascal_case, Croquis, EventHandlerScopeData, Scope,
-    ScopeData, ScopeId, ScopeKind,
+    ScopeData, ScopeId, ScopeKind, VSlotScopeData,
 };
 
 use super::{
@@ -96,6 +96,17 @@ pub(crate) fn generate_scope_closures(
                 .collect()
         });
 
+    // Slot props helper: reads a slot's parameter type from the component's `$slots`
+    let has_typed_slots = summary
+        .scopes
+        .iter()
+        .any(|scope| matches!(scope.data(), ScopeData::VSlot(data) if data.component.is_some()));
+    if has_typed_slots {
+        ts.push_str(
+            "  type __VizeSlotProps<C, N extends PropertyKey> = C extends new (...args: any) => { $slots: infer S } ? (N extends keyof S ? (NonNullable<S[N]> extends (props: infer P, ...args: any) => any ? P : any) : any) : any;\n",
+        );
+    }
+
     // Process non-nested scopes at template level
     for scope in summary.scopes.iter() {
         let scope_id = scope.id.as_u32();
@@ -419,9 +430,10 @@ fn generate_scope_node(
             append!(*ts, "\n{indent}// v-slot scope: #{}\n", data.name);
 
             let props_pattern = data.props_pattern.as_deref().unwrap_or("slotProps");
+            let props_type = slot_props_type(data);
             append!(
                 *ts,
-                "{indent}void function _slot_{}({props_pattern}: any) {{\n",
+                "{indent}void function _slot_{}({props_pattern}: {props_type}) {{\n",
                 data.name,
             );
             // Mark slot prop variables as used
@@ -720,9 +732,10 @@ fn generate_closure_component_props_recursive(
                 "\n{indent}// Component props in v-slot scope: #{}\n",
                 data.name
             );
+            let props_type = slot_props_type(data);
             append!(
                 *ts,
-                "{indent}void function _slot_props_{}({props_pattern}: any) {{\n",
+                "{indent}void function _slot_props_{}({props_pattern}: {props_type}) {{\n",
                 data.name,
             );
             // Mark slot prop variables as used
@@ -777,3 +790,26 @@ fn generate_closure_component_props_recursive(
         _ => {}
     }
 }
+
+/// Type of a v-slot's props parameter.
+///
+/// Resolved from the providing component's `$slots` (e.g. its `defineSlots`
+/// declaration) when the component is known, `any` otherwise.
+fn slot_props_type(data: &VSlotScopeData) -> String {
+    match data.component.as_deref() {
+        Some(component) if is_component_reference(component) => {
+            cstr!("__VizeSlotProps<typeof {component}, '{}'>", data.name)
+        }
+        _ => "any".into(),
+    }
+}
+
+/// Whether a component tag can be referenced as a TypeScript value (`Foo`, `Foo.Bar`).
+fn is_component_reference(tag: &str) -> bool {
+    tag.split('.').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    })
+}
@@ -1,5 +1,6 @@
 //! DOM tag configuration shared between compiler-dom and runtime-dom.
 
+use crate::{FxHashSet, String};
 use phf::phf_set;
 
 /// HTML tags
@@ -89,6 +90,71 @@ pub fn is_native_tag(tag: &str) -> bool {
     is_html_tag(tag) || is_svg_tag(tag) || is_math_ml_tag(tag)
 }
 
+/// Tag predicates backed by the built-in tag sets, extendable at runtime.
+///
+/// The default configuration has no additions and answers exactly like the
+/// free functions ([`is_html_tag`], [`is_svg_tag`], [`is_native_tag`]); the
+/// addition sets are only consulted when they are non-empty.
+#[derive(Debug, Clone, Default)]
+pub struct TagConfig {
+    /// Extra tags treated as native HTML elements
+    pub html_tags: FxHashSet<String>,
+    /// Extra tags treated as native SVG elements
+    pub svg_tags: FxHashSet<String>,
+}
+
+impl TagConfig {
+    /// Add tags to be treated as native HTML elements.
+    pub fn with_html_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.html_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add tags to be treated as native SVG elements.
+    pub fn with_svg_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.svg_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Whether any tags were added on top of the built-in sets
+    #[inline]
+    pub fn has_additions(&self) -> bool {
+        !self.html_tags.is_empty() || !self.svg_tags.is_empty()
+    }
+
+    /// Check if tag is a user-added HTML or SVG tag
+    #[inline]
+    pub fn is_additional_tag(&self, tag: &str) -> bool {
+        self.has_additions() && (self.html_tags.contains(tag) || self.svg_tags.contains(tag))
+    }
+
+    /// Check if tag is a valid HTML tag, including additions
+    #[inline]
+    pub fn is_html_tag(&self, tag: &str) -> bool {
+        is_html_tag(tag) || (!self.html_tags.is_empty() && self.html_tags.contains(tag))
+    }
+
+    /// Check if tag is a valid SVG tag, including additions
+    #[inline]
+    pub fn is_svg_tag(&self, tag: &str) -> bool {
+        is_svg_tag(tag) || (!self.svg_tags.is_empty() && self.svg_tags.contains(tag))
+    }
+
+    /// Check if tag is a native tag (HTML, SVG, or MathML), including additions
+    #[inline]
+    pub fn is_native_tag(&self, tag: &str) -> bool {
+        is_native_tag(tag) || self.is_additional_tag(tag)
+    }
+}
+
 /// Special tags that contain raw text
 pub static RAW_TEXT_TAGS: phf::Set<&'static str> = phf_set! {
     "style", "script", "textarea", "title"
@@ -113,7 +179,7 @@ pub fn is_rcdata_tag(tag: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{is_html_tag, is_raw_text_tag, is_svg_tag, is_void_tag};
+    use super::{is_html_tag, is_raw_text_tag, is_svg_tag, is_void_tag, TagConfig};
 
     #[test]
     fn test_html_tags() {
@@ -146,4 +212,24 @@ mod tests {
         assert!(is_raw_text_tag("style"));
         assert!(!is_raw_text_tag("div"));
     }
+
+    #[test]
+    fn test_tag_config_additions() {
+        let default = TagConfig::default();
+        assert!(!default.has_additions());
+        assert!(default.is_native_tag("div"));
+        assert!(!default.is_native_tag("selectedcontent"));
+
+        let config = TagConfig::default()
+            .with_html_tags(["selectedcontent"])
+            .with_svg_tags(["my-glyph"]);
+        assert!(config.has_additions());
+        assert!(config.is_html_tag("selectedcontent"));
+        assert!(!config.is_svg_tag("selectedcontent"));
+        assert!(config.is_svg_tag("my-glyph"));
+        assert!(config.is_native_tag("selectedcontent"));
+        assert!(config.is_native_tag("my-glyph"));
+        assert!(config.is_native_tag("circle"));
+        assert!(!config.is_native_tag("MyButton"));
+    }
 }
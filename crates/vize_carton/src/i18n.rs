@@ -14,6 +14,13 @@
 //! - `en` - English (default, always available)
 //! - `ja` - Japanese
 //! - `zh` - Chinese (Simplified)
+//! - `fr` - French (partial, falls back to English)
+//!
+//! ## Fallback Chains
+//!
+//! A lookup walks a [`LocaleChain`]: the primary locale, an optional
+//! fallback, then English. Missing keys in a partially translated locale
+//! therefore render in the next locale of the chain instead of as a raw key.
 //!
 //! ## Usage
 //!
@@ -27,6 +34,9 @@
 //!
 //! // With variable substitution (returns String)
 //! let msg = translator.format(Locale::Ja, "error.unexpected_token", &[("token", "}")]);
+//!
+//! // French first, then Japanese, then English
+//! let msg = translator.get(Locale::with_fallback(Locale::Fr, Locale::Ja), "error.parse_failed");
 //! ```
 
 use once_cell::sync::Lazy;
@@ -45,6 +55,8 @@ pub enum Locale {
     Ja = 1,
     /// Chinese (Simplified)
     Zh = 2,
+    /// French
+    Fr = 3,
 }
 
 /// Error type for parsing Locale from string
@@ -68,6 +80,7 @@ impl FromStr for Locale {
             "en" | "en-us" | "en-gb" | "english" => Ok(Self::En),
             "ja" | "ja-jp" | "japanese" => Ok(Self::Ja),
             "zh" | "zh-cn" | "zh-hans" | "chinese" => Ok(Self::Zh),
+            "fr" | "fr-fr" | "french" => Ok(Self::Fr),
             _ => Err(ParseLocaleError),
         }
     }
@@ -75,7 +88,7 @@ impl FromStr for Locale {
 
 impl Locale {
     /// All available locales
-    pub const ALL: &'static [Locale] = &[Locale::En, Locale::Ja, Locale::Zh];
+    pub const ALL: &'static [Locale] = &[Locale::En, Locale::Ja, Locale::Zh, Locale::Fr];
 
     /// Try to parse locale from string (case-insensitive)
     #[inline]
@@ -90,6 +103,7 @@ impl Locale {
             Self::En => "en",
             Self::Ja => "ja",
             Self::Zh => "zh",
+            Self::Fr => "fr",
         }
     }
 
@@ -100,6 +114,7 @@ impl Locale {
             Self::En => "English",
             Self::Ja => "日本語",
             Self::Zh => "中文",
+            Self::Fr => "Français",
         }
     }
 
@@ -108,8 +123,62 @@ impl Locale {
     pub const fn index(self) -> usize {
         self as usize
     }
+
+    /// Build a lookup chain that tries `primary`, then `fallback`, then English
+    #[inline]
+    pub const fn with_fallback(primary: Locale, fallback: Locale) -> LocaleChain {
+        LocaleChain {
+            primary,
+            fallback: Some(fallback),
+        }
+    }
 }
 
+/// Ordered list of locales consulted for a message lookup
+///
+/// English is always consulted last, so it does not need to be listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LocaleChain {
+    primary: Locale,
+    fallback: Option<Locale>,
+}
+
+impl LocaleChain {
+    /// Get the locale tried first
+    #[inline]
+    pub const fn primary(self) -> Locale {
+        self.primary
+    }
+
+    /// Get the explicit fallback locale, if any
+    #[inline]
+    pub const fn fallback(self) -> Option<Locale> {
+        self.fallback
+    }
+
+    /// Iterate the locales in lookup order, without duplicates
+    pub fn iter(self) -> impl Iterator<Item = Locale> {
+        let mut seen = [false; LOCALE_COUNT];
+        [Some(self.primary), self.fallback, Some(Locale::En)]
+            .into_iter()
+            .flatten()
+            .filter(move |locale| !std::mem::replace(&mut seen[locale.index()], true))
+    }
+}
+
+impl From<Locale> for LocaleChain {
+    #[inline]
+    fn from(primary: Locale) -> Self {
+        Self {
+            primary,
+            fallback: None,
+        }
+    }
+}
+
+/// Number of supported locales (size of the per-locale message table)
+const LOCALE_COUNT: usize = Locale::ALL.len();
+
 /// Message domain for organizing translations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Domain {
@@ -190,7 +259,7 @@ impl std::fmt::Display for Message {
 /// 2. HashMap lookup by key (O(1) average)
 pub struct Translator {
     /// Messages indexed by [locale][key]
-    messages: [FxHashMap<&'static str, &'static str>; 3],
+    messages: [FxHashMap<&'static str, &'static str>; LOCALE_COUNT],
 }
 
 impl Translator {
@@ -202,20 +271,13 @@ impl Translator {
 
     /// Get a message without variable substitution
     ///
-    /// Returns the English fallback if the key is not found in the requested locale.
+    /// Accepts a single [`Locale`] or a [`LocaleChain`]; each locale of the
+    /// chain is tried in order, ending with English.
     /// Returns the key itself if not found in any locale.
     #[inline]
-    pub fn get(&self, locale: Locale, key: &str) -> Cow<'static, str> {
-        let idx = locale.index();
-
-        // Try requested locale first
-        if let Some(msg) = self.messages[idx].get(key) {
-            return Cow::Borrowed(*msg);
-        }
-
-        // Fall back to English
-        if locale != Locale::En {
-            if let Some(msg) = self.messages[0].get(key) {
+    pub fn get(&self, locale: impl Into<LocaleChain>, key: &str) -> Cow<'static, str> {
+        for locale in locale.into().iter() {
+            if let Some(msg) = self.messages[locale.index()].get(key) {
                 return Cow::Borrowed(*msg);
             }
         }
@@ -228,7 +290,12 @@ impl Translator {
     ///
     /// Variables are specified as `{name}` in the message template.
     #[inline]
-    pub fn format(&self, locale: Locale, key: &str, vars: &[(&str, &str)]) -> String {
+    pub fn format(
+        &self,
+        locale: impl Into<LocaleChain>,
+        key: &str,
+        vars: &[(&str, &str)],
+    ) -> String {
         let template = self.get(locale, key);
 
         if vars.is_empty() {
@@ -272,16 +339,14 @@ impl Clone for Translator {
 
 // Global translator instance (initialized once, lives forever)
 static GLOBAL_TRANSLATOR: Lazy<Translator> = Lazy::new(|| {
-    let mut messages: [FxHashMap<&'static str, &'static str>; 3] = [
-        FxHashMap::default(),
-        FxHashMap::default(),
-        FxHashMap::default(),
-    ];
+    let mut messages: [FxHashMap<&'static str, &'static str>; LOCALE_COUNT] =
+        std::array::from_fn(|_| FxHashMap::default());
 
     // Load embedded translations
     load_json(&mut messages[0], include_str!("i18n/en.json"));
     load_json(&mut messages[1], include_str!("i18n/ja.json"));
     load_json(&mut messages[2], include_str!("i18n/zh.json"));
+    load_json(&mut messages[3], include_str!("i18n/fr.json"));
 
     Translator { messages }
 });
@@ -423,19 +488,19 @@ pub fn translator() -> &'static Translator {
 
 /// Convenience function to translate a message
 #[inline]
-pub fn t(locale: Locale, key: &str) -> Cow<'static, str> {
+pub fn t(locale: impl Into<LocaleChain>, key: &str) -> Cow<'static, str> {
     translator().get(locale, key)
 }
 
 /// Convenience function to translate with variables
 #[inline]
-pub fn t_fmt(locale: Locale, key: &str, vars: &[(&str, &str)]) -> String {
+pub fn t_fmt(locale: impl Into<LocaleChain>, key: &str, vars: &[(&str, &str)]) -> String {
     translator().format(locale, key, vars)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{unescape_json_string, Locale, Translator};
+    use super::{unescape_json_string, Locale, LocaleChain, Translator};
 
     #[test]
     fn test_locale_from_str() {
@@ -445,6 +510,7 @@ mod tests {
         assert_eq!("JA-JP".parse::<Locale>(), Ok(Locale::Ja));
         assert_eq!("zh".parse::<Locale>(), Ok(Locale::Zh));
         assert_eq!("zh-CN".parse::<Locale>(), Ok(Locale::Zh));
+        assert_eq!("fr-FR".parse::<Locale>(), Ok(Locale::Fr));
         assert!("unknown".parse::<Locale>().is_err());
     }
 
@@ -461,6 +527,7 @@ mod tests {
         assert_eq!(Locale::En.code(), "en");
         assert_eq!(Locale::Ja.code(), "ja");
         assert_eq!(Locale::Zh.code(), "zh");
+        assert_eq!(Locale::Fr.code(), "fr");
     }
 
     #[test]
@@ -468,6 +535,7 @@ mod tests {
         assert_eq!(Locale::En.display_name(), "English");
         assert_eq!(Locale::Ja.display_name(), "日本語");
         assert_eq!(Locale::Zh.display_name(), "中文");
+        assert_eq!(Locale::Fr.display_name(), "Français");
     }
 
     #[test]
@@ -487,6 +555,56 @@ mod tests {
         assert!(!msg.is_empty());
     }
 
+    #[test]
+    fn test_locale_chain_order() {
+        let chain = Locale::with_fallback(Locale::Fr, Locale::Ja);
+        assert_eq!(
+            chain.iter().collect::<Vec<_>>(),
+            [Locale::Fr, Locale::Ja, Locale::En]
+        );
+
+        let chain = Locale::with_fallback(Locale::Fr, Locale::En);
+        assert_eq!(chain.iter().collect::<Vec<_>>(), [Locale::Fr, Locale::En]);
+
+        let chain = LocaleChain::from(Locale::En);
+        assert_eq!(chain.iter().collect::<Vec<_>>(), [Locale::En]);
+    }
+
+    #[test]
+    fn test_fallback_chain_fills_missing_rule_message() {
+        let t = Translator::new();
+        let fr = Locale::with_fallback(Locale::Fr, Locale::En);
+
+        // Translated rule message renders in French
+        assert_eq!(
+            t.format(fr, "vue/require-v-for-key.message", &[("tag", "li")]),
+            "Les éléments itérés doivent avoir une directive 'v-bind:key'. Élément : <li>"
+        );
+
+        // Untranslated rule message falls back to English instead of the key
+        assert!(!t.has_key(Locale::Fr, "vue/valid-v-if.missing_expression"));
+        assert_eq!(
+            t.get(fr, "vue/valid-v-if.missing_expression"),
+            t.get(Locale::En, "vue/valid-v-if.missing_expression")
+        );
+        assert_eq!(
+            t.get(fr, "vue/valid-v-if.missing_expression"),
+            "v-if directive requires an expression"
+        );
+
+        // An explicit fallback is consulted before English
+        assert_eq!(
+            t.get(
+                Locale::with_fallback(Locale::Fr, Locale::Ja),
+                "vue/valid-v-if.missing_expression"
+            ),
+            "v-ifディレクティブには式が必要です"
+        );
+
+        // Unknown keys still render as the key
+        assert_eq!(t.get(fr, "missing.key"), "missing.key");
+    }
+
     #[test]
     fn test_unescape_json_string() {
         assert_eq!(unescape_json_string("hello"), "hello");
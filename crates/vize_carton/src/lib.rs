@@ -45,6 +45,7 @@ pub mod general;
 pub mod hash;
 pub mod i18n;
 pub mod lsp;
+pub mod ordered_map;
 pub mod profiler;
 pub mod source_range;
 pub mod string_builder;
@@ -77,6 +78,9 @@ pub use rustc_hash::{FxHashMap, FxHashSet};
 // Re-export phf for compile-time perfect hash functions
 pub use phf::{phf_map, phf_set, Map as PhfMap, Set as PhfSet};
 
+// Re-export insertion-ordered map
+pub use ordered_map::OrderedMap;
+
 // Re-export shared utilities
 pub use dom_tag_config::*;
 pub use flags::*;
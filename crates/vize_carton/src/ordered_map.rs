@@ -0,0 +1,403 @@
+//! Insertion-ordered map.
+//!
+//! [`OrderedMap`] keeps its entries in a `Vec` in insertion order, so
+//! iteration (and therefore codegen or serialization) follows the order in
+//! which keys were first inserted. Lookups are linear scans for small maps;
+//! once a map grows past [`INDEX_THRESHOLD`] entries, a hash index is built
+//! to keep lookups O(1).
+//!
+//! Attribute lists are almost always tiny, so most maps never allocate the
+//! index at all.
+//!
+//! # Example
+//!
+//! ```
+//! use vize_carton::OrderedMap;
+//!
+//! let mut attrs = OrderedMap::new();
+//! attrs.insert("lang", "ts");
+//! attrs.insert("setup", "");
+//! attrs.insert("lang", "tsx");
+//!
+//! assert_eq!(attrs.get("lang"), Some(&"tsx"));
+//! let keys: Vec<_> = attrs.keys().copied().collect();
+//! assert_eq!(keys, ["lang", "setup"]);
+//! ```
+
+use std::{borrow::Borrow, fmt, hash::Hash, marker::PhantomData};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::FxHashMap;
+
+/// Number of entries above which a hash index is maintained.
+pub const INDEX_THRESHOLD: usize = 16;
+
+/// A map that preserves insertion order.
+///
+/// Re-inserting an existing key replaces its value but keeps its original
+/// position.
+#[derive(Clone)]
+pub struct OrderedMap<K, V> {
+    entries: std::vec::Vec<(K, V)>,
+    index: Option<FxHashMap<K, usize>>,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Create an empty map.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: std::vec::Vec::new(),
+            index: None,
+        }
+    }
+
+    /// Create an empty map with room for `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: std::vec::Vec::with_capacity(capacity),
+            index: None,
+        }
+    }
+
+    /// Number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over `(key, value)` pairs in insertion order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over keys in insertion order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Iterate over values in insertion order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Get the entry at `index` (in insertion order).
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|entry| (&entry.0, &entry.1))
+    }
+
+    /// Remove all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index = None;
+    }
+}
+
+impl<K: Eq + Hash, V> OrderedMap<K, V> {
+    /// Position of `key` in insertion order.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        match &self.index {
+            Some(index) => index.get(key).copied(),
+            None => self.entries.iter().position(|(k, _)| k.borrow() == key),
+        }
+    }
+
+    /// Get a reference to the value for `key`.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.get_index_of(key).map(|i| &self.entries[i].1)
+    }
+
+    /// Get a mutable reference to the value for `key`.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.get_index_of(key).map(|i| &mut self.entries[i].1)
+    }
+
+    /// Whether the map contains `key`.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> OrderedMap<K, V> {
+    /// Insert a value, returning the previous value for the key.
+    ///
+    /// New keys are appended; existing keys keep their position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(i) = self.get_index_of(&key) {
+            return Some(std::mem::replace(&mut self.entries[i].1, value));
+        }
+
+        let position = self.entries.len();
+        match &mut self.index {
+            Some(index) => {
+                index.insert(key.clone(), position);
+            }
+            None if position >= INDEX_THRESHOLD => {
+                let mut index = self.build_index();
+                index.insert(key.clone(), position);
+                self.index = Some(index);
+            }
+            None => {}
+        }
+        self.entries.push((key, value));
+        None
+    }
+
+    /// Remove `key`, shifting later entries to keep the order.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let i = self.get_index_of(key)?;
+        let (_, value) = self.entries.remove(i);
+        if self.index.is_some() {
+            self.index = if self.entries.len() > INDEX_THRESHOLD {
+                Some(self.build_index())
+            } else {
+                None
+            };
+        }
+        Some(value)
+    }
+
+    fn build_index(&self) -> FxHashMap<K, usize> {
+        let mut index = FxHashMap::default();
+        index.reserve(self.entries.len() + 1);
+        for (i, (k, _)) in self.entries.iter().enumerate() {
+            index.insert(k.clone(), i);
+        }
+        index
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for OrderedMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for OrderedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for OrderedMap<K, V> {}
+
+impl<K: Eq + Hash + Clone, V> FromIterator<(K, V)> for OrderedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Extend<(K, V)> for OrderedMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'m, K, V> IntoIterator for &'m OrderedMap<K, V> {
+    type Item = (&'m K, &'m V);
+    type IntoIter = Iter<'m, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over `(&K, &V)` pairs of an [`OrderedMap`].
+pub struct Iter<'m, K, V> {
+    inner: std::slice::Iter<'m, (K, V)>,
+}
+
+impl<'m, K, V> Iterator for Iter<'m, K, V> {
+    type Item = (&'m K, &'m V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|entry| (&entry.0, &entry.1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|entry| (&entry.0, &entry.1))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K: Serialize, V: Serialize> Serialize for OrderedMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (k, v) in self.iter() {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for OrderedMap<K, V>
+where
+    K: Deserialize<'de> + Eq + Hash + Clone,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedMapVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for OrderedMapVisitor<K, V>
+        where
+            K: Deserialize<'de> + Eq + Hash + Clone,
+            V: Deserialize<'de>,
+        {
+            type Value = OrderedMap<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = OrderedMap::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some((k, v)) = access.next_entry()? {
+                    map.insert(k, v);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(OrderedMapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrderedMap, INDEX_THRESHOLD};
+
+    #[test]
+    fn test_insert_preserves_order() {
+        let mut map = OrderedMap::new();
+        map.insert("b", 1);
+        map.insert("a", 2);
+        map.insert("c", 3);
+        assert_eq!(map.insert("a", 20), Some(2));
+
+        let entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(entries, [("b", 1), ("a", 20), ("c", 3)]);
+        assert_eq!(map.get("a"), Some(&20));
+        assert!(map.contains_key("c"));
+        assert!(!map.contains_key("d"));
+    }
+
+    #[test]
+    fn test_remove_shifts_entries() {
+        let mut map: OrderedMap<_, _> = [("x", 1), ("y", 2), ("z", 3)].into_iter().collect();
+        assert_eq!(map.remove("y"), Some(2));
+        assert_eq!(map.remove("y"), None);
+        assert_eq!(map.get_index(1), Some((&"z", &3)));
+        assert_eq!(map.get_index_of("z"), Some(1));
+    }
+
+    #[test]
+    fn test_indexed_lookups() {
+        let mut map = OrderedMap::new();
+        for i in 0..INDEX_THRESHOLD * 2 {
+            map.insert(i, i * 10);
+        }
+        assert!(map.index.is_some());
+        assert_eq!(
+            map.get(&(INDEX_THRESHOLD + 3)),
+            Some(&((INDEX_THRESHOLD + 3) * 10))
+        );
+
+        map.remove(&0);
+        assert_eq!(map.get_index_of(&1), Some(0));
+        assert_eq!(
+            map.get(&(INDEX_THRESHOLD * 2 - 1)),
+            Some(&((INDEX_THRESHOLD * 2 - 1) * 10))
+        );
+
+        let keys: Vec<_> = map.keys().copied().collect();
+        assert_eq!(keys, (1..INDEX_THRESHOLD * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_serde_roundtrip_keeps_order() {
+        let mut map = OrderedMap::new();
+        map.insert("zeta", "1");
+        map.insert("alpha", "2");
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"zeta":"1","alpha":"2"}"#);
+
+        let back: OrderedMap<std::string::String, std::string::String> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(back.keys().collect::<Vec<_>>(), ["zeta", "alpha"]);
+    }
+}
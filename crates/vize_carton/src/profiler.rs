@@ -2,11 +2,18 @@
 //!
 //! Provides simple timing and metrics collection for tracking
 //! type checking and compilation performance.
+//!
+//! When tracing is enabled, every recorded timer is also kept as a
+//! timestamped [`Span`] and can be exported with [`Profiler::to_chrome_trace`]
+//! for inspection in `chrome://tracing` or Perfetto.
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 /// A lightweight timer for measuring durations.
 #[derive(Debug)]
@@ -40,10 +47,94 @@ impl Timer {
     /// Stop and record to a profiler.
     #[inline]
     pub fn record(self, profiler: &Profiler) {
-        profiler.record(self.name, self.elapsed());
+        profiler.record_span(self.name, self.start, self.elapsed());
     }
 }
 
+/// A timestamped operation recorded while tracing is enabled.
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// Operation name
+    pub name: &'static str,
+    /// File being processed on this thread, if any
+    pub file: Option<crate::String>,
+    /// Start offset from the beginning of the trace
+    pub start: Duration,
+    /// Duration of the operation
+    pub duration: Duration,
+    /// Trace thread id (stable for the lifetime of the thread)
+    pub thread: u64,
+}
+
+/// Recorded spans and the instant their offsets are relative to.
+#[derive(Debug)]
+struct TraceBuffer {
+    epoch: Instant,
+    spans: Vec<Span>,
+}
+
+impl Default for TraceBuffer {
+    fn default() -> Self {
+        Self {
+            epoch: Instant::now(),
+            spans: Vec::new(),
+        }
+    }
+}
+
+static NEXT_TRACE_THREAD: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static TRACE_THREAD: u64 = NEXT_TRACE_THREAD.fetch_add(1, Ordering::Relaxed);
+    static TRACE_FILE: RefCell<Option<crate::String>> = const { RefCell::new(None) };
+}
+
+/// Guard returned by [`Profiler::file_span`].
+///
+/// Spans recorded on the current thread while the guard is alive are
+/// attributed to the file. Dropping the guard records the file span itself.
+#[must_use = "the file span ends when the guard is dropped"]
+pub struct FileSpan<'p> {
+    profiler: &'p Profiler,
+    timer: Option<Timer>,
+    previous: Option<crate::String>,
+}
+
+impl Drop for FileSpan<'_> {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.record(self.profiler);
+            let previous = self.previous.take();
+            TRACE_FILE.with(|file| *file.borrow_mut() = previous);
+        }
+    }
+}
+
+/// A single event of the Chrome trace-event format.
+#[derive(Serialize)]
+struct TraceEvent<'s> {
+    name: &'s str,
+    cat: &'s str,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u32,
+    tid: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<TraceEventArgs<'s>>,
+}
+
+#[derive(Serialize)]
+struct TraceEventArgs<'s> {
+    file: &'s str,
+}
+
+/// Convert a duration to (fractional) microseconds.
+#[inline]
+fn as_micros_f64(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
 /// Profiling metrics for a single operation.
 #[derive(Debug, Clone)]
 pub struct Metrics {
@@ -98,7 +189,11 @@ pub struct Profiler {
     /// Metrics by operation name
     metrics: std::sync::RwLock<FxHashMap<&'static str, Metrics>>,
     /// Whether profiling is enabled
-    enabled: std::sync::atomic::AtomicBool,
+    enabled: AtomicBool,
+    /// Whether timestamped spans are kept for trace export
+    tracing: AtomicBool,
+    /// Recorded spans (only populated while tracing)
+    trace: Mutex<TraceBuffer>,
 }
 
 impl Profiler {
@@ -130,6 +225,22 @@ impl Profiler {
         self.enabled.load(Ordering::Relaxed)
     }
 
+    /// Keep timestamped spans for trace export (requires profiling to be enabled).
+    pub fn enable_tracing(&self) {
+        self.tracing.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop keeping timestamped spans.
+    pub fn disable_tracing(&self) {
+        self.tracing.store(false, Ordering::Relaxed);
+    }
+
+    /// Check if spans are being recorded.
+    #[inline]
+    pub fn is_tracing(&self) -> bool {
+        self.is_enabled() && self.tracing.load(Ordering::Relaxed)
+    }
+
     /// Start a timer for the given operation.
     #[inline]
     pub fn timer(&self, name: &'static str) -> Option<Timer> {
@@ -150,6 +261,86 @@ impl Profiler {
         metrics.entry(name).or_default().record(duration);
     }
 
+    /// Record an operation that started at `start`.
+    ///
+    /// Updates the metrics like [`Profiler::record`], and also keeps the span
+    /// for trace export when tracing is enabled.
+    pub fn record_span(&self, name: &'static str, start: Instant, duration: Duration) {
+        self.record(name, duration);
+        if !self.is_tracing() {
+            return;
+        }
+
+        let file = TRACE_FILE.with(|file| file.borrow().clone());
+        let thread = TRACE_THREAD.with(|thread| *thread);
+        let mut trace = self.trace.lock().unwrap();
+        let start = start.saturating_duration_since(trace.epoch);
+        trace.spans.push(Span {
+            name,
+            file,
+            start,
+            duration,
+            thread,
+        });
+    }
+
+    /// Attribute spans recorded on the current thread to `file` until the
+    /// returned guard is dropped, then record the whole file as `name`.
+    ///
+    /// Does nothing unless tracing is enabled.
+    pub fn file_span(&self, name: &'static str, file: &str) -> FileSpan<'_> {
+        if !self.is_tracing() {
+            return FileSpan {
+                profiler: self,
+                timer: None,
+                previous: None,
+            };
+        }
+
+        let previous = TRACE_FILE.with(|current| current.replace(Some(file.into())));
+        FileSpan {
+            profiler: self,
+            timer: Some(Timer::start(name)),
+            previous,
+        }
+    }
+
+    /// Get all recorded spans, ordered by start time.
+    pub fn spans(&self) -> Vec<Span> {
+        let mut spans = self.trace.lock().unwrap().spans.clone();
+        spans.sort_by_key(|span| (span.start, std::cmp::Reverse(span.duration)));
+        spans
+    }
+
+    /// Export recorded spans in the Chrome trace-event format.
+    ///
+    /// Produces a JSON array of complete (`"ph": "X"`) events with timestamps
+    /// in microseconds, loadable in `chrome://tracing` or Perfetto. The event
+    /// category is the first segment of the operation name (e.g. `atelier`),
+    /// and spans recorded inside a [`Profiler::file_span`] carry the file in
+    /// `args.file`.
+    pub fn to_chrome_trace(&self) -> crate::String {
+        let spans = self.spans();
+        let pid = std::process::id();
+        let events: Vec<_> = spans
+            .iter()
+            .map(|span| TraceEvent {
+                name: span.name,
+                cat: span.name.split('.').next().unwrap_or(span.name),
+                ph: "X",
+                ts: as_micros_f64(span.start),
+                dur: as_micros_f64(span.duration),
+                pid,
+                tid: span.thread,
+                args: span.file.as_deref().map(|file| TraceEventArgs { file }),
+            })
+            .collect();
+
+        serde_json::to_string(&events)
+            .map(crate::String::from)
+            .unwrap_or_else(|_| "[]".into())
+    }
+
     /// Get metrics for the given operation.
     pub fn get(&self, name: &str) -> Option<Metrics> {
         self.metrics.read().unwrap().get(name).cloned()
@@ -160,9 +351,10 @@ impl Profiler {
         self.metrics.read().unwrap().clone()
     }
 
-    /// Clear all metrics.
+    /// Clear all metrics and recorded spans.
     pub fn clear(&self) {
         self.metrics.write().unwrap().clear();
+        *self.trace.lock().unwrap() = TraceBuffer::default();
     }
 
     /// Generate a summary report.
@@ -340,7 +532,7 @@ impl std::fmt::Display for CacheStats {
 #[cfg(test)]
 mod tests {
     use super::{CacheStats, Profiler, Timer};
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn test_timer() {
@@ -364,6 +556,57 @@ mod tests {
         assert_eq!(metrics.average(), Duration::from_millis(15));
     }
 
+    #[test]
+    fn test_spans_require_tracing() {
+        let profiler = Profiler::enabled();
+        profiler.record_span("test", Instant::now(), Duration::from_millis(1));
+        assert!(profiler.spans().is_empty());
+        assert_eq!(profiler.get("test").unwrap().count, 1);
+    }
+
+    #[test]
+    fn test_chrome_trace_export() {
+        let profiler = Profiler::enabled();
+        profiler.enable_tracing();
+
+        {
+            let _file = profiler.file_span("build.file", "src/App.vue");
+            Timer::start("atelier.sfc.parse").record(&profiler);
+            Timer::start("atelier.sfc.styles").record(&profiler);
+        }
+        Timer::start("build.write").record(&profiler);
+
+        let trace: serde_json::Value = serde_json::from_str(&profiler.to_chrome_trace()).unwrap();
+        let events = trace.as_array().unwrap();
+        assert_eq!(events.len(), 4);
+
+        let event = |name: &str| events.iter().find(|e| e["name"] == name).unwrap();
+        for e in events {
+            assert_eq!(e["ph"], "X");
+            assert!(e["ts"].as_f64().unwrap() >= 0.0);
+            assert!(e["dur"].as_f64().is_some());
+            assert_eq!(e["pid"], std::process::id());
+            assert_eq!(e["tid"], events[0]["tid"]);
+        }
+
+        assert_eq!(event("atelier.sfc.parse")["cat"], "atelier");
+        assert_eq!(event("atelier.sfc.parse")["args"]["file"], "src/App.vue");
+        assert_eq!(event("atelier.sfc.styles")["args"]["file"], "src/App.vue");
+        assert_eq!(event("build.file")["args"]["file"], "src/App.vue");
+        assert!(event("build.write").get("args").is_none());
+
+        // The file span encloses the phases recorded inside it
+        let file = event("build.file");
+        let parse = event("atelier.sfc.parse");
+        let file_start = file["ts"].as_f64().unwrap();
+        let file_end = file_start + file["dur"].as_f64().unwrap();
+        assert!(parse["ts"].as_f64().unwrap() >= file_start);
+        assert!(parse["ts"].as_f64().unwrap() + parse["dur"].as_f64().unwrap() <= file_end);
+
+        profiler.clear();
+        assert_eq!(profiler.to_chrome_trace(), "[]");
+    }
+
     #[test]
     fn test_cache_stats() {
         let stats = CacheStats::new();
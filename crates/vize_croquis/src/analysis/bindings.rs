@@ -97,6 +97,24 @@ impl BindingMetadata {
     pub fn iter(&self) -> impl Iterator<Item = (&str, BindingType)> {
         self.bindings.iter().map(|(k, v)| (k.as_str(), *v))
     }
+
+    /// Check if a binding can be referenced from the template.
+    ///
+    /// Only top-level value bindings are tracked here, so type-only imports and
+    /// bindings declared in nested scopes are never visible. `<script setup>`
+    /// exposes every top-level binding; outside of it, Vue does not proxy keys
+    /// starting with the reserved `_` / `$` prefixes onto the render context.
+    #[inline]
+    pub fn is_template_visible(&self, name: &str) -> bool {
+        self.contains(name) && (self.is_script_setup || !name.starts_with(['_', '$']))
+    }
+
+    /// Iterate over the bindings that can be referenced from the template
+    #[inline]
+    pub fn template_bindings(&self) -> impl Iterator<Item = (&str, BindingType)> {
+        self.iter()
+            .filter(|(name, _)| self.is_script_setup || !name.starts_with(['_', '$']))
+    }
 }
 
 /// An undefined reference in template
@@ -42,11 +42,13 @@ pub use bindings::{
 pub use croquis::AnalysisStats;
 pub use template::{
     ComponentUsage, ElementIdInfo, ElementIdKind, EventListener, PassedProp, SlotUsage,
-    TemplateExpression, TemplateExpressionKind, TemplateInfo,
+    TemplateExpression, TemplateExpressionKind, TemplateInfo, TemplateRef, VForKeyIssue,
+    VForKeyIssueKind,
 };
 
 use crate::hoist::HoistTracker;
 use crate::macros::MacroTracker;
+use crate::migration::MigrationFinding;
 use crate::provide::ProvideInjectTracker;
 use crate::reactivity::ReactivityTracker;
 use crate::setup_context::SetupContextTracker;
@@ -78,6 +80,10 @@ pub struct Croquis {
     /// Setup context violation tracking (CSRP/memory leaks)
     pub setup_context: SetupContextTracker,
 
+    /// Vue 2 API usages in the analyzed script
+    /// (only with `AnalyzerOptions::detect_vue2_migration`)
+    pub migration_findings: Vec<MigrationFinding>,
+
     /// TypeScript type resolution
     pub types: TypeResolver,
 
@@ -90,6 +96,11 @@ pub struct Croquis {
     /// Template-level metadata (root count, $attrs usage, etc.)
     pub template_info: TemplateInfo,
 
+    /// Whether the template has no reactive dependencies: no components or
+    /// slots, no directives that need patching, and only constant expressions.
+    /// Computed by `analyze_template`, so analyze the script first.
+    pub is_static_component: bool,
+
     /// Components used in template (names only, for quick lookup)
     pub used_components: FxHashSet<CompactString>,
 
@@ -117,6 +128,12 @@ pub struct Croquis {
     /// Element IDs found in template (for cross-file uniqueness checking)
     pub element_ids: Vec<ElementIdInfo>,
 
+    /// v-for `:key` expressions that are constant or ignore the loop variables
+    pub vfor_key_issues: Vec<VForKeyIssue>,
+
+    /// Static `ref="name"` attributes and the setup bindings they resolve to
+    pub template_refs: Vec<TemplateRef>,
+
     /// Import statement spans in script content
     pub import_statements: Vec<ImportStatementInfo>,
 
@@ -94,6 +94,63 @@ impl ElementIdKind {
     }
 }
 
+/// A v-for `:key` that cannot tell list items apart.
+#[derive(Debug, Clone)]
+pub struct VForKeyIssue {
+    /// The key expression
+    pub key_expression: CompactString,
+    /// Why the key is not unique per item
+    pub kind: VForKeyIssueKind,
+    /// Start offset of the key expression in template
+    pub start: u32,
+    /// End offset of the key expression in template
+    pub end: u32,
+}
+
+/// Kind of v-for key issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VForKeyIssueKind {
+    /// The key references no variables at all (e.g. `:key="1"`)
+    Constant,
+    /// The key references variables, but none of the v-for aliases
+    NoLoopVariable,
+}
+
+impl VForKeyIssueKind {
+    /// Get the string representation.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::NoLoopVariable => "no-loop-variable",
+        }
+    }
+}
+
+/// A static template ref (`ref="name"`) and its matching setup binding.
+#[derive(Debug, Clone)]
+pub struct TemplateRef {
+    /// The ref name
+    pub name: CompactString,
+    /// Start offset of the `ref` attribute in template
+    pub start: u32,
+    /// End offset of the `ref` attribute in template
+    pub end: u32,
+    /// Whether this is inside a v-for loop (the ref receives an array)
+    pub in_loop: bool,
+    /// Setup binding that receives the element: a same-named ref binding,
+    /// or the binding initialized with `useTemplateRef('name')`
+    pub binding: Option<CompactString>,
+}
+
+impl TemplateRef {
+    /// Check if a setup binding is declared for this ref.
+    #[inline]
+    pub fn is_declared(&self) -> bool {
+        self.binding.is_some()
+    }
+}
+
 /// Template expression for type checking.
 #[derive(Debug, Clone)]
 pub struct TemplateExpression {
@@ -34,7 +34,8 @@ pub use helpers::{
 };
 
 use crate::analysis::Croquis;
-use vize_carton::{profile, CompactString};
+use crate::scope::ScopeId;
+use vize_carton::{profile, CompactString, FxHashMap};
 
 /// Analysis options for controlling what gets analyzed.
 ///
@@ -53,6 +54,8 @@ pub struct AnalyzerOptions {
     pub analyze_hoisting: bool,
     /// Collect template expressions for type checking
     pub collect_template_expressions: bool,
+    /// Detect Vue 2 APIs removed in Vue 3 (opt-in, name-based matching)
+    pub detect_vue2_migration: bool,
 }
 
 impl AnalyzerOptions {
@@ -66,6 +69,7 @@ impl AnalyzerOptions {
             detect_undefined: true,
             analyze_hoisting: true,
             collect_template_expressions: true,
+            detect_vue2_migration: false,
         }
     }
 
@@ -79,6 +83,7 @@ impl AnalyzerOptions {
             detect_undefined: true,
             analyze_hoisting: false,
             collect_template_expressions: false,
+            detect_vue2_migration: false,
         }
     }
 
@@ -92,6 +97,7 @@ impl AnalyzerOptions {
             detect_undefined: false,
             analyze_hoisting: true,
             collect_template_expressions: false,
+            detect_vue2_migration: false,
         }
     }
 }
@@ -106,6 +112,14 @@ pub struct Analyzer {
     pub(crate) script_analyzed: bool,
     /// Current v-if guard stack (for type narrowing in templates)
     pub(crate) vif_guard_stack: Vec<CompactString>,
+    /// Scope the template was analyzed in (anchor for incremental re-analysis)
+    pub(crate) template_scope: ScopeId,
+    /// Component whose direct children are being visited (owner of `<template #slot>`)
+    pub(crate) slot_owner: Option<CompactString>,
+    /// `useTemplateRef('key')` keys mapped to the bindings they initialize
+    pub(crate) template_ref_keys: FxHashMap<CompactString, CompactString>,
+    /// `lang` attribute of the script block being analyzed
+    pub(crate) script_lang: Option<CompactString>,
 }
 
 impl Analyzer {
@@ -123,6 +137,10 @@ impl Analyzer {
             summary: Croquis::new(),
             script_analyzed: false,
             vif_guard_stack: Vec::new(),
+            template_scope: ScopeId::ROOT,
+            slot_owner: None,
+            template_ref_keys: FxHashMap::default(),
+            script_lang: None,
         }
     }
 
@@ -147,6 +165,14 @@ impl Analyzer {
         Self::with_options(AnalyzerOptions::for_compile())
     }
 
+    /// Set the `lang` attribute of the script block analyzed next.
+    ///
+    /// `lang="jsx"`/`"tsx"` scripts are parsed with JSX enabled.
+    pub fn set_script_lang(&mut self, lang: Option<&str>) -> &mut Self {
+        self.script_lang = lang.map(CompactString::new);
+        self
+    }
+
     /// Analyze script setup source code.
     ///
     /// This uses OXC parser to extract:
@@ -182,7 +208,11 @@ impl Analyzer {
         // Use OXC-based parser for accurate AST analysis
         let result = profile!(
             "croquis.analyzer.script_setup",
-            crate::script_parser::parse_script_setup_with_generic(source, generic)
+            crate::script_parser::parse_script_setup_with_lang(
+                source,
+                generic,
+                self.script_lang.as_deref()
+            )
         );
 
         // Merge results into summary
@@ -196,7 +226,9 @@ impl Analyzer {
         self.summary.import_statements = result.import_statements;
         self.summary.re_exports = result.re_exports;
         self.summary.binding_spans = result.binding_spans;
+        self.template_ref_keys = result.template_ref_keys;
         self.summary.setup_context = result.setup_context;
+        self.detect_migration_findings(source);
 
         self
     }
@@ -212,7 +244,7 @@ impl Analyzer {
         // Use OXC-based parser for non-script-setup
         let result = profile!(
             "croquis.analyzer.script_plain",
-            crate::script_parser::parse_script(source)
+            crate::script_parser::parse_script_with_lang(source, self.script_lang.as_deref())
         );
 
         // Merge results into summary
@@ -226,11 +258,26 @@ impl Analyzer {
         self.summary.import_statements = result.import_statements;
         self.summary.re_exports = result.re_exports;
         self.summary.binding_spans = result.binding_spans;
+        self.template_ref_keys = result.template_ref_keys;
         self.summary.setup_context = result.setup_context;
+        self.detect_migration_findings(source);
 
         self
     }
 
+    /// Record Vue 2 API usages when `detect_vue2_migration` is enabled.
+    fn detect_migration_findings(&mut self, source: &str) {
+        if self.options.detect_vue2_migration {
+            self.summary.migration_findings = profile!(
+                "croquis.analyzer.migration",
+                crate::script_parser::detect_migration_findings(
+                    source,
+                    self.script_lang.as_deref()
+                )
+            );
+        }
+    }
+
     /// Finish analysis and return the summary.
     ///
     /// Consumes the analyzer.
@@ -426,6 +473,54 @@ const { name } = inject('user') as { name: string; id: number }"#,
 
     // ========== Snapshot Tests ==========
 
+    #[test]
+    fn test_template_visible_bindings() {
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(
+            r#"import type { User } from './types'
+import { type Theme, ref } from 'vue'
+
+const count = ref(0)
+const _internal = 1
+function increment() {
+  const step = 1
+  const count = 2
+  return step + count
+}"#,
+        );
+        let summary = analyzer.finish();
+        let bindings = &summary.bindings;
+
+        assert!(bindings.is_template_visible("count"));
+        assert!(bindings.is_template_visible("increment"));
+        assert!(bindings.is_template_visible("ref"));
+        // `<script setup>` exposes reserved prefixes as well
+        assert!(bindings.is_template_visible("_internal"));
+        // Type-only imports and nested bindings have no template value
+        assert!(!bindings.is_template_visible("User"));
+        assert!(!bindings.is_template_visible("Theme"));
+        assert!(!bindings.is_template_visible("step"));
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_plain(
+            r#"const _cache = new Map()
+const $format = (v) => v
+const label = 'hello'"#,
+        );
+        let summary = analyzer.finish();
+        let mut visible: Vec<_> = summary
+            .bindings
+            .template_bindings()
+            .map(|(name, _)| name)
+            .collect();
+        visible.sort_unstable();
+
+        assert!(summary.bindings.contains("_cache"));
+        assert!(!summary.bindings.is_template_visible("_cache"));
+        assert!(!summary.bindings.is_template_visible("$format"));
+        assert_eq!(visible, ["label"]);
+    }
+
     #[test]
     fn test_full_analysis_snapshot() {
         use insta::assert_snapshot;
@@ -630,4 +725,23 @@ const { name, id } = inject('user') as { name: string; id: number }
 
         insta::assert_debug_snapshot!(expressions);
     }
+
+    #[test]
+    fn test_vue2_migration_is_opt_in() {
+        let source = "import Vue from 'vue'\nexport default Vue.extend({ filters: {} })\n";
+
+        let mut analyzer = Analyzer::for_lint();
+        analyzer.analyze_script_plain(source);
+        assert!(analyzer.summary().migration_findings.is_empty());
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions {
+            detect_vue2_migration: true,
+            ..AnalyzerOptions::for_lint()
+        });
+        analyzer.analyze_script_plain(source);
+        let findings = &analyzer.summary().migration_findings;
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].api_name.as_str(), "Vue.extend");
+        assert_eq!(findings[1].api_name.as_str(), "filters");
+    }
 }
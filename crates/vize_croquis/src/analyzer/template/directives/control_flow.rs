@@ -1,8 +1,11 @@
 //! Control flow directive handling.
 //!
 //! Processes v-if/v-else-if/v-else and v-for nodes at the template AST level
-//! (as opposed to directive-level processing in `visit_element`).
+//! (as opposed to directive-level processing in `visit_element`), and checks
+//! v-for `:key` expressions.
 
+use crate::analysis::{VForKeyIssue, VForKeyIssueKind};
+use crate::analyzer::helpers::extract_identifiers_oxc;
 use crate::analyzer::Analyzer;
 use crate::scope::VForScopeData;
 use crate::ScopeBinding;
@@ -188,4 +191,29 @@ impl Analyzer {
 
         vars
     }
+
+    /// Record a v-for `:key` that is constant or ignores the loop aliases.
+    pub(in crate::analyzer) fn check_v_for_key(
+        &mut self,
+        key: &str,
+        vars: &[CompactString],
+        start: u32,
+        end: u32,
+    ) {
+        let identifiers = extract_identifiers_oxc(key);
+        let kind = if identifiers.is_empty() {
+            VForKeyIssueKind::Constant
+        } else if identifiers.iter().any(|ident| vars.contains(ident)) {
+            return;
+        } else {
+            VForKeyIssueKind::NoLoopVariable
+        };
+
+        self.summary.vfor_key_issues.push(VForKeyIssue {
+            key_expression: CompactString::new(key),
+            kind,
+            start,
+            end,
+        });
+    }
 }
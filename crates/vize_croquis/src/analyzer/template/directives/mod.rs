@@ -7,7 +7,7 @@
 //!
 //! - [`v_bind`] - v-bind directive handling (`:key`, `$attrs`, callbacks)
 //! - [`v_on`] - v-on directive handling (event handlers, inline callbacks)
-//! - [`control_flow`] - v-if/v-for visiting and variable extraction
+//! - [`control_flow`] - v-if/v-for visiting, variable extraction and key checks
 
 mod control_flow;
 mod v_bind;
@@ -117,7 +117,7 @@ impl Analyzer {
     }
 
     /// Check if the current scope is inside a v-for loop.
-    fn is_in_vfor_scope(&self) -> bool {
+    pub(super) fn is_in_vfor_scope(&self) -> bool {
         use crate::scope::ScopeKind;
 
         let current_id = self.summary.scopes.current_id();
@@ -193,7 +193,15 @@ impl Analyzer {
                 || crate::builtins::is_event_local(ident_str)
                 || is_keyword(ident_str);
 
-            let is_defined = in_scope_vars || in_bindings || in_scope_chain || is_builtin;
+            // Script bindings the template can't see (reserved `_` / `$` prefixes
+            // outside `<script setup>`) stay undefined even though the scope
+            // chain knows them.
+            let is_hidden_binding = !in_scope_vars
+                && in_bindings
+                && !self.summary.bindings.is_template_visible(ident_str);
+
+            let is_defined = !is_hidden_binding
+                && (in_scope_vars || in_bindings || in_scope_chain || is_builtin);
 
             if is_defined && !is_builtin {
                 self.summary.scopes.mark_used(ident_str);
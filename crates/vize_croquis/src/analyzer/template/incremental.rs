@@ -0,0 +1,162 @@
+//! Incremental template re-analysis.
+//!
+//! Re-running [`Analyzer::analyze_template`] on every keystroke is wasteful for
+//! large templates. Instead, the results anchored inside a single element
+//! subtree (scopes, expressions, component usages, IDs, template refs,
+//! undefined refs, v-for key issues) can be discarded and rebuilt in place, reusing the enclosing
+//! v-for/v-slot scopes.
+//!
+//! This is intentionally coarse:
+//! - Source offsets outside the element are not shifted, so edits that change
+//!   the element's length should be followed by a full `analyze_template`.
+//! - v-if guards of ancestor elements are not restored.
+//! - Usage marks on script bindings and `used_directives` are only ever added.
+//! - `is_static_component` is only ever cleared.
+
+use std::ops::Range;
+
+use super::super::Analyzer;
+use crate::scope::{ScopeId, ScopeKind};
+use vize_carton::CompactString;
+use vize_relief::ast::ElementNode;
+
+impl Analyzer {
+    /// Find the scope an element starting at `offset` should be re-analyzed in.
+    ///
+    /// Returns the innermost v-for/v-slot scope strictly enclosing `offset`,
+    /// or the scope the template was originally analyzed in.
+    pub fn template_scope_at(&self, offset: u32) -> ScopeId {
+        let scopes = &self.summary.scopes;
+        scopes
+            .iter()
+            .filter(|scope| {
+                matches!(scope.kind, ScopeKind::VFor | ScopeKind::VSlot)
+                    && scope.span.start < offset
+                    && offset < scope.span.end
+            })
+            .max_by_key(|scope| scopes.depth(scope.id))
+            .map_or(self.template_scope, |scope| scope.id)
+    }
+
+    /// Re-analyze a single element subtree without redoing the whole template.
+    ///
+    /// Results previously anchored within the element's source range are
+    /// replaced by a fresh visit of `el` under `parent_scope` (see
+    /// [`Analyzer::template_scope_at`]). Scope IDs are compacted, so IDs held
+    /// from before this call must be re-queried.
+    pub fn reanalyze_element(&mut self, el: &ElementNode<'_>, parent_scope: ScopeId) -> &mut Self {
+        if !self.options.analyze_template_scopes && !self.options.track_usage {
+            return self;
+        }
+
+        let range = el.loc.start.offset..el.loc.end.offset;
+
+        // Drop scopes created for the old subtree and remap surviving IDs
+        let remap = self
+            .summary
+            .scopes
+            .remove_template_scopes_within(range.start, range.end);
+        let remap_id = |id: ScopeId| {
+            remap
+                .get(id.as_u32() as usize)
+                .copied()
+                .flatten()
+                .unwrap_or(ScopeId::ROOT)
+        };
+        self.template_scope = remap_id(self.template_scope);
+        let parent_scope = remap
+            .get(parent_scope.as_u32() as usize)
+            .copied()
+            .flatten()
+            .unwrap_or(self.template_scope);
+
+        // Drop the old subtree's results, remembering where they were
+        let summary = &mut self.summary;
+        let expr_at = remove_in_range(&mut summary.template_expressions, &range, |e| e.start);
+        let usage_at = remove_in_range(&mut summary.component_usages, &range, |u| u.start);
+        let id_at = remove_in_range(&mut summary.element_ids, &range, |i| i.start);
+        let ref_at = remove_in_range(&mut summary.template_refs, &range, |r| r.start);
+        let undefined_at = remove_in_range(&mut summary.undefined_refs, &range, |r| r.offset);
+        let key_issue_at = remove_in_range(&mut summary.vfor_key_issues, &range, |k| k.start);
+        for expr in summary.template_expressions.iter_mut() {
+            expr.scope_id = remap_id(expr.scope_id);
+        }
+        for usage in summary.component_usages.iter_mut() {
+            usage.scope_id = remap_id(usage.scope_id);
+        }
+        for id in summary.element_ids.iter_mut() {
+            id.scope_id = remap_id(id.scope_id);
+        }
+
+        let expr_len = summary.template_expressions.len();
+        let usage_len = summary.component_usages.len();
+        let id_len = summary.element_ids.len();
+        let ref_len = summary.template_refs.len();
+        let undefined_len = summary.undefined_refs.len();
+        let key_issue_len = summary.vfor_key_issues.len();
+
+        // Re-visit the element with the enclosing template variables in scope
+        let mut scope_vars = self.template_scope_vars(parent_scope);
+        let previous_scope = self.summary.scopes.current_id();
+        self.summary.scopes.set_current(parent_scope);
+        self.visit_element(el, &mut scope_vars);
+        self.summary.scopes.set_current(previous_scope);
+
+        // Splice the new results back where the old ones were
+        let summary = &mut self.summary;
+        move_appended(&mut summary.template_expressions, expr_at, expr_len);
+        move_appended(&mut summary.component_usages, usage_at, usage_len);
+        move_appended(&mut summary.element_ids, id_at, id_len);
+        move_appended(&mut summary.template_refs, ref_at, ref_len);
+        move_appended(&mut summary.undefined_refs, undefined_at, undefined_len);
+        move_appended(&mut summary.vfor_key_issues, key_issue_at, key_issue_len);
+
+        if self.options.track_usage {
+            summary.used_components = summary
+                .component_usages
+                .iter()
+                .map(|usage| usage.name.clone())
+                .collect();
+        }
+        if !self.is_static_element(el) {
+            self.summary.is_static_component = false;
+        }
+
+        self
+    }
+
+    /// Collect template variables visible in `scope` (outermost first).
+    fn template_scope_vars(&self, scope: ScopeId) -> Vec<CompactString> {
+        let mut vars = Vec::new();
+        let mut current = self.summary.scopes.get_scope(scope);
+        while let Some(scope) = current {
+            if matches!(
+                scope.kind,
+                ScopeKind::VFor | ScopeKind::VSlot | ScopeKind::EventHandler | ScopeKind::Callback
+            ) {
+                vars.extend(scope.bindings().map(|(name, _)| CompactString::new(name)));
+            }
+            current = scope
+                .parent()
+                .and_then(|id| self.summary.scopes.get_scope(id));
+        }
+        vars.reverse();
+        vars
+    }
+}
+
+/// Remove entries anchored within `range`, returning where the first one was.
+fn remove_in_range<T>(items: &mut Vec<T>, range: &Range<u32>, offset: impl Fn(&T) -> u32) -> usize {
+    let at = items
+        .iter()
+        .position(|item| range.contains(&offset(item)))
+        .unwrap_or(items.len());
+    items.retain(|item| !range.contains(&offset(item)));
+    at
+}
+
+/// Move entries appended after `len` to index `at`.
+fn move_appended<T>(items: &mut [T], at: usize, len: usize) {
+    let appended = items.len() - len;
+    items[at..].rotate_right(appended);
+}
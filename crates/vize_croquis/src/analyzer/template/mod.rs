@@ -6,10 +6,16 @@
 //! - Undefined reference detection
 //! - Template expressions for type checking
 //! - Element IDs for cross-file uniqueness checking
+//! - Template refs and the setup bindings they resolve to
+//! - v-for keys that are constant or ignore the loop variables
+//! - Whether the component is static (no reactive dependencies)
 
 mod components;
 mod directives;
 mod ids;
+mod incremental;
+mod refs;
+mod static_check;
 mod visit_element;
 
 #[cfg(test)]
@@ -38,10 +44,18 @@ impl Analyzer {
         });
         self.summary.template_info.root_element_count = root_element_count;
 
+        // Remember the scope the template hangs off for incremental re-analysis
+        self.template_scope = self.summary.scopes.current_id();
+
         // Store template content range
         self.summary.template_info.content_start = root.loc.start.offset;
         self.summary.template_info.content_end = root.loc.end.offset;
 
+        self.summary.is_static_component = profile!(
+            "croquis.template.static_check",
+            self.is_static_children(&root.children)
+        );
+
         // Single-pass template traversal
         profile!("croquis.template.traverse", {
             for child in root.children.iter() {
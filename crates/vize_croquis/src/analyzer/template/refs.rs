@@ -0,0 +1,53 @@
+//! Template ref collection.
+//!
+//! Correlates static `ref="name"` attributes with the setup bindings that
+//! receive the element, either a same-named ref (`const el = ref(null)`) or a
+//! `useTemplateRef('name')` binding.
+
+use crate::analysis::TemplateRef;
+use vize_carton::CompactString;
+use vize_relief::ast::{ElementNode, PropNode};
+use vize_relief::BindingType;
+
+use super::super::Analyzer;
+
+impl Analyzer {
+    /// Collect static template refs from an element node.
+    pub(in crate::analyzer) fn collect_template_refs(&mut self, el: &ElementNode<'_>) {
+        for prop in &el.props {
+            let PropNode::Attribute(attr) = prop else {
+                continue;
+            };
+            if attr.name.as_str() != "ref" {
+                continue;
+            }
+            let Some(value) = attr.value.as_ref().filter(|v| !v.content.is_empty()) else {
+                continue;
+            };
+
+            let name = value.content.clone();
+            let binding = self.resolve_template_ref_binding(name.as_str());
+            let in_loop = self.is_in_vfor_scope();
+            self.summary.template_refs.push(TemplateRef {
+                name,
+                start: attr.loc.start.offset,
+                end: attr.loc.end.offset,
+                in_loop,
+                binding,
+            });
+        }
+    }
+
+    /// Find the setup binding a template ref is assigned to.
+    fn resolve_template_ref_binding(&self, name: &str) -> Option<CompactString> {
+        if let Some(binding) = self.template_ref_keys.get(name) {
+            return Some(binding.clone());
+        }
+        // A `useTemplateRef()` result is readonly, so it can't receive another key
+        let is_assignable = matches!(
+            self.summary.bindings.get(name),
+            Some(BindingType::SetupRef | BindingType::SetupMaybeRef | BindingType::SetupLet)
+        ) && !self.template_ref_keys.values().any(|b| b == name);
+        is_assignable.then(|| CompactString::new(name))
+    }
+}
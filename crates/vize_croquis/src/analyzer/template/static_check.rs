@@ -0,0 +1,69 @@
+//! Static component detection.
+//!
+//! A component is static when its template renders the same output on every
+//! render: no components or slots, no directives that need patching, and
+//! every bound expression is a constant (literals, or script bindings known
+//! to be literal constants).
+
+use super::super::Analyzer;
+use crate::analyzer::helpers::{extract_identifiers_oxc, is_component_tag};
+use vize_relief::ast::{ElementNode, ElementType, ExpressionNode, PropNode, TemplateChildNode};
+use vize_relief::BindingType;
+
+impl Analyzer {
+    /// Check whether every node in `children` is static.
+    pub(super) fn is_static_children(&self, children: &[TemplateChildNode<'_>]) -> bool {
+        children.iter().all(|child| match child {
+            TemplateChildNode::Element(el) => self.is_static_element(el),
+            TemplateChildNode::Text(_) | TemplateChildNode::Comment(_) => true,
+            TemplateChildNode::Interpolation(interp) => {
+                self.is_constant_expression(&interp.content)
+            }
+            _ => false,
+        })
+    }
+
+    /// Check whether an element subtree needs no patching.
+    pub(super) fn is_static_element(&self, el: &ElementNode<'_>) -> bool {
+        let tag = el.tag.as_str();
+        if is_component_tag(tag)
+            || matches!(el.tag_type, ElementType::Component | ElementType::Slot)
+            || matches!(tag, "slot" | "component")
+        {
+            return false;
+        }
+
+        let props_static = el.props.iter().all(|prop| match prop {
+            PropNode::Attribute(_) => true,
+            PropNode::Directive(dir) => match dir.name.as_str() {
+                "once" | "cloak" => true,
+                "bind" => {
+                    let static_arg = dir.arg.as_ref().is_some_and(
+                        |arg| matches!(arg, ExpressionNode::Simple(arg) if arg.is_static),
+                    );
+                    static_arg
+                        && !dir.shorthand
+                        && dir
+                            .exp
+                            .as_ref()
+                            .is_some_and(|exp| self.is_constant_expression(exp))
+                }
+                _ => false,
+            },
+        });
+
+        props_static && self.is_static_children(&el.children)
+    }
+
+    /// Check whether an expression only refers to literal constants.
+    fn is_constant_expression(&self, expr: &ExpressionNode<'_>) -> bool {
+        let content = match expr {
+            ExpressionNode::Simple(s) => s.content.as_str(),
+            ExpressionNode::Compound(c) => c.loc.source.as_str(),
+        };
+
+        extract_identifiers_oxc(content).iter().all(|ident| {
+            self.summary.bindings.get(ident.as_str()) == Some(BindingType::LiteralConst)
+        })
+    }
+}
@@ -34,3 +34,197 @@ fn test_vif_guard_in_template() {
 
     insta::assert_debug_snapshot!(expressions);
 }
+
+#[test]
+fn test_reanalyze_element_matches_full_analysis() {
+    use crate::scope::ScopeKind;
+    use vize_armature::parse;
+    use vize_carton::Bump;
+    use vize_relief::ast::{ElementNode, TemplateChildNode};
+
+    fn first_element<'a, 'b>(children: &'b [TemplateChildNode<'a>]) -> &'b ElementNode<'a> {
+        children
+            .iter()
+            .find_map(|child| match child {
+                TemplateChildNode::Element(el) => Some(&**el),
+                _ => None,
+            })
+            .expect("element child")
+    }
+
+    let before = r#"<div><ul><li v-for="item in items" :key="item.id"><Foo :value="item.name" @click="select(item)" /></li></ul><Bar /></div>"#;
+    let after = r#"<div><ul><li v-for="item in items" :key="item.id"><Foo :value="item.text" @click="select(item)" /></li></ul><Bar /></div>"#;
+
+    let allocator = Bump::new();
+    let (old_root, _) = parse(&allocator, before);
+    let (new_root, _) = parse(&allocator, after);
+
+    let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+    analyzer.analyze_template(&old_root);
+
+    let div = first_element(&new_root.children);
+    let ul = first_element(&div.children);
+    let li = first_element(&ul.children);
+    let foo = first_element(&li.children);
+
+    let parent = analyzer.template_scope_at(li.loc.start.offset);
+    analyzer.reanalyze_element(li, parent);
+
+    // The component inside the v-for resolves to the v-for scope
+    let foo_scope = analyzer.template_scope_at(foo.loc.start.offset);
+    let kind = analyzer
+        .summary()
+        .scopes
+        .get_scope(foo_scope)
+        .map(|scope| scope.kind);
+    assert_eq!(kind, Some(ScopeKind::VFor));
+
+    let mut full = Analyzer::with_options(AnalyzerOptions::full());
+    full.analyze_template(&new_root);
+
+    let describe = |analyzer: &Analyzer| {
+        let summary = analyzer.summary();
+        let expressions: Vec<_> = summary
+            .template_expressions
+            .iter()
+            .map(|expr| {
+                let scope_kind = summary.scopes.get_scope(expr.scope_id).map(|s| s.kind);
+                (
+                    expr.content.clone(),
+                    expr.kind,
+                    expr.start,
+                    expr.end,
+                    scope_kind,
+                )
+            })
+            .collect();
+        let usages: Vec<_> = summary
+            .component_usages
+            .iter()
+            .map(|usage| (usage.name.clone(), usage.start, usage.end))
+            .collect();
+        (expressions, usages, summary.scopes.len())
+    };
+
+    let incremental = describe(&analyzer);
+    assert!(incremental
+        .0
+        .iter()
+        .any(|(content, ..)| content.as_str() == "item.text"));
+    assert!(!incremental
+        .0
+        .iter()
+        .any(|(content, ..)| content.as_str() == "item.name"));
+    assert_eq!(incremental, describe(&full));
+    assert_eq!(
+        analyzer.summary().used_components,
+        full.summary().used_components
+    );
+}
+
+#[test]
+fn test_static_component_detection() {
+    use vize_armature::parse;
+    use vize_carton::Bump;
+
+    let script = "const title = 'Hello'\nconst count = ref(0)\n";
+    let is_static = |template: &str| {
+        let allocator = Bump::new();
+        let (root, _) = parse(&allocator, template);
+        let mut analyzer = Analyzer::for_lint();
+        analyzer.analyze_script(script);
+        analyzer.analyze_template(&root);
+        analyzer.finish().is_static_component
+    };
+
+    assert!(is_static(
+        r#"<div class="box"><p>Static text</p><!-- note --></div>"#
+    ));
+    assert!(is_static(
+        r#"<h1 :class="'heading'" v-once>{{ title }}</h1>"#
+    ));
+
+    assert!(!is_static(r#"<p>{{ count }}</p>"#));
+    assert!(!is_static(r#"<p :title="count">x</p>"#));
+    assert!(!is_static(r#"<p :[attr]="'x'">x</p>"#));
+    assert!(!is_static(r#"<button @click="'noop'">x</button>"#));
+    assert!(!is_static(r#"<p v-if="true">x</p>"#));
+    assert!(!is_static(r#"<div><MyButton /></div>"#));
+    assert!(!is_static(r#"<div><slot /></div>"#));
+}
+
+#[test]
+fn test_v_for_key_issues() {
+    use crate::analysis::VForKeyIssueKind;
+    use vize_armature::parse;
+    use vize_carton::Bump;
+
+    let allocator = Bump::new();
+    let template = r#"<div>
+        <p v-for="item in items" :key="1">{{ item }}</p>
+        <p v-for="item in items" :key="selectedId">{{ item }}</p>
+        <p v-for="(item, i) in items" :key="`row-${i}`">{{ item }}</p>
+        <p v-for="item in items" :key="item.id">{{ item }}</p>
+    </div>"#;
+    let (root, _) = parse(&allocator, template);
+
+    let mut analyzer = Analyzer::for_lint();
+    analyzer.analyze_template(&root);
+    let summary = analyzer.finish();
+
+    let issues: Vec<_> = summary
+        .vfor_key_issues
+        .iter()
+        .map(|issue| {
+            let text = &template[issue.start as usize..issue.end as usize];
+            (issue.kind, issue.key_expression.as_str(), text)
+        })
+        .collect();
+    assert_eq!(
+        issues,
+        vec![
+            (VForKeyIssueKind::Constant, "1", "1"),
+            (VForKeyIssueKind::NoLoopVariable, "selectedId", "selectedId"),
+        ]
+    );
+}
+
+#[test]
+fn test_template_refs_resolve_to_setup_bindings() {
+    use vize_armature::parse;
+    use vize_carton::Bump;
+
+    let allocator = Bump::new();
+    let template = r#"<div ref="el">
+        <input ref="input" />
+        <li v-for="item in items" ref="items">{{ item }}</li>
+        <span ref="missing" />
+    </div>"#;
+    let (root, _) = parse(&allocator, template);
+
+    let mut analyzer = Analyzer::for_lint();
+    analyzer.analyze_script(
+        "const el = ref(null)\nconst inputEl = useTemplateRef('input')\nconst items = ref([])\n",
+    );
+    analyzer.analyze_template(&root);
+    let summary = analyzer.finish();
+
+    let refs: Vec<_> = summary
+        .template_refs
+        .iter()
+        .map(|r| {
+            let text = &template[r.start as usize..r.end as usize];
+            (r.name.as_str(), r.binding.as_deref(), r.in_loop, text)
+        })
+        .collect();
+    assert_eq!(
+        refs,
+        vec![
+            ("el", Some("el"), false, r#"ref="el""#),
+            ("input", Some("inputEl"), false, r#"ref="input""#),
+            ("items", Some("items"), true, r#"ref="items""#),
+            ("missing", None, false, r#"ref="missing""#),
+        ]
+    );
+    assert!(!summary.template_refs[3].is_declared());
+}
@@ -58,6 +58,8 @@ impl Analyzer {
             Option<CompactString>,
             u32,
         )> = None;
+        // Component providing the v-slot scope (static slot names only)
+        let mut slot_component: Option<CompactString> = None;
 
         // Collect v-for scope
         #[allow(clippy::type_complexity)]
@@ -70,6 +72,7 @@ impl Analyzer {
         )> = None;
 
         let mut key_expression: Option<CompactString> = None;
+        let mut key_span: Option<(u32, u32)> = None;
 
         // Collect v-if condition for type narrowing
         let mut vif_condition: Option<CompactString> = None;
@@ -125,6 +128,8 @@ impl Analyzer {
                                         ExpressionNode::Compound(c) => c.loc.source.as_str(),
                                     };
                                     key_expression = Some(CompactString::new(content));
+                                    let loc = exp.loc();
+                                    key_span = Some((loc.start.offset, loc.end.offset));
                                 }
                             }
                         }
@@ -168,6 +173,20 @@ impl Analyzer {
                             (smallvec![], None)
                         };
 
+                        // `<Comp v-slot>` provides its own slot, `<template #name>`
+                        // one of the parent component's
+                        let is_static_name = match &dir.arg {
+                            Some(ExpressionNode::Simple(s)) => s.is_static,
+                            Some(ExpressionNode::Compound(_)) => false,
+                            None => true,
+                        };
+                        if is_static_name {
+                            slot_component = if is_component {
+                                Some(CompactString::new(tag))
+                            } else {
+                                self.slot_owner.clone()
+                            };
+                        }
                         slot_scope =
                             Some((slot_name, prop_names, props_pattern, dir.loc.start.offset));
                     }
@@ -186,6 +205,7 @@ impl Analyzer {
                             name: slot_name,
                             props_pattern,
                             prop_names: prop_names.iter().cloned().collect(),
+                            component: slot_component,
                         },
                         offset,
                         el.loc.end.offset,
@@ -206,6 +226,10 @@ impl Analyzer {
             let count = vars.len();
 
             if count > 0 {
+                if let (Some(key), Some((key_start, key_end))) = (&key_expression, key_span) {
+                    self.check_v_for_key(key, &vars, key_start, key_end);
+                }
+
                 let value_alias = vars
                     .first()
                     .cloned()
@@ -366,12 +390,20 @@ impl Analyzer {
             }
         });
 
+        // Collect template refs while the element's own v-for scope is active
+        profile!("croquis.template.refs", self.collect_template_refs(el));
+
         // Visit children
+        let parent_slot_owner = std::mem::replace(
+            &mut self.slot_owner,
+            is_component.then(|| CompactString::new(tag)),
+        );
         profile!("croquis.template.element.children", {
             for child in el.children.iter() {
                 self.visit_template_child(child, scope_vars);
             }
         });
+        self.slot_owner = parent_slot_owner;
 
         // Pop v-if guard after visiting children
         if vif_guard_pushed {
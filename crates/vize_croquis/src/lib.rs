@@ -45,6 +45,7 @@ pub mod display;
 pub mod hoist;
 pub mod import_resolver;
 pub mod macros;
+pub mod migration;
 pub mod naming;
 pub mod optimization;
 pub mod provide;
@@ -77,8 +78,8 @@ pub use symbol::{Symbol, SymbolFlags, SymbolId, SymbolTable};
 // Re-export analysis types
 pub use analysis::{
     AnalysisStats, BindingMetadata, Croquis, ImportStatementInfo, InvalidExport, InvalidExportKind,
-    ReExportInfo, TemplateExpression, TemplateExpressionKind, TypeExport, TypeExportKind,
-    UndefinedRef, UnusedTemplateVar, UnusedVarContext, COMPILER_MACRO_NAMES,
+    ReExportInfo, TemplateExpression, TemplateExpressionKind, TemplateRef, TypeExport,
+    TypeExportKind, UndefinedRef, UnusedTemplateVar, UnusedVarContext, COMPILER_MACRO_NAMES,
 };
 pub use analyzer::{Analyzer, AnalyzerOptions};
 
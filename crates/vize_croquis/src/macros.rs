@@ -5,6 +5,8 @@
 
 use vize_carton::{CompactString, FxHashMap};
 
+use crate::scope::Span;
+
 /// Built-in Vue compiler macros
 pub static BUILTIN_MACROS: &[&str] = &[
     "defineProps",
@@ -157,6 +159,22 @@ pub struct ModelDefinition {
     pub default_value: Option<CompactString>,
 }
 
+/// A key declared more than once in an object literal passed to a macro
+///
+/// The later declaration silently wins at runtime, which is almost always a
+/// mistake (e.g. `defineProps({ foo: String, foo: Number })`).
+#[derive(Debug, Clone)]
+pub struct DuplicateKey {
+    /// Duplicated key name
+    pub name: CompactString,
+    /// Macro whose argument contains the object literal
+    pub macro_kind: MacroKind,
+    /// Span of the first declaration of the key
+    pub first: Span,
+    /// Span of the duplicate declaration of the key
+    pub duplicate: Span,
+}
+
 /// Top-level await in script setup
 #[derive(Debug, Clone)]
 pub struct TopLevelAwait {
@@ -198,6 +216,8 @@ pub enum MacroBindingKind {
 #[derive(Debug, Default)]
 pub struct MacroTracker {
     calls: Vec<MacroCall>,
+    /// Kind and span of each call, parallel to `calls`
+    call_spans: Vec<(MacroKind, Span)>,
     props: Vec<PropDefinition>,
     emits: Vec<EmitDefinition>,
     /// Actual emit() calls in the code (not declarations)
@@ -207,6 +227,8 @@ pub struct MacroTracker {
     exposes: Vec<ExposeDefinition>,
     /// Slots from defineSlots
     slots: Vec<SlotsDefinition>,
+    /// Keys declared more than once in macro object arguments
+    duplicate_keys: Vec<DuplicateKey>,
     props_destructure: Option<PropsDestructuredBindings>,
     top_level_awaits: Vec<TopLevelAwait>,
     next_id: u32,
@@ -247,6 +269,7 @@ impl MacroTracker {
             _ => {}
         }
 
+        self.call_spans.push((kind, Span::new(start, end)));
         self.calls.push(MacroCall {
             id,
             name: name.into(),
@@ -266,6 +289,15 @@ impl MacroTracker {
         &self.calls
     }
 
+    /// Get the kind and span of every macro call, in source order.
+    ///
+    /// Used by the LSP to highlight and describe `define*` calls without
+    /// walking the full call records.
+    #[inline]
+    pub fn calls_with_kind(&self) -> &[(MacroKind, Span)] {
+        &self.call_spans
+    }
+
     /// Get defineProps call (cached lookup)
     #[inline]
     pub fn define_props(&self) -> Option<&MacroCall> {
@@ -391,6 +423,18 @@ impl MacroTracker {
         &self.slots
     }
 
+    /// Add a duplicate object key
+    #[inline]
+    pub fn add_duplicate_key(&mut self, duplicate: DuplicateKey) {
+        self.duplicate_keys.push(duplicate);
+    }
+
+    /// Get keys declared more than once in macro object arguments
+    #[inline]
+    pub fn duplicate_keys(&self) -> &[DuplicateKey] {
+        &self.duplicate_keys
+    }
+
     /// Set props destructure
     #[inline]
     pub fn set_props_destructure(&mut self, destructure: PropsDestructuredBindings) {
@@ -435,6 +479,7 @@ impl MacroTracker {
 #[cfg(test)]
 mod tests {
     use super::{MacroKind, MacroTracker};
+    use crate::scope::Span;
     use vize_carton::CompactString;
 
     #[test]
@@ -455,6 +500,21 @@ mod tests {
         assert!(tracker.define_emits().is_none());
     }
 
+    #[test]
+    fn test_calls_with_kind() {
+        let mut tracker = MacroTracker::new();
+        tracker.add_call("defineProps", MacroKind::DefineProps, 6, 20, None, None);
+        tracker.add_call("defineModel", MacroKind::DefineModel, 40, 53, None, None);
+
+        assert_eq!(
+            tracker.calls_with_kind(),
+            &[
+                (MacroKind::DefineProps, Span::new(6, 20)),
+                (MacroKind::DefineModel, Span::new(40, 53)),
+            ]
+        );
+    }
+
     #[test]
     fn test_top_level_await() {
         let mut tracker = MacroTracker::new();
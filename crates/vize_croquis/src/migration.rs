@@ -0,0 +1,65 @@
+//! Vue 2 migration findings.
+//!
+//! Records usages of APIs that were removed or replaced in Vue 3 so that an
+//! upgrade can be guided by lint rules. Detection is opt-in
+//! (`AnalyzerOptions::detect_vue2_migration`) because it matches names such as
+//! `Vue` and `$on` without resolving what they refer to.
+
+use vize_carton::CompactString;
+
+/// Kind of Vue 2 API usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MigrationFindingKind {
+    /// `Vue.extend(...)`
+    VueExtend = 0,
+    /// `new Vue(...)`
+    NewVue = 1,
+    /// `$on` / `$off` / `$once` instance event emitter
+    EventEmitter = 2,
+    /// `filters` component option or `Vue.filter(...)`
+    Filters = 3,
+    /// `Vue.observable(...)`
+    VueObservable = 4,
+}
+
+impl MigrationFindingKind {
+    /// Get display string for the finding kind
+    #[inline]
+    pub const fn to_display(self) -> &'static str {
+        match self {
+            Self::VueExtend => "vue-extend",
+            Self::NewVue => "new-vue",
+            Self::EventEmitter => "event-emitter",
+            Self::Filters => "filters",
+            Self::VueObservable => "vue-observable",
+        }
+    }
+
+    /// Get description of the removed API and its Vue 3 replacement
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::VueExtend => "Vue.extend() was removed in Vue 3; use defineComponent() instead",
+            Self::NewVue => "new Vue() was removed in Vue 3; use createApp() instead",
+            Self::EventEmitter => {
+                "$on/$off/$once were removed in Vue 3; use emits or an external event emitter"
+            }
+            Self::Filters => {
+                "Filters were removed in Vue 3; use methods or computed properties instead"
+            }
+            Self::VueObservable => "Vue.observable() was removed in Vue 3; use reactive() instead",
+        }
+    }
+}
+
+/// A detected Vue 2 API usage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationFinding {
+    pub kind: MigrationFindingKind,
+    /// The matched API (e.g. `Vue.extend`, `$on`, `filters`)
+    pub api_name: CompactString,
+    /// Start offset in the script content
+    pub start: u32,
+    /// End offset in the script content
+    pub end: u32,
+}
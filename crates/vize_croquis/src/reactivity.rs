@@ -128,6 +128,31 @@ pub struct ReactivityLoss {
     pub end: u32,
 }
 
+/// Operand position where a ref was used without `.value`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RefOperandKind {
+    /// Binary operand: `count > 5`, `count + 1`
+    Binary = 0,
+    /// Unary operand: `-count`, `!count`
+    Unary = 1,
+    /// Update operand: `count++`
+    Update = 2,
+    /// Left side of a logical expression: `count || fallback`
+    Logical = 3,
+    /// Condition of `if`/`while`/`for`/ternary: `if (count)`, `count ? a : b`
+    Condition = 4,
+}
+
+/// A ref used directly as an operand: `count > 5` instead of `count.value > 5`
+#[derive(Debug, Clone)]
+pub struct RefAsOperand {
+    pub name: CompactString,
+    pub kind: RefOperandKind,
+    pub start: u32,
+    pub end: u32,
+}
+
 /// Tracks reactive sources during analysis
 #[derive(Debug, Default)]
 pub struct ReactivityTracker {
@@ -137,6 +162,8 @@ pub struct ReactivityTracker {
     reactive_names: FxHashSet<CompactString>,
     /// Detected reactivity losses
     losses: Vec<ReactivityLoss>,
+    /// Refs used as operands without `.value` (script only)
+    ref_operands: Vec<RefAsOperand>,
     next_id: u32,
 }
 
@@ -214,6 +241,8 @@ impl ReactivityTracker {
     }
 
     /// Record destructuring of a reactive variable
+    ///
+    /// Destructuring the result of `toRefs()` yields refs and is not a loss.
     pub fn record_destructure(
         &mut self,
         source_name: CompactString,
@@ -222,6 +251,9 @@ impl ReactivityTracker {
         end: u32,
     ) {
         if let Some(source) = self.lookup(source_name.as_str()) {
+            if source.kind == ReactiveKind::ToRefs {
+                return;
+            }
             let kind = if source.kind.needs_value_access() {
                 // ref type - destructuring ref.value
                 ReactivityLossKind::RefValueDestructure {
@@ -294,6 +326,30 @@ impl ReactivityTracker {
     pub fn has_losses(&self) -> bool {
         !self.losses.is_empty()
     }
+
+    /// Record a ref identifier used directly as an operand
+    pub fn record_ref_operand(
+        &mut self,
+        name: CompactString,
+        kind: RefOperandKind,
+        start: u32,
+        end: u32,
+    ) {
+        if self.needs_value_access(name.as_str()) {
+            self.ref_operands.push(RefAsOperand {
+                name,
+                kind,
+                start,
+                end,
+            });
+        }
+    }
+
+    /// Get all refs used as operands without `.value`
+    #[inline]
+    pub fn ref_operands(&self) -> &[RefAsOperand] {
+        &self.ref_operands
+    }
 }
 
 #[cfg(test)]
@@ -401,4 +457,20 @@ mod tests {
 
         assert!(!tracker.has_losses());
     }
+
+    #[test]
+    fn test_to_refs_destructure_no_loss() {
+        let mut tracker = ReactivityTracker::new();
+        tracker.register(CompactString::new("refs"), ReactiveKind::ToRefs, 0);
+
+        // Destructuring toRefs() output yields refs, so reactivity is preserved
+        tracker.record_destructure(
+            CompactString::new("refs"),
+            vec![CompactString::new("count")],
+            10,
+            20,
+        );
+
+        assert!(!tracker.has_losses());
+    }
 }
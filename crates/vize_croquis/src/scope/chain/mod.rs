@@ -406,6 +406,63 @@ impl ScopeChain {
         self.current = id;
     }
 
+    /// Remove template scopes (v-for, v-slot, event handler, callback) whose span
+    /// lies within `start..=end`, together with every scope nested under them.
+    ///
+    /// Remaining scopes are compacted, so the returned table maps each old
+    /// `ScopeId` (by index) to its new id, or `None` if the scope was removed.
+    pub fn remove_template_scopes_within(&mut self, start: u32, end: u32) -> Vec<Option<ScopeId>> {
+        let mut remap: Vec<Option<ScopeId>> = Vec::with_capacity(self.scopes.len());
+        let mut next = 0u32;
+        for scope in &self.scopes {
+            let is_template = matches!(
+                scope.kind,
+                ScopeKind::VFor | ScopeKind::VSlot | ScopeKind::EventHandler | ScopeKind::Callback
+            );
+            let in_range = is_template && scope.span.start >= start && scope.span.end <= end;
+            // Parents always precede children, so the parent's fate is already known
+            let parent_removed = scope
+                .parent()
+                .is_some_and(|p| remap.get(p.as_u32() as usize) == Some(&None));
+            if scope.id != ScopeId::ROOT && (in_range || parent_removed) {
+                remap.push(None);
+            } else {
+                remap.push(Some(ScopeId::new(next)));
+                next += 1;
+            }
+        }
+
+        if next as usize == self.scopes.len() {
+            return remap;
+        }
+
+        // Fall back to the nearest surviving ancestor of the current scope
+        let mut current = self.current;
+        while remap[current.as_u32() as usize].is_none() {
+            current = self.scopes[current.as_u32() as usize]
+                .parent()
+                .unwrap_or(ScopeId::ROOT);
+        }
+        self.current = remap[current.as_u32() as usize].unwrap_or(ScopeId::ROOT);
+
+        let scopes = core::mem::take(&mut self.scopes);
+        self.scopes = scopes
+            .into_iter()
+            .filter_map(|mut scope| {
+                scope.id = remap[scope.id.as_u32() as usize]?;
+                scope
+                    .parents
+                    .retain(|p| remap.get(p.as_u32() as usize).is_some_and(Option::is_some));
+                for parent in scope.parents.iter_mut() {
+                    *parent = remap[parent.as_u32() as usize].unwrap_or(ScopeId::ROOT);
+                }
+                Some(scope)
+            })
+            .collect();
+
+        remap
+    }
+
     /// Build parents list including Vue global for template scopes
     pub(crate) fn build_template_parents(&self) -> ParentScopes {
         let mut parents: ParentScopes = smallvec![self.current];
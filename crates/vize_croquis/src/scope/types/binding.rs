@@ -9,7 +9,7 @@ use vize_carton::bitflags;
 use vize_relief::BindingType;
 
 /// Source span
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Span {
     pub start: u32,
     pub end: u32,
@@ -124,6 +124,8 @@ pub struct VSlotScopeData {
     pub props_pattern: Option<CompactString>,
     /// Extracted prop names (stack-allocated for typical cases)
     pub prop_names: ParamNames,
+    /// Component providing the slot, when the slot name is static
+    pub component: Option<CompactString>,
 }
 
 /// Data specific to event handler scope
@@ -1,15 +1,18 @@
-//! Extraction functions for props, emits, and reactivity detection.
+//! Extraction functions for props, emits, slots, and reactivity detection.
 
 use oxc_ast::ast::{
-    Argument, CallExpression, Declaration, Expression, ObjectPropertyKind, PropertyKey, TSType,
-    VariableDeclarationKind,
+    Argument, CallExpression, Declaration, Expression, FormalParameters, ObjectExpression,
+    ObjectPropertyKind, PropertyKey, PropertyKind, TSSignature, TSType, VariableDeclarationKind,
 };
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::analysis::{InvalidExport, InvalidExportKind, TypeExport, TypeExportKind};
-use crate::macros::{EmitDefinition, MacroKind, ModelDefinition, PropDefinition};
+use crate::macros::{
+    DuplicateKey, EmitDefinition, MacroKind, ModelDefinition, PropDefinition, SlotsDefinition,
+};
 use crate::provide::ProvideKey;
 use crate::reactivity::ReactiveKind;
+use crate::scope::Span as ScopeSpan;
 use crate::setup_context::SetupContextViolationKind;
 use vize_carton::{CompactString, FxHashMap, String};
 use vize_relief::BindingType;
@@ -84,6 +87,13 @@ pub fn process_call_expression(
         type_args.clone(),
     );
 
+    // Duplicate keys in object arguments (props, defaults, options, ...)
+    for arg in call.arguments.iter() {
+        if let Argument::ObjectExpression(obj) = arg {
+            collect_duplicate_keys(result, obj, macro_kind);
+        }
+    }
+
     // Process macro-specific content
     match macro_kind {
         MacroKind::DefineProps => {
@@ -127,6 +137,12 @@ pub fn process_call_expression(
             });
         }
 
+        MacroKind::DefineSlots => {
+            if let Some(ref type_params) = call.type_arguments {
+                extract_slots_from_type(result, &type_params.params, source);
+            }
+        }
+
         MacroKind::WithDefaults => {
             // withDefaults wraps defineProps - find the inner call
             if let Some(Argument::CallExpression(inner_call)) = call.arguments.first() {
@@ -140,6 +156,48 @@ pub fn process_call_expression(
     Some(macro_kind)
 }
 
+/// Record keys declared more than once in an object literal and in the
+/// object literals nested in its values
+pub fn collect_duplicate_keys(
+    result: &mut ScriptParseResult,
+    obj: &ObjectExpression<'_>,
+    macro_kind: MacroKind,
+) {
+    let mut seen: FxHashMap<&str, ScopeSpan> = FxHashMap::default();
+    for prop in obj.properties.iter() {
+        let ObjectPropertyKind::ObjectProperty(p) = prop else {
+            continue;
+        };
+
+        if let Expression::ObjectExpression(nested) = &p.value {
+            collect_duplicate_keys(result, nested, macro_kind);
+        }
+
+        // A getter/setter pair shares its key on purpose
+        if p.kind != PropertyKind::Init {
+            continue;
+        }
+        let name = match &p.key {
+            PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+            PropertyKey::StringLiteral(s) => s.value.as_str(),
+            _ => continue,
+        };
+
+        let key_span = p.key.span();
+        let span = ScopeSpan::new(key_span.start, key_span.end);
+        if let Some(first) = seen.get(name) {
+            result.macros.add_duplicate_key(DuplicateKey {
+                name: CompactString::new(name),
+                macro_kind,
+                first: *first,
+                duplicate: span,
+            });
+        } else {
+            seen.insert(name, span);
+        }
+    }
+}
+
 /// Extract props from TypeScript type parameters
 pub fn extract_props_from_type(
     result: &mut ScriptParseResult,
@@ -261,6 +319,56 @@ pub fn extract_emits_from_type(
     }
 }
 
+/// Extract slots from TypeScript type parameters
+///
+/// Accepts both `name?: (props: P) => any` and `name(props: P): any` members;
+/// the first parameter's type is recorded as the slot props type.
+pub fn extract_slots_from_type(
+    result: &mut ScriptParseResult,
+    type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
+    source: &str,
+) {
+    for tp in type_params.iter() {
+        if let TSType::TSTypeLiteral(lit) = tp {
+            for member in lit.members.iter() {
+                let (key, params) = match member {
+                    TSSignature::TSPropertySignature(prop) => {
+                        let params = prop.type_annotation.as_ref().and_then(|ann| {
+                            match &ann.type_annotation {
+                                TSType::TSFunctionType(func) => Some(&*func.params),
+                                _ => None,
+                            }
+                        });
+                        (&prop.key, params)
+                    }
+                    TSSignature::TSMethodSignature(method) => (&method.key, Some(&*method.params)),
+                    _ => continue,
+                };
+
+                let name = match key {
+                    PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+                    PropertyKey::StringLiteral(s) => s.value.as_str(),
+                    _ => continue,
+                };
+
+                result.macros.add_slot(SlotsDefinition {
+                    name: CompactString::new(name),
+                    props_type: params.and_then(|params| slot_props_type(params, source)),
+                });
+            }
+        }
+    }
+}
+
+/// Source text of the first parameter's type annotation
+fn slot_props_type(params: &FormalParameters<'_>, source: &str) -> Option<CompactString> {
+    let type_ann = params.items.first()?.type_annotation.as_ref()?;
+    let span = type_ann.type_annotation.span();
+    source
+        .get(span.start as usize..span.end as usize)
+        .map(CompactString::new)
+}
+
 /// Extract emits from runtime arguments (array)
 pub fn extract_emits_from_runtime(
     result: &mut ScriptParseResult,
@@ -305,6 +413,8 @@ pub fn detect_reactivity_call(
         "toRef" => Some((ReactiveKind::ToRef, BindingType::SetupRef)),
         "toRefs" => Some((ReactiveKind::ToRefs, BindingType::SetupRef)),
         "customRef" => Some((ReactiveKind::Ref, BindingType::SetupRef)),
+        // Vue 3.5+: a readonly shallow ref filled by the runtime from `ref="key"`
+        "useTemplateRef" => Some((ReactiveKind::ShallowRef, BindingType::SetupRef)),
         "readonly" => Some((ReactiveKind::Readonly, BindingType::SetupReactiveConst)),
         "shallowReadonly" => Some((
             ReactiveKind::ShallowReadonly,
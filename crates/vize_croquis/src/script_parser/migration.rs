@@ -0,0 +1,214 @@
+//! Vue 2 API detection.
+//!
+//! Walks the whole program (including nested functions) looking for the
+//! call shapes listed in [`MigrationFindingKind`].
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, CallExpression, ExportDefaultDeclaration, ExportDefaultDeclarationKind, Expression,
+    NewExpression, ObjectExpression, ObjectPropertyKind, PropertyKey,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_span::GetSpan;
+use vize_carton::CompactString;
+
+use crate::migration::{MigrationFinding, MigrationFindingKind};
+
+/// Detect Vue 2 API usages in script source.
+///
+/// `lang` is the script block's `lang` attribute. Findings are sorted by
+/// start offset.
+pub fn detect_migration_findings(source: &str, lang: Option<&str>) -> Vec<MigrationFinding> {
+    let allocator = Allocator::default();
+    let ret = super::parse_script_source(&allocator, source, lang);
+    if ret.panicked {
+        return Vec::new();
+    }
+
+    let mut collector = MigrationCollector::default();
+    collector.visit_program(&ret.program);
+    collector.findings.sort_by_key(|finding| finding.start);
+    collector.findings
+}
+
+#[derive(Default)]
+struct MigrationCollector {
+    findings: Vec<MigrationFinding>,
+}
+
+impl MigrationCollector {
+    fn push(&mut self, kind: MigrationFindingKind, api_name: &str, start: u32, end: u32) {
+        self.findings.push(MigrationFinding {
+            kind,
+            api_name: CompactString::new(api_name),
+            start,
+            end,
+        });
+    }
+
+    /// Check a component options argument for the `filters` option.
+    fn check_options_argument(&mut self, argument: Option<&Argument<'_>>) {
+        if let Some(Argument::ObjectExpression(options)) = argument {
+            self.check_component_options(options);
+        }
+    }
+
+    fn check_component_options(&mut self, options: &ObjectExpression<'_>) {
+        for property in options.properties.iter() {
+            if let ObjectPropertyKind::ObjectProperty(property) = property {
+                if let PropertyKey::StaticIdentifier(key) = &property.key {
+                    if key.name.as_str() == "filters" {
+                        self.push(
+                            MigrationFindingKind::Filters,
+                            "filters",
+                            key.span.start,
+                            key.span.end,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for MigrationCollector {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        match call.callee.without_parentheses() {
+            Expression::StaticMemberExpression(member) => {
+                let property = member.property.name.as_str();
+                let (start, end) = (member.span.start, member.span.end);
+                match property {
+                    "$on" | "$off" | "$once" => {
+                        self.push(MigrationFindingKind::EventEmitter, property, start, end);
+                    }
+                    _ if is_vue_identifier(&member.object) => match property {
+                        "extend" => {
+                            self.push(MigrationFindingKind::VueExtend, "Vue.extend", start, end);
+                            self.check_options_argument(call.arguments.first());
+                        }
+                        "observable" => {
+                            self.push(
+                                MigrationFindingKind::VueObservable,
+                                "Vue.observable",
+                                start,
+                                end,
+                            );
+                        }
+                        "filter" => {
+                            self.push(MigrationFindingKind::Filters, "Vue.filter", start, end);
+                        }
+                        "component" => self.check_options_argument(call.arguments.get(1)),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+            Expression::Identifier(ident) if ident.name.as_str() == "defineComponent" => {
+                self.check_options_argument(call.arguments.first());
+            }
+            _ => {}
+        }
+
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_new_expression(&mut self, expr: &NewExpression<'a>) {
+        if is_vue_identifier(&expr.callee) {
+            let end = expr.callee.without_parentheses().span().end;
+            self.push(
+                MigrationFindingKind::NewVue,
+                "new Vue",
+                expr.span.start,
+                end,
+            );
+            self.check_options_argument(expr.arguments.first());
+        }
+
+        walk::walk_new_expression(self, expr);
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        if let ExportDefaultDeclarationKind::ObjectExpression(options) = &decl.declaration {
+            self.check_component_options(options);
+        }
+
+        walk::walk_export_default_declaration(self, decl);
+    }
+}
+
+fn is_vue_identifier(expr: &Expression<'_>) -> bool {
+    matches!(
+        expr.without_parentheses(),
+        Expression::Identifier(ident) if ident.name.as_str() == "Vue"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_migration_findings;
+    use crate::migration::MigrationFindingKind;
+
+    fn kinds_and_names(source: &str) -> Vec<(MigrationFindingKind, &str)> {
+        detect_migration_findings(source, None)
+            .into_iter()
+            .map(|finding| {
+                let text = &source[finding.start as usize..finding.end as usize];
+                (finding.kind, text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_vue2_apis() {
+        let source = r#"
+import Vue from 'vue'
+
+const Base = Vue.extend({
+  filters: { upper: (s) => s.toUpperCase() },
+})
+const store = Vue.observable({ count: 0 })
+Vue.filter('currency', (value) => value)
+export const bus = new Vue()
+
+export default {
+  filters: {},
+  mounted() {
+    bus.$on('refresh', this.load)
+    this.$once('hook:beforeDestroy', () => bus.$off('refresh'))
+  },
+}
+"#;
+
+        assert_eq!(
+            kinds_and_names(source),
+            vec![
+                (MigrationFindingKind::VueExtend, "Vue.extend"),
+                (MigrationFindingKind::Filters, "filters"),
+                (MigrationFindingKind::VueObservable, "Vue.observable"),
+                (MigrationFindingKind::Filters, "Vue.filter"),
+                (MigrationFindingKind::NewVue, "new Vue"),
+                (MigrationFindingKind::Filters, "filters"),
+                (MigrationFindingKind::EventEmitter, "bus.$on"),
+                (MigrationFindingKind::EventEmitter, "this.$once"),
+                (MigrationFindingKind::EventEmitter, "bus.$off"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignores_unrelated_code() {
+        let source = r#"
+import { defineComponent, ref } from 'vue'
+
+const query = { filters: ['open'] }
+const observable = Other.observable(query)
+export default defineComponent({
+  setup() {
+    return { query: ref(query), observable }
+  },
+})
+"#;
+
+        assert!(detect_migration_findings(source, None).is_empty());
+    }
+}
@@ -7,19 +7,22 @@
 //! - Reactivity wrappers (ref, computed, reactive)
 //! - Invalid exports in script setup
 //! - Nested function scopes (arrow functions, callbacks)
+//! - Vue 2 API usages for migration (opt-in, see [`detect_migration_findings`])
 //!
 //! ## Module Structure
 //!
 //! - [`process`] - Statement and variable processing
 //! - [`extract`] - Props/emits extraction and reactivity detection
 //! - [`walk`] - Scope walking functions
+//! - [`migration`] - Vue 2 API detection
 
 mod extract;
+mod migration;
 mod process;
 mod walk;
 
 use oxc_allocator::Allocator;
-use oxc_parser::Parser;
+use oxc_parser::{Parser, ParserReturn};
 use oxc_span::SourceType;
 
 use crate::analysis::BindingMetadata;
@@ -34,6 +37,7 @@ use crate::scope::{
 use crate::setup_context::SetupContextTracker;
 use vize_carton::{profile, CompactString, FxHashMap, FxHashSet};
 
+pub use migration::detect_migration_findings;
 pub use process::process_statement;
 
 /// Result of parsing a script setup block
@@ -67,6 +71,8 @@ pub struct ScriptParseResult {
     pub re_exports: Vec<ReExportInfo>,
     /// Definition spans for bindings (name -> (start, end) offset in script)
     pub binding_spans: FxHashMap<CompactString, (u32, u32)>,
+    /// `useTemplateRef('key')` keys mapped to the bindings they initialize
+    pub template_ref_keys: FxHashMap<CompactString, CompactString>,
 }
 
 /// Setup global scopes hierarchy:
@@ -190,6 +196,27 @@ fn setup_global_scopes(scopes: &mut ScopeChain, source_len: u32) {
     // Stay in module scope - setup/plain will be created as children
 }
 
+/// Source type for a script block with the given `lang` attribute.
+///
+/// Blocks are parsed as TypeScript, a superset of JavaScript. Only
+/// `lang="jsx"`/`"tsx"` blocks enable JSX, since TSX rejects `<T>value`
+/// type assertions.
+pub fn script_source_type(lang: Option<&str>) -> SourceType {
+    match lang {
+        Some("jsx" | "tsx") => SourceType::tsx(),
+        _ => SourceType::ts(),
+    }
+}
+
+/// Parse a script block with the source type for its `lang` attribute.
+pub fn parse_script_source<'a>(
+    allocator: &'a Allocator,
+    source: &'a str,
+    lang: Option<&str>,
+) -> ParserReturn<'a> {
+    Parser::new(allocator, source, script_source_type(lang)).parse()
+}
+
 /// Parse script setup source code using OXC parser with an optional generic parameter.
 ///
 /// `generic` is the value from `<script setup generic="T">` attribute, if present.
@@ -197,12 +224,21 @@ fn setup_global_scopes(scopes: &mut ScopeChain, source_len: u32) {
 /// This is a high-performance alternative to string-based analysis,
 /// providing accurate AST-based detection with proper span tracking.
 pub fn parse_script_setup_with_generic(source: &str, generic: Option<&str>) -> ScriptParseResult {
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
+    parse_script_setup_with_lang(source, generic, None)
+}
 
+/// Parse script setup source code written in the given block `lang`.
+///
+/// `lang="jsx"`/`"tsx"` blocks are parsed with JSX enabled.
+pub fn parse_script_setup_with_lang(
+    source: &str,
+    generic: Option<&str>,
+    lang: Option<&str>,
+) -> ScriptParseResult {
+    let allocator = Allocator::default();
     let ret = profile!(
         "croquis.script_setup.oxc_parse",
-        Parser::new(&allocator, source, source_type).parse()
+        parse_script_source(&allocator, source, lang)
     );
 
     if ret.panicked {
@@ -254,12 +290,15 @@ pub fn parse_script_setup(source: &str) -> ScriptParseResult {
 
 /// Parse non-script-setup (Options API) source code using OXC parser.
 pub fn parse_script(source: &str) -> ScriptParseResult {
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
+    parse_script_with_lang(source, None)
+}
 
+/// Parse non-script-setup source code written in the given block `lang`.
+pub fn parse_script_with_lang(source: &str, lang: Option<&str>) -> ScriptParseResult {
+    let allocator = Allocator::default();
     let ret = profile!(
         "croquis.script_plain.oxc_parse",
-        Parser::new(&allocator, source, source_type).parse()
+        parse_script_source(&allocator, source, lang)
     );
 
     if ret.panicked {
@@ -303,7 +342,7 @@ pub fn parse_script(source: &str) -> ScriptParseResult {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_script_setup;
+    use super::{parse_script_setup, parse_script_setup_with_lang};
     use vize_carton::{append, cstr, CompactString};
 
     #[test]
@@ -330,6 +369,23 @@ mod tests {
         assert!(prop_names.contains(&"count"));
     }
 
+    #[test]
+    fn test_parse_script_setup_uses_block_lang() {
+        let jsx = "const Greeting = () => <span>{msg}</span>";
+        assert!(parse_script_setup_with_lang(jsx, None, Some("tsx"))
+            .bindings
+            .contains("Greeting"));
+        assert!(parse_script_setup_with_lang(jsx, None, Some("jsx"))
+            .bindings
+            .contains("Greeting"));
+
+        // `<T>value` assertions are only valid outside TSX
+        let assertion = "const n = <number>value";
+        assert!(parse_script_setup_with_lang(assertion, None, Some("ts"))
+            .bindings
+            .contains("n"));
+    }
+
     #[test]
     fn test_parse_define_props_runtime() {
         let result = parse_script_setup(
@@ -353,6 +409,35 @@ mod tests {
         assert_eq!(result.macros.emits().len(), 2);
     }
 
+    #[test]
+    fn test_parse_define_slots() {
+        let result = parse_script_setup(
+            r#"
+            const slots = defineSlots<{
+                default(props: { msg: string }): any
+                'item-row'?: (props: { item: Item; index: number }) => any
+                footer: () => any
+            }>()
+        "#,
+        );
+
+        assert!(result.macros.define_slots().is_some());
+        let slots: Vec<_> = result
+            .macros
+            .slots()
+            .iter()
+            .map(|slot| (slot.name.as_str(), slot.props_type.as_deref()))
+            .collect();
+        assert_eq!(
+            slots,
+            vec![
+                ("default", Some("{ msg: string }")),
+                ("item-row", Some("{ item: Item; index: number }")),
+                ("footer", None),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_reactivity() {
         let result = parse_script_setup(
@@ -655,6 +740,91 @@ const copy = { ...state }
         assert_snapshot!(output);
     }
 
+    #[test]
+    fn test_to_refs_destructure_preserves_reactivity() {
+        let result = parse_script_setup(
+            r#"
+const props = defineProps<{ title: string }>()
+const { title } = toRefs(props)
+
+const state = reactive({ count: 0 })
+const refs = toRefs(state)
+const { count } = refs
+
+const tr = toRefs
+const { count: aliased } = tr(state)
+"#,
+        );
+
+        assert!(!result.reactivity.has_losses());
+        for name in ["title", "count", "aliased"] {
+            assert!(result.reactivity.needs_value_access(name), "{name}");
+            assert_eq!(
+                result.bindings.get(name),
+                Some(vize_relief::BindingType::SetupRef),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_use_template_ref_binding() {
+        let result = parse_script_setup(
+            r#"
+const inputEl = useTemplateRef<HTMLInputElement>('input')
+"#,
+        );
+
+        assert_eq!(
+            result.bindings.get("inputEl"),
+            Some(vize_relief::BindingType::SetupRef)
+        );
+        assert!(result.reactivity.needs_value_access("inputEl"));
+        assert_eq!(
+            result.template_ref_keys.get("input").map(|b| b.as_str()),
+            Some("inputEl")
+        );
+    }
+
+    #[test]
+    fn test_ref_as_operand() {
+        use crate::reactivity::RefOperandKind;
+
+        let result = parse_script_setup(
+            r#"
+const count = ref(0)
+const state = reactive({ total: 0 })
+const doubled = computed(() => count.value * 2)
+
+if (count > 5) {}
+const label = doubled ? 'yes' : 'no'
+const next = -count
+count++
+
+function ok(count) {
+    return count + 1 + state.total + count.value
+}
+const typeName = typeof count
+"#,
+        );
+
+        let operands: Vec<_> = result
+            .reactivity
+            .ref_operands()
+            .iter()
+            .map(|op| (op.name.as_str(), op.kind))
+            .collect();
+        assert_eq!(
+            operands,
+            [
+                ("count", RefOperandKind::Binary),
+                ("doubled", RefOperandKind::Condition),
+                ("count", RefOperandKind::Unary),
+                ("count", RefOperandKind::Update),
+            ]
+        );
+    }
+
     #[test]
     fn test_scope_structure_snapshot() {
         use crate::scope::ScopeKind;
@@ -715,4 +885,47 @@ function processItem(item) {
 
         assert_snapshot!(output);
     }
+
+    #[test]
+    fn test_duplicate_macro_object_keys() {
+        use crate::macros::MacroKind;
+
+        let source = r#"
+            const props = withDefaults(defineProps({
+                foo: String,
+                'foo': Number,
+                bar: { type: String, type: Number },
+                baz: Boolean,
+            }), { baz: true, baz: false })
+            defineOptions({
+                get name() { return 'A' },
+                set name(v) {},
+            })
+        "#;
+        let result = parse_script_setup(source);
+
+        let duplicates: Vec<_> = result
+            .macros
+            .duplicate_keys()
+            .iter()
+            .map(|d| (d.name.as_str(), d.macro_kind))
+            .collect();
+        assert_eq!(
+            duplicates,
+            [
+                ("baz", MacroKind::WithDefaults),
+                ("type", MacroKind::DefineProps),
+                ("foo", MacroKind::DefineProps),
+            ]
+        );
+
+        let foo = &result.macros.duplicate_keys()[2];
+        let first = source.find("foo:").unwrap() as u32;
+        let duplicate = source.find("'foo'").unwrap() as u32;
+        assert_eq!((foo.first.start, foo.first.end), (first, first + 3));
+        assert_eq!(
+            (foo.duplicate.start, foo.duplicate.end),
+            (duplicate, duplicate + 5)
+        );
+    }
 }
@@ -71,6 +71,18 @@ pub(in crate::script_parser) fn process_variable_declarator(
                     return;
                 }
 
+                // Track useTemplateRef('key') so template `ref="key"` resolves to this binding
+                if let Expression::Identifier(callee_id) = &call.callee {
+                    if callee_id.name.as_str() == "useTemplateRef" {
+                        if let Some(Argument::StringLiteral(key)) = call.arguments.first() {
+                            result.template_ref_keys.insert(
+                                CompactString::new(key.value.as_str()),
+                                CompactString::new(name),
+                            );
+                        }
+                    }
+                }
+
                 // Check for reactivity wrappers (also handles aliases)
                 if let Some((reactive_kind, binding_type)) =
                     detect_reactivity_call(call, &result.reactivity_aliases)
@@ -266,6 +278,25 @@ pub(in crate::script_parser) fn process_variable_declarator(
                 None
             });
 
+            // Check if this is destructuring from toRefs(), directly or through a variable
+            // e.g., const { count } = toRefs(props); const refs = toRefs(state); const { a } = refs
+            // Each destructured property is itself a ref, so reactivity is preserved.
+            let is_to_refs_destructure = declarator.init.as_ref().is_some_and(|init| {
+                if let Some(call) = extract_call_expression(init) {
+                    return matches!(
+                        detect_reactivity_call(call, &result.reactivity_aliases),
+                        Some((ReactiveKind::ToRefs, _))
+                    );
+                }
+                if let Expression::Identifier(id) = init {
+                    return result
+                        .reactivity
+                        .lookup(id.name.as_str())
+                        .is_some_and(|s| s.kind == ReactiveKind::ToRefs);
+                }
+                false
+            });
+
             // Check if this is destructuring directly from reactive() or ref().value
             // e.g., const { count } = reactive({ count: 0 })
             let direct_reactive_call = declarator.init.as_ref().and_then(|init| {
@@ -381,6 +412,13 @@ pub(in crate::script_parser) fn process_variable_declarator(
                     // If destructuring from defineProps, use Props binding type
                     let binding_type = if is_define_props {
                         BindingType::Props
+                    } else if is_to_refs_destructure {
+                        result.reactivity.register(
+                            CompactString::new(&local_name),
+                            ReactiveKind::ToRef,
+                            0,
+                        );
+                        BindingType::SetupRef
                     } else {
                         infer_destructure_binding_type(kind, declarator.init.as_ref())
                     };
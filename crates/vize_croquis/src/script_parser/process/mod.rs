@@ -251,6 +251,18 @@ pub fn process_statement(result: &mut ScriptParseResult, stmt: &Statement<'_>, s
             result.scopes.exit_scope();
         }
 
+        // Control flow at top level (scopes, ref operands in conditions)
+        Statement::IfStatement(_)
+        | Statement::ForStatement(_)
+        | Statement::ForInStatement(_)
+        | Statement::ForOfStatement(_)
+        | Statement::WhileStatement(_)
+        | Statement::DoWhileStatement(_)
+        | Statement::SwitchStatement(_)
+        | Statement::TryStatement(_) => {
+            walk_statement(result, stmt, source);
+        }
+
         _ => {}
     }
 }
@@ -3,12 +3,17 @@
 //! Recursively walks expression nodes to find nested function scopes,
 //! callback arguments, reactivity losses, and client-only lifecycle hooks.
 
-use oxc_ast::ast::{Argument, AssignmentTarget, CallExpression, ObjectPropertyKind, Statement};
+use oxc_ast::ast::{
+    Argument, AssignmentTarget, CallExpression, ObjectPropertyKind, SimpleAssignmentTarget,
+    Statement, UnaryOperator,
+};
 
 use super::{
     detect_provide_inject_call, extract_function_params, is_client_only_hook, walk_statement,
     ClientOnlyScopeData, ClosureScopeData, CompactString, Expression, ScriptParseResult,
 };
+use crate::reactivity::RefOperandKind;
+use crate::ScopeKind;
 
 /// Walk an expression to find nested scopes (arrow functions, callbacks, etc.)
 ///
@@ -124,6 +129,7 @@ pub(in crate::script_parser) fn walk_expression(
 
         // Conditional expression
         Expression::ConditionalExpression(cond) => {
+            check_ref_operand(result, &cond.test, RefOperandKind::Condition);
             walk_expression(result, &cond.test, source);
             walk_expression(result, &cond.consequent, source);
             walk_expression(result, &cond.alternate, source);
@@ -131,10 +137,13 @@ pub(in crate::script_parser) fn walk_expression(
 
         // Logical/Binary expressions
         Expression::LogicalExpression(logical) => {
+            check_ref_operand(result, &logical.left, RefOperandKind::Logical);
             walk_expression(result, &logical.left, source);
             walk_expression(result, &logical.right, source);
         }
         Expression::BinaryExpression(binary) => {
+            check_ref_operand(result, &binary.left, RefOperandKind::Binary);
+            check_ref_operand(result, &binary.right, RefOperandKind::Binary);
             walk_expression(result, &binary.left, source);
             walk_expression(result, &binary.right, source);
         }
@@ -184,8 +193,20 @@ pub(in crate::script_parser) fn walk_expression(
             walk_expression(result, &await_expr.argument, source);
         }
         Expression::UnaryExpression(unary) => {
+            // `typeof count` / `delete` / `void` inspect the binding itself
+            if !matches!(
+                unary.operator,
+                UnaryOperator::Typeof | UnaryOperator::Void | UnaryOperator::Delete
+            ) {
+                check_ref_operand(result, &unary.argument, RefOperandKind::Unary);
+            }
             walk_expression(result, &unary.argument, source);
         }
+        Expression::UpdateExpression(update) => {
+            if let SimpleAssignmentTarget::AssignmentTargetIdentifier(id) = &update.argument {
+                record_ref_operand(result, id.name.as_str(), RefOperandKind::Update, id.span);
+            }
+        }
 
         // Sequence expression
         Expression::SequenceExpression(seq) => {
@@ -230,6 +251,53 @@ pub(in crate::script_parser) fn walk_expression(
     }
 }
 
+/// Record `expr` if it is a bare ref identifier used as an operand (missing `.value`).
+pub(in crate::script_parser) fn check_ref_operand(
+    result: &mut ScriptParseResult,
+    expr: &Expression<'_>,
+    kind: RefOperandKind,
+) {
+    let mut expr = expr;
+    loop {
+        expr = match expr {
+            Expression::ParenthesizedExpression(paren) => &paren.expression,
+            Expression::TSAsExpression(ts_as) => &ts_as.expression,
+            Expression::TSSatisfiesExpression(ts_satisfies) => &ts_satisfies.expression,
+            Expression::TSNonNullExpression(ts_non_null) => &ts_non_null.expression,
+            _ => break,
+        };
+    }
+
+    if let Expression::Identifier(id) = expr {
+        record_ref_operand(result, id.name.as_str(), kind, id.span);
+    }
+}
+
+fn record_ref_operand(
+    result: &mut ScriptParseResult,
+    name: &str,
+    kind: RefOperandKind,
+    span: oxc_span::Span,
+) {
+    if !result.reactivity.needs_value_access(name) {
+        return;
+    }
+
+    // Refs are tracked by their top-level name; a parameter or local with the
+    // same name in a nested function/block shadows them.
+    let shadowed = result.scopes.lookup(name).is_some_and(|(scope, _)| {
+        matches!(
+            scope.kind,
+            ScopeKind::Closure | ScopeKind::Function | ScopeKind::Block | ScopeKind::Callback
+        )
+    });
+    if !shadowed {
+        result
+            .reactivity
+            .record_ref_operand(CompactString::new(name), kind, span.start, span.end);
+    }
+}
+
 /// Walk call expression arguments to find callbacks
 #[inline]
 pub(in crate::script_parser) fn walk_call_arguments(
@@ -24,7 +24,9 @@ use vize_relief::BindingType;
 use super::extract::detect_provide_inject_call;
 use super::ScriptParseResult;
 
-pub(in crate::script_parser) use expressions::{walk_call_arguments, walk_expression};
+pub(in crate::script_parser) use expressions::{
+    check_ref_operand, walk_call_arguments, walk_expression,
+};
 pub(in crate::script_parser) use statements::walk_statement;
 
 /// Check if a function name is a client-only lifecycle hook
@@ -6,10 +6,11 @@
 use oxc_ast::ast::Statement;
 
 use super::{
-    add_binding_pattern_to_scope, extract_function_params, extract_param_names, walk_expression,
-    BindingType, BlockKind, BlockScopeData, ClosureScopeData, CompactString, GetSpan, ScopeBinding,
-    ScriptParseResult,
+    add_binding_pattern_to_scope, check_ref_operand, extract_function_params, extract_param_names,
+    walk_expression, BindingType, BlockKind, BlockScopeData, ClosureScopeData, CompactString,
+    GetSpan, ScopeBinding, ScriptParseResult,
 };
+use crate::reactivity::RefOperandKind;
 
 /// Walk a statement to find nested scopes
 #[inline]
@@ -124,6 +125,7 @@ pub(in crate::script_parser) fn walk_statement(
             result.scopes.exit_scope();
         }
         Statement::IfStatement(if_stmt) => {
+            check_ref_operand(result, &if_stmt.test, RefOperandKind::Condition);
             walk_expression(result, &if_stmt.test, source);
 
             // Consequent block
@@ -178,6 +180,7 @@ pub(in crate::script_parser) fn walk_statement(
                 }
             }
             if let Some(test) = &for_stmt.test {
+                check_ref_operand(result, test, RefOperandKind::Condition);
                 walk_expression(result, test, source);
             }
             if let Some(update) = &for_stmt.update {
@@ -230,6 +233,7 @@ pub(in crate::script_parser) fn walk_statement(
                 while_stmt.span.start,
                 while_stmt.span.end,
             );
+            check_ref_operand(result, &while_stmt.test, RefOperandKind::Condition);
             walk_expression(result, &while_stmt.test, source);
             walk_statement(result, &while_stmt.body, source);
             result.scopes.exit_scope();
@@ -243,6 +247,7 @@ pub(in crate::script_parser) fn walk_statement(
                 do_while.span.end,
             );
             walk_statement(result, &do_while.body, source);
+            check_ref_operand(result, &do_while.test, RefOperandKind::Condition);
             walk_expression(result, &do_while.test, source);
             result.scopes.exit_scope();
         }
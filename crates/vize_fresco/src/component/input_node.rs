@@ -14,6 +14,7 @@ pub struct InputNode {
     focused: bool,
     mask: bool,
     mask_char: char,
+    tab_index: Option<i32>,
     style: FlexStyle,
     appearance: Appearance,
 }
@@ -58,6 +59,12 @@ impl InputNode {
         self
     }
 
+    /// Set the tab order (`tabindex`).
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
     /// Set width.
     pub fn width(mut self, width: f32) -> Self {
         self.style.width = Dimension::Points(width);
@@ -91,6 +98,7 @@ impl InputNode {
             focused: self.focused,
             mask: self.mask,
             mask_char: self.mask_char,
+            tab_index: self.tab_index,
         };
         RenderNode::new(id, NodeKind::Input(content))
             .with_style(self.style)
@@ -3,7 +3,7 @@
 use compact_str::CompactString;
 
 use crate::layout::FlexStyle;
-use crate::render::{Appearance, NodeKind, RenderNode, TextContent};
+use crate::render::{Appearance, NodeKind, RenderNode, TextContent, TextOverflow};
 use crate::terminal::Color;
 
 /// Builder for Text nodes.
@@ -11,6 +11,7 @@ use crate::terminal::Color;
 pub struct TextNode {
     text: CompactString,
     wrap: bool,
+    overflow: TextOverflow,
     style: FlexStyle,
     appearance: Appearance,
 }
@@ -30,6 +31,22 @@ impl TextNode {
         self
     }
 
+    /// Set overflow behavior for non-wrapping text.
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Truncate overflowing text with an ellipsis.
+    pub fn truncate(self) -> Self {
+        self.overflow(TextOverflow::Ellipsis)
+    }
+
+    /// Clip overflowing text without an ellipsis.
+    pub fn clip(self) -> Self {
+        self.overflow(TextOverflow::Clip)
+    }
+
     /// Set foreground color.
     pub fn fg(mut self, color: Color) -> Self {
         self.appearance.fg = Some(color);
@@ -83,6 +100,7 @@ impl TextNode {
         let content = TextContent {
             text: self.text,
             wrap: self.wrap,
+            overflow: self.overflow,
         };
         RenderNode::new(id, NodeKind::Text(content))
             .with_style(self.style)
@@ -121,7 +139,7 @@ impl TextNode {
 #[cfg(test)]
 mod tests {
     use super::TextNode;
-    use crate::render::NodeKind;
+    use crate::render::{NodeKind, TextOverflow};
     use crate::terminal::Color;
 
     #[test]
@@ -144,6 +162,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_node_overflow() {
+        let node = TextNode::new("Hello").truncate().build(1);
+        if let NodeKind::Text(content) = &node.kind {
+            assert_eq!(content.overflow, TextOverflow::Ellipsis);
+        }
+
+        let node = TextNode::new("Hello").clip().build(1);
+        if let NodeKind::Text(content) = &node.kind {
+            assert_eq!(content.overflow, TextOverflow::Clip);
+        }
+    }
+
     #[test]
     fn test_text_node_styles() {
         let builder = TextNode::new("Test").fg(Color::Red).bold().underline();
@@ -151,6 +151,23 @@ impl LayoutEngine {
         let padding_left = layout.padding.left;
         let is_column = style.flex_direction == taffy::FlexDirection::Column;
 
+        // Main-axis gap, resolved against this node's content box
+        let main_gap = if is_column {
+            let content_height = layout.size.height
+                - layout.padding.top
+                - layout.padding.bottom
+                - layout.border.top
+                - layout.border.bottom;
+            resolve_gap(style.gap.height, content_height)
+        } else {
+            let content_width = layout.size.width
+                - layout.padding.left
+                - layout.padding.right
+                - layout.border.left
+                - layout.border.right;
+            resolve_gap(style.gap.width, content_width)
+        };
+
         // Store this node's layout
         if let Some(&id) = self.reverse_map.get(&node_id) {
             self.layout_cache.insert(
@@ -192,9 +209,9 @@ impl LayoutEngine {
             self.cache_layouts(child_id, child_x, child_y);
 
             if is_column {
-                offset_y += mt + child_height + mb;
+                offset_y += mt + child_height + mb + main_gap;
             } else {
-                offset_x += ml + child_width + mr;
+                offset_x += ml + child_width + mr + main_gap;
             }
         }
     }
@@ -240,9 +257,17 @@ fn resolve_margin(m: taffy::LengthPercentageAuto) -> f32 {
     }
 }
 
+/// Resolve gap value against the container's content size
+fn resolve_gap(gap: taffy::LengthPercentage, basis: f32) -> f32 {
+    match gap {
+        taffy::LengthPercentage::Length(v) => v,
+        taffy::LengthPercentage::Percent(p) => p * basis.max(0.0),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FlexStyle, LayoutEngine};
+    use super::{FlexStyle, LayoutEngine, Rect};
 
     #[test]
     fn test_engine_new() {
@@ -301,4 +326,32 @@ mod tests {
         assert_eq!(child_layout.width, 100);
         assert_eq!(child_layout.height, 50);
     }
+
+    #[test]
+    fn test_engine_two_column_layout_with_gap() {
+        use super::super::flex::{Dimension, Gap};
+
+        let mut engine = LayoutEngine::new();
+
+        let mut root_style = FlexStyle::new();
+        root_style.width = Dimension::Points(21.0);
+        root_style.height = Dimension::Points(5.0);
+        root_style.gap = Gap::new(0.0, 1.0);
+        let root = engine.new_node(&root_style);
+
+        let mut column_style = FlexStyle::new();
+        column_style.flex_grow = 1.0;
+        column_style.flex_basis = Dimension::Points(0.0);
+        column_style.height = Dimension::Percent(100.0);
+        let left = engine.new_node(&column_style);
+        let right = engine.new_node(&column_style);
+
+        engine.add_child(root, left);
+        engine.add_child(root, right);
+        engine.set_root(root);
+        engine.compute(21.0, 5.0);
+
+        assert_eq!(engine.layout(left).unwrap(), Rect::new(0, 0, 10, 5));
+        assert_eq!(engine.layout(right).unwrap(), Rect::new(11, 0, 10, 5));
+    }
 }
@@ -30,6 +30,8 @@ pub struct FlexStyle {
     pub min_height: Dimension,
     pub max_width: Dimension,
     pub max_height: Dimension,
+    /// Preferred width / height ratio, applied when one axis is auto.
+    pub aspect_ratio: Option<f32>,
 
     // Spacing
     pub margin: Edges,
@@ -64,6 +66,7 @@ impl Default for FlexStyle {
             min_height: Dimension::default(),
             max_width: Dimension::default(),
             max_height: Dimension::default(),
+            aspect_ratio: None,
             margin: Edges::default(),
             padding: Edges::default(),
             gap: Gap::default(),
@@ -111,7 +114,7 @@ impl FlexStyle {
                 width: self.max_width.to_taffy(),
                 height: self.max_height.to_taffy(),
             },
-            aspect_ratio: None,
+            aspect_ratio: self.aspect_ratio,
             margin: self.margin.to_taffy(),
             padding: self.padding.to_taffy_no_auto(),
             gap: self.gap.to_taffy(),
@@ -421,26 +424,55 @@ impl LengthPercentageAuto {
     }
 }
 
+/// Length or percentage value (no auto).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LengthPercentage {
+    Points(f32),
+    Percent(f32),
+}
+
+impl Default for LengthPercentage {
+    fn default() -> Self {
+        LengthPercentage::Points(0.0)
+    }
+}
+
+impl LengthPercentage {
+    fn to_taffy(self) -> taffy::LengthPercentage {
+        match self {
+            LengthPercentage::Points(v) => taffy::LengthPercentage::Length(v),
+            LengthPercentage::Percent(v) => taffy::LengthPercentage::Percent(v / 100.0),
+        }
+    }
+}
+
 /// Gap values for row and column gaps.
 #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub struct Gap {
-    pub row: f32,
-    pub column: f32,
+    /// Gap between rows (vertical spacing).
+    pub row: LengthPercentage,
+    /// Gap between columns (horizontal spacing).
+    pub column: LengthPercentage,
 }
 
 impl Gap {
     /// Create gap with same value for rows and columns.
     pub fn all(value: f32) -> Self {
+        Self::new(value, value)
+    }
+
+    /// Create gap with separate row and column values.
+    pub fn new(row: f32, column: f32) -> Self {
         Self {
-            row: value,
-            column: value,
+            row: LengthPercentage::Points(row),
+            column: LengthPercentage::Points(column),
         }
     }
 
     fn to_taffy(self) -> taffy::Size<taffy::LengthPercentage> {
         taffy::Size {
-            width: taffy::LengthPercentage::Length(self.column),
-            height: taffy::LengthPercentage::Length(self.row),
+            width: self.column.to_taffy(),
+            height: self.row.to_taffy(),
         }
     }
 }
@@ -467,7 +499,10 @@ impl Inset {
 
 #[cfg(test)]
 mod tests {
-    use super::{Dimension, Display, Edges, FlexDirection, FlexStyle, LengthPercentageAuto};
+    use super::{
+        Dimension, Display, Edges, FlexDirection, FlexStyle, Gap, LengthPercentage,
+        LengthPercentageAuto,
+    };
 
     #[test]
     fn test_flex_style_default() {
@@ -492,4 +527,21 @@ mod tests {
         assert_eq!(edges.top, LengthPercentageAuto::Points(10.0));
         assert_eq!(edges.right, LengthPercentageAuto::Points(10.0));
     }
+
+    #[test]
+    fn test_flex_style_gap_and_aspect_ratio_to_taffy() {
+        let mut style = FlexStyle::new();
+        style.gap = Gap {
+            row: LengthPercentage::Points(1.0),
+            column: LengthPercentage::Percent(50.0),
+        };
+        style.min_width = Dimension::Percent(25.0);
+        style.aspect_ratio = Some(2.0);
+
+        let taffy_style = style.to_taffy();
+        assert_eq!(taffy_style.gap.height, taffy::LengthPercentage::Length(1.0));
+        assert_eq!(taffy_style.gap.width, taffy::LengthPercentage::Percent(0.5));
+        assert_eq!(taffy_style.min_size.width, taffy::Dimension::Percent(0.25));
+        assert_eq!(taffy_style.aspect_ratio, Some(2.0));
+    }
 }
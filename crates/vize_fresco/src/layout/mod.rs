@@ -12,6 +12,7 @@ mod rect;
 pub use engine::LayoutEngine;
 pub use flex::{
     AlignContent, AlignItems, AlignSelf, Dimension, Display, Edges, FlexDirection, FlexStyle,
-    FlexWrap, Gap, Inset, JustifyContent, LengthPercentageAuto, Overflow, Position,
+    FlexWrap, Gap, Inset, JustifyContent, LengthPercentage, LengthPercentageAuto, Overflow,
+    Position,
 };
 pub use rect::Rect;
@@ -0,0 +1,129 @@
+//! Focus NAPI bindings.
+//!
+//! Focus lives on the tree built by the last `renderTree` / `FrameRenderer.render`
+//! call and carries over to the next frame, so the Vue side only has to
+//! drive it: mark inputs with `autofocus`, call `focusNode` on mount, or let
+//! Tab / Shift+Tab key events move it. A `focused: true` prop moves focus
+//! only in the frame where it is first set.
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use std::sync::Mutex;
+
+use super::types::RenderNodeNapi;
+use crate::input::KeyEvent;
+use crate::render::RenderTree;
+
+/// Focus carried over from the last rendered frame.
+struct FocusState {
+    /// Tree from the last frame, holding the current focus
+    tree: RenderTree,
+    /// Inputs flagged `focused` by the Vue side in the last frame
+    requested: Vec<u64>,
+}
+
+static FOCUS_STATE: Mutex<Option<FocusState>> = Mutex::new(None);
+
+/// Run a closure against the last focus state, if any.
+fn with_focus_state<R>(f: impl FnOnce(&mut FocusState) -> R) -> Result<Option<R>> {
+    let mut guard = FOCUS_STATE
+        .lock()
+        .map_err(|e| Error::new(Status::GenericFailure, format!("Lock error: {}", e)))?;
+    Ok(guard.as_mut().map(f))
+}
+
+/// Run a closure against the last rendered tree, if any.
+fn with_focus_tree<R>(f: impl FnOnce(&mut RenderTree) -> R) -> Result<Option<R>> {
+    with_focus_state(|state| f(&mut state.tree))
+}
+
+/// IDs of the inputs flagged `focused` by the Vue side.
+fn requested_focus(nodes: &[RenderNodeNapi]) -> impl Iterator<Item = u64> + '_ {
+    nodes
+        .iter()
+        .filter(|node| node.focused.unwrap_or(false))
+        .map(|node| node.id as u64)
+}
+
+/// Move focus to an input node. Returns whether the node took focus.
+#[napi(js_name = "focusNode")]
+pub fn focus_node(id: i64) -> Result<bool> {
+    Ok(with_focus_tree(|tree| tree.focus(id as u64))?.unwrap_or(false))
+}
+
+/// Clear focus.
+#[napi(js_name = "blurFocus")]
+pub fn blur_focus() -> Result<()> {
+    with_focus_tree(RenderTree::blur)?;
+    Ok(())
+}
+
+/// Move focus to the next input in tab order and return its ID.
+#[napi(js_name = "focusNext")]
+pub fn focus_next() -> Result<Option<i64>> {
+    Ok(with_focus_tree(|tree| tree.focus_next())?
+        .flatten()
+        .map(|id| id as i64))
+}
+
+/// Move focus to the previous input in tab order and return its ID.
+#[napi(js_name = "focusPrev")]
+pub fn focus_prev() -> Result<Option<i64>> {
+    Ok(with_focus_tree(|tree| tree.focus_prev())?
+        .flatten()
+        .map(|id| id as i64))
+}
+
+/// Get the ID of the focused input node.
+#[napi(js_name = "getFocusedId")]
+pub fn get_focused_id() -> Result<Option<i64>> {
+    Ok(with_focus_tree(|tree| tree.focused_id())?
+        .flatten()
+        .map(|id| id as i64))
+}
+
+/// Route a key event through the focus tree, returning the target node ID.
+pub(super) fn route_key_event(event: &KeyEvent) -> Result<Option<i64>> {
+    Ok(with_focus_tree(|tree| tree.dispatch_key(event))?
+        .flatten()
+        .map(|id| id as i64))
+}
+
+/// Focus a freshly built tree.
+///
+/// An input newly flagged `focused` by the Vue side wins, then the input
+/// focused in the previous frame, then the first `autofocus` input. A flag
+/// left set from an earlier frame does not pull focus back after Tab or
+/// `focusNode` moved it elsewhere.
+pub(super) fn restore_focus(tree: &mut RenderTree, nodes: &[RenderNodeNapi]) -> Result<()> {
+    let (previous, newly_requested) = with_focus_state(|state| {
+        let newly_requested = requested_focus(nodes).find(|id| !state.requested.contains(id));
+        (state.tree.focused_id(), newly_requested)
+    })?
+    .unwrap_or_else(|| (None, requested_focus(nodes).next()));
+
+    if newly_requested.is_some_and(|id| tree.focus(id)) {
+        return Ok(());
+    }
+    if previous.is_some_and(|id| tree.focus(id)) {
+        return Ok(());
+    }
+
+    if let Some(node) = nodes.iter().find(|node| node.autofocus.unwrap_or(false)) {
+        tree.focus(node.id as u64);
+    }
+    Ok(())
+}
+
+/// Keep a rendered tree as the target of focus calls.
+pub(super) fn store_focus_tree(tree: RenderTree, nodes: &[RenderNodeNapi]) -> Result<()> {
+    let mut guard = FOCUS_STATE
+        .lock()
+        .map_err(|e| Error::new(Status::GenericFailure, format!("Lock error: {}", e)))?;
+    *guard = Some(FocusState {
+        tree,
+        requested: requested_focus(nodes).collect(),
+    });
+    Ok(())
+}
@@ -3,6 +3,7 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
+use super::focus::route_key_event;
 use super::types::{ImeStateNapi, InputEventNapi};
 use crate::input;
 
@@ -15,7 +16,7 @@ pub fn poll_event(timeout_ms: u32) -> Result<Option<InputEventNapi>> {
     let event = input::poll(timeout_ms as u64)
         .map_err(|e| Error::new(Status::GenericFailure, format!("Poll error: {}", e)))?;
 
-    Ok(event.map(InputEventNapi::from))
+    event.map(convert_event).transpose()
 }
 
 /// Poll for input events without blocking.
@@ -25,7 +26,7 @@ pub fn poll_event_non_blocking() -> Result<Option<InputEventNapi>> {
     let event = input::poll_nonblocking()
         .map_err(|e| Error::new(Status::GenericFailure, format!("Poll error: {}", e)))?;
 
-    Ok(event.map(InputEventNapi::from))
+    event.map(convert_event).transpose()
 }
 
 /// Read an input event, blocking until one is available.
@@ -35,7 +36,18 @@ pub fn read_event() -> Result<InputEventNapi> {
     let event = input::read_event()
         .map_err(|e| Error::new(Status::GenericFailure, format!("Read error: {}", e)))?;
 
-    Ok(InputEventNapi::from(event))
+    convert_event(event)
+}
+
+/// Convert an event for JS, routing key events to the focused input.
+fn convert_event(event: input::Event) -> Result<InputEventNapi> {
+    let target = match event.as_key() {
+        Some(key) => route_key_event(key)?,
+        None => None,
+    };
+    let mut event = InputEventNapi::from(event);
+    event.target = target;
+    Ok(event)
 }
 
 /// Get current IME state.
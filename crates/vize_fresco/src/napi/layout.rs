@@ -289,6 +289,18 @@ fn convert_flex_style(style: FlexStyleNapi) -> FlexStyle {
         result.gap = Gap::all(g as f32);
     }
 
+    if let Some(g) = style.row_gap {
+        result.gap.row = LengthPercentage::Points(g as f32);
+    }
+
+    if let Some(g) = style.column_gap {
+        result.gap.column = LengthPercentage::Points(g as f32);
+    }
+
+    if let Some(ratio) = style.aspect_ratio {
+        result.aspect_ratio = Some(ratio as f32);
+    }
+
     result
 }
 
@@ -2,6 +2,12 @@
 //!
 //! Provides JavaScript/Node.js bindings for the Fresco terminal UI framework.
 
+#[allow(
+    clippy::disallowed_types,
+    clippy::disallowed_methods,
+    clippy::disallowed_macros
+)]
+mod focus;
 #[allow(
     clippy::disallowed_types,
     clippy::disallowed_methods,
@@ -33,6 +39,7 @@ mod terminal;
 )]
 mod types;
 
+pub use focus::{blur_focus, focus_next, focus_node, focus_prev, get_focused_id};
 pub use input::{
     disable_ime, enable_ime, get_ime_state, poll_event, poll_event_non_blocking, read_event,
     set_ime_mode,
@@ -44,7 +51,7 @@ pub use layout::{
 };
 pub use render::{
     clear_rect, fill_rect, hide_cursor, render_box, render_text, render_tree, set_cursor,
-    set_cursor_shape, show_cursor,
+    set_cursor_shape, show_cursor, FrameRenderer,
 };
 pub use terminal::{
     clear_screen, flush_terminal, get_terminal_info, init_terminal, init_terminal_with_mouse,
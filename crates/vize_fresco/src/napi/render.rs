@@ -3,10 +3,12 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
+use super::focus::{restore_focus, store_focus_tree};
 use super::terminal::with_backend;
 use super::types::{RenderNodeNapi, StyleNapi};
 use crate::layout::Rect;
-use crate::terminal::{Color, Style};
+use crate::render::RenderTree;
+use crate::terminal::{Buffer as TerminalBuffer, Color, FrameWriter, Style};
 
 /// Render text at position.
 #[napi(js_name = "renderText")]
@@ -124,328 +126,395 @@ pub fn set_cursor_shape(shape: String) -> Result<()> {
 /// Render a tree of nodes.
 #[napi(js_name = "renderTree")]
 pub fn render_tree(nodes: Vec<RenderNodeNapi>) -> Result<()> {
+    use crate::render::Painter;
+
+    let mut tree = build_tree(&nodes);
+    restore_focus(&mut tree, &nodes)?;
+
+    with_backend(|backend| {
+        // Compute layout
+        let (width, height) = (backend.width(), backend.height());
+        tree.compute_layout(width, height);
+
+        // Paint to buffer
+        let mut painter = Painter::new(backend.buffer_mut());
+        painter.paint_tree(&tree);
+
+        // Position cursor for IME at the focused input
+        if let Some((cursor_x, cursor_y)) = tree.focused_cursor_position() {
+            backend.cursor_mut().move_to(cursor_x, cursor_y);
+            backend
+                .cursor_mut()
+                .set_shape(crate::terminal::CursorShape::Bar);
+            backend.cursor_mut().set_blinking(true);
+            backend.cursor_mut().show();
+        } else {
+            backend.cursor_mut().hide();
+        }
+    })?;
+
+    store_focus_tree(tree, &nodes)
+}
+
+/// Renders full frames to terminal output.
+///
+/// Each renderer keeps the last frame it produced and diffs the next one
+/// against it, so separate renderers (e.g. one per app) do not interfere.
+#[napi]
+pub struct FrameRenderer {
+    frames: FrameWriter,
+}
+
+#[napi]
+impl FrameRenderer {
+    /// Create a renderer with no previous frame.
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            frames: FrameWriter::new(),
+        }
+    }
+
+    /// Render a full frame and return the terminal output for it.
+    ///
+    /// Runs layout and paint for the given nodes (same shape as `renderTree`),
+    /// diffs the result against this renderer's previous frame and returns the
+    /// escape sequences (cursor moves and styled spans) needed to update the
+    /// screen. The first frame, and any frame after a size change, clears the
+    /// screen.
+    #[napi]
+    pub fn render(
+        &mut self,
+        nodes: Vec<RenderNodeNapi>,
+        width: i32,
+        height: i32,
+    ) -> Result<Buffer> {
+        use crate::render::Painter;
+
+        let (width, height) = (width.max(0) as u16, height.max(0) as u16);
+
+        let mut tree = build_tree(&nodes);
+        restore_focus(&mut tree, &nodes)?;
+        tree.compute_layout(width, height);
+
+        let mut frame = TerminalBuffer::new(width, height);
+        Painter::new(&mut frame).paint_tree(&tree);
+
+        let mut out = Vec::new();
+        self.frames
+            .write(&mut out, frame, tree.focused_cursor_position())
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to write frame: {}", e),
+                )
+            })?;
+
+        store_focus_tree(tree, &nodes)?;
+        Ok(out.into())
+    }
+
+    /// Forget the previous frame so the next render redraws the whole screen.
+    #[napi]
+    pub fn reset(&mut self) {
+        self.frames.reset();
+    }
+}
+
+/// Build a render tree from NAPI nodes. The first node is the root.
+fn build_tree(nodes: &[RenderNodeNapi]) -> RenderTree {
     use crate::layout::{
-        AlignItems, AlignSelf, Dimension, Display, FlexDirection, FlexWrap, JustifyContent,
-        LengthPercentageAuto,
+        AlignItems, AlignSelf, Dimension, Display, FlexDirection, FlexWrap, Gap, JustifyContent,
+        LengthPercentage, LengthPercentageAuto,
     };
     use crate::render::{
-        Appearance, BorderStyle, InputContent, NodeKind, Painter, RenderNode, RenderTree,
-        TextContent,
+        Appearance, BorderStyle, InputContent, NodeKind, RenderNode, TextContent, TextOverflow,
     };
 
-    with_backend(|backend| {
-        let mut tree = RenderTree::new();
-
-        // Build tree from NAPI nodes
-        for node in &nodes {
-            let text_content = node.text.clone().unwrap_or_default();
-            let kind = match node.node_type.as_str() {
-                "text" => NodeKind::Text(TextContent {
-                    text: text_content.clone().into(),
-                    wrap: node.wrap.unwrap_or(false),
-                }),
-                "input" => NodeKind::Input(InputContent {
-                    value: node.value.clone().unwrap_or_default().into(),
-                    placeholder: node.placeholder.clone().unwrap_or_default().into(),
-                    cursor: node.cursor.unwrap_or(0) as usize,
-                    focused: node.focused.unwrap_or(false),
-                    mask: node.mask.unwrap_or(false),
-                    mask_char: '*',
-                }),
-                _ => NodeKind::Box,
-            };
-
-            let mut render_node = RenderNode::new(node.id as u64, kind);
+    let mut tree = RenderTree::new();
+
+    // Build tree from NAPI nodes
+    for node in nodes {
+        let text_content = node.text.clone().unwrap_or_default();
+        let kind = match node.node_type.as_str() {
+            "text" => NodeKind::Text(TextContent {
+                text: text_content.clone().into(),
+                wrap: node.wrap.unwrap_or(false),
+                overflow: match node.overflow.as_deref() {
+                    Some("ellipsis") => TextOverflow::Ellipsis,
+                    Some("clip") => TextOverflow::Clip,
+                    _ => TextOverflow::Visible,
+                },
+            }),
+            "input" => NodeKind::Input(InputContent {
+                value: node.value.clone().unwrap_or_default().into(),
+                placeholder: node.placeholder.clone().unwrap_or_default().into(),
+                cursor: node.cursor.unwrap_or(0) as usize,
+                // Set from the tree's focus by `restore_focus`
+                focused: false,
+                mask: node.mask.unwrap_or(false),
+                mask_char: '*',
+                tab_index: node.tab_index,
+            }),
+            _ => NodeKind::Box,
+        };
 
-            // Force all nodes to align to start (workaround for taffy centering)
-            render_node.style.align_self = AlignSelf::FlexStart;
+        let mut render_node = RenderNode::new(node.id as u64, kind);
 
-            // For text nodes, set the size based on text content
-            if node.node_type == "text" && !text_content.is_empty() {
-                use crate::text::TextWidth;
-                let text_width = TextWidth::width(&text_content) as f32;
-                let text_height = text_content.lines().count().max(1) as f32;
-                render_node.style.width = Dimension::Points(text_width);
-                render_node.style.height = Dimension::Points(text_height);
-            }
+        // Force all nodes to align to start (workaround for taffy centering)
+        render_node.style.align_self = AlignSelf::FlexStart;
 
-            // For input nodes, set size with text wrapping support
-            if node.node_type == "input" {
-                use crate::text::TextWidth;
-                let value = node.value.as_deref().unwrap_or("");
-                let placeholder = node.placeholder.as_deref().unwrap_or("");
-                let content = if value.is_empty() { placeholder } else { value };
+        // For text nodes, set the size based on text content
+        if node.node_type == "text" && !text_content.is_empty() {
+            use crate::text::TextWidth;
+            let text_width = TextWidth::width(&text_content) as f32;
+            let text_height = text_content.lines().count().max(1) as f32;
+            render_node.style.width = Dimension::Points(text_width);
+            render_node.style.height = Dimension::Points(text_height);
+        }
 
-                // Fixed width for wrapping (can be overridden by style)
-                let input_width = 30_usize;
-                let content_width = TextWidth::width(content);
+        // For input nodes, set size with text wrapping support
+        if node.node_type == "input" {
+            use crate::text::TextWidth;
+            let value = node.value.as_deref().unwrap_or("");
+            let placeholder = node.placeholder.as_deref().unwrap_or("");
+            let content = if value.is_empty() { placeholder } else { value };
 
-                // Calculate height based on wrapped lines
-                let num_lines = (content_width / input_width) + 1;
-                let height = num_lines.max(1) as f32;
+            // Fixed width for wrapping (can be overridden by style)
+            let input_width = 30_usize;
+            let content_width = TextWidth::width(content);
 
-                render_node.style.width = Dimension::Points(input_width as f32);
-                render_node.style.height = Dimension::Points(height);
-            }
+            // Calculate height based on wrapped lines
+            let num_lines = (content_width / input_width) + 1;
+            let height = num_lines.max(1) as f32;
 
-            // Set flex style (start from existing style to preserve text/input sizes)
-            if let Some(ref style) = node.style {
-                let mut flex_style = render_node.style.clone();
+            render_node.style.width = Dimension::Points(input_width as f32);
+            render_node.style.height = Dimension::Points(height);
+        }
 
-                // Display
-                if let Some(ref display) = style.display {
-                    flex_style.display = match display.as_str() {
-                        "none" => Display::None,
-                        _ => Display::Flex,
-                    };
-                }
+        // Set flex style (start from existing style to preserve text/input sizes)
+        if let Some(ref style) = node.style {
+            let mut flex_style = render_node.style.clone();
 
-                // Flex direction
-                if let Some(ref dir) = style.flex_direction {
-                    flex_style.flex_direction = match dir.as_str() {
-                        "row" => FlexDirection::Row,
-                        "column" => FlexDirection::Column,
-                        "row-reverse" => FlexDirection::RowReverse,
-                        "column-reverse" => FlexDirection::ColumnReverse,
-                        _ => FlexDirection::Column,
-                    };
-                }
+            // Display
+            if let Some(ref display) = style.display {
+                flex_style.display = match display.as_str() {
+                    "none" => Display::None,
+                    _ => Display::Flex,
+                };
+            }
 
-                // Flex wrap
-                if let Some(ref wrap) = style.flex_wrap {
-                    flex_style.flex_wrap = match wrap.as_str() {
-                        "wrap" => FlexWrap::Wrap,
-                        "wrap-reverse" => FlexWrap::WrapReverse,
-                        _ => FlexWrap::NoWrap,
-                    };
-                }
+            // Flex direction
+            if let Some(ref dir) = style.flex_direction {
+                flex_style.flex_direction = match dir.as_str() {
+                    "row" => FlexDirection::Row,
+                    "column" => FlexDirection::Column,
+                    "row-reverse" => FlexDirection::RowReverse,
+                    "column-reverse" => FlexDirection::ColumnReverse,
+                    _ => FlexDirection::Column,
+                };
+            }
 
-                // Justify content
-                if let Some(ref jc) = style.justify_content {
-                    flex_style.justify_content = match jc.as_str() {
-                        "flex-start" | "start" => JustifyContent::FlexStart,
-                        "flex-end" | "end" => JustifyContent::FlexEnd,
-                        "center" => JustifyContent::Center,
-                        "space-between" => JustifyContent::SpaceBetween,
-                        "space-around" => JustifyContent::SpaceAround,
-                        "space-evenly" => JustifyContent::SpaceEvenly,
-                        _ => JustifyContent::FlexStart,
-                    };
-                }
+            // Flex wrap
+            if let Some(ref wrap) = style.flex_wrap {
+                flex_style.flex_wrap = match wrap.as_str() {
+                    "wrap" => FlexWrap::Wrap,
+                    "wrap-reverse" => FlexWrap::WrapReverse,
+                    _ => FlexWrap::NoWrap,
+                };
+            }
 
-                // Align items
-                if let Some(ref ai) = style.align_items {
-                    flex_style.align_items = match ai.as_str() {
-                        "flex-start" | "start" => AlignItems::FlexStart,
-                        "flex-end" | "end" => AlignItems::FlexEnd,
-                        "center" => AlignItems::Center,
-                        "stretch" => AlignItems::Stretch,
-                        "baseline" => AlignItems::Baseline,
-                        _ => AlignItems::FlexStart,
-                    };
-                }
+            // Justify content
+            if let Some(ref jc) = style.justify_content {
+                flex_style.justify_content = match jc.as_str() {
+                    "flex-start" | "start" => JustifyContent::FlexStart,
+                    "flex-end" | "end" => JustifyContent::FlexEnd,
+                    "center" => JustifyContent::Center,
+                    "space-between" => JustifyContent::SpaceBetween,
+                    "space-around" => JustifyContent::SpaceAround,
+                    "space-evenly" => JustifyContent::SpaceEvenly,
+                    _ => JustifyContent::FlexStart,
+                };
+            }
 
-                // Align self
-                if let Some(ref a_self) = style.align_self {
-                    flex_style.align_self = match a_self.as_str() {
-                        "auto" => AlignSelf::Auto,
-                        "flex-start" | "start" => AlignSelf::FlexStart,
-                        "flex-end" | "end" => AlignSelf::FlexEnd,
-                        "center" => AlignSelf::Center,
-                        "stretch" => AlignSelf::Stretch,
-                        "baseline" => AlignSelf::Baseline,
-                        _ => AlignSelf::Auto,
-                    };
-                }
+            // Align items
+            if let Some(ref ai) = style.align_items {
+                flex_style.align_items = match ai.as_str() {
+                    "flex-start" | "start" => AlignItems::FlexStart,
+                    "flex-end" | "end" => AlignItems::FlexEnd,
+                    "center" => AlignItems::Center,
+                    "stretch" => AlignItems::Stretch,
+                    "baseline" => AlignItems::Baseline,
+                    _ => AlignItems::FlexStart,
+                };
+            }
 
-                // Flex grow/shrink
-                if let Some(grow) = style.flex_grow {
-                    flex_style.flex_grow = grow as f32;
-                }
-                if let Some(shrink) = style.flex_shrink {
-                    flex_style.flex_shrink = shrink as f32;
-                }
+            // Align self
+            if let Some(ref a_self) = style.align_self {
+                flex_style.align_self = match a_self.as_str() {
+                    "auto" => AlignSelf::Auto,
+                    "flex-start" | "start" => AlignSelf::FlexStart,
+                    "flex-end" | "end" => AlignSelf::FlexEnd,
+                    "center" => AlignSelf::Center,
+                    "stretch" => AlignSelf::Stretch,
+                    "baseline" => AlignSelf::Baseline,
+                    _ => AlignSelf::Auto,
+                };
+            }
 
-                // Dimensions
-                if let Some(ref w) = style.width {
-                    flex_style.width = parse_dimension(w);
-                }
-                if let Some(ref h) = style.height {
-                    flex_style.height = parse_dimension(h);
-                }
-                if let Some(ref w) = style.min_width {
-                    flex_style.min_width = parse_dimension(w);
-                }
-                if let Some(ref h) = style.min_height {
-                    flex_style.min_height = parse_dimension(h);
-                }
-                if let Some(ref w) = style.max_width {
-                    flex_style.max_width = parse_dimension(w);
-                }
-                if let Some(ref h) = style.max_height {
-                    flex_style.max_height = parse_dimension(h);
-                }
+            // Flex grow/shrink
+            if let Some(grow) = style.flex_grow {
+                flex_style.flex_grow = grow as f32;
+            }
+            if let Some(shrink) = style.flex_shrink {
+                flex_style.flex_shrink = shrink as f32;
+            }
 
-                // Padding
-                if let Some(p) = style.padding {
-                    let val = LengthPercentageAuto::Points(p as f32);
-                    flex_style.padding.top = val;
-                    flex_style.padding.right = val;
-                    flex_style.padding.bottom = val;
-                    flex_style.padding.left = val;
-                }
-                if let Some(p) = style.padding_top {
-                    flex_style.padding.top = LengthPercentageAuto::Points(p as f32);
-                }
-                if let Some(p) = style.padding_right {
-                    flex_style.padding.right = LengthPercentageAuto::Points(p as f32);
-                }
-                if let Some(p) = style.padding_bottom {
-                    flex_style.padding.bottom = LengthPercentageAuto::Points(p as f32);
-                }
-                if let Some(p) = style.padding_left {
-                    flex_style.padding.left = LengthPercentageAuto::Points(p as f32);
-                }
+            // Dimensions
+            if let Some(ref w) = style.width {
+                flex_style.width = parse_dimension(w);
+            }
+            if let Some(ref h) = style.height {
+                flex_style.height = parse_dimension(h);
+            }
+            if let Some(ref w) = style.min_width {
+                flex_style.min_width = parse_dimension(w);
+            }
+            if let Some(ref h) = style.min_height {
+                flex_style.min_height = parse_dimension(h);
+            }
+            if let Some(ref w) = style.max_width {
+                flex_style.max_width = parse_dimension(w);
+            }
+            if let Some(ref h) = style.max_height {
+                flex_style.max_height = parse_dimension(h);
+            }
 
-                // Margin
-                if let Some(m) = style.margin {
-                    let val = LengthPercentageAuto::Points(m as f32);
-                    flex_style.margin.top = val;
-                    flex_style.margin.right = val;
-                    flex_style.margin.bottom = val;
-                    flex_style.margin.left = val;
-                }
-                if let Some(m) = style.margin_top {
-                    flex_style.margin.top = LengthPercentageAuto::Points(m as f32);
-                }
-                if let Some(m) = style.margin_right {
-                    flex_style.margin.right = LengthPercentageAuto::Points(m as f32);
-                }
-                if let Some(m) = style.margin_bottom {
-                    flex_style.margin.bottom = LengthPercentageAuto::Points(m as f32);
-                }
-                if let Some(m) = style.margin_left {
-                    flex_style.margin.left = LengthPercentageAuto::Points(m as f32);
-                }
+            // Padding
+            if let Some(p) = style.padding {
+                let val = LengthPercentageAuto::Points(p as f32);
+                flex_style.padding.top = val;
+                flex_style.padding.right = val;
+                flex_style.padding.bottom = val;
+                flex_style.padding.left = val;
+            }
+            if let Some(p) = style.padding_top {
+                flex_style.padding.top = LengthPercentageAuto::Points(p as f32);
+            }
+            if let Some(p) = style.padding_right {
+                flex_style.padding.right = LengthPercentageAuto::Points(p as f32);
+            }
+            if let Some(p) = style.padding_bottom {
+                flex_style.padding.bottom = LengthPercentageAuto::Points(p as f32);
+            }
+            if let Some(p) = style.padding_left {
+                flex_style.padding.left = LengthPercentageAuto::Points(p as f32);
+            }
 
-                // Gap
-                if let Some(g) = style.gap {
-                    flex_style.gap.row = g as f32;
-                    flex_style.gap.column = g as f32;
-                }
+            // Margin
+            if let Some(m) = style.margin {
+                let val = LengthPercentageAuto::Points(m as f32);
+                flex_style.margin.top = val;
+                flex_style.margin.right = val;
+                flex_style.margin.bottom = val;
+                flex_style.margin.left = val;
+            }
+            if let Some(m) = style.margin_top {
+                flex_style.margin.top = LengthPercentageAuto::Points(m as f32);
+            }
+            if let Some(m) = style.margin_right {
+                flex_style.margin.right = LengthPercentageAuto::Points(m as f32);
+            }
+            if let Some(m) = style.margin_bottom {
+                flex_style.margin.bottom = LengthPercentageAuto::Points(m as f32);
+            }
+            if let Some(m) = style.margin_left {
+                flex_style.margin.left = LengthPercentageAuto::Points(m as f32);
+            }
 
-                render_node.style = flex_style;
+            // Gap
+            if let Some(g) = style.gap {
+                flex_style.gap = Gap::all(g as f32);
+            }
+            if let Some(g) = style.row_gap {
+                flex_style.gap.row = LengthPercentage::Points(g as f32);
+            }
+            if let Some(g) = style.column_gap {
+                flex_style.gap.column = LengthPercentage::Points(g as f32);
             }
 
-            // Set appearance
-            if let Some(ref app) = node.appearance {
-                let mut appearance = Appearance::default();
-                if let Some(ref fg) = app.fg {
-                    appearance.fg = parse_color(fg);
-                }
-                if let Some(ref bg) = app.bg {
-                    appearance.bg = parse_color(bg);
-                }
-                appearance.bold = app.bold.unwrap_or(false);
-                appearance.dim = app.dim.unwrap_or(false);
-                appearance.italic = app.italic.unwrap_or(false);
-                appearance.underline = app.underline.unwrap_or(false);
-                appearance.strikethrough = app.strikethrough.unwrap_or(false);
-                render_node.appearance = appearance;
-            }
-
-            // Set border
-            if let Some(ref border) = node.border {
-                render_node.appearance.border = Some(match border.as_str() {
-                    "single" => BorderStyle::Single,
-                    "double" => BorderStyle::Double,
-                    "rounded" => BorderStyle::Rounded,
-                    "heavy" => BorderStyle::Heavy,
-                    "dashed" => BorderStyle::Dashed,
-                    _ => BorderStyle::None,
-                });
-            }
-
-            tree.insert(render_node);
-        }
+            // Aspect ratio
+            if let Some(ratio) = style.aspect_ratio {
+                flex_style.aspect_ratio = Some(ratio as f32);
+            }
 
-        // Set root (first node)
-        if let Some(first) = nodes.first() {
-            tree.set_root(first.id as u64);
+            render_node.style = flex_style;
         }
 
-        // Add children
-        for node in &nodes {
-            if let Some(ref children) = node.children {
-                for &child_id in children {
-                    tree.add_child(node.id as u64, child_id as u64);
-                }
+        // Set appearance
+        if let Some(ref app) = node.appearance {
+            let mut appearance = Appearance::default();
+            if let Some(ref fg) = app.fg {
+                appearance.fg = parse_color(fg);
             }
+            if let Some(ref bg) = app.bg {
+                appearance.bg = parse_color(bg);
+            }
+            appearance.bold = app.bold.unwrap_or(false);
+            appearance.dim = app.dim.unwrap_or(false);
+            appearance.italic = app.italic.unwrap_or(false);
+            appearance.underline = app.underline.unwrap_or(false);
+            appearance.strikethrough = app.strikethrough.unwrap_or(false);
+            render_node.appearance = appearance;
         }
 
-        // Force root's direct child to have width: 100% to prevent centering
-        if let Some(first) = nodes.first() {
-            if let Some(ref children) = first.children {
-                for &child_id in children {
-                    let child_id_u64 = child_id as u64;
-                    if let Some(node) = tree.get(child_id_u64) {
-                        if matches!(node.style.width, Dimension::Auto) {
-                            let mut style = node.style.clone();
-                            style.width = Dimension::Percent(100.0);
-                            tree.set_style(child_id_u64, style);
-                        }
-                    }
-                }
-            }
+        // Set border
+        if let Some(ref border) = node.border {
+            render_node.appearance.border = Some(match border.as_str() {
+                "single" => BorderStyle::Single,
+                "double" => BorderStyle::Double,
+                "rounded" => BorderStyle::Rounded,
+                "heavy" => BorderStyle::Heavy,
+                "dashed" => BorderStyle::Dashed,
+                _ => BorderStyle::None,
+            });
         }
 
-        // Compute layout
-        let (width, height) = (backend.width(), backend.height());
-        tree.compute_layout(width, height);
+        tree.insert(render_node);
+    }
 
-        // Paint to buffer
-        let mut painter = Painter::new(backend.buffer_mut());
-        painter.paint_tree(&tree);
+    // Set root (first node)
+    if let Some(first) = nodes.first() {
+        tree.set_root(first.id as u64);
+    }
+
+    // Add children
+    for node in nodes {
+        if let Some(ref children) = node.children {
+            for &child_id in children {
+                tree.add_child(node.id as u64, child_id as u64);
+            }
+        }
+    }
 
-        // Find focused input and position cursor for IME
-        let mut found_focused = false;
-        for node in &nodes {
-            if node.node_type == "input" && node.focused.unwrap_or(false) {
-                if let Some(render_node) = tree.get(node.id as u64) {
-                    if let Some(layout) = render_node.layout {
-                        // Calculate cursor position considering character widths and wrapping
-                        let value = node.value.as_deref().unwrap_or("");
-                        let cursor_idx = node.cursor.unwrap_or(0) as usize;
-
-                        // Get display width up to cursor position
-                        use crate::text::SegmentedText;
-                        let st = SegmentedText::new(value);
-                        let cursor_col = st.column_at_index(cursor_idx.min(st.grapheme_count));
-                        let area_width = layout.width as usize;
-
-                        // Calculate cursor line and column with text wrapping
-                        let cursor_line = cursor_col / area_width;
-                        let cursor_col_in_line = cursor_col % area_width;
-
-                        let cursor_x = layout.x + cursor_col_in_line as u16;
-                        let cursor_y =
-                            layout.y + (cursor_line as u16).min(layout.height.saturating_sub(1));
-                        backend.cursor_mut().move_to(cursor_x, cursor_y);
-                        backend
-                            .cursor_mut()
-                            .set_shape(crate::terminal::CursorShape::Bar);
-                        backend.cursor_mut().set_blinking(true);
-                        backend.cursor_mut().show();
-                        found_focused = true;
-                        break;
+    // Force root's direct child to have width: 100% to prevent centering
+    if let Some(first) = nodes.first() {
+        if let Some(ref children) = first.children {
+            for &child_id in children {
+                let child_id_u64 = child_id as u64;
+                if let Some(node) = tree.get(child_id_u64) {
+                    if matches!(node.style.width, Dimension::Auto) {
+                        let mut style = node.style.clone();
+                        style.width = Dimension::Percent(100.0);
+                        tree.set_style(child_id_u64, style);
                     }
                 }
             }
         }
-        if !found_focused {
-            backend.cursor_mut().hide();
-        }
-    })
+    }
+
+    tree
 }
 
 /// Parse dimension string to Dimension.
@@ -74,6 +74,12 @@ pub struct FlexStyleNapi {
     #[napi(js_name = "marginLeft")]
     pub margin_left: Option<f64>,
     pub gap: Option<f64>,
+    #[napi(js_name = "rowGap")]
+    pub row_gap: Option<f64>,
+    #[napi(js_name = "columnGap")]
+    pub column_gap: Option<f64>,
+    #[napi(js_name = "aspectRatio")]
+    pub aspect_ratio: Option<f64>,
 }
 
 /// Render node for NAPI.
@@ -89,6 +95,8 @@ pub struct RenderNodeNapi {
     pub text: Option<String>,
     /// Whether text should wrap
     pub wrap: Option<bool>,
+    /// Text overflow: "visible" | "ellipsis" | "clip"
+    pub overflow: Option<String>,
     /// Input value (for input nodes)
     pub value: Option<String>,
     /// Placeholder text (for input nodes)
@@ -99,6 +107,11 @@ pub struct RenderNodeNapi {
     pub cursor: Option<i64>,
     /// Whether to mask input (password)
     pub mask: Option<bool>,
+    /// Focus this input when nothing is focused yet
+    pub autofocus: Option<bool>,
+    /// Explicit tab order; negative values are skipped by Tab
+    #[napi(js_name = "tabIndex")]
+    pub tab_index: Option<i32>,
     /// Flex style
     pub style: Option<FlexStyleNapi>,
     /// Visual appearance
@@ -149,6 +162,8 @@ pub struct InputEventNapi {
     pub height: Option<i32>,
     /// Pasted text (for paste events)
     pub text: Option<String>,
+    /// Focused node the event was routed to (for key events)
+    pub target: Option<i64>,
 }
 
 /// Key modifiers for NAPI.
@@ -219,6 +234,7 @@ impl From<crate::input::Event> for InputEventNapi {
                             width: None,
                             height: None,
                             text: None,
+                            target: None,
                         };
                     }
                     crate::input::Key::Enter => "enter",
@@ -253,6 +269,7 @@ impl From<crate::input::Event> for InputEventNapi {
                             width: None,
                             height: None,
                             text: None,
+                            target: None,
                         };
                     }
                     _ => "unknown",
@@ -274,6 +291,7 @@ impl From<crate::input::Event> for InputEventNapi {
                     width: None,
                     height: None,
                     text: None,
+                    target: None,
                 }
             }
             Event::Mouse(mouse) => {
@@ -299,6 +317,7 @@ impl From<crate::input::Event> for InputEventNapi {
                     width: None,
                     height: None,
                     text: None,
+                    target: None,
                 }
             }
             Event::Resize(w, h) => InputEventNapi {
@@ -312,6 +331,7 @@ impl From<crate::input::Event> for InputEventNapi {
                 width: Some(w as i32),
                 height: Some(h as i32),
                 text: None,
+                target: None,
             },
             Event::FocusGained => InputEventNapi {
                 event_type: "focus".to_string(),
@@ -324,6 +344,7 @@ impl From<crate::input::Event> for InputEventNapi {
                 width: None,
                 height: None,
                 text: None,
+                target: None,
             },
             Event::FocusLost => InputEventNapi {
                 event_type: "focus".to_string(),
@@ -336,6 +357,7 @@ impl From<crate::input::Event> for InputEventNapi {
                 width: None,
                 height: None,
                 text: None,
+                target: None,
             },
             Event::Paste(text) => InputEventNapi {
                 event_type: "paste".to_string(),
@@ -348,6 +370,7 @@ impl From<crate::input::Event> for InputEventNapi {
                 width: None,
                 height: None,
                 text: Some(text.into()),
+                target: None,
             },
         }
     }
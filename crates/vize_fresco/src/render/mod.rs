@@ -13,6 +13,7 @@ mod tree;
 
 pub use node::{
     Appearance, BorderStyle, InputContent, NodeId, NodeKind, RawContent, RenderNode, TextContent,
+    TextOverflow,
 };
 pub use painter::Painter;
 pub use tree::RenderTree;
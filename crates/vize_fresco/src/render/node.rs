@@ -6,6 +6,7 @@ use smallvec::SmallVec;
 
 use crate::layout::{FlexStyle, Rect};
 use crate::terminal::{Color, Style};
+use crate::text::WrapMode;
 
 /// Unique identifier for render nodes.
 pub type NodeId = u64;
@@ -108,6 +109,8 @@ pub struct TextContent {
     pub text: CompactString,
     /// Whether text should wrap
     pub wrap: bool,
+    /// How non-wrapping text is cut when it exceeds its area
+    pub overflow: TextOverflow,
 }
 
 impl TextContent {
@@ -116,6 +119,7 @@ impl TextContent {
         Self {
             text: text.into(),
             wrap: false,
+            overflow: TextOverflow::Visible,
         }
     }
 
@@ -124,6 +128,36 @@ impl TextContent {
         self.wrap = true;
         self
     }
+
+    /// Set the overflow mode.
+    pub fn with_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Get the wrap mode used to lay out this text.
+    pub fn wrap_mode(&self) -> WrapMode {
+        if self.wrap {
+            return WrapMode::Word;
+        }
+        match self.overflow {
+            TextOverflow::Visible => WrapMode::NoWrap,
+            TextOverflow::Ellipsis => WrapMode::Truncate,
+            TextOverflow::Clip => WrapMode::Clip,
+        }
+    }
+}
+
+/// Overflow behavior for text that doesn't wrap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    /// Text overflows its area
+    #[default]
+    Visible,
+    /// Cut at the area width and append `…`
+    Ellipsis,
+    /// Hard cut at the area width
+    Clip,
 }
 
 /// Input content for input nodes.
@@ -141,6 +175,8 @@ pub struct InputContent {
     pub mask: bool,
     /// Mask character
     pub mask_char: char,
+    /// Explicit tab order (`tabindex`); negative values are skipped by Tab
+    pub tab_index: Option<i32>,
 }
 
 impl InputContent {
@@ -170,6 +206,12 @@ impl InputContent {
         self.mask_char = mask_char;
         self
     }
+
+    /// Set the tab order.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
 }
 
 /// Raw content for custom rendering.
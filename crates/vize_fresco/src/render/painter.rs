@@ -63,7 +63,7 @@ impl<'a> Painter<'a> {
                 // Box nodes just provide layout, content is drawn by children
             }
             NodeKind::Text(text) => {
-                self.paint_text(&text.text, content_area, style, text.wrap);
+                self.paint_text(&text.text, content_area, style, text.wrap_mode());
             }
             NodeKind::Input(input) => {
                 self.paint_input(
@@ -117,16 +117,11 @@ impl<'a> Painter<'a> {
     }
 
     /// Paint text content.
-    fn paint_text(&mut self, text: &str, area: Rect, style: Style, wrap: bool) {
+    fn paint_text(&mut self, text: &str, area: Rect, style: Style, mode: WrapMode) {
         if area.is_empty() {
             return;
         }
 
-        let mode = if wrap {
-            WrapMode::Word
-        } else {
-            WrapMode::NoWrap
-        };
         let lines = TextWrap::wrap(text, area.width as usize, mode);
 
         for (i, line) in lines.iter().enumerate() {
@@ -205,7 +200,9 @@ impl<'a> Painter<'a> {
 mod tests {
     use super::{BorderStyle, Painter, RenderNode, RenderTree};
     use crate::layout::Rect;
+    use crate::render::{NodeKind, TextContent, TextOverflow};
     use crate::terminal::{Buffer, Style};
+    use crate::text::WrapMode;
 
     #[test]
     fn test_paint_text() {
@@ -214,7 +211,7 @@ mod tests {
 
         let area = Rect::new(0, 0, 20, 5);
         let style = Style::new();
-        painter.paint_text("Hello World", area, style, false);
+        painter.paint_text("Hello World", area, style, WrapMode::NoWrap);
 
         assert_eq!(buffer.get(0, 0).map(|c| c.symbol.as_str()), Some("H"));
         assert_eq!(buffer.get(5, 0).map(|c| c.symbol.as_str()), Some(" "));
@@ -248,4 +245,22 @@ mod tests {
 
         assert_eq!(buffer.get(0, 0).map(|c| c.symbol.as_str()), Some("H"));
     }
+
+    #[test]
+    fn test_paint_text_truncate() {
+        let mut tree = RenderTree::new();
+        let id = tree.next_id();
+        let content = TextContent::new("Hello World").with_overflow(TextOverflow::Ellipsis);
+        let mut node = RenderNode::new(id, NodeKind::Text(content));
+        node.layout = Some(Rect::new(0, 0, 6, 1));
+        tree.insert_root(node);
+
+        let mut buffer = Buffer::new(20, 1);
+        let mut painter = Painter::new(&mut buffer);
+        painter.paint_tree(&tree);
+
+        assert_eq!(buffer.get(4, 0).map(|c| c.symbol.as_str()), Some("o"));
+        assert_eq!(buffer.get(5, 0).map(|c| c.symbol.as_str()), Some("…"));
+        assert_eq!(buffer.get(6, 0).map(|c| c.symbol.as_str()), Some(" "));
+    }
 }
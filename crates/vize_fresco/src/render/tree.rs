@@ -2,8 +2,10 @@
 
 use rustc_hash::FxHashMap;
 
-use super::node::{NodeId, RenderNode};
-use crate::layout::{FlexStyle, LayoutEngine};
+use super::node::{NodeId, NodeKind, RenderNode};
+use crate::input::{Key, KeyEvent};
+use crate::layout::{Display, FlexStyle, LayoutEngine};
+use crate::text::SegmentedText;
 
 /// A tree of render nodes.
 pub struct RenderTree {
@@ -17,6 +19,8 @@ pub struct RenderTree {
     next_id: NodeId,
     /// Mapping from our IDs to layout IDs
     layout_ids: FxHashMap<NodeId, u64>,
+    /// Input node receiving keyboard events
+    focused: Option<NodeId>,
 }
 
 impl RenderTree {
@@ -28,6 +32,7 @@ impl RenderTree {
             layout: LayoutEngine::new(),
             next_id: 0,
             layout_ids: FxHashMap::default(),
+            focused: None,
         }
     }
 
@@ -114,6 +119,13 @@ impl RenderTree {
         // Collect all descendant IDs first
         let descendants = self.collect_descendants(id);
 
+        if self
+            .focused
+            .is_some_and(|focused| descendants.contains(&focused))
+        {
+            self.focused = None;
+        }
+
         // Remove all nodes
         for node_id in descendants {
             if let Some(layout_id) = self.layout_ids.remove(&node_id) {
@@ -192,6 +204,7 @@ impl RenderTree {
         self.layout_ids.clear();
         self.root = None;
         self.next_id = 0;
+        self.focused = None;
     }
 
     /// Iterate over all nodes.
@@ -208,6 +221,189 @@ impl RenderTree {
     pub fn walk_preorder(&self, start: NodeId) -> TreeWalker<'_> {
         TreeWalker::new(self, start)
     }
+
+    /// Find the topmost node whose computed layout contains the point.
+    ///
+    /// Nodes are painted parent first and siblings in order, so later
+    /// siblings and descendants win over what they overlap.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<NodeId> {
+        self.root.and_then(|root| self.hit_test_node(root, x, y))
+    }
+
+    fn hit_test_node(&self, id: NodeId, x: u16, y: u16) -> Option<NodeId> {
+        let node = self.nodes.get(&id)?;
+        if node.style.display == Display::None {
+            return None;
+        }
+
+        // Children may overflow their parent, so check them regardless of the parent's rect
+        node.children
+            .iter()
+            .rev()
+            .find_map(|&child_id| self.hit_test_node(child_id, x, y))
+            .or_else(|| node.layout.filter(|rect| rect.contains(x, y)).map(|_| id))
+    }
+
+    /// Get the focused node ID.
+    pub fn focused_id(&self) -> Option<NodeId> {
+        self.focused
+    }
+
+    /// Move focus to an input node.
+    ///
+    /// Inputs with a negative `tab_index` can still be focused this way.
+    /// Returns `false` (leaving focus unchanged) if the node is not a
+    /// visible input in the tree.
+    pub fn focus(&mut self, id: NodeId) -> bool {
+        if !self.is_focusable(id) {
+            return false;
+        }
+        if self.focused != Some(id) {
+            self.set_input_focused(self.focused, false);
+            self.set_input_focused(Some(id), true);
+            self.focused = Some(id);
+        }
+        true
+    }
+
+    /// Clear focus.
+    pub fn blur(&mut self) {
+        self.set_input_focused(self.focused.take(), false);
+    }
+
+    /// Move focus to the next input in tab order, wrapping around.
+    pub fn focus_next(&mut self) -> Option<NodeId> {
+        let order = self.focus_order();
+        let current = self
+            .focused
+            .and_then(|id| order.iter().position(|&o| o == id));
+        let next = match current {
+            Some(index) => order.get((index + 1) % order.len()),
+            None => order.first(),
+        };
+        next.map(|&id| {
+            self.focus(id);
+            id
+        })
+    }
+
+    /// Move focus to the previous input in tab order, wrapping around.
+    pub fn focus_prev(&mut self) -> Option<NodeId> {
+        let order = self.focus_order();
+        let current = self
+            .focused
+            .and_then(|id| order.iter().position(|&o| o == id));
+        let prev = match current {
+            Some(index) => order.get((index + order.len() - 1) % order.len()),
+            None => order.last(),
+        };
+        prev.map(|&id| {
+            self.focus(id);
+            id
+        })
+    }
+
+    /// Get the visible input nodes in tab order.
+    ///
+    /// Like HTML, inputs with a positive `tab_index` come first in ascending
+    /// order, followed by the rest in layout position (top to bottom, then
+    /// left to right). Inputs with a negative `tab_index` are skipped.
+    pub fn focus_order(&self) -> Vec<NodeId> {
+        let mut focusable = Vec::new();
+        if let Some(root) = self.root {
+            self.collect_focusable(root, &mut focusable);
+        }
+
+        let mut keyed: Vec<_> = focusable
+            .into_iter()
+            .filter(|&(_, tab_index)| tab_index >= 0)
+            .enumerate()
+            .filter_map(|(tree_index, (id, tab_index))| {
+                let node = self.nodes.get(&id)?;
+                let explicit = if tab_index > 0 { tab_index } else { i32::MAX };
+                let position = node.layout.map(|rect| (rect.y, rect.x));
+                Some(((explicit, position, tree_index), id))
+            })
+            .collect();
+        keyed.sort_by_key(|(key, _)| *key);
+        keyed.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Route a key event.
+    ///
+    /// Tab and Shift+Tab move focus; other keys go to the focused input.
+    /// Returns the node that received the event, if any.
+    ///
+    /// Editing keys are not applied to the input here: the Vue side owns the
+    /// value and cursor and sends them back with the next frame.
+    pub fn dispatch_key(&mut self, event: &KeyEvent) -> Option<NodeId> {
+        match event.key {
+            Key::BackTab => self.focus_prev(),
+            Key::Tab if event.shift() => self.focus_prev(),
+            Key::Tab => self.focus_next(),
+            _ => self.focused,
+        }
+    }
+
+    /// Terminal cursor position inside the focused input, if any.
+    ///
+    /// Accounts for character widths and wraps at the input's layout width.
+    pub fn focused_cursor_position(&self) -> Option<(u16, u16)> {
+        let node = self.nodes.get(&self.focused?)?;
+        let NodeKind::Input(content) = &node.kind else {
+            return None;
+        };
+        let layout = node.layout?;
+
+        let text = SegmentedText::new(&content.value);
+        let cursor_col = text.column_at_index(content.cursor.min(text.grapheme_count));
+        let area_width = layout.width as usize;
+        if area_width == 0 {
+            return Some((layout.x, layout.y));
+        }
+
+        let cursor_line = cursor_col / area_width;
+        let cursor_col_in_line = cursor_col % area_width;
+        let cursor_x = layout.x + cursor_col_in_line as u16;
+        let cursor_y = layout.y + (cursor_line as u16).min(layout.height.saturating_sub(1));
+        Some((cursor_x, cursor_y))
+    }
+
+    /// Collect visible input nodes with their tab index, in tree order.
+    fn collect_focusable(&self, id: NodeId, out: &mut Vec<(NodeId, i32)>) {
+        let Some(node) = self.nodes.get(&id) else {
+            return;
+        };
+        if node.style.display == Display::None {
+            return;
+        }
+        if let NodeKind::Input(content) = &node.kind {
+            out.push((id, content.tab_index.unwrap_or(0)));
+        }
+        for &child_id in &node.children {
+            self.collect_focusable(child_id, out);
+        }
+    }
+
+    fn is_focusable(&self, id: NodeId) -> bool {
+        let mut focusable = Vec::new();
+        if let Some(root) = self.root {
+            self.collect_focusable(root, &mut focusable);
+        }
+        focusable
+            .iter()
+            .any(|&(focusable_id, _)| focusable_id == id)
+    }
+
+    fn set_input_focused(&mut self, id: Option<NodeId>, focused: bool) {
+        let Some(node) = id.and_then(|id| self.nodes.get_mut(&id)) else {
+            return;
+        };
+        if let NodeKind::Input(content) = &mut node.kind {
+            content.focused = focused;
+            node.mark_dirty();
+        }
+    }
 }
 
 impl Default for RenderTree {
@@ -251,7 +447,9 @@ impl<'a> Iterator for TreeWalker<'a> {
 #[cfg(test)]
 mod tests {
     use super::RenderTree;
-    use crate::render::node::{NodeKind, RenderNode};
+    use crate::input::{Key, KeyEvent, KeyModifiers};
+    use crate::layout::{Display, Rect};
+    use crate::render::node::{InputContent, NodeKind, RenderNode};
 
     #[test]
     fn test_tree_new() {
@@ -307,4 +505,118 @@ mod tests {
         let ids: Vec<_> = tree.walk_preorder(root_id).map(|n| n.id).collect();
         assert_eq!(ids, vec![root_id, child1_id, child2_id]);
     }
+
+    #[test]
+    fn test_hit_test() {
+        let mut tree = RenderTree::new();
+
+        let root_id = tree.next_id();
+        tree.insert_root(RenderNode::new(root_id, NodeKind::Box));
+
+        let below_id = tree.next_id();
+        tree.insert(RenderNode::new(below_id, NodeKind::Box));
+        tree.add_child(root_id, below_id);
+
+        let above_id = tree.next_id();
+        tree.insert(RenderNode::new(above_id, NodeKind::Box));
+        tree.add_child(root_id, above_id);
+
+        tree.get_mut(root_id).unwrap().layout = Some(Rect::new(0, 0, 20, 10));
+        tree.get_mut(below_id).unwrap().layout = Some(Rect::new(0, 0, 10, 5));
+        tree.get_mut(above_id).unwrap().layout = Some(Rect::new(5, 2, 10, 5));
+
+        assert_eq!(tree.hit_test(1, 1), Some(below_id));
+        // Overlapping area resolves to the sibling painted last
+        assert_eq!(tree.hit_test(6, 3), Some(above_id));
+        assert_eq!(tree.hit_test(18, 8), Some(root_id));
+        assert_eq!(tree.hit_test(30, 30), None);
+
+        tree.get_mut(above_id).unwrap().style.display = Display::None;
+        assert_eq!(tree.hit_test(6, 3), Some(below_id));
+    }
+
+    #[test]
+    fn test_tab_traversal_order() {
+        let mut tree = RenderTree::new();
+
+        let root_id = tree.next_id();
+        tree.insert_root(RenderNode::new(root_id, NodeKind::Box));
+
+        let add_input = |tree: &mut RenderTree, parent, y, tab_index: Option<i32>| {
+            let id = tree.next_id();
+            let mut content = InputContent::new();
+            content.tab_index = tab_index;
+            let mut node = RenderNode::new(id, NodeKind::Input(content));
+            node.layout = Some(Rect::new(0, y, 10, 1));
+            tree.insert(node);
+            tree.add_child(parent, id);
+            id
+        };
+
+        // Tree order differs from layout order
+        let lower = add_input(&mut tree, root_id, 5, None);
+        let upper = add_input(&mut tree, root_id, 1, Some(0));
+        let explicit = add_input(&mut tree, root_id, 9, Some(2));
+        let skipped = add_input(&mut tree, root_id, 3, Some(-1));
+
+        let hidden_id = tree.next_id();
+        tree.insert(RenderNode::new(hidden_id, NodeKind::Box));
+        tree.add_child(root_id, hidden_id);
+        tree.get_mut(hidden_id).unwrap().style.display = Display::None;
+        let hidden = add_input(&mut tree, hidden_id, 0, None);
+
+        assert_eq!(tree.focus_order(), vec![explicit, upper, lower]);
+
+        assert_eq!(tree.focus_next(), Some(explicit));
+        assert_eq!(tree.focus_next(), Some(upper));
+        assert_eq!(tree.focus_next(), Some(lower));
+        assert_eq!(tree.focus_next(), Some(explicit));
+        assert_eq!(tree.focus_prev(), Some(lower));
+
+        // Only the focused input is flagged
+        let focused: Vec<_> = tree
+            .iter()
+            .filter(|(_, node)| matches!(&node.kind, NodeKind::Input(c) if c.focused))
+            .map(|(&id, _)| id)
+            .collect();
+        assert_eq!(focused, vec![lower]);
+
+        // Negative tab index is focusable programmatically, hidden inputs are not
+        assert!(tree.focus(skipped));
+        assert!(!tree.focus(hidden));
+        assert_eq!(tree.focused_id(), Some(skipped));
+
+        let shift = KeyModifiers {
+            shift: true,
+            ..KeyModifiers::NONE
+        };
+        let shift_tab = KeyEvent::new(Key::Tab, shift);
+        assert_eq!(tree.dispatch_key(&shift_tab), Some(lower));
+        assert_eq!(tree.dispatch_key(&KeyEvent::char('x')), Some(lower));
+        assert_eq!(tree.focused_id(), Some(lower));
+
+        tree.remove(lower);
+        assert_eq!(tree.focused_id(), None);
+    }
+
+    #[test]
+    fn test_focused_cursor_position() {
+        let mut tree = RenderTree::new();
+        assert_eq!(tree.focused_cursor_position(), None);
+
+        let id = tree.next_id();
+        let mut content = InputContent::new().with_value("日本語abc");
+        content.cursor = 4;
+        let mut node = RenderNode::new(id, NodeKind::Input(content));
+        node.layout = Some(Rect::new(2, 1, 4, 3));
+        tree.insert_root(node);
+        tree.focus(id);
+
+        // "日本語a" is 7 columns wide, wrapping at 4
+        assert_eq!(tree.focused_cursor_position(), Some((5, 2)));
+
+        // A zero-width input keeps the cursor at its origin
+        tree.get_mut(id).unwrap().layout = Some(Rect::new(2, 1, 0, 3));
+        assert_eq!(tree.focused_cursor_position(), Some((2, 1)));
+    }
 }
@@ -144,44 +144,7 @@ impl Backend {
     /// Uses differential rendering for efficiency.
     pub fn flush(&mut self) -> io::Result<()> {
         let mut stdout = io::stdout();
-        let mut last_style = Style::new();
-        let mut last_x: i32 = -1;
-        let mut last_y: i32 = -1;
-
-        // Collect changes
-        let changes: Vec<_> = self.current.diff(&self.previous).collect();
-
-        for (x, y, cell) in changes {
-            // Skip continuation cells
-            if cell.is_continuation {
-                continue;
-            }
-
-            // Move cursor if not adjacent
-            if x as i32 != last_x + 1 || y as i32 != last_y {
-                queue!(stdout, MoveTo(x, y))?;
-            }
-
-            // Apply style changes
-            if cell.style != last_style {
-                self.apply_style(&mut stdout, &cell.style, &last_style)?;
-                last_style = cell.style;
-            }
-
-            // Print the character
-            queue!(stdout, Print(&cell.symbol))?;
-
-            last_x = x as i32;
-            last_y = y as i32;
-        }
-
-        // Reset style
-        queue!(
-            stdout,
-            SetForegroundColor(crossterm::style::Color::Reset),
-            SetBackgroundColor(crossterm::style::Color::Reset),
-            SetAttribute(Attribute::Reset)
-        )?;
+        write_diff(&mut stdout, &self.current, &self.previous)?;
 
         // Update cursor
         if self.cursor.visible {
@@ -208,118 +171,162 @@ impl Backend {
 
         Ok(())
     }
+}
 
-    /// Apply style changes to stdout.
-    fn apply_style<W: Write>(&self, writer: &mut W, new: &Style, old: &Style) -> io::Result<()> {
-        // Foreground color
-        if new.fg != old.fg {
-            if let Some(fg) = new.fg {
-                queue!(writer, SetForegroundColor(fg.into()))?;
-            } else {
-                queue!(writer, SetForegroundColor(crossterm::style::Color::Reset))?;
-            }
+/// Queue the escape sequences that turn `previous` into `current`.
+///
+/// Only changed cells are written, and the cursor is only moved when the
+/// next changed cell does not directly follow the last one written.
+pub fn write_diff<W: Write>(writer: &mut W, current: &Buffer, previous: &Buffer) -> io::Result<()> {
+    let mut last_style = Style::new();
+    let mut last_x: i32 = -1;
+    let mut last_y: i32 = -1;
+
+    for (x, y, cell) in current.diff(previous) {
+        // Skip continuation cells
+        if cell.is_continuation {
+            continue;
         }
 
-        // Background color
-        if new.bg != old.bg {
-            if let Some(bg) = new.bg {
-                queue!(writer, SetBackgroundColor(bg.into()))?;
-            } else {
-                queue!(writer, SetBackgroundColor(crossterm::style::Color::Reset))?;
-            }
+        // Move cursor if not adjacent
+        if x as i32 != last_x + 1 || y as i32 != last_y {
+            queue!(writer, MoveTo(x, y))?;
         }
 
-        // Attributes
-        if new.bold != old.bold {
-            queue!(
-                writer,
-                SetAttribute(if new.bold {
-                    Attribute::Bold
-                } else {
-                    Attribute::NormalIntensity
-                })
-            )?;
+        // Apply style changes
+        if cell.style != last_style {
+            apply_style(writer, &cell.style, &last_style)?;
+            last_style = cell.style;
         }
 
-        if new.dim != old.dim {
-            queue!(
-                writer,
-                SetAttribute(if new.dim {
-                    Attribute::Dim
-                } else {
-                    Attribute::NormalIntensity
-                })
-            )?;
-        }
+        // Print the character
+        queue!(writer, Print(&cell.symbol))?;
 
-        if new.italic != old.italic {
-            queue!(
-                writer,
-                SetAttribute(if new.italic {
-                    Attribute::Italic
-                } else {
-                    Attribute::NoItalic
-                })
-            )?;
-        }
+        last_x = x as i32;
+        last_y = y as i32;
+    }
 
-        if new.underline != old.underline {
-            queue!(
-                writer,
-                SetAttribute(if new.underline {
-                    Attribute::Underlined
-                } else {
-                    Attribute::NoUnderline
-                })
-            )?;
-        }
+    // Reset style
+    queue!(
+        writer,
+        SetForegroundColor(crossterm::style::Color::Reset),
+        SetBackgroundColor(crossterm::style::Color::Reset),
+        SetAttribute(Attribute::Reset)
+    )?;
 
-        if new.blink != old.blink {
-            queue!(
-                writer,
-                SetAttribute(if new.blink {
-                    Attribute::SlowBlink
-                } else {
-                    Attribute::NoBlink
-                })
-            )?;
-        }
+    Ok(())
+}
 
-        if new.strikethrough != old.strikethrough {
-            queue!(
-                writer,
-                SetAttribute(if new.strikethrough {
-                    Attribute::CrossedOut
-                } else {
-                    Attribute::NotCrossedOut
-                })
-            )?;
+/// Queue the attribute changes needed to switch from `old` to `new`.
+fn apply_style<W: Write>(writer: &mut W, new: &Style, old: &Style) -> io::Result<()> {
+    // Foreground color
+    if new.fg != old.fg {
+        if let Some(fg) = new.fg {
+            queue!(writer, SetForegroundColor(fg.into()))?;
+        } else {
+            queue!(writer, SetForegroundColor(crossterm::style::Color::Reset))?;
         }
+    }
 
-        if new.reverse != old.reverse {
-            queue!(
-                writer,
-                SetAttribute(if new.reverse {
-                    Attribute::Reverse
-                } else {
-                    Attribute::NoReverse
-                })
-            )?;
+    // Background color
+    if new.bg != old.bg {
+        if let Some(bg) = new.bg {
+            queue!(writer, SetBackgroundColor(bg.into()))?;
+        } else {
+            queue!(writer, SetBackgroundColor(crossterm::style::Color::Reset))?;
         }
+    }
 
-        if new.hidden != old.hidden {
-            queue!(
-                writer,
-                SetAttribute(if new.hidden {
-                    Attribute::Hidden
-                } else {
-                    Attribute::NoHidden
-                })
-            )?;
-        }
+    // Attributes
+    if new.bold != old.bold {
+        queue!(
+            writer,
+            SetAttribute(if new.bold {
+                Attribute::Bold
+            } else {
+                Attribute::NormalIntensity
+            })
+        )?;
+    }
 
-        Ok(())
+    if new.dim != old.dim {
+        queue!(
+            writer,
+            SetAttribute(if new.dim {
+                Attribute::Dim
+            } else {
+                Attribute::NormalIntensity
+            })
+        )?;
+    }
+
+    if new.italic != old.italic {
+        queue!(
+            writer,
+            SetAttribute(if new.italic {
+                Attribute::Italic
+            } else {
+                Attribute::NoItalic
+            })
+        )?;
+    }
+
+    if new.underline != old.underline {
+        queue!(
+            writer,
+            SetAttribute(if new.underline {
+                Attribute::Underlined
+            } else {
+                Attribute::NoUnderline
+            })
+        )?;
+    }
+
+    if new.blink != old.blink {
+        queue!(
+            writer,
+            SetAttribute(if new.blink {
+                Attribute::SlowBlink
+            } else {
+                Attribute::NoBlink
+            })
+        )?;
     }
+
+    if new.strikethrough != old.strikethrough {
+        queue!(
+            writer,
+            SetAttribute(if new.strikethrough {
+                Attribute::CrossedOut
+            } else {
+                Attribute::NotCrossedOut
+            })
+        )?;
+    }
+
+    if new.reverse != old.reverse {
+        queue!(
+            writer,
+            SetAttribute(if new.reverse {
+                Attribute::Reverse
+            } else {
+                Attribute::NoReverse
+            })
+        )?;
+    }
+
+    if new.hidden != old.hidden {
+        queue!(
+            writer,
+            SetAttribute(if new.hidden {
+                Attribute::Hidden
+            } else {
+                Attribute::NoHidden
+            })
+        )?;
+    }
+
+    Ok(())
 }
 
 impl Default for Backend {
@@ -336,7 +343,8 @@ impl Drop for Backend {
 
 #[cfg(test)]
 mod tests {
-    use super::Backend;
+    use super::{write_diff, Backend};
+    use crate::terminal::{Buffer, Style};
 
     #[test]
     fn test_backend_size() {
@@ -346,4 +354,23 @@ mod tests {
             assert!(backend.height() > 0);
         }
     }
+
+    #[test]
+    fn test_write_diff_only_changed_cells() {
+        let previous = Buffer::new(10, 2);
+        let mut current = Buffer::new(10, 2);
+        current.set_string(2, 1, "hi", Style::default());
+
+        let mut out = Vec::new();
+        write_diff(&mut out, &current, &previous).unwrap();
+        let out = std::str::from_utf8(&out).unwrap();
+
+        // A single move to column 2, row 1 (`CSI row;col H` is 1-based), then the span
+        assert!(out.starts_with("\x1b[2;3Hhi"));
+        assert_eq!(out.matches('H').count(), 1);
+
+        let mut out = Vec::new();
+        write_diff(&mut out, &current, &current).unwrap();
+        assert!(!std::str::from_utf8(&out).unwrap().contains('H'));
+    }
 }
@@ -0,0 +1,140 @@
+//! Frame-by-frame terminal output.
+
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, SetCursorStyle, Show},
+    queue,
+    terminal::{Clear, ClearType},
+};
+
+use super::{backend::write_diff, buffer::Buffer};
+
+/// Writes rendered frames as terminal output.
+///
+/// Each frame is diffed against the previous one written by the same
+/// writer, so only changed cells produce output.
+#[derive(Default)]
+pub struct FrameWriter {
+    /// Last frame written
+    previous: Option<Buffer>,
+}
+
+impl FrameWriter {
+    /// Create a writer with no previous frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write the output that updates the screen to `frame`.
+    ///
+    /// The first frame, and any frame after a size change, clears the screen
+    /// and is drawn in full. `cursor` shows a blinking bar cursor at the
+    /// given position; `None` hides the cursor.
+    pub fn write<W: Write>(
+        &mut self,
+        writer: &mut W,
+        frame: Buffer,
+        cursor: Option<(u16, u16)>,
+    ) -> io::Result<()> {
+        let previous = self.previous.take().filter(|previous| {
+            previous.width() == frame.width() && previous.height() == frame.height()
+        });
+
+        let previous = match previous {
+            Some(previous) => previous,
+            None => {
+                queue!(writer, Clear(ClearType::All))?;
+                Buffer::new(frame.width(), frame.height())
+            }
+        };
+        write_diff(writer, &frame, &previous)?;
+
+        if let Some((x, y)) = cursor {
+            queue!(writer, MoveTo(x, y), SetCursorStyle::BlinkingBar, Show)?;
+        } else {
+            queue!(writer, Hide)?;
+        }
+
+        self.previous = Some(frame);
+        Ok(())
+    }
+
+    /// Forget the previous frame so the next one is drawn in full.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameWriter;
+    use crate::terminal::{Buffer, Style};
+    use compact_str::CompactString;
+
+    const CLEAR: &str = "\x1b[2J";
+
+    fn write(frames: &mut FrameWriter, frame: Buffer) -> CompactString {
+        let mut out = Vec::new();
+        frames.write(&mut out, frame, None).unwrap();
+        CompactString::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_frame_writer_writes_only_changed_cells() {
+        let mut frames = FrameWriter::new();
+
+        let mut first = Buffer::new(10, 2);
+        first.set_string(0, 0, "hello", Style::default());
+        let out = write(&mut frames, first.clone());
+        assert!(out.starts_with(CLEAR));
+        assert!(out.contains("hello"));
+
+        // Only the changed cell is written, after a single cursor move
+        let mut second = first.clone();
+        second.set_string(1, 0, "a", Style::default());
+        let out = write(&mut frames, second.clone());
+        assert!(!out.contains(CLEAR));
+        assert!(out.contains("\x1b[1;2Ha"));
+        assert!(!out.contains("hello"));
+        assert!(!out.contains("llo"));
+
+        // An identical frame writes no cells
+        let out = write(&mut frames, second);
+        assert!(!out.contains(CLEAR));
+        assert!(!out.contains("\x1b[1;"));
+    }
+
+    #[test]
+    fn test_frame_writer_redraws_after_resize_or_reset() {
+        let mut frames = FrameWriter::new();
+        let mut frame = Buffer::new(10, 2);
+        frame.set_string(0, 0, "hi", Style::default());
+        write(&mut frames, frame.clone());
+
+        let mut resized = Buffer::new(12, 2);
+        resized.set_string(0, 0, "hi", Style::default());
+        let out = write(&mut frames, resized.clone());
+        assert!(out.starts_with(CLEAR));
+        assert!(out.contains("hi"));
+
+        frames.reset();
+        let out = write(&mut frames, resized);
+        assert!(out.starts_with(CLEAR));
+    }
+
+    #[test]
+    fn test_frame_writers_keep_separate_frames() {
+        let mut frame = Buffer::new(4, 1);
+        frame.set_string(0, 0, "ab", Style::default());
+
+        let mut a = FrameWriter::new();
+        let mut b = FrameWriter::new();
+        write(&mut a, frame.clone());
+
+        // `b` has not seen a frame yet, so it still draws in full
+        let out = write(&mut b, frame);
+        assert!(out.starts_with(CLEAR));
+        assert!(out.contains("ab"));
+    }
+}
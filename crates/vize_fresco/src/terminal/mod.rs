@@ -10,8 +10,10 @@ mod backend;
 mod buffer;
 mod cell;
 mod cursor;
+mod frame;
 
-pub use backend::Backend;
+pub use backend::{write_diff, Backend};
 pub use buffer::Buffer;
 pub use cell::{Cell, Color, Style};
 pub use cursor::{Cursor, CursorShape};
+pub use frame::FrameWriter;
@@ -73,6 +73,25 @@ impl TextWidth {
         CompactString::from(format!("{}...", truncated))
     }
 
+    /// Truncate string with a single-column `…` if needed.
+    ///
+    /// Width is measured in display columns, so wide (CJK) characters are
+    /// never split and the result never exceeds `max_width`.
+    pub fn ellipsize(s: &str, max_width: usize) -> CompactString {
+        if Self::width(s) <= max_width {
+            return s.to_compact_string();
+        }
+
+        if max_width == 0 {
+            return CompactString::default();
+        }
+
+        let (truncated, _) = Self::truncate(s, max_width - 1);
+        let mut result = CompactString::from(truncated);
+        result.push('…');
+        result
+    }
+
     /// Pad string to specified width.
     #[allow(clippy::disallowed_macros)]
     pub fn pad_right(s: &str, target_width: usize) -> CompactString {
@@ -181,6 +200,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ellipsize() {
+        assert_eq!(TextWidth::ellipsize("Hello", 5), "Hello");
+        assert_eq!(TextWidth::ellipsize("Hello World", 8), "Hello W…");
+        assert_eq!(TextWidth::ellipsize("Hello", 0), "");
+
+        // Wide chars are never split; the result may be narrower than max
+        assert_eq!(TextWidth::ellipsize("あいうえお", 5), "あい…");
+        assert_eq!(TextWidth::ellipsize("あいうえお", 6), "あい…");
+        assert_eq!(TextWidth::width(&TextWidth::ellipsize("あいうえお", 6)), 5);
+    }
+
     #[test]
     fn test_pad_right() {
         assert_eq!(TextWidth::pad_right("Hi", 5), "Hi   ");
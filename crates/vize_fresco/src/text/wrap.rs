@@ -14,8 +14,10 @@ pub enum WrapMode {
     Word,
     /// Wrap at character boundaries
     Char,
-    /// Truncate with ellipsis
+    /// Truncate to a single line ending with `…`
     Truncate,
+    /// Hard cut to a single line, no ellipsis
+    Clip,
 }
 
 /// Text wrapper for terminal output.
@@ -28,9 +30,8 @@ impl TextWrap {
             WrapMode::NoWrap => vec![CompactString::from(text)],
             WrapMode::Word => Self::wrap_word(text, max_width),
             WrapMode::Char => Self::wrap_char(text, max_width),
-            WrapMode::Truncate => {
-                vec![TextWidth::truncate_with_ellipsis(text, max_width)]
-            }
+            WrapMode::Truncate => vec![TextWidth::ellipsize(text, max_width)],
+            WrapMode::Clip => vec![CompactString::from(TextWidth::truncate(text, max_width).0)],
         }
     }
 
@@ -229,7 +230,24 @@ mod tests {
     fn test_wrap_truncate() {
         let lines = TextWrap::wrap("Hello World", 8, WrapMode::Truncate);
         assert_eq!(lines.len(), 1);
-        assert_eq!(lines[0].as_str(), "Hello...");
+        assert_eq!(lines[0].as_str(), "Hello W…");
+    }
+
+    #[test]
+    fn test_wrap_truncate_cjk() {
+        let lines = TextWrap::wrap("日本語のテキスト", 7, WrapMode::Truncate);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].as_str(), "日本語…");
+    }
+
+    #[test]
+    fn test_wrap_clip() {
+        let lines = TextWrap::wrap("Hello World", 8, WrapMode::Clip);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].as_str(), "Hello Wo");
+
+        let lines = TextWrap::wrap("あいうえお", 5, WrapMode::Clip);
+        assert_eq!(lines[0].as_str(), "あい");
     }
 
     #[test]
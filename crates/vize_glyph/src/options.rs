@@ -101,6 +101,11 @@ pub struct FormatOptions {
     /// When false, blocks are preserved in their original source order.
     #[serde(default = "default_true")]
     pub sort_blocks: bool,
+
+    /// Self-closing style for childless template elements (default: Prettier style)
+    /// Native non-void elements are always closed explicitly (`<div></div>`).
+    #[serde(default)]
+    pub self_closing: SelfClosing,
 }
 
 impl Default for FormatOptions {
@@ -127,6 +132,7 @@ impl Default for FormatOptions {
             attribute_groups: None,
             normalize_directive_shorthands: true,
             sort_blocks: true,
+            self_closing: SelfClosing::default(),
         }
     }
 }
@@ -193,6 +199,28 @@ pub enum AttributeSortOrder {
     AsWritten,
 }
 
+/// Self-closing style for childless template elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfClosing {
+    /// Close void elements as `<br />` instead of `<br>` (default: true)
+    #[serde(default = "default_true")]
+    pub void: bool,
+
+    /// Close components without children as `<Foo />` instead of `<Foo></Foo>` (default: true)
+    #[serde(default = "default_true")]
+    pub component: bool,
+}
+
+impl Default for SelfClosing {
+    fn default() -> Self {
+        Self {
+            void: true,
+            component: true,
+        }
+    }
+}
+
 /// Quote properties options
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
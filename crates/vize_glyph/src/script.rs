@@ -89,8 +89,8 @@ pub fn format_js_expression(expr: &str, options: &FormatOptions) -> Option<Strin
     let formatted = formatted.strip_suffix(';').unwrap_or(formatted);
     let inner = formatted.strip_prefix("void ").unwrap_or(formatted);
 
-    // Strip outer parens if the formatter kept them
-    let inner = if inner.starts_with('(') && inner.ends_with(')') {
+    // Strip outer parens if the formatter kept them around the whole expression
+    let inner = if is_wrapped_in_parens(inner) {
         &inner[1..inner.len() - 1]
     } else {
         inner
@@ -99,6 +99,42 @@ pub fn format_js_expression(expr: &str, options: &FormatOptions) -> Option<Strin
     Some(inner.trim().to_compact_string())
 }
 
+/// Check whether the first `(` is closed by the last character,
+/// i.e. `(a + b)` but not `(a) + (b)`.
+fn is_wrapped_in_parens(expr: &str) -> bool {
+    if !expr.starts_with('(') || !expr.ends_with(')') {
+        return false;
+    }
+
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut escaped = false;
+    for (i, &b) in expr.as_bytes().iter().enumerate() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == q {
+                quote = None;
+            }
+            continue;
+        }
+        match b {
+            b'\'' | b'"' | b'`' => quote = Some(b),
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == expr.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::{format_js_expression, format_script_content, Allocator, FormatOptions};
@@ -171,6 +207,13 @@ mod tests {
         insta::assert_snapshot!(expr.as_str());
     }
 
+    #[test]
+    fn test_format_js_expression_keeps_separate_parens() {
+        let options = FormatOptions::default();
+        let expr = format_js_expression("(a||b)&&(c||d)", &options).unwrap();
+        assert_eq!(expr, "(a || b) && (c || d)");
+    }
+
     #[test]
     fn test_format_js_expression_empty() {
         let options = FormatOptions::default();
@@ -79,6 +79,49 @@ fn format_directive_value(name: &str, value: &str, options: &FormatOptions) -> S
     script::format_js_expression(trimmed, options).unwrap_or_else(|| value.to_compact_string())
 }
 
+/// Re-format a directive value that does not fit on one line.
+///
+/// The value is formatted with the print width left after `indent_width`
+/// columns, so the script formatter decides where to break it. Returns `None`
+/// if the attribute is not an expression or the expression cannot be parsed.
+pub(crate) fn wrap_directive_value(
+    name: &str,
+    value: &str,
+    indent_width: usize,
+    options: &FormatOptions,
+) -> Option<String> {
+    if !should_format_expression(name) || value.trim().is_empty() {
+        return None;
+    }
+
+    let available = (options.print_width as usize).saturating_sub(indent_width);
+    let narrowed = FormatOptions {
+        print_width: available.max(1) as u32,
+        ..options.clone()
+    };
+
+    if name != "v-for" {
+        return script::format_js_expression(value, &narrowed);
+    }
+
+    // Only the collection part of `v-for` is a JS expression; it continues on
+    // the same line as the `(item, index) in ` prefix
+    let formatted = format_v_for_expression(value.trim());
+    let idx = find_v_for_keyword(&formatted, " in ")
+        .or_else(|| find_v_for_keyword(&formatted, " of "))?;
+    let (prefix, collection) = formatted.split_at(idx + 4);
+    let narrowed = FormatOptions {
+        print_width: available.saturating_sub(prefix.len()).max(1) as u32,
+        ..narrowed
+    };
+    let collection = script::format_js_expression(collection, &narrowed)?;
+
+    let mut wrapped = String::with_capacity(prefix.len() + collection.len());
+    wrapped.push_str(prefix);
+    wrapped.push_str(&collection);
+    Some(wrapped)
+}
+
 /// Format `v-for` expression: normalize spacing in `(item, index) in items`.
 #[allow(clippy::disallowed_macros)]
 pub(crate) fn format_v_for_expression(expr: &str) -> String {
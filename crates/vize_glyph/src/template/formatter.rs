@@ -9,11 +9,10 @@ use vize_carton::{String, ToCompactString};
 
 use super::{
     attributes::{render_attribute, sort_attributes, ParsedAttribute},
-    directives::normalize_attribute,
-    helpers::{
-        find_bytes, is_tag_name_char, is_void_element_str, is_whitespace, parse_closing_tag,
-    },
+    directives::{normalize_attribute, wrap_directive_value},
+    helpers::{find_bytes, is_tag_name_char, is_whitespace, parse_closing_tag},
 };
+use vize_carton::{is_html_tag, is_void_tag};
 
 /// High-performance template formatter.
 pub(crate) struct TemplateFormatter<'a> {
@@ -108,8 +107,10 @@ impl<'a> TemplateFormatter<'a> {
                     output.extend_from_slice(tag_name.as_bytes());
 
                     if !sorted_attrs.is_empty() {
-                        let use_multiline =
-                            self.should_use_multiline_attrs(&tag_name, &sorted_attrs, depth);
+                        let has_wrapped_value =
+                            self.wrap_long_attribute_values(&mut sorted_attrs, depth);
+                        let use_multiline = has_wrapped_value
+                            || self.should_use_multiline_attrs(&tag_name, &sorted_attrs, depth);
 
                         if use_multiline {
                             let max_per_line = self
@@ -145,23 +146,69 @@ impl<'a> TemplateFormatter<'a> {
                         }
                     }
 
-                    if is_self_closing {
-                        output.extend_from_slice(b" />");
+                    pos = end_pos;
+                    if is_void_tag(&tag_name) {
+                        if self.options.self_closing.void {
+                            output.extend_from_slice(b" />");
+                        } else {
+                            output.push(b'>');
+                        }
+                    } else if is_explicitly_closed(&tag_name) {
+                        if is_self_closing {
+                            // `<div />` is only valid in Vue templates, not in HTML
+                            output.extend_from_slice(b"></");
+                            output.extend_from_slice(tag_name.as_bytes());
+                            output.push(b'>');
+                        } else {
+                            output.push(b'>');
+                            depth += 1;
+                        }
                     } else {
-                        output.push(b'>');
-                        if !is_void_element_str(&tag_name) {
+                        let empty_close = if is_self_closing {
+                            None
+                        } else {
+                            find_empty_close(source, end_pos, &tag_name)
+                        };
+                        if let Some(close_end) = empty_close {
+                            pos = close_end;
+                        }
+                        if !is_self_closing && empty_close.is_none() {
+                            output.push(b'>');
                             depth += 1;
+                        } else if self.options.self_closing.component {
+                            output.extend_from_slice(b" />");
+                        } else {
+                            output.push(b'>');
+                            output.extend_from_slice(b"</");
+                            output.extend_from_slice(tag_name.as_bytes());
+                            output.push(b'>');
                         }
                     }
                     output.extend_from_slice(self.newline);
-                    pos = end_pos;
                     continue;
                 }
             }
 
-            // Accumulate text content until newline or tag
+            // Accumulate text content until newline or tag. Interpolations are
+            // kept whole since they may contain `<`, and a `<` that did not
+            // start a tag above is plain text.
             let content_start = pos;
-            while pos < len && source[pos] != b'\n' && source[pos] != b'<' {
+            while pos < len && source[pos] != b'\n' {
+                if source[pos..].starts_with(b"{{") {
+                    // The closing `}}` must come before the end of the line or
+                    // the next closing tag; an unmatched `{{` is plain text.
+                    let rest = &source[pos + 2..];
+                    let bound = find_bytes(rest, b"\n").unwrap_or(rest.len());
+                    let bound = find_bytes(&rest[..bound], b"</").unwrap_or(bound);
+                    pos += match find_bytes(&rest[..bound], b"}}") {
+                        Some(end_offset) => end_offset + 4,
+                        None => 2,
+                    };
+                    continue;
+                }
+                if source[pos] == b'<' && pos > content_start {
+                    break;
+                }
                 pos += 1;
             }
 
@@ -225,6 +272,52 @@ impl<'a> TemplateFormatter<'a> {
         output.extend_from_slice(self.newline);
     }
 
+    /// Wrap expression values that do not fit within the print width.
+    ///
+    /// A wrapped value starts on its own line inside the quotes, indented one
+    /// level deeper than the attribute, with the closing quote back at the
+    /// attribute's indentation. Returns `true` if any value was wrapped.
+    fn wrap_long_attribute_values(&self, attrs: &mut [ParsedAttribute], depth: usize) -> bool {
+        let attr_indent = self.indent.len() * (depth + 1);
+        let mut wrapped_any = false;
+
+        for attr in attrs.iter_mut() {
+            let Some(value) = attr.value.as_deref() else {
+                continue;
+            };
+            let fits = !value.contains('\n')
+                && attr_indent + render_attribute(attr).len() <= self.options.print_width as usize;
+            if fits {
+                continue;
+            }
+            let Some(expr) = wrap_directive_value(
+                &attr.name,
+                value,
+                attr_indent + self.indent.len(),
+                self.options,
+            ) else {
+                continue;
+            };
+
+            let mut wrapped = Vec::with_capacity(expr.len() + 64);
+            wrapped.extend_from_slice(self.newline);
+            for line in expr.lines() {
+                if !line.trim().is_empty() {
+                    self.write_indent(&mut wrapped, depth + 2);
+                    wrapped.extend_from_slice(line.trim_end().as_bytes());
+                }
+                wrapped.extend_from_slice(self.newline);
+            }
+            self.write_indent(&mut wrapped, depth + 1);
+
+            // SAFETY: Built from UTF-8 lines and ASCII indentation
+            attr.value = Some(unsafe { String::from_utf8_unchecked(wrapped) });
+            wrapped_any = true;
+        }
+
+        wrapped_any
+    }
+
     /// Determine whether attributes should be rendered in multiline mode.
     fn should_use_multiline_attrs(
         &self,
@@ -418,6 +511,27 @@ impl<'a> TemplateFormatter<'a> {
     }
 }
 
+/// Whether a non-void element must keep an explicit closing tag.
+///
+/// Native HTML elements can't self-close; `<slot>` and `<component>` are Vue
+/// built-ins and follow the component style.
+fn is_explicitly_closed(tag: &str) -> bool {
+    is_html_tag(tag) && !matches!(tag, "slot" | "component")
+}
+
+/// If only whitespace separates an opening tag from its `</tag>`, return the
+/// position after the closing tag.
+fn find_empty_close(source: &[u8], mut pos: usize, tag_name: &str) -> Option<usize> {
+    while pos < source.len() && is_whitespace(source[pos]) {
+        pos += 1;
+    }
+    if !source[pos..].starts_with(b"</") {
+        return None;
+    }
+    let (close_name, end_pos) = parse_closing_tag(source, pos)?;
+    (close_name == tag_name).then_some(end_pos)
+}
+
 /// Format interpolations in text content: `{{expr}}` -> `{{ expr }}`.
 pub(crate) fn format_interpolations(text: &str, options: &FormatOptions) -> String {
     let bytes = text.as_bytes();
@@ -452,7 +566,15 @@ pub(crate) fn format_interpolations(text: &str, options: &FormatOptions) -> Stri
                 let formatted_expr = script::format_js_expression(expr, options)
                     .unwrap_or_else(|| expr.trim().to_compact_string());
                 result.push_str("{{ ");
-                result.push_str(&formatted_expr);
+                // Keep object literals parenthesized so their braces don't
+                // read as (or run into) the interpolation delimiters
+                if formatted_expr.starts_with('{') {
+                    result.push('(');
+                    result.push_str(&formatted_expr);
+                    result.push(')');
+                } else {
+                    result.push_str(&formatted_expr);
+                }
                 result.push_str(" }}");
                 pos = expr_end + 2;
             } else {
@@ -5,6 +5,7 @@
 //! - Directive shorthand normalization (`v-bind:` -> `:`, `v-on:` -> `@`, `v-slot:` -> `#`)
 //! - Interpolation spacing normalization (`{{expr}}` -> `{{ expr }}`)
 //! - JS expression formatting in directive values via oxc_formatter
+//! - Wrapping of directive expressions that exceed `print_width`
 //! - Attribute sorting following Vue style guide order
 //! - `single_attribute_per_line` support with `bracket_same_line`
 
@@ -39,11 +40,11 @@ pub fn format_template_content(
 #[cfg(test)]
 mod tests {
     use super::{attributes, directives, format_template_content, formatter, helpers};
-    use crate::options::{AttributeSortOrder, FormatOptions};
+    use crate::options::{AttributeSortOrder, FormatOptions, SelfClosing};
     use attributes::attribute_priority;
     use directives::{custom_attribute_priority, format_v_for_expression, matches_attr_pattern};
     use formatter::format_interpolations;
-    use helpers::{is_tag_name_char, is_void_element_str};
+    use helpers::is_tag_name_char;
     use vize_carton::ToCompactString;
 
     #[test]
@@ -130,6 +131,112 @@ mod tests {
         insta::assert_snapshot!(result.as_str());
     }
 
+    #[test]
+    fn test_interpolation_expression_formatted() {
+        let options = FormatOptions::default();
+        assert_eq!(
+            format_interpolations("{{  msg  }}", &options).as_str(),
+            "{{ msg }}"
+        );
+        assert_eq!(
+            format_interpolations("{{foo(1,2)}}", &options).as_str(),
+            "{{ foo(1, 2) }}"
+        );
+        assert_eq!(
+            format_interpolations("{{ (a||b)&&(c||d) }}", &options).as_str(),
+            "{{ (a || b) && (c || d) }}"
+        );
+    }
+
+    #[test]
+    fn test_interpolation_object_literal_stays_parenthesized() {
+        let options = FormatOptions::default();
+        assert_eq!(
+            format_interpolations("{{ {a:1} }}", &options).as_str(),
+            "{{ ({ a: 1 }) }}"
+        );
+        assert_eq!(
+            format_interpolations("{{ ({a:1}) }}", &options).as_str(),
+            "{{ ({ a: 1 }) }}"
+        );
+    }
+
+    #[test]
+    fn test_template_v_for_wrapper_is_stable() {
+        let options = FormatOptions::default();
+        let source = concat!(
+            r#"<ul><template v-for="item in items" :key="item.id">"#,
+            r#"<li v-if="item.visible">{{ item.name }}</li>"#,
+            r#"<li v-else-if="item.count<limit">{{ item.count<limit ? item.count : limit }}</li>"#,
+            r#"<li v-else>hidden</li></template></ul>"#,
+        );
+        let result = format_template_content(source, &options).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[0], "<ul>");
+        assert_eq!(
+            lines[1],
+            r#"  <template v-for="item in items" :key="item.id">"#
+        );
+        assert_eq!(lines[2], r#"    <li v-if="item.visible">"#);
+        assert_eq!(lines[3], "      {{ item.name }}");
+        assert_eq!(lines[5], r#"    <li v-else-if="item.count < limit">"#);
+        assert_eq!(
+            lines[6],
+            "      {{ item.count < limit ? item.count : limit }}"
+        );
+        assert_eq!(lines[8], "    <li v-else>");
+        assert_eq!(lines[lines.len() - 2], "  </template>");
+        assert_eq!(lines[lines.len() - 1], "</ul>");
+
+        let reformatted = format_template_content(&result, &options).unwrap();
+        assert_eq!(reformatted, result);
+    }
+
+    #[test]
+    fn test_template_v_if_wrapper_is_stable() {
+        let options = FormatOptions::default();
+        let source = concat!(
+            r#"<template v-if="ready"><template v-for="(row,i) in rows">"#,
+            r#"<span>{{ i }}</span></template></template>"#,
+            r#"<template v-else>Loading</template>"#,
+        );
+        let result = format_template_content(source, &options).unwrap();
+
+        assert_eq!(
+            result.as_str(),
+            concat!(
+                "<template v-if=\"ready\">\n",
+                "  <template v-for=\"(row, i) in rows\">\n",
+                "    <span>\n",
+                "      {{ i }}\n",
+                "    </span>\n",
+                "  </template>\n",
+                "</template>\n",
+                "<template v-else>\n",
+                "  Loading\n",
+                "</template>",
+            )
+        );
+        let reformatted = format_template_content(&result, &options).unwrap();
+        assert_eq!(reformatted, result);
+    }
+
+    #[test]
+    fn test_unmatched_interpolation_is_text() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<p>{{ a</p>\n<p>b }}</p>", &options).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines, ["<p>", "  {{ a", "</p>", "<p>", "  b }}", "</p>"]);
+    }
+
+    #[test]
+    fn test_text_less_than_is_not_a_tag() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<p>a < b</p>", &options).unwrap();
+        assert_eq!(result.as_str(), "<p>\n  a < b\n</p>");
+    }
+
     #[test]
     fn test_v_for_normalization() {
         let result = format_v_for_expression("(item,index) in items");
@@ -173,11 +280,51 @@ mod tests {
 
     #[test]
     fn test_void_elements() {
-        assert!(is_void_element_str("br"));
-        assert!(is_void_element_str("img"));
-        assert!(is_void_element_str("input"));
-        assert!(!is_void_element_str("div"));
-        assert!(!is_void_element_str("span"));
+        let options = FormatOptions::default();
+        let format = |source: &str| format_template_content(source, &options).unwrap();
+        assert_eq!(format("<br>").as_str(), "<br />");
+        assert_eq!(
+            format("<img src=\"a.png\">").as_str(),
+            "<img src=\"a.png\" />"
+        );
+        assert_eq!(format("<input />").as_str(), "<input />");
+
+        let options = FormatOptions {
+            self_closing: SelfClosing {
+                void: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = format_template_content("<br />", &options).unwrap();
+        assert_eq!(result.as_str(), "<br>");
+    }
+
+    #[test]
+    fn test_self_closing_components() {
+        let options = FormatOptions::default();
+        let format = |source: &str| format_template_content(source, &options).unwrap();
+        assert_eq!(format("<Foo></Foo>").as_str(), "<Foo />");
+        assert_eq!(format("<Foo>\n</Foo>").as_str(), "<Foo />");
+        assert_eq!(format("<slot></slot>").as_str(), "<slot />");
+        assert_eq!(format("<Foo />").as_str(), "<Foo />");
+
+        let options = FormatOptions {
+            self_closing: SelfClosing {
+                component: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = format_template_content("<Foo />", &options).unwrap();
+        assert_eq!(result.as_str(), "<Foo></Foo>");
+    }
+
+    #[test]
+    fn test_native_elements_never_self_close() {
+        let options = FormatOptions::default();
+        let result = format_template_content("<div />", &options).unwrap();
+        assert_eq!(result.as_str(), "<div></div>");
     }
 
     #[test]
@@ -456,4 +603,58 @@ mod tests {
             "Narrow print_width should trigger multiline attributes"
         );
     }
+
+    #[test]
+    fn test_long_handler_wrapped_relative_to_attribute() {
+        let source = r#"<button @click="() => { first(); second(); third() }">Go</button>"#;
+        let options = FormatOptions {
+            print_width: 40,
+            ..Default::default()
+        };
+        let result = format_template_content(source, &options).unwrap();
+
+        assert!(
+            result.starts_with("<button\n  @click=\"\n    () => {\n      first();\n"),
+            "{result}"
+        );
+        assert!(
+            result.contains("\n    }\n  \"\n>\n  Go\n</button>"),
+            "{result}"
+        );
+
+        // Formatting the wrapped output again is stable
+        let again = format_template_content(&result, &options).unwrap();
+        assert_eq!(again, result);
+    }
+
+    #[test]
+    fn test_long_v_if_condition_wrapped() {
+        let source = r#"<div><p v-if="someCondition && anotherCondition && yetAnotherCondition">x</p></div>"#;
+        let options = FormatOptions {
+            print_width: 40,
+            ..Default::default()
+        };
+        let result = format_template_content(source, &options).unwrap();
+
+        assert!(
+            result.contains("  <p\n    v-if=\"\n      someCondition &&"),
+            "{result}"
+        );
+        assert!(result.contains("\n    \"\n  >\n"), "{result}");
+        assert!(result.lines().all(|line| line.len() <= 40), "{result}");
+    }
+
+    #[test]
+    fn test_short_expressions_stay_inline() {
+        let source = r#"<p v-if="ok" @click="toggle">x</p>"#;
+        let options = FormatOptions {
+            print_width: 40,
+            ..Default::default()
+        };
+        let result = format_template_content(source, &options).unwrap();
+        assert_eq!(
+            result.as_str(),
+            "<p v-if=\"ok\" @click=\"toggle\">\n  x\n</p>"
+        );
+    }
 }
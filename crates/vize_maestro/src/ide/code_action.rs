@@ -10,6 +10,7 @@ use super::IdeContext;
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit, WorkspaceEdit,
 };
+use vize_relief::LineColumnCache;
 
 /// Code action service for providing quick fixes and refactorings.
 pub struct CodeActionService;
@@ -53,6 +54,7 @@ impl CodeActionService {
 
         // Template block offset in SFC
         let template_start_line = template.loc.start_line as u32;
+        let lines = LineColumnCache::new(&template.content);
 
         for lint_diag in result.diagnostics {
             // Check if diagnostic has a fix
@@ -61,9 +63,8 @@ impl CodeActionService {
             };
 
             // Convert lint diagnostic position to SFC position
-            let (start_line, start_col) =
-                offset_to_line_col(&template.content, lint_diag.start as usize);
-            let (end_line, end_col) = offset_to_line_col(&template.content, lint_diag.end as usize);
+            let (start_line, start_col) = offset_to_line_col(&lines, lint_diag.start as usize);
+            let (end_line, end_col) = offset_to_line_col(&lines, lint_diag.end as usize);
 
             let diag_range = Range {
                 start: Position {
@@ -87,9 +88,9 @@ impl CodeActionService {
                 .iter()
                 .map(|edit| {
                     let (edit_start_line, edit_start_col) =
-                        offset_to_line_col(&template.content, edit.start as usize);
+                        offset_to_line_col(&lines, edit.start as usize);
                     let (edit_end_line, edit_end_col) =
-                        offset_to_line_col(&template.content, edit.end as usize);
+                        offset_to_line_col(&lines, edit.end as usize);
 
                     TextEdit {
                         range: Range {
@@ -160,12 +161,12 @@ impl CodeActionService {
         let result = linter.lint_template(&template.content, ctx.uri.path());
 
         let template_start_line = template.loc.start_line as u32;
+        let lines = LineColumnCache::new(&template.content);
 
         for lint_diag in result.diagnostics {
             // Convert diagnostic position to SFC position
-            let (start_line, start_col) =
-                offset_to_line_col(&template.content, lint_diag.start as usize);
-            let (end_line, end_col) = offset_to_line_col(&template.content, lint_diag.end as usize);
+            let (start_line, start_col) = offset_to_line_col(&lines, lint_diag.start as usize);
+            let (end_line, end_col) = offset_to_line_col(&lines, lint_diag.end as usize);
 
             let diag_range = Range {
                 start: Position {
@@ -246,6 +247,7 @@ impl CodeActionService {
         let result = linter.lint_template(&template.content, ctx.uri.path());
 
         let template_start_line = template.loc.start_line as u32;
+        let lines = LineColumnCache::new(&template.content);
 
         let mut all_edits: Vec<TextEdit> = Vec::new();
 
@@ -253,9 +255,9 @@ impl CodeActionService {
             if let Some(ref fix) = lint_diag.fix {
                 for edit in &fix.edits {
                     let (edit_start_line, edit_start_col) =
-                        offset_to_line_col(&template.content, edit.start as usize);
+                        offset_to_line_col(&lines, edit.start as usize);
                     let (edit_end_line, edit_end_col) =
-                        offset_to_line_col(&template.content, edit.end as usize);
+                        offset_to_line_col(&lines, edit.end as usize);
 
                     all_edits.push(TextEdit {
                         range: Range {
@@ -312,25 +314,9 @@ impl CodeActionService {
 }
 
 /// Convert byte offset to (line, column) - both 0-indexed.
-fn offset_to_line_col(source: &str, offset: usize) -> (u32, u32) {
-    let mut line = 0u32;
-    let mut col = 0u32;
-    let mut current_offset = 0;
-
-    for ch in source.chars() {
-        if current_offset >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
-        }
-        current_offset += ch.len_utf8();
-    }
-
-    (line, col)
+fn offset_to_line_col(lines: &LineColumnCache<'_>, offset: usize) -> (u32, u32) {
+    let pos = lines.position(offset as u32);
+    (pos.line, pos.column)
 }
 
 /// Get the leading whitespace (indentation) for the line containing the given byte offset.
@@ -370,6 +356,7 @@ fn ranges_overlap(a: &Range, b: &Range) -> bool {
 mod tests {
     use super::{offset_to_line_col, ranges_overlap};
     use tower_lsp::lsp_types::{Position, Range};
+    use vize_relief::LineColumnCache;
 
     #[test]
     fn test_ranges_overlap() {
@@ -410,11 +397,11 @@ mod tests {
 
     #[test]
     fn test_offset_to_line_col() {
-        let source = "abc\ndef\nghi";
-        assert_eq!(offset_to_line_col(source, 0), (0, 0));
-        assert_eq!(offset_to_line_col(source, 3), (0, 3));
-        assert_eq!(offset_to_line_col(source, 4), (1, 0));
-        assert_eq!(offset_to_line_col(source, 8), (2, 0));
+        let lines = LineColumnCache::new("abc\ndef\nghi");
+        assert_eq!(offset_to_line_col(&lines, 0), (0, 0));
+        assert_eq!(offset_to_line_col(&lines, 3), (0, 3));
+        assert_eq!(offset_to_line_col(&lines, 4), (1, 0));
+        assert_eq!(offset_to_line_col(&lines, 8), (2, 0));
     }
 
     #[test]
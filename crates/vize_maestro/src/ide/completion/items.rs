@@ -108,6 +108,18 @@ pub(crate) fn directive_item(label: &str, description: &str, snippet: &str) -> C
     }
 }
 
+/// Create a directive modifier completion item.
+#[allow(clippy::disallowed_macros)]
+pub(crate) fn modifier_item(label: &str, description: &str, order: usize) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(CompletionItemKind::ENUM_MEMBER),
+        detail: Some(description.to_string()),
+        sort_text: Some(format!("{:02}", order)),
+        ..Default::default()
+    }
+}
+
 /// Create a @vize: directive completion item.
 #[allow(clippy::disallowed_macros)]
 pub(crate) fn vize_directive_item(label: &str, snippet: &str, description: &str) -> CompletionItem {
@@ -228,6 +240,22 @@ pub(crate) fn attr_item(label: &str, description: &str, snippet: &str) -> Comple
     }
 }
 
+/// Create a completion item for a class defined in a `<style>` block.
+#[allow(clippy::disallowed_macros)]
+pub(crate) fn style_class_item(
+    label: &str,
+    kind: CompletionItemKind,
+    detail: &str,
+) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail: Some(detail.to_string()),
+        sort_text: Some(format!("0{}", label)),
+        ..Default::default()
+    }
+}
+
 /// Create a CSS completion item.
 #[allow(clippy::disallowed_macros)]
 pub(crate) fn css_item(
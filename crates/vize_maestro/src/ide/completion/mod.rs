@@ -122,6 +122,95 @@ mod tests {
         assert!(define_props.is_some());
     }
 
+    #[test]
+    fn test_macro_type_literal_detection() {
+        use script::{macro_type_literal_at, MacroTypeLiteral};
+
+        assert_eq!(
+            macro_type_literal_at("const props = defineProps<{\n  "),
+            Some(MacroTypeLiteral::Props)
+        );
+        assert_eq!(
+            macro_type_literal_at("withDefaults(defineProps<{ foo: string; ba"),
+            Some(MacroTypeLiteral::Props)
+        );
+        assert_eq!(
+            macro_type_literal_at(
+                "defineProps<{\n  cb: (v: number) => void\n  nested: { a: string }\n  "
+            ),
+            Some(MacroTypeLiteral::Props)
+        );
+        assert_eq!(
+            macro_type_literal_at("defineEmits<{\n  (e: 'change', id: number): void\n  "),
+            Some(MacroTypeLiteral::Emits)
+        );
+        assert_eq!(
+            macro_type_literal_at("defineEmits<{ ("),
+            Some(MacroTypeLiteral::Emits)
+        );
+
+        // Type position, nested literal, other generics
+        assert_eq!(macro_type_literal_at("defineProps<{ foo: str"), None);
+        assert_eq!(macro_type_literal_at("defineProps<{ foo: { "), None);
+        assert_eq!(macro_type_literal_at("defineEmits<{ (e: 'a', "), None);
+        assert_eq!(macro_type_literal_at("ref<{ "), None);
+        assert_eq!(macro_type_literal_at("mydefineProps<{ "), None);
+        assert_eq!(
+            macro_type_literal_at("defineProps<{ a: string }>()\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_macro_type_literal_completions() {
+        let props = script::define_props_member_completions();
+        assert!(props
+            .iter()
+            .all(|i| i.insert_text_format == Some(InsertTextFormat::SNIPPET)));
+        assert!(props
+            .iter()
+            .any(|i| i.insert_text.as_deref() == Some("${1:name}?: ${2:string}")));
+
+        let emits = script::define_emits_member_completions();
+        let emit = emits.iter().find(|i| i.label == "emit").unwrap();
+        assert_eq!(
+            emit.insert_text.as_deref(),
+            Some("(e: '${1:name}', ${2:payload}: ${3:unknown}): void")
+        );
+    }
+
+    #[test]
+    fn test_directive_modifier_context() {
+        use template::{directive_modifier_context, ModifierContext};
+
+        assert_eq!(
+            directive_modifier_context("<button @click."),
+            Some((ModifierContext::MouseEvent, vec![]))
+        );
+        assert_eq!(
+            directive_modifier_context("<input v-on:keyup.enter.pre"),
+            Some((ModifierContext::KeyboardEvent, vec!["enter"]))
+        );
+        assert_eq!(
+            directive_modifier_context("<div :title=\"a > b\" @scroll."),
+            Some((ModifierContext::Event, vec![]))
+        );
+        assert_eq!(
+            directive_modifier_context("<input v-model.trim."),
+            Some((ModifierContext::Model, vec!["trim"]))
+        );
+        assert_eq!(
+            directive_modifier_context("<Comp v-model:title."),
+            Some((ModifierContext::Model, vec![]))
+        );
+
+        // Not at a modifier position
+        assert_eq!(directive_modifier_context("<button @click"), None);
+        assert_eq!(directive_modifier_context("<button @click=\"foo."), None);
+        assert_eq!(directive_modifier_context("<div :title.prop."), None);
+        assert_eq!(directive_modifier_context("<div></div> @click."), None);
+    }
+
     #[test]
     fn test_vue_css_completions() {
         let items = style::vue_css_completions();
@@ -196,4 +285,74 @@ mod tests {
         assert!(is_inside_html_comment("<!-- a --> <!-- b", 17));
         assert!(!is_inside_html_comment("<!-- a --> <!-- b --> after", 26));
     }
+
+    #[test]
+    fn test_class_value_context() {
+        use style::ClassValueContext::{Bound, Static};
+
+        assert_eq!(
+            style::class_value_context(r#"<div class="card "#),
+            Some(Static)
+        );
+        assert_eq!(style::class_value_context(r#"<div class='"#), Some(Static));
+        assert_eq!(
+            style::class_value_context(r#"<div :class="{ 'is-"#),
+            Some(Bound { in_string: true })
+        );
+        assert_eq!(
+            style::class_value_context(r#"<div v-bind:class="[$style."#),
+            Some(Bound { in_string: false })
+        );
+
+        // Closed values and other attributes
+        assert_eq!(style::class_value_context(r#"<div class="card" "#), None);
+        assert_eq!(style::class_value_context(r#"<div data-class=""#), None);
+        assert_eq!(style::class_value_context(r#"<div class=card"#), None);
+    }
+
+    #[test]
+    fn test_style_class_completions() {
+        let content = r#"<template>
+  <div class="" :class="[$style.]"></div>
+</template>
+
+<style scoped>
+.card, .card .title { color: red; }
+@media (min-width: 600px) {
+  .wide { width: 100%; }
+}
+</style>
+
+<style module>
+.active { color: blue; }
+.is-open { display: block; }
+</style>
+"#;
+        let classes = style::collect_style_classes(content);
+        let names: Vec<&str> = classes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["card", "title", "wide", "active", "is-open"]);
+        assert!(classes[0].scoped);
+        assert_eq!(classes[3].module.as_deref(), Some("$style"));
+
+        // Static value: plain classes only
+        let offset = content.find(r#"class="""#).unwrap() + 7;
+        let items = style::complete_class_value(content, offset).unwrap();
+        let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels, ["card", "title", "wide"]);
+        assert_eq!(items[0].kind, Some(CompletionItemKind::CLASS));
+        assert_eq!(items[0].detail.as_deref(), Some("<style scoped>"));
+
+        // After `$style.`: identifier-safe module keys
+        let offset = content.find("$style.").unwrap() + 7;
+        let items = style::complete_class_value(content, offset).unwrap();
+        let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels, ["active"]);
+
+        // Elsewhere in the expression: module accessors
+        let offset = content.find("[$style").unwrap() + 1;
+        assert!(style::complete_class_value(content, offset).is_none());
+        let items = style::css_module_completions(content, offset);
+        let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels, ["$style.active", "$style['is-open']"]);
+    }
 }
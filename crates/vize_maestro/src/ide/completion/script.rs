@@ -20,6 +20,13 @@ use crate::ide::IdeContext;
 
 /// Get completions for script context.
 pub(crate) fn complete_script(ctx: &IdeContext, is_setup: bool) -> Vec<CompletionItem> {
+    // Inside `defineProps<{ }>()` / `defineEmits<{ }>()`: only member snippets make sense
+    if is_setup {
+        if let Some(items) = complete_macro_type_literal(ctx) {
+            return items;
+        }
+    }
+
     let mut items_vec = Vec::new();
 
     // Add Vue Composition API
@@ -128,6 +135,124 @@ pub(crate) fn complete_script(ctx: &IdeContext, is_setup: bool) -> Vec<Completio
     items_vec
 }
 
+/// Compiler macro whose type argument the cursor is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MacroTypeLiteral {
+    /// `defineProps<{ ... }>()`
+    Props,
+    /// `defineEmits<{ ... }>()`
+    Emits,
+}
+
+/// Get member snippets when the cursor is at a member position inside
+/// a `defineProps` / `defineEmits` type literal.
+pub(crate) fn complete_macro_type_literal(ctx: &IdeContext) -> Option<Vec<CompletionItem>> {
+    let before = ctx.content.get(..ctx.offset)?;
+    match macro_type_literal_at(before)? {
+        MacroTypeLiteral::Props => Some(define_props_member_completions()),
+        MacroTypeLiteral::Emits => Some(define_emits_member_completions()),
+    }
+}
+
+/// Detect whether `before` (text up to the cursor) ends at a member-name
+/// position directly inside the type literal of `defineProps<{` or `defineEmits<{`.
+///
+/// Uses simple bracket tracking: scanning backwards, the first unmatched bracket
+/// must be the literal's `{`, and it must be preceded by `<` and the macro name.
+pub(crate) fn macro_type_literal_at(before: &str) -> Option<MacroTypeLiteral> {
+    // The member being typed must still be at its name: `|`, `na|`, `(|`
+    let member_start = before
+        .rfind(|c: char| matches!(c, '{' | '}' | ';' | ',' | '\n'))
+        .map_or(0, |i| i + 1);
+    let member = before[member_start..].trim_start();
+    let member = member.strip_prefix('(').unwrap_or(member);
+    if !member
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    {
+        return None;
+    }
+
+    let mut braces = 0usize;
+    let mut parens = 0usize;
+    let mut angles = 0usize;
+    let before = &before[..member_start];
+    for (i, ch) in before.char_indices().rev() {
+        match ch {
+            '}' => braces += 1,
+            ')' => parens += 1,
+            // Skip the `>` of an arrow function type
+            '>' if before[..i].ends_with('=') => {}
+            '>' => angles += 1,
+            '{' if braces > 0 => braces -= 1,
+            '(' if parens > 0 => parens -= 1,
+            '<' if angles > 0 => angles -= 1,
+            '{' if parens == 0 && angles == 0 => {
+                let head = before[..i].trim_end().strip_suffix('<')?.trim_end();
+                let (rest, kind) = if let Some(rest) = head.strip_suffix("defineProps") {
+                    (rest, MacroTypeLiteral::Props)
+                } else if let Some(rest) = head.strip_suffix("defineEmits") {
+                    (rest, MacroTypeLiteral::Emits)
+                } else {
+                    return None;
+                };
+                let is_ident_boundary =
+                    !rest.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+                return is_ident_boundary.then_some(kind);
+            }
+            // Any other unmatched opener means we're nested elsewhere
+            '{' | '(' | '<' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Member snippets for the `defineProps` type literal.
+pub(crate) fn define_props_member_completions() -> Vec<CompletionItem> {
+    vec![
+        items::snippet_item("prop", "Required prop", "${1:name}: ${2:string}"),
+        items::snippet_item("prop?", "Optional prop", "${1:name}?: ${2:string}"),
+        items::snippet_item(
+            "prop boolean",
+            "Optional boolean flag",
+            "${1:disabled}?: boolean",
+        ),
+        items::snippet_item(
+            "prop union",
+            "String literal union prop",
+            "${1:size}?: ${2:'sm' | 'md' | 'lg'}",
+        ),
+        items::snippet_item("prop array", "Array prop", "${1:items}: ${2:string}[]"),
+        items::snippet_item(
+            "prop callback",
+            "Function prop",
+            "${1:onChange}?: (${2:value}: ${3:string}) => void",
+        ),
+    ]
+}
+
+/// Member snippets for the `defineEmits` type literal.
+pub(crate) fn define_emits_member_completions() -> Vec<CompletionItem> {
+    vec![
+        items::snippet_item(
+            "emit",
+            "Event call signature",
+            "(e: '${1:name}', ${2:payload}: ${3:unknown}): void",
+        ),
+        items::snippet_item(
+            "emit (no payload)",
+            "Event call signature without payload",
+            "(e: '${1:name}'): void",
+        ),
+        items::snippet_item(
+            "emit tuple",
+            "Named tuple event (Vue 3.3+)",
+            "${1:name}: [${2:payload}: ${3:unknown}]",
+        ),
+    ]
+}
+
 /// Vue Composition API completions.
 pub(crate) fn composition_api_completions() -> Vec<CompletionItem> {
     vec![
@@ -146,6 +146,23 @@ impl super::CompletionService {
             };
         }
 
+        // Modifiers after `@click.` / `v-model.`
+        if matches!(block_type, BlockType::Template) {
+            if let Some(items) = template::complete_directive_modifiers(ctx) {
+                return Some(CompletionResponse::Array(items));
+            }
+            if let Some(items) = style::complete_class_value(&ctx.content, ctx.offset) {
+                return Some(CompletionResponse::Array(items));
+            }
+        }
+
+        // Member snippets inside `defineProps<{ }>()` / `defineEmits<{ }>()`
+        if matches!(block_type, BlockType::ScriptSetup) {
+            if let Some(items) = script::complete_macro_type_literal(ctx) {
+                return Some(CompletionResponse::Array(items));
+            }
+        }
+
         // Try Corsa completion first.
         if let Some(bridge) = corsa_bridge {
             let corsa_items = match block_type {
@@ -161,7 +178,11 @@ impl super::CompletionService {
             if !corsa_items.is_empty() {
                 let mut items = corsa_items;
                 items.extend(match block_type {
-                    BlockType::Template => template::directive_completions(),
+                    BlockType::Template => {
+                        let mut v = style::css_module_completions(&ctx.content, ctx.offset);
+                        v.extend(template::directive_completions());
+                        v
+                    }
                     BlockType::Script => script::composition_api_completions(),
                     BlockType::ScriptSetup => {
                         let mut v = script::composition_api_completions();
@@ -1,10 +1,19 @@
 //! Style completion provider.
 //!
-//! Handles completions within `<style>` blocks including Vue CSS features.
+//! Handles completions within `<style>` blocks including Vue CSS features,
+//! and completions of the classes those blocks define inside template
+//! `class` / `:class` values.
+#![allow(
+    clippy::disallowed_types,
+    clippy::disallowed_methods,
+    clippy::disallowed_macros
+)]
 
-use tower_lsp::lsp_types::CompletionItem;
+use lightningcss::selector::Component;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
 
 use super::items;
+use crate::ide::style_rules::{for_each_css_style, for_each_style_rule};
 use crate::ide::IdeContext;
 
 /// Get completions for style context.
@@ -31,3 +40,181 @@ pub(crate) fn vue_css_completions() -> Vec<CompletionItem> {
         items::css_item(":global", ":global()", "Global selector", ":global($1)"),
     ]
 }
+
+/// Where the cursor sits inside a template `class` attribute value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClassValueContext {
+    /// Inside a static `class="..."` value.
+    Static,
+    /// Inside a `:class="..."` expression.
+    Bound {
+        /// Whether the cursor is within a string literal of the expression.
+        in_string: bool,
+    },
+}
+
+/// A class selector defined in one of the SFC's `<style>` blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StyleClass {
+    /// Class name without the leading dot.
+    pub name: String,
+    /// CSS module name (`$style` for a bare `module` attribute).
+    pub module: Option<String>,
+    /// Whether the defining block is `scoped`.
+    pub scoped: bool,
+}
+
+/// Detect whether the text before the cursor ends inside a `class` value.
+pub(crate) fn class_value_context(before: &str) -> Option<ClassValueContext> {
+    let attr = before.rfind("class=")?;
+    let value_start = attr + "class=".len();
+    let quote = before[value_start..]
+        .chars()
+        .next()
+        .filter(|c| *c == '"' || *c == '\'')?;
+    let value = &before[value_start + 1..];
+    if value.contains(quote) {
+        return None;
+    }
+
+    let prefix = &before[..attr];
+    if prefix.ends_with(|c: char| c.is_ascii_whitespace()) {
+        if value.contains(['<', '>']) {
+            return None;
+        }
+        Some(ClassValueContext::Static)
+    } else if prefix.ends_with(':') {
+        // `:class` and `v-bind:class`
+        let inner = if quote == '"' { '\'' } else { '"' };
+        let in_string =
+            value.matches(inner).count() % 2 == 1 || value.matches('`').count() % 2 == 1;
+        Some(ClassValueContext::Bound { in_string })
+    } else {
+        None
+    }
+}
+
+/// Collect the class selectors of every CSS `<style>` block in the SFC.
+pub(crate) fn collect_style_classes(content: &str) -> Vec<StyleClass> {
+    let mut classes: Vec<StyleClass> = Vec::new();
+    for_each_css_style(content, |style, stylesheet| {
+        let module = style.module.as_ref().map(|module| module.to_string());
+        for_each_style_rule(&stylesheet.rules.0, &mut |_, style_rule| {
+            for selector in &style_rule.selectors.0 {
+                for component in selector.iter_raw_match_order() {
+                    let Component::Class(class) = component else {
+                        continue;
+                    };
+                    let name: &str = &class.0;
+                    if !classes
+                        .iter()
+                        .any(|class| class.name == name && class.module == module)
+                    {
+                        classes.push(StyleClass {
+                            name: name.to_string(),
+                            module: module.clone(),
+                            scoped: style.scoped,
+                        });
+                    }
+                }
+            }
+        });
+    });
+    classes
+}
+
+/// Get class name completions inside a `class` / `:class` value.
+///
+/// Returns `None` when the cursor is not in a position where only style
+/// classes make sense, so the caller can fall back to other completions.
+pub(crate) fn complete_class_value(content: &str, offset: usize) -> Option<Vec<CompletionItem>> {
+    let before = content.get(..offset)?;
+    let context = class_value_context(before)?;
+
+    let items: Vec<CompletionItem> = match context {
+        // CSS module classes are hashed, so only plain classes apply here
+        ClassValueContext::Static | ClassValueContext::Bound { in_string: true } => {
+            collect_style_classes(content)
+                .into_iter()
+                .filter(|class| class.module.is_none())
+                .map(|class| {
+                    let detail = if class.scoped {
+                        "<style scoped>"
+                    } else {
+                        "<style>"
+                    };
+                    items::style_class_item(&class.name, CompletionItemKind::CLASS, detail)
+                })
+                .collect()
+        }
+        // Keys after `$style.`
+        ClassValueContext::Bound { in_string: false } => {
+            let object = member_access_object(before)?;
+            collect_style_classes(content)
+                .into_iter()
+                .filter(|class| {
+                    class.module.as_deref() == Some(object) && is_identifier(&class.name)
+                })
+                .map(|class| {
+                    let detail = format!("CSS module `{}`", object);
+                    items::style_class_item(&class.name, CompletionItemKind::PROPERTY, &detail)
+                })
+                .collect()
+        }
+    };
+
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+/// Get `$style.foo` completions inside a `:class` expression.
+pub(crate) fn css_module_completions(content: &str, offset: usize) -> Vec<CompletionItem> {
+    let Some(before) = content.get(..offset) else {
+        return vec![];
+    };
+    if class_value_context(before) != Some(ClassValueContext::Bound { in_string: false }) {
+        return vec![];
+    }
+
+    collect_style_classes(content)
+        .into_iter()
+        .filter_map(|class| {
+            let module = class.module?;
+            let label = if is_identifier(&class.name) {
+                format!("{}.{}", module, class.name)
+            } else {
+                format!("{}['{}']", module, class.name)
+            };
+            let detail = format!("CSS module `{}`", module);
+            Some(items::style_class_item(
+                &label,
+                CompletionItemKind::PROPERTY,
+                &detail,
+            ))
+        })
+        .collect()
+}
+
+/// Get `obj` when the text before the cursor ends with `obj.` plus a
+/// partially typed member name.
+fn member_access_object(before: &str) -> Option<&str> {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    let before = before.trim_end_matches(is_ident_char).strip_suffix('.')?;
+    let start = before
+        .rfind(|c: char| !is_ident_char(c))
+        .map_or(0, |index| index + 1);
+    let object = &before[start..];
+    (!object.is_empty()).then_some(object)
+}
+
+/// Check whether a class name can be used as a dotted member key.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
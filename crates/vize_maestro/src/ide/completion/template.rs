@@ -16,7 +16,7 @@ use vize_croquis::{Analyzer, AnalyzerOptions};
 
 use super::{
     is_inside_art_tag, is_inside_html_comment, is_inside_variant_tag, items,
-    should_suggest_art_block, should_suggest_variant_block,
+    should_suggest_art_block, should_suggest_variant_block, style,
 };
 use crate::ide::IdeContext;
 
@@ -27,6 +27,16 @@ pub(crate) fn complete_template(ctx: &IdeContext) -> Vec<CompletionItem> {
         return vize_directive_completions();
     }
 
+    // After `@click.` / `v-model.`, only modifiers make sense
+    if let Some(items) = complete_directive_modifiers(ctx) {
+        return items;
+    }
+
+    // Classes from `<style>` blocks inside `class="..."` / `:class="'...'"`
+    if let Some(items) = style::complete_class_value(&ctx.content, ctx.offset) {
+        return items;
+    }
+
     let mut items_vec = Vec::new();
 
     // Add Vue directives
@@ -51,7 +61,7 @@ pub(crate) fn complete_template(ctx: &IdeContext) -> Vec<CompletionItem> {
             let croquis = analyzer.finish();
 
             // Add bindings with accurate type information
-            for (name, binding_type) in croquis.bindings.iter() {
+            for (name, binding_type) in croquis.bindings.template_bindings() {
                 let (kind, type_detail, doc) = items::binding_type_to_completion_info(binding_type);
                 #[allow(clippy::disallowed_macros)]
                 items_vec.push(CompletionItem {
@@ -141,6 +151,9 @@ pub(crate) fn complete_template(ctx: &IdeContext) -> Vec<CompletionItem> {
         }
     }
 
+    // Add `$style.foo` keys of CSS modules inside `:class`
+    items_vec.extend(style::css_module_completions(&ctx.content, ctx.offset));
+
     // Add common template snippets
     items_vec.extend(template_snippets());
 
@@ -283,6 +296,151 @@ pub(crate) fn builtin_component_completions() -> Vec<CompletionItem> {
     ]
 }
 
+/// Directive whose modifier list the cursor is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModifierContext {
+    /// `@keyup.` / `v-on:keydown.`
+    KeyboardEvent,
+    /// `@click.` / `v-on:mousedown.`
+    MouseEvent,
+    /// Any other `@event.`
+    Event,
+    /// `v-model.`
+    Model,
+}
+
+/// Generic `v-on` modifiers.
+const EVENT_MODIFIERS: &[(&str, &str)] = &[
+    ("stop", "Call `event.stopPropagation()`"),
+    ("prevent", "Call `event.preventDefault()`"),
+    ("self", "Only trigger if dispatched from this element"),
+    ("capture", "Add the listener in capture mode"),
+    ("once", "Trigger the handler at most once"),
+    ("passive", "Attach the listener with `{ passive: true }`"),
+];
+
+/// Key modifiers for keyboard events.
+const KEY_MODIFIERS: &[(&str, &str)] = &[
+    ("enter", "Only trigger on the Enter key"),
+    ("tab", "Only trigger on the Tab key"),
+    ("delete", "Only trigger on the Delete or Backspace key"),
+    ("esc", "Only trigger on the Escape key"),
+    ("space", "Only trigger on the Space key"),
+    ("up", "Only trigger on the Up arrow key"),
+    ("down", "Only trigger on the Down arrow key"),
+    ("left", "Only trigger on the Left arrow key"),
+    ("right", "Only trigger on the Right arrow key"),
+];
+
+/// Mouse button modifiers for mouse events.
+const MOUSE_MODIFIERS: &[(&str, &str)] = &[
+    ("left", "Only trigger for the left mouse button"),
+    ("right", "Only trigger for the right mouse button"),
+    ("middle", "Only trigger for the middle mouse button"),
+];
+
+/// System modifier keys, valid for keyboard and mouse events.
+const SYSTEM_MODIFIERS: &[(&str, &str)] = &[
+    ("ctrl", "Only trigger while Ctrl is pressed"),
+    ("alt", "Only trigger while Alt is pressed"),
+    ("shift", "Only trigger while Shift is pressed"),
+    ("meta", "Only trigger while Meta is pressed"),
+    ("exact", "Require exactly these system modifiers"),
+];
+
+/// `v-model` modifiers.
+const MODEL_MODIFIERS: &[(&str, &str)] = &[
+    ("lazy", "Sync on `change` instead of `input`"),
+    ("number", "Cast the input value to a number"),
+    ("trim", "Trim whitespace from the input value"),
+];
+
+/// Get modifier completions when the cursor follows a `.` in a
+/// `v-on` or `v-model` attribute name.
+pub(crate) fn complete_directive_modifiers(ctx: &IdeContext) -> Option<Vec<CompletionItem>> {
+    let before = ctx.content.get(..ctx.offset)?;
+    let (context, used) = directive_modifier_context(before)?;
+
+    let sets: &[&[(&str, &str)]] = match context {
+        ModifierContext::KeyboardEvent => &[EVENT_MODIFIERS, KEY_MODIFIERS, SYSTEM_MODIFIERS],
+        ModifierContext::MouseEvent => &[EVENT_MODIFIERS, MOUSE_MODIFIERS, SYSTEM_MODIFIERS],
+        ModifierContext::Event => &[EVENT_MODIFIERS],
+        ModifierContext::Model => &[MODEL_MODIFIERS],
+    };
+
+    let items = sets
+        .iter()
+        .flat_map(|set| set.iter())
+        .filter(|(label, _)| !used.contains(label))
+        .enumerate()
+        .map(|(i, (label, description))| items::modifier_item(label, description, i))
+        .collect();
+    Some(items)
+}
+
+/// Detect whether `before` (text up to the cursor) ends inside the modifier
+/// list of a directive attribute name, e.g. `<input @keyup.enter.` or
+/// `<input v-model.`.
+///
+/// Returns the modifier context and the modifiers already present.
+pub(crate) fn directive_modifier_context(before: &str) -> Option<(ModifierContext, Vec<&str>)> {
+    let attr_start = before
+        .rfind(|c: char| c.is_ascii_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '='))
+        .map(|i| i + 1)?;
+    if !before[..attr_start].ends_with(|c: char| c.is_ascii_whitespace())
+        || !is_inside_start_tag(&before[..attr_start])
+    {
+        return None;
+    }
+
+    let attr = &before[attr_start..];
+    let mut parts = attr.split('.');
+    let head = parts.next()?;
+    let mut modifiers: Vec<&str> = parts.collect();
+    // The last segment is the modifier being typed
+    let typed = modifiers.pop()?;
+    if !typed.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+
+    let context = if let Some(event) = head
+        .strip_prefix('@')
+        .or_else(|| head.strip_prefix("v-on:"))
+    {
+        match event {
+            "keydown" | "keyup" | "keypress" => ModifierContext::KeyboardEvent,
+            "click" | "dblclick" | "contextmenu" | "mousedown" | "mouseup" => {
+                ModifierContext::MouseEvent
+            }
+            _ => ModifierContext::Event,
+        }
+    } else if head == "v-model" || head.starts_with("v-model:") {
+        ModifierContext::Model
+    } else {
+        return None;
+    };
+
+    Some((context, modifiers))
+}
+
+/// Check whether `before` ends inside an unclosed start tag.
+fn is_inside_start_tag(before: &str) -> bool {
+    let Some(tag_start) = before.rfind('<') else {
+        return false;
+    };
+    let mut quote = None;
+    for c in before[tag_start..].chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return false,
+            None => {}
+        }
+    }
+    quote.is_none()
+}
+
 /// Template snippet completions.
 fn template_snippets() -> Vec<CompletionItem> {
     vec![
@@ -10,6 +10,7 @@ use tower_lsp::lsp_types::{
 };
 
 use vize_patina::{render_help, HelpRenderTarget};
+use vize_relief::LineColumnCache;
 
 use super::{offset_to_line_col, sources, DiagnosticService};
 use vize_carton::append;
@@ -19,6 +20,8 @@ impl DiagnosticService {
     pub(super) fn collect_musea_diagnostics(_uri: &Url, content: &str) -> Vec<Diagnostic> {
         use vize_patina::rules::musea::MuseaLinter;
 
+        let lines = LineColumnCache::new(content);
+
         let linter = MuseaLinter::new();
         let result = linter.lint(content);
 
@@ -27,8 +30,8 @@ impl DiagnosticService {
             .into_iter()
             .map(|lint_diag| {
                 // Convert byte offset to line/column
-                let (start_line, start_col) = offset_to_line_col(content, lint_diag.start as usize);
-                let (end_line, end_col) = offset_to_line_col(content, lint_diag.end as usize);
+                let (start_line, start_col) = offset_to_line_col(&lines, lint_diag.start as usize);
+                let (end_line, end_col) = offset_to_line_col(&lines, lint_diag.end as usize);
 
                 // Build the diagnostic message with help text (render as plain text for LSP)
                 #[allow(clippy::disallowed_macros)]
@@ -76,6 +79,8 @@ impl DiagnosticService {
     pub(super) fn collect_inline_art_diagnostics(uri: &Url, content: &str) -> Vec<Diagnostic> {
         use vize_patina::rules::musea::MuseaLinter;
 
+        let lines = LineColumnCache::new(content);
+
         let options = vize_atelier_sfc::SfcParseOptions {
             filename: uri.path().to_string().into(),
             ..Default::default()
@@ -119,9 +124,9 @@ impl DiagnosticService {
                 // Only process diagnostics that fall within the content area
                 if (lint_diag.start as usize) < art_tag_prefix_len {
                     // Diagnostic is on the <art> tag itself - map to the original tag
-                    let (start_line, start_col) = offset_to_line_col(content, custom.loc.tag_start);
+                    let (start_line, start_col) = offset_to_line_col(&lines, custom.loc.tag_start);
                     let (end_line, end_col) =
-                        offset_to_line_col(content, custom.loc.tag_end.min(content.len()));
+                        offset_to_line_col(&lines, custom.loc.tag_end.min(content.len()));
 
                     #[allow(clippy::disallowed_macros)]
                     let message = if let Some(ref help) = lint_diag.help {
@@ -165,9 +170,9 @@ impl DiagnosticService {
                     let sfc_end = block_content_start + content_relative_end;
 
                     let (start_line, start_col) =
-                        offset_to_line_col(content, sfc_start.min(content.len()));
+                        offset_to_line_col(&lines, sfc_start.min(content.len()));
                     let (end_line, end_col) =
-                        offset_to_line_col(content, sfc_end.min(content.len()));
+                        offset_to_line_col(&lines, sfc_end.min(content.len()));
 
                     #[allow(clippy::disallowed_macros)]
                     let message = if let Some(ref help) = lint_diag.help {
@@ -312,6 +317,7 @@ impl DiagnosticService {
         // Create linter and lint the template content
         let linter = vize_patina::Linter::new();
         let result = linter.lint_template(&template.content, uri.path());
+        let lines = LineColumnCache::new(&template.content);
 
         // Convert lint diagnostics to LSP diagnostics
         result
@@ -319,10 +325,8 @@ impl DiagnosticService {
             .into_iter()
             .map(|lint_diag| {
                 // Convert byte offset to line/column within template
-                let (start_line, start_col) =
-                    offset_to_line_col(&template.content, lint_diag.start as usize);
-                let (end_line, end_col) =
-                    offset_to_line_col(&template.content, lint_diag.end as usize);
+                let (start_line, start_col) = offset_to_line_col(&lines, lint_diag.start as usize);
+                let (end_line, end_col) = offset_to_line_col(&lines, lint_diag.end as usize);
 
                 // Adjust line numbers based on template block position in SFC
                 let sfc_start_line = template.loc.start_line as u32 + start_line;
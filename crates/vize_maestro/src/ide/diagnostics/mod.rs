@@ -12,6 +12,7 @@ mod collectors;
 mod corsa;
 
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range, Url};
+use vize_relief::LineColumnCache;
 
 use crate::server::ServerState;
 
@@ -25,6 +26,54 @@ pub mod sources {
     pub const MUSEA: &str = "vize/musea";
 }
 
+/// Diagnostic sources that can be toggled through LSP initialization options.
+///
+/// Read from the `diagnostics` section, e.g.
+/// `{ "diagnostics": { "typescript": false } }`. Every source is enabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+pub struct DiagnosticsConfig {
+    /// Type checker diagnostics (vize_canon and Corsa)
+    pub typescript: bool,
+    /// Linter diagnostics (vize_patina, including Musea rules)
+    pub lint: bool,
+    /// SFC and template parser diagnostics
+    pub compiler: bool,
+}
+
+impl Default for DiagnosticsConfig {
+    fn default() -> Self {
+        Self {
+            typescript: true,
+            lint: true,
+            compiler: true,
+        }
+    }
+}
+
+impl DiagnosticsConfig {
+    /// Read the config from LSP initialization options.
+    ///
+    /// A plain boolean (`"diagnostics": false`) toggles every source at once.
+    /// Falls back to the defaults when the section is missing or malformed.
+    pub fn from_initialization_options(options: Option<&serde_json::Value>) -> Self {
+        let Some(value) = options.and_then(|o| o.get("diagnostics")) else {
+            return Self::default();
+        };
+        if let Some(enabled) = value.as_bool() {
+            return Self {
+                typescript: enabled,
+                lint: enabled,
+                compiler: enabled,
+            };
+        }
+        serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            tracing::warn!("invalid diagnostics initialization options: {}", e);
+            Self::default()
+        })
+    }
+}
+
 /// Diagnostic severity levels.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Severity {
@@ -85,61 +134,93 @@ impl DiagnosticService {
         };
 
         let content = doc.text();
+        let config = state.get_diagnostics_config();
         let mut diagnostics = Vec::new();
 
         // Check if this is an Art file (*.art.vue)
         let path = uri.path();
         if path.ends_with(".art.vue") {
             // Musea-specific diagnostics for Art files
-            diagnostics.extend(Self::collect_musea_diagnostics(uri, &content));
+            if config.lint {
+                diagnostics.extend(Self::collect_musea_diagnostics(uri, &content));
+            }
             // Don't return early here; async collection still adds Corsa diagnostics.
             return diagnostics;
         }
 
         // Standard SFC processing
-        // Collect SFC parser diagnostics
-        let sfc_diags = Self::collect_sfc_diagnostics(uri, &content);
-        tracing::info!("collect: SFC parser diagnostics: {}", sfc_diags.len());
-        diagnostics.extend(sfc_diags);
-
-        // Collect template parser diagnostics
-        let template_diags = Self::collect_template_diagnostics(uri, &content);
-        tracing::info!(
-            "collect: template parser diagnostics: {}",
-            template_diags.len()
-        );
-        diagnostics.extend(template_diags);
-
-        // Collect linter diagnostics (vize_patina)
-        let lint_diags = Self::collect_lint_diagnostics(uri, &content);
-        tracing::info!("collect: patina lint diagnostics: {}", lint_diags.len());
-        diagnostics.extend(lint_diags);
-
-        // Collect type checker diagnostics (vize_canon)
-        let type_diags = super::TypeService::collect_diagnostics(state, uri);
-        tracing::info!("collect: type checker diagnostics: {}", type_diags.len());
-        diagnostics.extend(type_diags);
-
-        // Also lint inline <art> blocks in regular .vue files
-        let inline_art_diags = Self::collect_inline_art_diagnostics(uri, &content);
-        tracing::info!(
-            "collect: inline art diagnostics: {}",
-            inline_art_diags.len()
-        );
-        diagnostics.extend(inline_art_diags);
+        if config.compiler {
+            // Collect SFC parser diagnostics
+            let sfc_diags = Self::collect_sfc_diagnostics(uri, &content);
+            tracing::info!("collect: SFC parser diagnostics: {}", sfc_diags.len());
+            diagnostics.extend(sfc_diags);
+
+            // Collect template parser diagnostics
+            let template_diags = Self::collect_template_diagnostics(uri, &content);
+            tracing::info!(
+                "collect: template parser diagnostics: {}",
+                template_diags.len()
+            );
+            diagnostics.extend(template_diags);
+        }
+
+        if config.lint {
+            // Collect linter diagnostics (vize_patina)
+            let lint_diags = Self::collect_lint_diagnostics(uri, &content);
+            tracing::info!("collect: patina lint diagnostics: {}", lint_diags.len());
+            diagnostics.extend(lint_diags);
+        }
+
+        if config.typescript {
+            // Collect type checker diagnostics (vize_canon)
+            let type_diags = super::TypeService::collect_diagnostics(state, uri);
+            tracing::info!("collect: type checker diagnostics: {}", type_diags.len());
+            diagnostics.extend(type_diags);
+        }
+
+        if config.lint {
+            // Also lint inline <art> blocks in regular .vue files
+            let inline_art_diags = Self::collect_inline_art_diagnostics(uri, &content);
+            tracing::info!(
+                "collect: inline art diagnostics: {}",
+                inline_art_diags.len()
+            );
+            diagnostics.extend(inline_art_diags);
+        }
 
         diagnostics
     }
 
     /// Collect diagnostics asynchronously (includes Corsa diagnostics when available).
+    ///
+    /// Returns `None` when the request went stale while waiting for Corsa: a newer
+    /// request for the same document was started, or the document was edited or
+    /// closed. The caller should drop the result instead of publishing it.
     #[cfg(feature = "native")]
-    pub async fn collect_async(state: &ServerState, uri: &Url) -> Vec<Diagnostic> {
+    pub async fn collect_async(state: &ServerState, uri: &Url) -> Option<Vec<Diagnostic>> {
         tracing::info!("collect_async: {}", uri);
 
+        let token = state.begin_diagnostics_request(uri);
+        let version = state.documents.get(uri).map(|doc| doc.version);
+        let is_current = || {
+            state.is_current_diagnostics_request(uri, token)
+                && state.documents.get(uri).map(|doc| doc.version) == version
+        };
+
         // Start with sync diagnostics (patina, etc.)
         let mut diagnostics = Self::collect(state, uri);
         tracing::info!("sync diagnostics count: {}", diagnostics.len());
 
+        if !state.get_diagnostics_config().typescript {
+            return Some(diagnostics);
+        }
+
+        // Don't start a type check for a request that was already superseded.
+        if !is_current() {
+            tracing::info!("collect_async: skipping stale request for {}", uri);
+            return None;
+        }
+
         // Try to get Corsa diagnostics (with timeout, skip on failure).
         // Use 10s timeout - polling for diagnostics internally uses 5s
         let corsa_future = Self::collect_corsa_diagnostics(state, uri);
@@ -153,7 +234,13 @@ impl DiagnosticService {
             }
         }
 
-        diagnostics
+        // The document changed while Corsa was running; its result is outdated.
+        if !is_current() {
+            tracing::info!("collect_async: discarding stale diagnostics for {}", uri);
+            return None;
+        }
+
+        Some(diagnostics)
     }
 
     /// Create a diagnostic from a custom error.
@@ -255,31 +342,16 @@ impl DiagnosticBuilder {
 }
 
 /// Convert byte offset to (line, column) - both 0-indexed for LSP.
-pub(super) fn offset_to_line_col(source: &str, offset: usize) -> (u32, u32) {
-    let mut line = 0u32;
-    let mut col = 0u32;
-    let mut current_offset = 0;
-
-    for ch in source.chars() {
-        if current_offset >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
-        }
-        current_offset += ch.len_utf8();
-    }
-
-    (line, col)
+pub(super) fn offset_to_line_col(lines: &LineColumnCache<'_>, offset: usize) -> (u32, u32) {
+    let pos = lines.position(offset as u32);
+    (pos.line, pos.column)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DiagnosticBuilder, Severity};
-    use tower_lsp::lsp_types::{DiagnosticSeverity, NumberOrString};
+    use super::{DiagnosticBuilder, DiagnosticService, DiagnosticsConfig, Severity};
+    use crate::server::ServerState;
+    use tower_lsp::lsp_types::{DiagnosticSeverity, NumberOrString, Url};
 
     #[test]
     fn test_diagnostic_builder() {
@@ -314,4 +386,58 @@ mod tests {
             DiagnosticSeverity::HINT
         );
     }
+
+    #[test]
+    fn test_diagnostics_config_from_initialization_options() {
+        assert_eq!(
+            DiagnosticsConfig::from_initialization_options(None),
+            DiagnosticsConfig::default()
+        );
+
+        let options = serde_json::json!({ "diagnostics": { "typescript": false } });
+        let config = DiagnosticsConfig::from_initialization_options(Some(&options));
+        assert!(!config.typescript);
+        assert!(config.lint);
+        assert!(config.compiler);
+
+        let disabled = serde_json::json!({ "diagnostics": false });
+        let config = DiagnosticsConfig::from_initialization_options(Some(&disabled));
+        assert!(!config.typescript && !config.lint && !config.compiler);
+
+        let malformed = serde_json::json!({ "diagnostics": { "lint": "off" } });
+        assert_eq!(
+            DiagnosticsConfig::from_initialization_options(Some(&malformed)),
+            DiagnosticsConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_collect_skips_disabled_sources() {
+        let state = ServerState::new();
+        let uri = Url::parse("file:///test.vue").unwrap();
+        state.documents.open(
+            uri.clone(),
+            "<template><div></template>".to_string(),
+            1,
+            "vue".to_string(),
+        );
+
+        let has_compiler_diag = |diags: &[tower_lsp::lsp_types::Diagnostic]| {
+            diags.iter().any(|d| {
+                matches!(
+                    d.source.as_deref(),
+                    Some(super::sources::SFC_PARSER | super::sources::TEMPLATE_PARSER)
+                )
+            })
+        };
+        assert!(has_compiler_diag(&DiagnosticService::collect(&state, &uri)));
+
+        state.set_diagnostics_config(DiagnosticsConfig {
+            compiler: false,
+            ..Default::default()
+        });
+        assert!(!has_compiler_diag(&DiagnosticService::collect(
+            &state, &uri
+        )));
+    }
 }
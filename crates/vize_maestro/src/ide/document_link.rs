@@ -3,14 +3,16 @@
 //! Provides clickable links for:
 //! - Import statements in script blocks
 //! - src attributes on script/style/template blocks
-#![allow(clippy::disallowed_types, clippy::disallowed_methods)]
 //! - CSS @import statements
+#![allow(clippy::disallowed_types, clippy::disallowed_methods)]
 
 use std::path::Path;
 
 use tower_lsp::lsp_types::{DocumentLink, Position, Range, Url};
+use vize_atelier_sfc::BlockLocation;
+use vize_relief::LineColumnCache;
 
-use super::offset_to_position;
+use super::file_rename::{collect_module_specifiers, normalize_path_buf, script_source_type};
 
 /// Document link service.
 pub struct DocumentLinkService;
@@ -30,79 +32,40 @@ impl DocumentLinkService {
         };
 
         let base_path = uri.to_file_path().ok();
+        let base_path = base_path.as_deref();
+        let lines = LineColumnCache::new(content);
 
-        // Collect links from script setup
-        if let Some(ref script_setup) = descriptor.script_setup {
-            // src attribute
-            if let Some(ref src) = script_setup.src {
-                if let Some((start, end)) =
-                    Self::find_src_attr_range(content, script_setup.loc.start)
-                {
-                    if let Some(target) = Self::resolve_path(src, base_path.as_deref()) {
-                        links.push(Self::create_link(content, start, end, target));
-                    }
-                }
-            }
-
-            // Import statements
-            Self::collect_import_links(
-                &script_setup.content,
-                script_setup.loc.start,
-                content,
-                base_path.as_deref(),
-                &mut links,
-            );
-        }
-
-        // Collect links from script
-        if let Some(ref script) = descriptor.script {
-            // src attribute
+        for script in descriptor.script_setup.iter().chain(&descriptor.script) {
             if let Some(ref src) = script.src {
-                if let Some((start, end)) = Self::find_src_attr_range(content, script.loc.start) {
-                    if let Some(target) = Self::resolve_path(src, base_path.as_deref()) {
-                        links.push(Self::create_link(content, start, end, target));
-                    }
-                }
+                Self::collect_src_link(src, &script.loc, &lines, base_path, &mut links);
             }
 
-            // Import statements
             Self::collect_import_links(
                 &script.content,
                 script.loc.start,
-                content,
-                base_path.as_deref(),
+                script_source_type(script.lang.as_deref()),
+                &lines,
+                base_path,
                 &mut links,
             );
         }
 
-        // Collect links from template src
         if let Some(ref template) = descriptor.template {
             if let Some(ref src) = template.src {
-                if let Some((start, end)) = Self::find_src_attr_range(content, template.loc.start) {
-                    if let Some(target) = Self::resolve_path(src, base_path.as_deref()) {
-                        links.push(Self::create_link(content, start, end, target));
-                    }
-                }
+                Self::collect_src_link(src, &template.loc, &lines, base_path, &mut links);
             }
         }
 
-        // Collect links from styles
         for style in &descriptor.styles {
-            // src attribute
             if let Some(ref src) = style.src {
-                if let Some((start, end)) = Self::find_src_attr_range(content, style.loc.start) {
-                    if let Some(target) = Self::resolve_path(src, base_path.as_deref()) {
-                        links.push(Self::create_link(content, start, end, target));
-                    }
-                }
+                Self::collect_src_link(src, &style.loc, &lines, base_path, &mut links);
             }
 
-            // @import statements
             Self::collect_css_import_links(
                 &style.content,
                 style.loc.start,
-                content,
-                base_path.as_deref(),
+                &lines,
+                base_path,
                 &mut links,
             );
         }
@@ -110,89 +73,45 @@ impl DocumentLinkService {
         links
     }
 
+    /// Collect the link for a block's `src` attribute.
+    fn collect_src_link(
+        src: &str,
+        loc: &BlockLocation,
+        lines: &LineColumnCache<'_>,
+        base_path: Option<&Path>,
+        links: &mut Vec<DocumentLink>,
+    ) {
+        let Some((start, end)) = Self::find_src_attr_range(lines.source(), loc) else {
+            return;
+        };
+        if let Some(target) = Self::resolve_path(src, base_path) {
+            links.push(Self::create_link(lines, start, end, target));
+        }
+    }
+
     /// Collect import statement links from script content.
+    ///
+    /// Covers static imports, re-exports and dynamic imports; only relative
+    /// specifiers are linked.
     fn collect_import_links(
         script: &str,
         base_offset: usize,
-        full_content: &str,
+        source_type: oxc_span::SourceType,
+        lines: &LineColumnCache<'_>,
         base_path: Option<&Path>,
         links: &mut Vec<DocumentLink>,
     ) {
-        // Match: import ... from "path" or import ... from 'path'
-        // Match: import "path" or import 'path' (side-effect imports)
-        // Match: export ... from "path" or export ... from 'path'
-        let mut pos = 0;
-        let bytes = script.as_bytes();
-
-        while pos < script.len() {
-            // Find "import" or "export"
-            let import_start = script[pos..].find("import");
-            let export_start = script[pos..].find("export");
-
-            let keyword_start = match (import_start, export_start) {
-                (Some(i), Some(e)) => Some(pos + i.min(e)),
-                (Some(i), None) => Some(pos + i),
-                (None, Some(e)) => Some(pos + e),
-                (None, None) => None,
-            };
-
-            let Some(start) = keyword_start else {
-                break;
-            };
-
-            // Check it's at word boundary
-            if start > 0 && Self::is_ident_char(bytes[start - 1] as char) {
-                pos = start + 1;
+        for occurrence in collect_module_specifiers(script, source_type) {
+            let path = occurrence.specifier.as_str();
+            if !path.starts_with('.') && !path.starts_with('/') {
                 continue;
             }
-
-            // Find the end of this statement (semicolon or newline)
-            let stmt_end = script[start..]
-                .find([';', '\n'])
-                .map(|i| start + i)
-                .unwrap_or(script.len());
-
-            let stmt = &script[start..stmt_end];
-
-            // Find string literal (the path)
-            if let Some((path, rel_start, rel_end)) = Self::extract_import_path(stmt) {
-                // Only link relative imports (start with . or /)
-                if path.starts_with('.') || path.starts_with('/') {
-                    if let Some(target) = Self::resolve_path(&path, base_path) {
-                        let abs_start = base_offset + start + rel_start;
-                        let abs_end = base_offset + start + rel_end;
-                        links.push(Self::create_link(full_content, abs_start, abs_end, target));
-                    }
-                }
-            }
-
-            pos = stmt_end + 1;
-        }
-    }
-
-    /// Extract import path from an import/export statement.
-    /// Returns (path, start, end_offset) where offsets are relative to stmt start.
-    fn extract_import_path(stmt: &str) -> Option<(String, usize, usize)> {
-        // Look for 'from' keyword followed by string
-        if let Some(from_pos) = stmt.find(" from ") {
-            let after_from = &stmt[from_pos + 6..];
-            return Self::extract_string_literal(after_from)
-                .map(|(path, s, e)| (path, from_pos + 6 + s, from_pos + 6 + e));
-        }
-
-        // Side-effect import: import "path"
-        if stmt.starts_with("import ") || stmt.starts_with("import\t") {
-            let after_import = &stmt[7..];
-            // Check if it's a side-effect import (no identifier before the string)
-            let trimmed = after_import.trim_start();
-            if trimmed.starts_with('"') || trimmed.starts_with('\'') {
-                let ws_len = after_import.len() - trimmed.len();
-                return Self::extract_string_literal(trimmed)
-                    .map(|(path, s, e)| (path, 7 + ws_len + s, 7 + ws_len + e));
+            if let Some(target) = Self::resolve_path(path, base_path) {
+                let start = base_offset + occurrence.start;
+                let end = base_offset + occurrence.end;
+                links.push(Self::create_link(lines, start, end, target));
             }
         }
-
-        None
     }
 
     /// Extract string literal from text.
@@ -225,7 +144,7 @@ impl DocumentLinkService {
     fn collect_css_import_links(
         css: &str,
         base_offset: usize,
-        full_content: &str,
+        lines: &LineColumnCache<'_>,
         base_path: Option<&Path>,
         links: &mut Vec<DocumentLink>,
     ) {
@@ -246,7 +165,7 @@ impl DocumentLinkService {
                         if let Some(target) = Self::resolve_path(&path, base_path) {
                             let abs_start = base_offset + start + 7 + ws_len + 4 + inner_ws + s;
                             let abs_end = base_offset + start + 7 + ws_len + 4 + inner_ws + e;
-                            links.push(Self::create_link(full_content, abs_start, abs_end, target));
+                            links.push(Self::create_link(lines, abs_start, abs_end, target));
                         }
                     }
                 }
@@ -257,7 +176,7 @@ impl DocumentLinkService {
                     if let Some(target) = Self::resolve_path(&path, base_path) {
                         let abs_start = base_offset + start + 7 + ws_len + s;
                         let abs_end = base_offset + start + 7 + ws_len + e;
-                        links.push(Self::create_link(full_content, abs_start, abs_end, target));
+                        links.push(Self::create_link(lines, abs_start, abs_end, target));
                     }
                 }
             }
@@ -266,26 +185,38 @@ impl DocumentLinkService {
         }
     }
 
-    /// Find src attribute range in the opening tag.
-    fn find_src_attr_range(content: &str, tag_start: usize) -> Option<(usize, usize)> {
-        // Find the end of opening tag (>)
-        let tag_content = &content[tag_start..];
-        let tag_end = tag_content.find('>')?;
-        let tag = &tag_content[..tag_end];
+    /// Find the value range of the `src` attribute in a block's opening tag.
+    fn find_src_attr_range(content: &str, loc: &BlockLocation) -> Option<(usize, usize)> {
+        let tag = content.get(loc.tag_start..loc.start)?;
 
-        // Find src="..." or src='...'
-        let src_pos = tag.find("src=")?;
-        let after_src = &tag[src_pos + 4..];
+        let mut search = 0;
+        while let Some(found) = tag[search..].find("src") {
+            let name_start = search + found;
+            search = name_start + 3;
 
-        let quote = after_src.chars().next()?;
-        if quote != '"' && quote != '\'' {
-            return None;
-        }
+            // Skip `data-src` and the like
+            if !tag[..name_start].ends_with(|c: char| c.is_ascii_whitespace()) {
+                continue;
+            }
+
+            // src="..." or src='...', allowing whitespace around `=`
+            let Some(value) = tag[search..].trim_start().strip_prefix('=') else {
+                continue;
+            };
+            let value = value.trim_start();
+            let Some(quote) = value.chars().next().filter(|&c| matches!(c, '"' | '\'')) else {
+                continue;
+            };
 
-        let value_start = src_pos + 5; // src=" plus quote
-        let value_end = after_src[1..].find(quote)? + 1; // content length
+            let value_start = tag.len() - value.len() + 1;
+            let value_len = value[1..].find(quote)?;
+            return Some((
+                loc.tag_start + value_start,
+                loc.tag_start + value_start + value_len,
+            ));
+        }
 
-        Some((tag_start + value_start, tag_start + value_start + value_end))
+        None
     }
 
     /// Resolve a relative path to an absolute URL.
@@ -303,6 +234,7 @@ impl DocumentLinkService {
         } else {
             parent.join(clean_path)
         };
+        let resolved = normalize_path_buf(&resolved);
 
         // Try common extensions if file doesn't exist
         let candidates = [
@@ -328,19 +260,24 @@ impl DocumentLinkService {
     }
 
     /// Create a document link.
-    fn create_link(content: &str, start: usize, end: usize, target: Url) -> DocumentLink {
-        let (start_line, start_char) = offset_to_position(content, start);
-        let (end_line, end_char) = offset_to_position(content, end);
+    fn create_link(
+        lines: &LineColumnCache<'_>,
+        start: usize,
+        end: usize,
+        target: Url,
+    ) -> DocumentLink {
+        let start = lines.position(start as u32);
+        let end = lines.position(end as u32);
 
         DocumentLink {
             range: Range {
                 start: Position {
-                    line: start_line,
-                    character: start_char,
+                    line: start.line,
+                    character: start.column,
                 },
                 end: Position {
-                    line: end_line,
-                    character: end_char,
+                    line: end.line,
+                    character: end.column,
                 },
             },
             target: Some(target),
@@ -348,32 +285,71 @@ impl DocumentLinkService {
             data: None,
         }
     }
-
-    fn is_ident_char(c: char) -> bool {
-        c.is_ascii_alphanumeric() || c == '_' || c == '$'
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::DocumentLinkService;
+    use tower_lsp::lsp_types::{DocumentLink, Url};
+
+    fn link_targets(content: &str) -> Vec<(String, String)> {
+        let uri = Url::parse("file:///project/src/App.vue").unwrap();
+        DocumentLinkService::get_links(content, &uri)
+            .into_iter()
+            .map(|DocumentLink { range, target, .. }| {
+                let lines: Vec<&str> = content.lines().collect();
+                let line = lines[range.start.line as usize];
+                let text = &line[range.start.character as usize..range.end.character as usize];
+                (text.to_string(), target.unwrap().path().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_import_links() {
+        let content = r#"<script setup lang="ts">
+import Child from './Child.vue'
+import {
+  helper,
+  type Options,
+} from '../utils/helper.ts'
+import './styles.css'
+import { ref } from 'vue'
+export { foo } from './foo.ts'
+const Lazy = () => import('./Lazy.vue')
+</script>
+"#;
+
+        assert_eq!(
+            link_targets(content),
+            vec![
+                ("./Child.vue".into(), "/project/src/Child.vue".into()),
+                (
+                    "../utils/helper.ts".into(),
+                    "/project/utils/helper.ts".into()
+                ),
+                ("./styles.css".into(), "/project/src/styles.css".into()),
+                ("./foo.ts".into(), "/project/src/foo.ts".into()),
+                ("./Lazy.vue".into(), "/project/src/Lazy.vue".into()),
+            ]
+        );
+    }
 
     #[test]
-    fn test_extract_import_path() {
-        // Regular import
-        let stmt = r#"import { ref } from "./utils""#;
-        let (path, _, _) = DocumentLinkService::extract_import_path(stmt).unwrap();
-        assert_eq!(path, "./utils");
-
-        // Side-effect import
-        let stmt = r#"import "./styles.css""#;
-        let (path, _, _) = DocumentLinkService::extract_import_path(stmt).unwrap();
-        assert_eq!(path, "./styles.css");
-
-        // Export from
-        let stmt = r#"export { foo } from './foo'"#;
-        let (path, _, _) = DocumentLinkService::extract_import_path(stmt).unwrap();
-        assert_eq!(path, "./foo");
+    fn test_src_attribute_links() {
+        let content = r#"<template src="./App.html"></template>
+<script data-src="./ignored.ts" src = './App.ts'></script>
+<style scoped src="../styles/app.css"></style>
+"#;
+
+        assert_eq!(
+            link_targets(content),
+            vec![
+                ("./App.ts".into(), "/project/src/App.ts".into()),
+                ("./App.html".into(), "/project/src/App.html".into()),
+                ("../styles/app.css".into(), "/project/styles/app.css".into()),
+            ]
+        );
     }
 
     #[test]
@@ -41,17 +41,27 @@ enum RenderStyle {
     DirectoryIndex,
 }
 
+/// A wildcard `compilerOptions.paths` entry such as `"@/*": ["src/*"]`.
+#[derive(Clone)]
+struct PathAlias {
+    /// Specifier prefix without the wildcard (`@/`).
+    prefix: std::string::String,
+    /// Directory the prefix maps to (`<baseUrl>/src/`).
+    target_dir: PathBuf,
+}
+
 #[derive(Clone)]
 struct SpecifierCandidate {
     resolved: PathBuf,
     style: RenderStyle,
 }
 
+/// A module specifier in script source; `start..end` excludes the quotes.
 #[derive(Clone)]
-struct SpecifierOccurrence {
-    start: usize,
-    end: usize,
-    specifier: std::string::String,
+pub(crate) struct SpecifierOccurrence {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) specifier: std::string::String,
 }
 
 struct ScriptEditContext<'a> {
@@ -60,6 +70,7 @@ struct ScriptEditContext<'a> {
     future_path: &'a Path,
     full_source: &'a str,
     rename_targets: &'a [RenameTarget],
+    aliases: &'a [PathAlias],
 }
 
 #[derive(Default)]
@@ -152,6 +163,20 @@ impl<'a> Visit<'a> for ModuleSpecifierCollector {
     }
 }
 
+/// Collect the specifiers of imports, re-exports, dynamic imports, `require`
+/// calls and `import()` types in script source.
+pub(crate) fn collect_module_specifiers(
+    source: &str,
+    source_type: SourceType,
+) -> Vec<SpecifierOccurrence> {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, source_type).parse();
+
+    let mut collector = ModuleSpecifierCollector::default();
+    collector.visit_program(&parsed.program);
+    collector.specifiers
+}
+
 pub(super) fn collect_import_rename_edits(
     state: &ServerState,
     renames: &[FileRename],
@@ -163,6 +188,7 @@ pub(super) fn collect_import_rename_edits(
     }
 
     let workspace_root = workspace_root(state);
+    let aliases = path_aliases(&workspace_root);
     let changes = Mutex::new(HashMap::new());
     let seen_paths = Mutex::new(HashSet::new());
 
@@ -174,6 +200,7 @@ pub(super) fn collect_import_rename_edits(
             let changes = &changes;
             let seen_paths = &seen_paths;
             let rename_targets = &rename_targets;
+            let aliases = &aliases;
 
             Box::new(move |entry| {
                 let Ok(entry) = entry else {
@@ -189,7 +216,8 @@ pub(super) fn collect_import_rename_edits(
                     seen.insert(path.to_path_buf());
                 }
 
-                if let Some((uri, edits)) = process_importer_path(state, path, kind, rename_targets)
+                if let Some((uri, edits)) =
+                    process_importer_path(state, path, kind, rename_targets, aliases)
                 {
                     if let Ok(mut changes) = changes.lock() {
                         changes.insert(uri, edits);
@@ -220,6 +248,7 @@ pub(super) fn collect_import_rename_edits(
             &document.value().text(),
             kind,
             &rename_targets,
+            &aliases,
         ) {
             if let Ok(mut changes) = changes.lock() {
                 changes.insert(uri, edits);
@@ -281,10 +310,11 @@ fn process_importer_path(
     path: &Path,
     kind: ImporterKind,
     rename_targets: &[RenameTarget],
+    aliases: &[PathAlias],
 ) -> Option<(Url, Vec<TextEdit>)> {
     let uri = Url::from_file_path(path).ok()?;
     let source = read_workspace_source(state, path)?;
-    process_source(state, uri, path, &source, kind, rename_targets)
+    process_source(state, uri, path, &source, kind, rename_targets, aliases)
 }
 
 fn process_source(
@@ -294,14 +324,17 @@ fn process_source(
     source: &str,
     kind: ImporterKind,
     rename_targets: &[RenameTarget],
+    aliases: &[PathAlias],
 ) -> Option<(Url, Vec<TextEdit>)> {
     let future_path =
         apply_all_path_renames(path, rename_targets).unwrap_or_else(|| normalize_path_buf(path));
 
     let mut edits = match kind {
-        ImporterKind::Vue => collect_vue_edits(state, path, &future_path, source, rename_targets),
+        ImporterKind::Vue => {
+            collect_vue_edits(state, path, &future_path, source, rename_targets, aliases)
+        }
         ImporterKind::Script => {
-            collect_script_file_edits(state, path, &future_path, source, rename_targets)
+            collect_script_file_edits(state, path, &future_path, source, rename_targets, aliases)
         }
     };
 
@@ -326,6 +359,7 @@ fn collect_vue_edits(
     future_path: &Path,
     source: &str,
     rename_targets: &[RenameTarget],
+    aliases: &[PathAlias],
 ) -> Vec<TextEdit> {
     let options = vize_atelier_sfc::SfcParseOptions {
         filename: path.to_string_lossy().to_string().into(),
@@ -343,6 +377,7 @@ fn collect_vue_edits(
         future_path,
         full_source: source,
         rename_targets,
+        aliases,
     };
 
     if let Some(script) = descriptor.script.as_ref() {
@@ -372,6 +407,7 @@ fn collect_script_file_edits(
     future_path: &Path,
     source: &str,
     rename_targets: &[RenameTarget],
+    aliases: &[PathAlias],
 ) -> Vec<TextEdit> {
     let Some(source_type) = SourceType::from_path(path).ok() else {
         return Vec::new();
@@ -383,6 +419,7 @@ fn collect_script_file_edits(
         future_path,
         full_source: source,
         rename_targets,
+        aliases,
     };
 
     collect_script_content_edits(&edit_context, source, source_type, 0)
@@ -394,11 +431,7 @@ fn collect_script_content_edits(
     source_type: SourceType,
     base_offset: usize,
 ) -> Vec<TextEdit> {
-    let allocator = Allocator::default();
-    let parsed = Parser::new(&allocator, script_source, source_type).parse();
-
-    let mut collector = ModuleSpecifierCollector::default();
-    collector.visit_program(&parsed.program);
+    let specifiers = collect_module_specifiers(script_source, source_type);
 
     let Some(current_dir) = context.current_path.parent() else {
         return Vec::new();
@@ -407,8 +440,7 @@ fn collect_script_content_edits(
         return Vec::new();
     };
 
-    collector
-        .specifiers
+    specifiers
         .into_iter()
         .filter_map(|specifier| {
             let new_text = rewrite_relative_specifier(
@@ -417,7 +449,16 @@ fn collect_script_content_edits(
                 future_dir,
                 &specifier.specifier,
                 context.rename_targets,
-            )?;
+            )
+            .or_else(|| {
+                rewrite_alias_specifier(
+                    context.state,
+                    future_dir,
+                    &specifier.specifier,
+                    context.rename_targets,
+                    context.aliases,
+                )
+            })?;
 
             if new_text == specifier.specifier {
                 return None;
@@ -470,6 +511,56 @@ fn rewrite_relative_specifier(
     Some(rewritten)
 }
 
+/// Rewrite a tsconfig-aliased specifier (`@/components/Button.vue`) whose target moved.
+///
+/// The alias is kept when the new location is still under the alias directory;
+/// otherwise the specifier falls back to a relative path from the importer.
+fn rewrite_alias_specifier(
+    state: &ServerState,
+    future_importer_dir: &Path,
+    specifier: &str,
+    rename_targets: &[RenameTarget],
+    aliases: &[PathAlias],
+) -> Option<std::string::String> {
+    let (specifier_path, suffix) = split_specifier_suffix(specifier);
+    let (alias, rest) = aliases.iter().find_map(|alias| {
+        specifier_path
+            .strip_prefix(alias.prefix.as_str())
+            .map(|rest| (alias, rest))
+    })?;
+
+    let selected = specifier_candidates(&alias.target_dir, rest)
+        .into_iter()
+        .find(|candidate| {
+            candidate_exists(state, &candidate.resolved)
+                || apply_all_path_renames(&candidate.resolved, rename_targets).is_some()
+        })?;
+    let future_target = apply_all_path_renames(&selected.resolved, rename_targets)?;
+
+    let target_dir = normalize_path_buf(&alias.target_dir);
+    let mut rewritten = if future_target.starts_with(&target_dir) {
+        let rendered = match selected.style {
+            RenderStyle::Explicit => future_target.clone(),
+            RenderStyle::Extensionless => strip_extension(&future_target),
+            RenderStyle::DirectoryIndex => {
+                if is_index_file(&future_target) {
+                    normalize_path_buf(future_target.parent()?)
+                } else {
+                    strip_extension(&future_target)
+                }
+            }
+        };
+        let relative = rendered.strip_prefix(&target_dir).ok()?;
+        let mut aliased = alias.prefix.clone();
+        aliased.push_str(&relative.to_string_lossy().replace('\\', "/"));
+        aliased
+    } else {
+        render_module_specifier(future_importer_dir, &future_target, selected.style)?
+    };
+    rewritten.push_str(suffix);
+    Some(rewritten)
+}
+
 fn render_module_specifier(
     importer_dir: &Path,
     target_path: &Path,
@@ -693,7 +784,7 @@ fn offset_range(source: &str, start: usize, end: usize) -> Option<Range> {
     })
 }
 
-fn script_source_type(lang: Option<&str>) -> SourceType {
+pub(crate) fn script_source_type(lang: Option<&str>) -> SourceType {
     match lang.unwrap_or("js") {
         "ts" => SourceType::ts(),
         "tsx" => SourceType::tsx(),
@@ -702,7 +793,7 @@ fn script_source_type(lang: Option<&str>) -> SourceType {
     }
 }
 
-fn normalize_path_buf(path: &Path) -> PathBuf {
+pub(crate) fn normalize_path_buf(path: &Path) -> PathBuf {
     let mut normalized = PathBuf::new();
 
     for component in path.components() {
@@ -734,6 +825,28 @@ fn is_index_file(path: &Path) -> bool {
         .is_some_and(|stem| stem == "index")
 }
 
+/// Collect wildcard path aliases from the workspace `tsconfig.json`.
+fn path_aliases(workspace_root: &Path) -> Vec<PathAlias> {
+    let resolver = vize_croquis::import_resolver::ImportResolver::new(workspace_root);
+    let base = resolver.base_url().unwrap_or(resolver.project_root());
+
+    let mut aliases = resolver
+        .path_mappings()
+        .iter()
+        .filter_map(|(pattern, replacements)| {
+            let prefix = pattern.strip_suffix('*')?;
+            let replacement = replacements.first()?.strip_suffix('*')?;
+            Some(PathAlias {
+                prefix: prefix.to_string(),
+                target_dir: normalize_path_buf(&base.join(replacement)),
+            })
+        })
+        .collect::<Vec<_>>();
+    // Longest prefix first so `@/components/*` wins over `@/*`.
+    aliases.sort_by_key(|alias| std::cmp::Reverse(alias.prefix.len()));
+    aliases
+}
+
 #[cfg(feature = "native")]
 fn workspace_root(state: &ServerState) -> PathBuf {
     state
@@ -891,6 +1004,68 @@ type FooModule = typeof import("./components/Foo.vue");
         "###);
     }
 
+    #[test]
+    fn rewrites_tsconfig_alias_imports_for_component_rename() {
+        let dir = test_dir();
+        let root = dir.path();
+        let src_dir = root.join("src");
+        let components_dir = src_dir.join("components");
+        fs::create_dir_all(&components_dir).unwrap();
+
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/*": ["src/*"] } } }"#,
+        )
+        .unwrap();
+
+        let app_path = src_dir.join("App.vue");
+        let old_component = components_dir.join("Foo.vue");
+        let new_component = src_dir.join("ui/Bar.vue");
+
+        fs::write(
+            &app_path,
+            r#"<script setup lang="ts">
+import Foo from "@/components/Foo.vue";
+</script>
+"#,
+        )
+        .unwrap();
+        fs::write(&old_component, "<template><div>foo</div></template>").unwrap();
+
+        let state = ServerState::new();
+        state.set_workspace_root(root.to_path_buf());
+
+        let edit = collect_import_rename_edits(
+            &state,
+            &[FileRename {
+                old_uri: file_uri(&old_component),
+                new_uri: file_uri(&new_component),
+            }],
+            true,
+        )
+        .unwrap();
+
+        assert_snapshot!(serde_json::to_string_pretty(&normalize_edit(root, &edit)).unwrap(), @r###"
+        {
+          "src/App.vue": [
+            {
+              "newText": "@/ui/Bar.vue",
+              "range": {
+                "end": {
+                  "character": 37,
+                  "line": 1
+                },
+                "start": {
+                  "character": 17,
+                  "line": 1
+                }
+              }
+            }
+          ]
+        }
+        "###);
+    }
+
     #[test]
     fn rewrites_extensionless_ts_imports_without_corsa() {
         let dir = test_dir();
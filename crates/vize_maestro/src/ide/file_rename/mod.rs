@@ -3,6 +3,8 @@
 
 mod manual;
 
+pub(crate) use manual::{collect_module_specifiers, normalize_path_buf, script_source_type};
+
 use tower_lsp::lsp_types::{
     DocumentChangeOperation, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
     RenameFilesParams, TextDocumentEdit, TextEdit, Url, WorkspaceEdit,
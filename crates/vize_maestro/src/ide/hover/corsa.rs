@@ -107,17 +107,17 @@ impl HoverService {
         info: &ArtVariantInfo,
         corsa_bridge: Option<Arc<CorsaBridge>>,
     ) -> Option<Hover> {
+        // Check for Vue directives first; these do not need Corsa.
+        if let Some(hover) = Self::hover_directive_at(&ctx.content, ctx.offset) {
+            return Some(hover);
+        }
+
         let word = Self::get_word_at_offset(&ctx.content, ctx.offset);
 
         if word.is_empty() {
             return None;
         }
 
-        // Check for Vue directives first; these do not need Corsa.
-        if let Some(hover) = Self::hover_directive(&word) {
-            return Some(hover);
-        }
-
         // Try to get type information from Corsa via virtual TypeScript.
         if let Some(bridge) = corsa_bridge {
             if let Some(ref virtual_docs) = ctx.virtual_docs {
@@ -0,0 +1,188 @@
+//! CSS class hover provider.
+//!
+//! Shows the style rules that target a class when hovering one of the tokens
+//! in a static `class="..."` attribute of the template. Rules are looked up in
+//! the SFC's own `<style>` blocks, parsed with lightningcss.
+#![allow(
+    clippy::disallowed_types,
+    clippy::disallowed_methods,
+    clippy::disallowed_macros
+)]
+
+use lightningcss::selector::{Component, Selector};
+use lightningcss::stylesheet::PrinterOptions;
+use lightningcss::traits::ToCss;
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+
+use super::HoverService;
+use crate::ide::style_rules::{for_each_css_style, for_each_style_rule};
+
+impl HoverService {
+    /// Get hover for the class token under the cursor, if any style block
+    /// in the SFC has a rule selecting it.
+    pub(super) fn hover_class_rule_at(content: &str, offset: usize) -> Option<Hover> {
+        let class_name = class_token_at(content, offset)?;
+
+        let mut sections = Vec::new();
+        for_each_css_style(content, |style, stylesheet| {
+            let mut rules = Vec::new();
+            for_each_style_rule(&stylesheet.rules.0, &mut |rule, style_rule| {
+                let selects_class = style_rule
+                    .selectors
+                    .0
+                    .iter()
+                    .any(|selector| selector_has_class(selector, class_name));
+                if selects_class {
+                    if let Ok(css) = rule.to_css_string(PrinterOptions::default()) {
+                        rules.push(css);
+                    }
+                }
+            });
+            if rules.is_empty() {
+                return;
+            }
+
+            let block = if style.scoped {
+                "<style scoped>"
+            } else if style.module.is_some() {
+                "<style module>"
+            } else {
+                "<style>"
+            };
+            sections.push(format!(
+                "```css\n{}\n```\n\n*Defined in `{}`*",
+                rules.join("\n\n"),
+                block
+            ));
+        });
+
+        if sections.is_empty() {
+            return None;
+        }
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("**.{}**\n\n{}", class_name, sections.join("\n\n")),
+            }),
+            range: None,
+        })
+    }
+}
+
+/// Check whether any compound of the selector uses `.class_name`.
+fn selector_has_class(selector: &Selector, class_name: &str) -> bool {
+    selector
+        .iter_raw_match_order()
+        .any(|component| matches!(component, Component::Class(class) if *class.0 == *class_name))
+}
+
+/// Get the class token under the cursor inside a static `class="..."` value.
+fn class_token_at(content: &str, offset: usize) -> Option<&str> {
+    let bytes = content.as_bytes();
+    let is_token_byte = |b: u8| !b.is_ascii_whitespace() && b != b'"' && b != b'\'';
+    if !is_token_byte(*bytes.get(offset)?) {
+        return None;
+    }
+
+    // The value must be the quoted value of a plain `class` attribute
+    let open = content[..offset].rfind(['"', '\''])?;
+    let quote = bytes[open] as char;
+    let close = offset + content[offset..].find(quote)?;
+    let name = content[..open].trim_end().strip_suffix('=')?.trim_end();
+    let prefix = name.strip_suffix("class")?;
+    if !prefix.ends_with(|c: char| c.is_ascii_whitespace()) {
+        return None;
+    }
+
+    let value = &content[open + 1..close];
+    if value.contains(['<', '>']) {
+        return None;
+    }
+
+    let start = content[..offset]
+        .rfind(|c: char| c.is_ascii_whitespace() || c == quote)
+        .map_or(0, |index| index + 1);
+    let end = offset
+        + content[offset..]
+            .find(|c: char| c.is_ascii_whitespace() || c == quote)
+            .unwrap_or(content.len() - offset);
+    Some(&content[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::class_token_at;
+    use crate::ide::hover::HoverService;
+    use tower_lsp::lsp_types::HoverContents;
+
+    #[test]
+    fn hover_class_concatenates_matching_rules() {
+        let content = r#"<template>
+  <div class="container">x</div>
+</template>
+
+<style scoped>
+.container { display: flex; }
+.other { color: red; }
+@media (min-width: 600px) {
+  .page .container { gap: 8px; }
+}
+</style>
+"#;
+        let offset = content.find("container").unwrap() + 2;
+        let hover = HoverService::hover_class_rule_at(content, offset).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected Markup content");
+        };
+
+        assert!(markup.value.starts_with("**.container**"));
+        assert!(markup.value.contains("display: flex"));
+        assert!(markup.value.contains(".page .container"));
+        assert!(markup.value.contains("gap: 8px"));
+        assert!(!markup.value.contains("color: red"));
+        assert!(markup.value.contains("*Defined in `<style scoped>`*"));
+
+        // Classes without a rule fall through to the regular template hover
+        let content = content.replace("class=\"container\"", "class=\"unstyled\"");
+        let offset = content.find("unstyled").unwrap();
+        assert!(HoverService::hover_class_rule_at(&content, offset).is_none());
+    }
+
+    #[test]
+    fn hover_class_includes_nested_rules() {
+        let content = r#"<template>
+  <div class="card"><h2 class="title">x</h2></div>
+</template>
+
+<style>
+.card {
+  padding: 4px;
+  .title { font-weight: bold; }
+}
+</style>
+"#;
+        let offset = content.find("title").unwrap();
+        let hover = HoverService::hover_class_rule_at(content, offset).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected Markup content");
+        };
+
+        assert!(markup.value.starts_with("**.title**"));
+        assert!(markup.value.contains("font-weight: bold"));
+        assert!(!markup.value.contains("padding: 4px"));
+    }
+
+    #[test]
+    fn class_token_only_in_static_class_values() {
+        let content = r#"<div class="card  is-active" :class="dyn" id="card">"#;
+
+        assert_eq!(class_token_at(content, 12), Some("card"));
+        assert_eq!(class_token_at(content, 21), Some("is-active"));
+        // Whitespace between tokens, bound classes and other attributes
+        assert_eq!(class_token_at(content, 16), None);
+        assert_eq!(class_token_at(content, 37), None);
+        assert_eq!(class_token_at(content, 47), None);
+        assert_eq!(class_token_at(content, 5), None);
+    }
+}
@@ -0,0 +1,309 @@
+//! Directive hover provider.
+//!
+//! Provides documentation for Vue's built-in directives, including the
+//! expected value and available modifiers, when hovering an attribute name
+//! such as `v-model.trim` or `@click.stop`.
+#![allow(
+    clippy::disallowed_types,
+    clippy::disallowed_methods,
+    clippy::disallowed_macros
+)]
+
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+
+use super::HoverService;
+
+/// Static documentation for a built-in directive.
+struct DirectiveDoc {
+    name: &'static str,
+    shorthand: Option<&'static str>,
+    description: &'static str,
+    expects: Option<&'static str>,
+    modifiers: &'static [(&'static str, &'static str)],
+    notes: &'static [&'static str],
+}
+
+const V_ON_MODIFIERS: &[(&str, &str)] = &[
+    ("stop", "Call `event.stopPropagation()`."),
+    ("prevent", "Call `event.preventDefault()`."),
+    ("capture", "Add the event listener in capture mode."),
+    (
+        "self",
+        "Only trigger the handler if the event was dispatched from this element.",
+    ),
+    ("once", "Trigger the handler at most once."),
+    (
+        "passive",
+        "Attach a DOM event listener with `{ passive: true }`.",
+    ),
+    (
+        "{keyAlias}",
+        "Only trigger the handler on certain keys (e.g. `.enter`, `.esc`).",
+    ),
+    (
+        "left",
+        "Only trigger the handler for left mouse button events.",
+    ),
+    (
+        "right",
+        "Only trigger the handler for right mouse button events.",
+    ),
+    (
+        "middle",
+        "Only trigger the handler for middle mouse button events.",
+    ),
+    (
+        "exact",
+        "Only trigger when exactly the listed system modifiers are pressed.",
+    ),
+];
+
+const V_BIND_MODIFIERS: &[(&str, &str)] = &[
+    (
+        "camel",
+        "Transform the kebab-case attribute name into camelCase.",
+    ),
+    (
+        "prop",
+        "Force a binding to be set as a DOM property (shorthand `.`).",
+    ),
+    (
+        "attr",
+        "Force a binding to be set as a DOM attribute (shorthand `^`).",
+    ),
+];
+
+const V_MODEL_MODIFIERS: &[(&str, &str)] = &[
+    ("lazy", "Listen to `change` events instead of `input`."),
+    ("number", "Cast valid input string to numbers."),
+    ("trim", "Trim input."),
+];
+
+const V_MODEL_NOTES: &[&str] = &[
+    "`<input>` / `<textarea>`: binds `value` and listens to `input`",
+    "`<input type=\"checkbox\">` / `<input type=\"radio\">`: binds `checked` \
+     and listens to `change`",
+    "`<select>`: binds the selected `value` and listens to `change`",
+    "Components: binds the `modelValue` prop and listens to `update:modelValue` \
+     (or `update:<arg>` with an argument)",
+];
+
+impl HoverService {
+    /// Get hover for the directive attribute under the cursor, if any.
+    ///
+    /// Unlike word-based lookup this sees the whole attribute name, so
+    /// shorthands (`@click.stop`, `:foo`, `#default`) resolve as well.
+    pub(super) fn hover_directive_at(content: &str, offset: usize) -> Option<Hover> {
+        let attr = Self::directive_attr_at_offset(content, offset)?;
+        Self::hover_directive(attr)
+    }
+
+    /// Get hover for Vue directives.
+    ///
+    /// Accepts a bare directive name (`v-if`) or a full attribute name with
+    /// argument and modifiers (`v-on:click.stop`, `@click.stop`).
+    pub(super) fn hover_directive(word: &str) -> Option<Hover> {
+        let doc = directive_doc(directive_name(word)?)?;
+
+        let mut value = format!("**{}**", doc.name);
+        if let Some(shorthand) = doc.shorthand {
+            value.push_str(&format!(" (shorthand: `{}`)", shorthand));
+        }
+        value.push_str("\n\n");
+        value.push_str(doc.description);
+
+        if let Some(expects) = doc.expects {
+            value.push_str(&format!("\n\n**Expects:** `{}`", expects));
+        }
+
+        if !doc.notes.is_empty() {
+            value.push_str("\n\n**Behavior:**");
+            for note in doc.notes {
+                value.push_str(&format!("\n- {}", note));
+            }
+        }
+
+        if !doc.modifiers.is_empty() {
+            value.push_str("\n\n**Modifiers:**");
+            for (modifier, description) in doc.modifiers {
+                value.push_str(&format!("\n- `.{}` - {}", modifier, description));
+            }
+        }
+
+        value.push_str("\n\n[Vue Documentation](https://vuejs.org/api/built-in-directives.html)");
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: None,
+        })
+    }
+
+    /// Find the directive attribute name under the cursor.
+    ///
+    /// Returns `None` when the cursor is not on an attribute name inside a
+    /// start tag, or when the attribute is not a directive.
+    fn directive_attr_at_offset(content: &str, offset: usize) -> Option<&str> {
+        let bytes = content.as_bytes();
+        if offset >= bytes.len() || !is_attr_name_char(bytes[offset]) {
+            return None;
+        }
+
+        let mut start = offset;
+        while start > 0 && is_attr_name_char(bytes[start - 1]) {
+            start -= 1;
+        }
+        let mut end = offset;
+        while end < bytes.len() && is_attr_name_char(bytes[end]) {
+            end += 1;
+        }
+
+        // Attribute names always follow whitespace inside a start tag
+        if start == 0 || !bytes[start - 1].is_ascii_whitespace() {
+            return None;
+        }
+        let tag_start = content[..start].rfind('<')?;
+        let mut quote = None;
+        for &b in &bytes[tag_start..start] {
+            match quote {
+                Some(q) if b == q => quote = None,
+                Some(_) => {}
+                None if b == b'"' || b == b'\'' => quote = Some(b),
+                None if b == b'>' => return None,
+                None => {}
+            }
+        }
+        if quote.is_some() {
+            return None;
+        }
+
+        let attr = &content[start..end];
+        (attr.starts_with(['@', ':', '#', '.']) || attr.starts_with("v-")).then_some(attr)
+    }
+}
+
+/// Resolve an attribute name to its directive name, stripping the argument
+/// and modifiers.
+fn directive_name(attr: &str) -> Option<&str> {
+    let name = match attr.as_bytes().first()? {
+        b'@' => "v-on",
+        b':' | b'.' => "v-bind",
+        b'#' => "v-slot",
+        _ => {
+            let rest = attr.strip_prefix("v-")?;
+            let len = rest.find([':', '.']).unwrap_or(rest.len());
+            &attr[..2 + len]
+        }
+    };
+    Some(name)
+}
+
+fn directive_doc(name: &str) -> Option<DirectiveDoc> {
+    let doc = |name, description, expects| DirectiveDoc {
+        name,
+        shorthand: None,
+        description,
+        expects,
+        modifiers: &[],
+        notes: &[],
+    };
+
+    Some(match name {
+        "v-if" => doc(
+            "v-if",
+            "Conditionally render the element based on the truthy-ness of the expression value.",
+            Some("any"),
+        ),
+        "v-else-if" => doc(
+            "v-else-if",
+            "Denote the \"else if block\" for `v-if`. Can be chained.",
+            Some("any"),
+        ),
+        "v-else" => doc(
+            "v-else",
+            "Denote the \"else block\" for `v-if` or `v-if`/`v-else-if` chain.",
+            None,
+        ),
+        "v-for" => doc(
+            "v-for",
+            "Render the element or template block multiple times based on the source data.",
+            Some("Array | Object | number | string | Iterable"),
+        ),
+        "v-on" => DirectiveDoc {
+            shorthand: Some("@"),
+            modifiers: V_ON_MODIFIERS,
+            ..doc(
+                "v-on",
+                "Attach an event listener to the element. \
+                 The event type is denoted by the argument.",
+                Some("Function | Inline Statement | Object (without argument)"),
+            )
+        },
+        "v-bind" => DirectiveDoc {
+            shorthand: Some(":"),
+            modifiers: V_BIND_MODIFIERS,
+            ..doc(
+                "v-bind",
+                "Dynamically bind one or more attributes, or a component prop to an expression.",
+                Some("any (with argument) | Object (without argument)"),
+            )
+        },
+        "v-model" => DirectiveDoc {
+            modifiers: V_MODEL_MODIFIERS,
+            notes: V_MODEL_NOTES,
+            ..doc(
+                "v-model",
+                "Create a two-way binding on a form input element or a component.",
+                Some("varies based on value of form inputs element or output of components"),
+            )
+        },
+        "v-slot" => DirectiveDoc {
+            shorthand: Some("#"),
+            ..doc(
+                "v-slot",
+                "Denote named slots or scoped slots that expect to receive props.",
+                Some("JavaScript expression that is valid in a function argument position"),
+            )
+        },
+        "v-pre" => doc(
+            "v-pre",
+            "Skip compilation for this element and all its children.",
+            None,
+        ),
+        "v-once" => doc(
+            "v-once",
+            "Render the element and component once only, and skip future updates.",
+            None,
+        ),
+        "v-memo" => doc(
+            "v-memo",
+            "Memoize a sub-tree of the template. Can be used on both elements and components.",
+            Some("any[]"),
+        ),
+        "v-cloak" => doc(
+            "v-cloak",
+            "Used to hide un-compiled template until it is ready.",
+            None,
+        ),
+        "v-show" => doc(
+            "v-show",
+            "Toggle the element's visibility based on the truthy-ness of the expression value.",
+            Some("any"),
+        ),
+        "v-text" => doc(
+            "v-text",
+            "Update the element's text content.",
+            Some("string"),
+        ),
+        "v-html" => doc("v-html", "Update the element's innerHTML.", Some("string")),
+        _ => return None,
+    })
+}
+
+/// Check if a byte can be part of an attribute name.
+#[inline]
+fn is_attr_name_char(c: u8) -> bool {
+    !c.is_ascii_whitespace() && !matches!(c, b'=' | b'<' | b'>' | b'"' | b'\'' | b'/')
+}
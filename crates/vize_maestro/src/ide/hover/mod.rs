@@ -5,6 +5,7 @@
 //! - Vue directives
 //! - Script bindings and imports
 //! - CSS properties and Vue-specific selectors
+//! - Style rules matching static template classes
 //! - TypeScript type information from croquis analysis
 //! - Real type information from Corsa (when available)
 #![allow(
@@ -15,6 +16,8 @@
 
 #[cfg(feature = "native")]
 mod corsa;
+mod css_class;
+mod directive;
 mod script;
 mod template;
 
@@ -510,6 +513,42 @@ mod tests {
         assert!(hover.is_none());
     }
 
+    #[test]
+    fn test_hover_directive_lists_modifiers() {
+        let hover = HoverService::hover_directive("v-on:click.stop").unwrap();
+        let HoverContents::Markup(content) = hover.contents else {
+            panic!("Expected Markup content");
+        };
+        assert!(content.value.starts_with("**v-on** (shorthand: `@`)"));
+        assert!(content.value.contains("- `.stop`"));
+        assert!(content.value.contains("- `.once`"));
+
+        let hover = HoverService::hover_directive("v-model.trim").unwrap();
+        let HoverContents::Markup(content) = hover.contents else {
+            panic!("Expected Markup content");
+        };
+        assert!(content.value.contains("- `.lazy`"));
+        assert!(content.value.contains("`update:modelValue`"));
+    }
+
+    #[test]
+    fn test_hover_directive_at_offset() {
+        let content = r#"<button @click.stop="onClick" :title="a > b" class="btn">"#;
+        let title = |offset| {
+            HoverService::hover_directive_at(content, offset).map(|hover| match hover.contents {
+                HoverContents::Markup(markup) => markup.value.lines().next().unwrap().to_owned(),
+                _ => panic!("Expected Markup content"),
+            })
+        };
+
+        assert_eq!(title(8).as_deref(), Some("**v-on** (shorthand: `@`)"));
+        assert_eq!(title(16).as_deref(), Some("**v-on** (shorthand: `@`)"));
+        assert_eq!(title(31).as_deref(), Some("**v-bind** (shorthand: `:`)"));
+        // Attribute values and plain attributes are not directives
+        assert_eq!(title(22), None);
+        assert_eq!(title(46), None);
+    }
+
     #[test]
     fn test_hover_vue_api() {
         let hover = HoverService::hover_vue_api("ref");
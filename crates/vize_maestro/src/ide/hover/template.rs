@@ -24,6 +24,16 @@ use vize_carton::append;
 impl HoverService {
     /// Get hover for template context.
     pub(super) fn hover_template(ctx: &IdeContext) -> Option<Hover> {
+        // Check for Vue directives
+        if let Some(hover) = Self::hover_directive_at(&ctx.content, ctx.offset) {
+            return Some(hover);
+        }
+
+        // Check for static class tokens styled in this SFC
+        if let Some(hover) = Self::hover_class_rule_at(&ctx.content, ctx.offset) {
+            return Some(hover);
+        }
+
         // Try to find what's under the cursor
         let word = Self::get_word_at_offset(&ctx.content, ctx.offset);
 
@@ -31,11 +41,6 @@ impl HoverService {
             return None;
         }
 
-        // Check for Vue directives
-        if let Some(hover) = Self::hover_directive(&word) {
-            return Some(hover);
-        }
-
         // Try to get TypeScript type information from croquis analysis
         if let Some(hover) = Self::hover_ts_binding(ctx, &word) {
             return Some(hover);
@@ -94,17 +99,20 @@ impl HoverService {
         ctx: &IdeContext<'_>,
         corsa_bridge: Option<Arc<CorsaBridge>>,
     ) -> Option<Hover> {
+        // Check for Vue directives first; these do not need Corsa.
+        if let Some(hover) = Self::hover_directive_at(&ctx.content, ctx.offset) {
+            return Some(hover);
+        }
+        if let Some(hover) = Self::hover_class_rule_at(&ctx.content, ctx.offset) {
+            return Some(hover);
+        }
+
         let word = Self::get_word_at_offset(&ctx.content, ctx.offset);
 
         if word.is_empty() {
             return None;
         }
 
-        // Check for Vue directives first; these do not need Corsa.
-        if let Some(hover) = Self::hover_directive(&word) {
-            return Some(hover);
-        }
-
         // Try to get type information from Corsa via virtual TypeScript.
         if let Some(bridge) = corsa_bridge {
             if let Some(ref virtual_docs) = ctx.virtual_docs {
@@ -199,35 +207,4 @@ impl HoverService {
             range: None,
         })
     }
-
-    /// Get hover for Vue directives.
-    pub(super) fn hover_directive(word: &str) -> Option<Hover> {
-        let (title, description) = match word {
-            "v-if" => ("v-if", "Conditionally render the element based on the truthy-ness of the expression value."),
-            "v-else-if" => ("v-else-if", "Denote the \"else if block\" for `v-if`. Can be chained."),
-            "v-else" => ("v-else", "Denote the \"else block\" for `v-if` or `v-if`/`v-else-if` chain."),
-            "v-for" => ("v-for", "Render the element or template block multiple times based on the source data."),
-            "v-on" | "@" => ("v-on", "Attach an event listener to the element. The event type is denoted by the argument."),
-            "v-bind" | ":" => ("v-bind", "Dynamically bind one or more attributes, or a component prop to an expression."),
-            "v-model" => ("v-model", "Create a two-way binding on a form input element or a component."),
-            "v-slot" | "#" => ("v-slot", "Denote named slots or scoped slots that expect to receive props."),
-            "v-pre" => ("v-pre", "Skip compilation for this element and all its children."),
-            "v-once" => ("v-once", "Render the element and component once only, and skip future updates."),
-            "v-memo" => ("v-memo", "Memoize a sub-tree of the template. Can be used on both elements and components."),
-            "v-cloak" => ("v-cloak", "Used to hide un-compiled template until it is ready."),
-            "v-show" => ("v-show", "Toggle the element's visibility based on the truthy-ness of the expression value."),
-            "v-text" => ("v-text", "Update the element's text content."),
-            "v-html" => ("v-html", "Update the element's innerHTML."),
-            _ => return None,
-        };
-
-        Some(Hover {
-            contents: HoverContents::Markup(MarkupContent {
-                kind: MarkupKind::Markdown,
-                #[allow(clippy::disallowed_macros)]
-                value: format!("**{}**\n\n{}\n\n[Vue Documentation](https://vuejs.org/api/built-in-directives.html)", title, description),
-            }),
-            range: None,
-        })
-    }
 }
@@ -12,6 +12,8 @@
 //! - Semantic tokens
 //! - Code lens
 //! - Workspace symbols
+//! - Selection ranges
+//! - Convert to `<script setup>` command
 #![allow(clippy::disallowed_types, clippy::disallowed_methods)]
 
 pub mod code_action;
@@ -26,7 +28,10 @@ pub mod hover;
 pub mod inlay_hint;
 pub mod references;
 pub mod rename;
+pub mod script_setup;
+pub mod selection_range;
 pub mod semantic_tokens;
+mod style_rules;
 pub mod type_service;
 pub mod workspace_symbols;
 
@@ -34,18 +39,21 @@ pub use code_action::CodeActionService;
 pub use code_lens::CodeLensService;
 pub use completion::{trigger_characters, CompletionService, TRIGGER_CHARACTERS};
 pub use definition::{BindingKind, BindingLocation, DefinitionService};
-pub use diagnostics::{sources, DiagnosticBuilder, DiagnosticService, Severity};
+pub use diagnostics::{sources, DiagnosticBuilder, DiagnosticService, DiagnosticsConfig, Severity};
 pub use document_link::DocumentLinkService;
 pub use file_rename::FileRenameService;
 pub use hover::{HoverBuilder, HoverService};
 pub use inlay_hint::InlayHintService;
 pub use references::ReferencesService;
 pub use rename::RenameService;
+pub use script_setup::{ScriptSetupConversionService, CONVERT_TO_SCRIPT_SETUP_COMMAND};
+pub use selection_range::SelectionRangeService;
 pub use semantic_tokens::{SemanticTokensService, TokenModifier, TokenType};
 pub use type_service::{LspTypeCheckOptions, TypeService};
 pub use workspace_symbols::WorkspaceSymbolsService;
 
 use tower_lsp::lsp_types::Url;
+use vize_relief::LineColumnCache;
 
 use crate::server::ServerState;
 use crate::virtual_code::{
@@ -57,58 +65,23 @@ use crate::virtual_code::{
 // =============================================================================
 
 /// Convert byte offset to (line, character) position in a document.
+///
+/// Builds a [`LineColumnCache`] per call; convert many offsets in the same
+/// document through a shared cache instead.
 #[inline]
 pub fn offset_to_position(content: &str, offset: usize) -> (u32, u32) {
-    let mut line = 0u32;
-    let mut col = 0u32;
-    let mut current = 0usize;
-
-    for ch in content.chars() {
-        if current >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
-        }
-        current += ch.len_utf8();
-    }
-
-    (line, col)
+    let pos = LineColumnCache::new(content).position(offset as u32);
+    (pos.line, pos.column)
 }
 
 /// Convert (line, character) position to byte offset in a document.
+///
+/// Characters past the end of the line clamp to the line end.
 #[inline]
 pub fn position_to_offset(content: &str, line: u32, character: u32) -> Option<usize> {
-    let mut current_line = 0u32;
-    let mut current_col = 0u32;
-    let mut offset = 0usize;
-
-    for ch in content.chars() {
-        if current_line == line && current_col == character {
-            return Some(offset);
-        }
-        if ch == '\n' {
-            if current_line == line {
-                // Reached end of target line
-                return Some(offset);
-            }
-            current_line += 1;
-            current_col = 0;
-        } else {
-            current_col += 1;
-        }
-        offset += ch.len_utf8();
-    }
-
-    // Handle end of file
-    if current_line == line && current_col == character {
-        return Some(offset);
-    }
-
-    None
+    LineColumnCache::new(content)
+        .offset(line, character)
+        .map(|offset| offset as usize)
 }
 
 // =============================================================================
@@ -247,28 +247,6 @@ impl ReferencesService {
     fn is_identifier_char(c: u8) -> bool {
         c.is_ascii_alphanumeric() || c == b'_' || c == b'$'
     }
-
-    /// Convert offset to (line, character).
-    pub(crate) fn offset_to_position(content: &str, offset: usize) -> (u32, u32) {
-        let mut line = 0u32;
-        let mut col = 0u32;
-        let mut current = 0usize;
-
-        for ch in content.chars() {
-            if current >= offset {
-                break;
-            }
-            if ch == '\n' {
-                line += 1;
-                col = 0;
-            } else {
-                col += 1;
-            }
-            current += ch.len_utf8();
-        }
-
-        (line, col)
-    }
 }
 
 #[cfg(test)]
@@ -16,7 +16,8 @@ impl ReferencesService {
             if let Some(ref script_setup) = virtual_docs.script_setup {
                 if let Some(loc) = Self::find_binding_in_script(&script_setup.content, word) {
                     let script_start_line = Self::get_script_setup_start_line(&ctx.content)?;
-                    let (line, character) = Self::offset_to_position(&script_setup.content, loc);
+                    let (line, character) =
+                        crate::ide::offset_to_position(&script_setup.content, loc);
 
                     return Some(Location {
                         uri: ctx.uri.clone(),
@@ -38,7 +39,7 @@ impl ReferencesService {
             if let Some(ref script) = virtual_docs.script {
                 if let Some(loc) = Self::find_binding_in_script(&script.content, word) {
                     let script_start_line = Self::get_script_start_line(&ctx.content)?;
-                    let (line, character) = Self::offset_to_position(&script.content, loc);
+                    let (line, character) = crate::ide::offset_to_position(&script.content, loc);
 
                     return Some(Location {
                         uri: ctx.uri.clone(),
@@ -5,6 +5,7 @@
 #![allow(clippy::disallowed_types, clippy::disallowed_methods)]
 
 use tower_lsp::lsp_types::{Location, Position, Range};
+use vize_relief::LineColumnCache;
 
 use super::{IdeContext, ReferencesService};
 
@@ -36,6 +37,7 @@ impl ReferencesService {
 
         // Extract expression locations from the AST
         let expressions = Self::extract_template_expressions(&ast);
+        let lines = LineColumnCache::new(template_content);
 
         for (expr_text, expr_offset) in expressions {
             // Find word occurrences within the expression
@@ -43,7 +45,8 @@ impl ReferencesService {
 
             for word_offset_in_expr in word_positions {
                 let absolute_offset = expr_offset + word_offset_in_expr;
-                let (line, character) = Self::offset_to_position(template_content, absolute_offset);
+                let pos = lines.position(absolute_offset as u32);
+                let (line, character) = (pos.line, pos.column);
 
                 locations.push(Location {
                     uri: ctx.uri.clone(),
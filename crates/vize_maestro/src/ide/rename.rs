@@ -12,6 +12,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use tower_lsp::lsp_types::{Position, PrepareRenameResponse, Range, TextEdit, WorkspaceEdit};
+use vize_relief::LineColumnCache;
 
 #[cfg(feature = "native")]
 use vize_canon::CorsaBridge;
@@ -499,36 +500,12 @@ impl RenameService {
 
     /// Convert byte offset range to LSP Range.
     fn offset_range_to_lsp(content: &str, start: usize, end: usize) -> Range {
-        let start_pos = Self::offset_to_position(content, start);
-        let end_pos = Self::offset_to_position(content, end);
+        let lines = LineColumnCache::new(content);
+        let start_pos = lines.position(start as u32);
+        let end_pos = lines.position(end as u32);
         Range {
-            start: start_pos,
-            end: end_pos,
-        }
-    }
-
-    /// Convert byte offset to LSP Position.
-    fn offset_to_position(content: &str, offset: usize) -> Position {
-        let mut line = 0u32;
-        let mut col = 0u32;
-        let mut current = 0;
-
-        for ch in content.chars() {
-            if current >= offset {
-                break;
-            }
-            if ch == '\n' {
-                line += 1;
-                col = 0;
-            } else {
-                col += 1;
-            }
-            current += ch.len_utf8();
-        }
-
-        Position {
-            line,
-            character: col,
+            start: Position::new(start_pos.line, start_pos.column),
+            end: Position::new(end_pos.line, end_pos.column),
         }
     }
 
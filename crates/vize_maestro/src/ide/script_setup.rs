@@ -0,0 +1,654 @@
+//! "Convert to `<script setup>`" refactoring.
+//!
+//! Rewrites a component whose `<script>` block default-exports an options
+//! object (plain or wrapped in `defineComponent`) into a `<script setup>`
+//! block. Only the straightforward shape is handled: `name`, `props`,
+//! `emits` and `components` options plus a `setup()` that ends with a single
+//! `return { ... }` object. Anything else is left untouched and the reason is
+//! reported back to the user. The converted script is analyzed with croquis to
+//! make sure every returned name stays visible to the template.
+#![allow(clippy::disallowed_types, clippy::disallowed_methods)]
+
+use std::collections::HashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, BindingPattern, CallExpression, ExportDefaultDeclarationKind, Expression,
+    FormalParameters, FunctionBody, IdentifierReference, ImportDeclarationSpecifier,
+    ObjectExpression, ObjectPropertyKind, PropertyKey, PropertyKind, Statement, StringLiteral,
+    TemplateLiteral,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_span::GetSpan;
+use tower_lsp::lsp_types::{Position, Range, TextEdit, Url, WorkspaceEdit};
+use vize_carton::{append, String};
+use vize_relief::LineColumnCache;
+
+use super::file_rename::script_source_type;
+
+/// Command identifier for `workspace/executeCommand`.
+pub const CONVERT_TO_SCRIPT_SETUP_COMMAND: &str = "vize.convertToScriptSetup";
+
+/// Script setup conversion service.
+pub struct ScriptSetupConversionService;
+
+impl ScriptSetupConversionService {
+    /// Build the edit replacing the `<script>` block with `<script setup>`.
+    ///
+    /// Returns the reason the component was left untouched as `Err`.
+    pub fn convert(content: &str, uri: &Url) -> Result<WorkspaceEdit, &'static str> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: uri.path().to_string().into(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options)
+            .map_err(|_| "The file could not be parsed as a Vue SFC")?;
+
+        if descriptor.script_setup.is_some() {
+            return Err("The component already uses <script setup>");
+        }
+        let script = descriptor
+            .script
+            .as_ref()
+            .ok_or("The component has no <script> block")?;
+        if script.src.is_some() {
+            return Err("External <script src> blocks cannot be converted");
+        }
+
+        let body = convert_script(&script.content, script.lang.as_deref())?;
+
+        let mut block = String::from("<script setup");
+        if let Some(lang) = &script.lang {
+            append!(block, " lang=\"{lang}\"");
+        }
+        block.push_str(">\n");
+        block.push_str(&body);
+        block.push_str("</script>");
+
+        let lines = LineColumnCache::new(content);
+        let start = lines.position(script.loc.tag_start as u32);
+        let end = lines.position(script.loc.tag_end as u32);
+        let edit = TextEdit {
+            range: Range {
+                start: Position::new(start.line, start.column),
+                end: Position::new(end.line, end.column),
+            },
+            new_text: block.into_string(),
+        };
+
+        let mut changes = HashMap::new();
+        changes.insert(uri.clone(), vec![edit]);
+        Ok(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        })
+    }
+}
+
+/// Options of the default-exported component object.
+#[derive(Default)]
+struct ComponentOptions<'s> {
+    name: Option<&'s str>,
+    props: Option<&'s str>,
+    emits: Option<&'s str>,
+}
+
+/// Convert the `<script>` content into `<script setup>` content.
+fn convert_script(source: &str, lang: Option<&str>) -> Result<String, &'static str> {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, script_source_type(lang)).parse();
+    if parsed.panicked || !parsed.errors.is_empty() {
+        return Err("The <script> block has syntax errors");
+    }
+
+    let mut export = None;
+    for stmt in parsed.program.body.iter() {
+        match stmt {
+            Statement::ExportDefaultDeclaration(decl) => export = Some(decl),
+            Statement::ExportNamedDeclaration(_) | Statement::ExportAllDeclaration(_) => {
+                return Err("Named exports cannot be moved into <script setup>");
+            }
+            _ => {}
+        }
+    }
+    let export = export.ok_or("The <script> block has no default export")?;
+
+    let (object, uses_define_component) = match &export.declaration {
+        ExportDefaultDeclarationKind::ObjectExpression(object) => (&**object, false),
+        ExportDefaultDeclarationKind::CallExpression(call)
+            if matches!(
+                &call.callee,
+                Expression::Identifier(ident) if ident.name.as_str() == "defineComponent"
+            ) && call.arguments.len() == 1 =>
+        {
+            match call.arguments[0].as_expression() {
+                Some(Expression::ObjectExpression(object)) => (&**object, true),
+                _ => return Err("defineComponent() must be called with an options object"),
+            }
+        }
+        _ => return Err("The default export is not a component options object"),
+    };
+
+    let mut options = ComponentOptions::default();
+    let mut setup = None;
+    for property in object.properties.iter() {
+        let ObjectPropertyKind::ObjectProperty(property) = property else {
+            return Err("Spread component options cannot be converted");
+        };
+        let value_source = || property.value.span().source_text(source);
+        match static_key(&property.key) {
+            Some("name") => options.name = Some(value_source()),
+            Some("props") => options.props = Some(value_source()),
+            Some("emits") => options.emits = Some(value_source()),
+            Some("components") => check_components(&property.value)?,
+            Some("setup") => {
+                setup = match &property.value {
+                    Expression::FunctionExpression(func) => {
+                        func.body.as_deref().map(|body| (&*func.params, body))
+                    }
+                    Expression::ArrowFunctionExpression(arrow) if !arrow.expression => {
+                        Some((&*arrow.params, &*arrow.body))
+                    }
+                    _ => None,
+                };
+                if setup.is_none() {
+                    return Err("setup must be a function with a block body");
+                }
+            }
+            _ => return Err("Only name, props, emits, components and setup can be converted"),
+        }
+    }
+    let (params, body) = setup.ok_or("The component has no setup() function")?;
+
+    let (props_name, emit_name) = setup_params(params)?;
+    let (statements, returned) = split_setup_body(body, source)?;
+    let emitted = emit_name.map(|name| EmitUsage::collect(name, body, source));
+
+    let mut removed = vec![(export.span.start, export.span.end)];
+    if uses_define_component {
+        removed.extend(define_component_import(&parsed.program.body));
+    }
+    removed.sort_unstable();
+
+    let mut module = String::default();
+    let mut cursor = 0;
+    for (start, end) in removed {
+        module.push_str(&source[cursor..start as usize]);
+        cursor = end as usize;
+    }
+    module.push_str(&source[cursor..]);
+
+    let mut out = String::default();
+    let module = module.trim();
+    if !module.is_empty() {
+        out.push_str(module);
+        out.push_str("\n\n");
+    }
+    if let Some(name) = options.name {
+        append!(out, "defineOptions({{ name: {name} }})\n");
+    }
+    if options.props.is_some() || props_name.is_some() {
+        if let Some(name) = props_name {
+            append!(out, "const {name} = ");
+        }
+        append!(out, "defineProps({})\n", options.props.unwrap_or_default());
+    }
+    if options.emits.is_some() || emit_name.is_some() {
+        if let Some(name) = emit_name {
+            append!(out, "const {name} = ");
+        }
+        match (options.emits, &emitted) {
+            (Some(emits), _) => append!(out, "defineEmits({emits})\n"),
+            // Declare exactly the events emitted by name; an empty list would make
+            // every emit() an undeclared event
+            (None, Some(usage)) if !usage.dynamic && !usage.events.is_empty() => {
+                append!(out, "defineEmits([{}])\n", usage.events.join(", "));
+            }
+            (None, _) => out.push_str("defineEmits()\n"),
+        }
+    }
+    if !statements.is_empty() {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&statements);
+        out.push('\n');
+    }
+    for (name, value) in &returned {
+        if let Some(value) = value {
+            append!(out, "const {name} = {value}\n");
+        }
+    }
+
+    // The result must be a valid `<script setup>` as far as croquis is concerned,
+    // exposing everything setup() returned as a template binding.
+    let check = vize_croquis::script_parser::parse_script_setup(&out);
+    if !check.invalid_exports.is_empty() {
+        return Err("The converted script would contain exports");
+    }
+    if !returned
+        .iter()
+        .all(|(name, _)| check.bindings.bindings.contains_key(*name))
+    {
+        return Err("A value returned from setup() would not be visible to the template");
+    }
+
+    Ok(out)
+}
+
+/// Name of a non-computed identifier or string key.
+fn static_key<'s>(key: &'s PropertyKey<'_>) -> Option<&'s str> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Locally registered components must be used under their binding name.
+fn check_components(value: &Expression<'_>) -> Result<(), &'static str> {
+    let Expression::ObjectExpression(components) = value else {
+        return Err("components must be an object literal");
+    };
+    let same_name = components.properties.iter().all(|property| {
+        matches!(
+            property,
+            ObjectPropertyKind::ObjectProperty(property)
+                if matches!(
+                    (&property.value, static_key(&property.key)),
+                    (Expression::Identifier(ident), Some(key)) if ident.name.as_str() == key
+                )
+        )
+    });
+    if same_name {
+        Ok(())
+    } else {
+        Err("Components registered under a different name cannot be converted")
+    }
+}
+
+/// Names bound to the `props` parameter and the destructured `emit`.
+fn setup_params<'s>(
+    params: &'s FormalParameters<'_>,
+) -> Result<(Option<&'s str>, Option<&'s str>), &'static str> {
+    if params.items.len() > 2 || params.rest.is_some() {
+        return Err("setup() takes at most props and the setup context");
+    }
+
+    let props_name = match params.items.first().map(|param| &param.pattern) {
+        None => None,
+        Some(BindingPattern::BindingIdentifier(ident)) => Some(ident.name.as_str()),
+        Some(_) => return Err("Destructured setup() props cannot be converted"),
+    };
+
+    let emit_name = match params.items.get(1).map(|param| &param.pattern) {
+        None => None,
+        Some(BindingPattern::ObjectPattern(context)) if context.rest.is_none() => {
+            let mut emit_name = None;
+            for property in context.properties.iter() {
+                match (static_key(&property.key), &property.value) {
+                    (Some("emit"), BindingPattern::BindingIdentifier(ident)) => {
+                        emit_name = Some(ident.name.as_str());
+                    }
+                    _ => return Err("Only emit can be taken from the setup() context"),
+                }
+            }
+            emit_name
+        }
+        Some(_) => return Err("Destructure emit from the setup() context to convert it"),
+    };
+
+    Ok((props_name, emit_name))
+}
+
+/// Split the setup body into its statements (dedented) and the returned
+/// names, with the value of those that need a `const` declaration.
+#[allow(clippy::type_complexity)]
+fn split_setup_body<'s>(
+    body: &FunctionBody<'_>,
+    source: &'s str,
+) -> Result<(String, Vec<(&'s str, Option<&'s str>)>), &'static str> {
+    const SINGLE_RETURN: &str = "setup() must end with a single `return { ... }`";
+
+    let Some((Statement::ReturnStatement(ret), statements)) = body.statements.split_last() else {
+        return Err(SINGLE_RETURN);
+    };
+    let Some(Expression::ObjectExpression(object)) = &ret.argument else {
+        return Err(SINGLE_RETURN);
+    };
+
+    let mut counter = ReturnCounter::default();
+    for stmt in statements {
+        counter.visit_statement(stmt);
+    }
+    if counter.count > 0 {
+        return Err(SINGLE_RETURN);
+    }
+
+    let returned = returned_bindings(object, source)?;
+
+    let text = match (statements.first(), statements.last()) {
+        (Some(first), Some(last)) => {
+            let start = first.span().start as usize;
+            let indent = source[..start]
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.len() - line.trim_start().len());
+            let mut literals = LiteralSpans::default();
+            for stmt in statements {
+                literals.visit_statement(stmt);
+            }
+            let text = &source[start..last.span().end as usize];
+            dedent(text, start as u32, indent, &literals.spans)
+        }
+        _ => String::default(),
+    };
+
+    Ok((text, returned))
+}
+
+/// Names of the returned properties, with the value of those that are not
+/// plain shorthand references.
+fn returned_bindings<'s>(
+    object: &ObjectExpression<'_>,
+    source: &'s str,
+) -> Result<Vec<(&'s str, Option<&'s str>)>, &'static str> {
+    let mut returned = Vec::new();
+    for property in object.properties.iter() {
+        let ObjectPropertyKind::ObjectProperty(property) = property else {
+            return Err("Spread in the setup() return object cannot be converted");
+        };
+        if !matches!(property.kind, PropertyKind::Init) || property.method || property.computed {
+            return Err("Only plain properties in the setup() return object can be converted");
+        }
+        let PropertyKey::StaticIdentifier(key) = &property.key else {
+            return Err("Only plain properties in the setup() return object can be converted");
+        };
+        let name = key.span.source_text(source);
+        if matches!(&property.value, Expression::Identifier(ident) if ident.name == key.name) {
+            returned.push((name, None));
+        } else {
+            returned.push((name, Some(property.value.span().source_text(source))));
+        }
+    }
+    Ok(returned)
+}
+
+/// Spans of the `defineComponent` import that is no longer needed.
+fn define_component_import(body: &[Statement<'_>]) -> Option<(u32, u32)> {
+    body.iter().find_map(|stmt| {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            return None;
+        };
+        if decl.source.value.as_str() != "vue" {
+            return None;
+        }
+        let specifiers = decl.specifiers.as_ref()?;
+        let index = specifiers.iter().position(|specifier| {
+            matches!(
+                specifier,
+                ImportDeclarationSpecifier::ImportSpecifier(spec)
+                    if spec.local.name.as_str() == "defineComponent"
+            )
+        })?;
+
+        if specifiers.len() == 1 {
+            return Some((decl.span.start, decl.span.end));
+        }
+        let span = specifiers[index].span();
+        Some(match specifiers.get(index + 1) {
+            Some(next) => (span.start, next.span().start),
+            None => (specifiers[index - 1].span().end, span.end),
+        })
+    })
+}
+
+/// Remove up to `indent` columns of leading whitespace from every line but
+/// the first (which starts at the statement itself).
+///
+/// `offset` is the position of `text` in the script; lines starting inside one
+/// of the `literals` spans are part of a string and kept verbatim.
+fn dedent(text: &str, offset: u32, indent: usize, literals: &[(u32, u32)]) -> String {
+    let mut out = String::default();
+    let mut line_start = offset;
+    for (i, line) in text.split('\n').enumerate() {
+        let in_literal = literals
+            .iter()
+            .any(|&(start, end)| start < line_start && line_start < end);
+        if i > 0 {
+            out.push('\n');
+        }
+        if i > 0 && !in_literal {
+            let strip = line.len() - line.trim_start().len();
+            out.push_str(&line[strip.min(indent)..]);
+        } else {
+            out.push_str(line);
+        }
+        line_start += line.len() as u32 + 1;
+    }
+    out
+}
+
+/// Collects the spans of string and template literals.
+#[derive(Default)]
+struct LiteralSpans {
+    spans: Vec<(u32, u32)>,
+}
+
+impl<'a> Visit<'a> for LiteralSpans {
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        self.spans.push((lit.span.start, lit.span.end));
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.spans.push((lit.span.start, lit.span.end));
+        walk::walk_template_literal(self, lit);
+    }
+}
+
+/// Events emitted through the destructured `emit` of the setup context.
+struct EmitUsage<'s> {
+    name: &'s str,
+    source: &'s str,
+    /// Event names (as written in the source) of `emit('...')` calls, in order
+    events: Vec<&'s str>,
+    /// Whether `emit` is called with a computed event or used as a value
+    dynamic: bool,
+}
+
+impl<'s> EmitUsage<'s> {
+    fn collect(name: &'s str, body: &FunctionBody<'_>, source: &'s str) -> Self {
+        let mut usage = Self {
+            name,
+            source,
+            events: Vec::new(),
+            dynamic: false,
+        };
+        usage.visit_function_body(body);
+        usage
+    }
+}
+
+impl<'a> Visit<'a> for EmitUsage<'_> {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        let Expression::Identifier(callee) = &call.callee else {
+            walk::walk_call_expression(self, call);
+            return;
+        };
+        if callee.name.as_str() != self.name {
+            walk::walk_call_expression(self, call);
+            return;
+        }
+
+        match call.arguments.first() {
+            Some(Argument::StringLiteral(event)) => {
+                let event = event.span.source_text(self.source);
+                if !self.events.contains(&event) {
+                    self.events.push(event);
+                }
+            }
+            _ => self.dynamic = true,
+        }
+        for argument in call.arguments.iter() {
+            self.visit_argument(argument);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name.as_str() == self.name {
+            self.dynamic = true;
+        }
+    }
+}
+
+/// Counts `return` statements outside nested function bodies.
+#[derive(Default)]
+struct ReturnCounter {
+    count: usize,
+}
+
+impl<'a> Visit<'a> for ReturnCounter {
+    fn visit_return_statement(&mut self, _stmt: &oxc_ast::ast::ReturnStatement<'a>) {
+        self.count += 1;
+    }
+
+    fn visit_function_body(&mut self, _body: &FunctionBody<'a>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert_script, ScriptSetupConversionService};
+    use tower_lsp::lsp_types::Url;
+
+    #[test]
+    fn test_convert_define_component_setup() {
+        let source = r#"
+import { defineComponent, ref } from 'vue'
+import Child from './Child.vue'
+
+export default defineComponent({
+  name: 'Counter',
+  components: { Child },
+  props: { start: Number },
+  emits: ['change'],
+  setup(props, { emit }) {
+    const count = ref(props.start)
+    function inc() {
+      count.value++
+      emit('change', count.value)
+    }
+    return { count, inc, label: 'Count' }
+  },
+})
+"#;
+
+        let converted = convert_script(source, Some("ts")).unwrap();
+        assert_eq!(
+            converted,
+            r#"import { ref } from 'vue'
+import Child from './Child.vue'
+
+defineOptions({ name: 'Counter' })
+const props = defineProps({ start: Number })
+const emit = defineEmits(['change'])
+
+const count = ref(props.start)
+function inc() {
+  count.value++
+  emit('change', count.value)
+}
+const label = 'Count'
+"#
+        );
+    }
+
+    #[test]
+    fn test_convert_declares_emitted_events_and_keeps_literals() {
+        let source = r#"export default {
+  setup(props, { emit }) {
+    const message = `first
+    second`
+    function save() {
+      emit('save', message)
+      emit('update:open', false)
+      emit('save')
+    }
+    return { save }
+  },
+}
+"#;
+
+        let converted = convert_script(source, None).unwrap();
+        assert_eq!(
+            converted,
+            r#"const props = defineProps()
+const emit = defineEmits(['save', 'update:open'])
+
+const message = `first
+    second`
+function save() {
+  emit('save', message)
+  emit('update:open', false)
+  emit('save')
+}
+"#
+        );
+
+        // Events that cannot be listed statically are left undeclared
+        let dynamic = "export default { setup(p, { emit }) { emit(name); return {} } }";
+        assert!(convert_script(dynamic, None)
+            .unwrap()
+            .contains("const emit = defineEmits()\n"));
+    }
+
+    #[test]
+    fn test_convert_leaves_complex_components_untouched() {
+        let options_api = "export default { data() { return { a: 1 } } }";
+        assert!(convert_script(options_api, None).is_err());
+
+        let early_return = r#"export default {
+  setup() {
+    if (cond) return {}
+    return { a }
+  },
+}"#;
+        assert!(convert_script(early_return, None).is_err());
+
+        let context = "export default { setup(props, ctx) { return {} } }";
+        assert!(convert_script(context, None).is_err());
+
+        let uri = Url::parse("file:///project/src/App.vue").unwrap();
+        let sfc = "<script setup>\nconst a = 1\n</script>\n";
+        assert!(ScriptSetupConversionService::convert(sfc, &uri).is_err());
+    }
+
+    #[test]
+    fn test_convert_replaces_script_block() {
+        let uri = Url::parse("file:///project/src/App.vue").unwrap();
+        let sfc = r#"<template>
+  <p>{{ msg }}</p>
+</template>
+
+<script lang="ts">
+export default {
+  setup() {
+    const msg = 'hi'
+    return { msg }
+  },
+}
+</script>
+"#;
+
+        let edit = ScriptSetupConversionService::convert(sfc, &uri).unwrap();
+        let edits = &edit.changes.unwrap()[&uri];
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start.line, 4);
+        assert_eq!(edits[0].range.end.line, 11);
+        assert_eq!(
+            edits[0].new_text,
+            "<script setup lang=\"ts\">\nconst msg = 'hi'\n</script>"
+        );
+    }
+}
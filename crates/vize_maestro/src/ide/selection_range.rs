@@ -0,0 +1,402 @@
+//! Selection range provider.
+//!
+//! Provides "expand selection" ranges built from the parse trees:
+//! - Template: identifier → expression → attribute → element → parent element
+//! - Script: identifier → expression → statement → block
+//!
+//! Every chain ends with the block content and then the whole block
+//! including its tags.
+#![allow(clippy::disallowed_types, clippy::disallowed_methods)]
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_ast_visit::Visit;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use tower_lsp::lsp_types::{Position, Range, SelectionRange, Url};
+use vize_atelier_sfc::{BlockLocation, SfcDescriptor};
+use vize_relief::ast::{ExpressionNode, PropNode, SourceLocation, TemplateChildNode};
+use vize_relief::LineColumnCache;
+
+use super::file_rename::script_source_type;
+
+/// Selection range service.
+pub struct SelectionRangeService;
+
+impl SelectionRangeService {
+    /// Get one selection range chain per requested position.
+    pub fn get_ranges(content: &str, uri: &Url, positions: &[Position]) -> Vec<SelectionRange> {
+        let options = vize_atelier_sfc::SfcParseOptions {
+            filename: uri.path().to_string().into(),
+            ..Default::default()
+        };
+        let descriptor = vize_atelier_sfc::parse_sfc(content, options).ok();
+        let lines = LineColumnCache::new(content);
+
+        positions
+            .iter()
+            .map(|&position| {
+                let spans = match (&descriptor, lines.offset(position.line, position.character)) {
+                    (Some(descriptor), Some(offset)) => {
+                        Self::collect_spans(descriptor, offset as usize)
+                    }
+                    _ => Vec::new(),
+                };
+                Self::build_selection_range(&lines, position, spans)
+            })
+            .collect()
+    }
+
+    /// Collect the spans containing `offset`, in no particular order.
+    fn collect_spans(descriptor: &SfcDescriptor, offset: usize) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+
+        if let Some(ref template) = descriptor.template {
+            if Self::push_block_spans(&template.loc, offset, &mut spans) {
+                let source_type = if descriptor.is_typescript {
+                    SourceType::ts()
+                } else {
+                    SourceType::mjs()
+                };
+                let allocator = vize_carton::Bump::new();
+                let (root, _) = vize_armature::parse(&allocator, &template.content);
+                let mut collector = TemplateSpanCollector {
+                    source: &template.content,
+                    base_offset: template.loc.start,
+                    offset: offset - template.loc.start,
+                    source_type,
+                    spans: &mut spans,
+                };
+                collector.visit_children(&root.children);
+            }
+        }
+
+        for script in descriptor.script_setup.iter().chain(&descriptor.script) {
+            if Self::push_block_spans(&script.loc, offset, &mut spans) {
+                collect_script_spans(
+                    &script.content,
+                    script.loc.start,
+                    offset - script.loc.start,
+                    script_source_type(script.lang.as_deref()),
+                    false,
+                    &mut spans,
+                );
+            }
+        }
+
+        for style in &descriptor.styles {
+            Self::push_block_spans(&style.loc, offset, &mut spans);
+        }
+
+        spans
+    }
+
+    /// Push the whole block and its content when the block contains `offset`.
+    ///
+    /// Returns whether `offset` lies inside the block content.
+    fn push_block_spans(
+        loc: &BlockLocation,
+        offset: usize,
+        spans: &mut Vec<(usize, usize)>,
+    ) -> bool {
+        if offset < loc.tag_start || offset > loc.tag_end {
+            return false;
+        }
+        spans.push((loc.tag_start, loc.tag_end));
+        if offset < loc.start || offset > loc.end {
+            return false;
+        }
+        spans.push((loc.start, loc.end));
+        true
+    }
+
+    /// Nest the spans into a selection range chain, innermost first.
+    ///
+    /// Spans that do not strictly contain the next smaller one (siblings
+    /// touching the cursor, duplicates) are dropped.
+    fn build_selection_range(
+        lines: &LineColumnCache<'_>,
+        position: Position,
+        mut spans: Vec<(usize, usize)>,
+    ) -> SelectionRange {
+        spans.sort_by(|a, b| (b.1 - b.0).cmp(&(a.1 - a.0)).then(a.0.cmp(&b.0)));
+
+        let mut selection: Option<SelectionRange> = None;
+        let mut outer: Option<(usize, usize)> = None;
+        for (start, end) in spans {
+            if let Some((outer_start, outer_end)) = outer {
+                let nested = outer_start <= start && end <= outer_end;
+                if !nested || (start, end) == (outer_start, outer_end) {
+                    continue;
+                }
+            }
+            outer = Some((start, end));
+
+            let start = lines.position(start as u32);
+            let end = lines.position(end as u32);
+            selection = Some(SelectionRange {
+                range: Range {
+                    start: Position {
+                        line: start.line,
+                        character: start.column,
+                    },
+                    end: Position {
+                        line: end.line,
+                        character: end.column,
+                    },
+                },
+                parent: selection.map(Box::new),
+            });
+        }
+
+        selection.unwrap_or(SelectionRange {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            parent: None,
+        })
+    }
+}
+
+/// Collects the template node spans containing the cursor.
+struct TemplateSpanCollector<'s, 'v> {
+    /// Template block content.
+    source: &'s str,
+    /// Offset of the template content in the document.
+    base_offset: usize,
+    /// Cursor offset relative to the template content.
+    offset: usize,
+    /// Source type used to parse template expressions.
+    source_type: SourceType,
+    spans: &'v mut Vec<(usize, usize)>,
+}
+
+impl TemplateSpanCollector<'_, '_> {
+    fn visit_children(&mut self, children: &[TemplateChildNode<'_>]) {
+        for child in children {
+            match child {
+                TemplateChildNode::Element(element) if self.push(&element.loc) => {
+                    for prop in element.props.iter() {
+                        if !self.push_prop(prop.loc()) {
+                            continue;
+                        }
+                        match prop {
+                            PropNode::Attribute(attr) => {
+                                if let Some(ref value) = attr.value {
+                                    self.push(&value.loc);
+                                }
+                            }
+                            PropNode::Directive(dir) => {
+                                if let Some(ref arg) = dir.arg {
+                                    self.visit_expression(arg);
+                                }
+                                if let Some(ref exp) = dir.exp {
+                                    self.visit_expression(exp);
+                                }
+                            }
+                        }
+                    }
+                    self.visit_children(&element.children);
+                }
+                TemplateChildNode::Interpolation(interpolation)
+                    if self.push(&interpolation.loc) =>
+                {
+                    self.visit_expression(&interpolation.content);
+                }
+                TemplateChildNode::Text(text) => {
+                    self.push(&text.loc);
+                }
+                TemplateChildNode::Comment(comment) => {
+                    self.push(&comment.loc);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Push the expression and the JS nodes inside it.
+    fn visit_expression(&mut self, expression: &ExpressionNode<'_>) {
+        let loc = expression.loc();
+        if !self.push(loc) {
+            return;
+        }
+        let (start, end) = (loc.start.offset as usize, loc.end.offset as usize);
+        if let Some(code) = self.source.get(start..end) {
+            collect_script_spans(
+                code,
+                self.base_offset + start,
+                self.offset - start,
+                self.source_type,
+                true,
+                self.spans,
+            );
+        }
+    }
+
+    /// Push `loc` if it contains the cursor, and return whether it did.
+    fn push(&mut self, loc: &SourceLocation) -> bool {
+        self.push_span(loc.start.offset as usize, loc.end.offset as usize)
+    }
+
+    /// Push an attribute, including the closing quote its location stops at.
+    fn push_prop(&mut self, loc: &SourceLocation) -> bool {
+        let end = loc.end.offset as usize;
+        let quoted = matches!(self.source.as_bytes().get(end), Some(b'"' | b'\''));
+        self.push_span(loc.start.offset as usize, end + usize::from(quoted))
+    }
+
+    fn push_span(&mut self, start: usize, end: usize) -> bool {
+        if start > self.offset || self.offset > end {
+            return false;
+        }
+        self.spans
+            .push((self.base_offset + start, self.base_offset + end));
+        true
+    }
+}
+
+/// Collect the spans of the JS/TS nodes in `code` that contain `offset`.
+///
+/// `code` is parsed as a single expression when `is_expression` is set and
+/// as a module otherwise; spans are shifted by `base_offset`.
+fn collect_script_spans(
+    code: &str,
+    base_offset: usize,
+    offset: usize,
+    source_type: SourceType,
+    is_expression: bool,
+    spans: &mut Vec<(usize, usize)>,
+) {
+    let allocator = Allocator::default();
+    let mut collector = ScriptSpanCollector {
+        offset: offset as u32,
+        spans: Vec::new(),
+    };
+
+    let parser = Parser::new(&allocator, code, source_type);
+    if is_expression {
+        let Ok(expression) = parser.parse_expression() else {
+            return;
+        };
+        collector.visit_expression(&expression);
+    } else {
+        let parsed = parser.parse();
+        collector.visit_program(&parsed.program);
+    }
+
+    spans.extend(
+        collector
+            .spans
+            .into_iter()
+            .map(|(start, end)| (base_offset + start as usize, base_offset + end as usize)),
+    );
+}
+
+/// Collects the spans of all AST nodes containing the cursor.
+struct ScriptSpanCollector {
+    offset: u32,
+    spans: Vec<(u32, u32)>,
+}
+
+impl<'a> Visit<'a> for ScriptSpanCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        if span.start <= self.offset && self.offset <= span.end {
+            self.spans.push((span.start, span.end));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelectionRangeService;
+    use tower_lsp::lsp_types::{Position, SelectionRange, Url};
+    use vize_relief::LineColumnCache;
+
+    /// Selection texts from the innermost range outwards.
+    fn selection_texts(content: &str, needle: &str) -> Vec<String> {
+        let lines = LineColumnCache::new(content);
+        let offset = content.find(needle).unwrap() as u32 + 1;
+        let pos = lines.position(offset);
+        let uri = Url::parse("file:///test.vue").unwrap();
+        let ranges = SelectionRangeService::get_ranges(
+            content,
+            &uri,
+            &[Position::new(pos.line, pos.column)],
+        );
+        assert_eq!(ranges.len(), 1);
+
+        let mut texts = Vec::new();
+        let mut current: Option<&SelectionRange> = ranges.first();
+        while let Some(selection) = current {
+            let start = lines
+                .offset(selection.range.start.line, selection.range.start.character)
+                .unwrap() as usize;
+            let end = lines
+                .offset(selection.range.end.line, selection.range.end.character)
+                .unwrap() as usize;
+            texts.push(content[start..end].to_string());
+            current = selection.parent.as_deref();
+        }
+        texts
+    }
+
+    #[test]
+    fn test_template_selection_ranges() {
+        let content = r#"<template>
+  <div class="card">
+    <span :title="user.name">{{ count + 1 }}</span>
+  </div>
+</template>
+"#;
+
+        let texts = selection_texts(content, "name");
+        assert_eq!(texts[0], "name");
+        assert_eq!(texts[1], "user.name");
+        assert_eq!(texts[2], r#":title="user.name""#);
+        assert_eq!(
+            texts[3],
+            r#"<span :title="user.name">{{ count + 1 }}</span>"#
+        );
+        assert!(texts[4].starts_with(r#"<div class="card">"#));
+        assert!(texts[4].ends_with("</div>"));
+        assert!(texts[texts.len() - 2].starts_with("\n  <div"));
+        assert_eq!(texts.last().unwrap(), &content[..content.len() - 1]);
+
+        let texts = selection_texts(content, "count");
+        assert_eq!(texts[0], "count");
+        assert_eq!(texts[1], "count + 1");
+        assert_eq!(texts[2], "{{ count + 1 }}");
+        assert!(texts[3].starts_with("<span"));
+    }
+
+    #[test]
+    fn test_script_selection_ranges() {
+        let content = r#"<script setup lang="ts">
+const total = items.map((item) => item.price)
+</script>
+"#;
+
+        let texts = selection_texts(content, "price");
+        assert_eq!(texts[0], "price");
+        assert_eq!(texts[1], "item.price");
+        assert!(texts.contains(&"(item) => item.price".to_string()));
+        assert!(texts.contains(&"items.map((item) => item.price)".to_string()));
+        assert!(texts.contains(&"const total = items.map((item) => item.price)".to_string()));
+        assert_eq!(texts.last().unwrap(), &content[..content.len() - 1]);
+    }
+
+    #[test]
+    fn test_position_outside_blocks() {
+        let content = "<template><div /></template>\n\n<style></style>\n";
+        let uri = Url::parse("file:///test.vue").unwrap();
+        let position = Position::new(1, 0);
+        let ranges = SelectionRangeService::get_ranges(content, &uri, &[position]);
+
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].range.start, position);
+        assert_eq!(ranges[0].range.end, position);
+        assert!(ranges[0].parent.is_none());
+    }
+}
@@ -3,29 +3,14 @@
 //! Provides delta encoding, position conversion, and identifier helpers.
 
 use tower_lsp::lsp_types::SemanticToken;
+use vize_relief::LineColumnCache;
 
 use super::types::AbsoluteToken;
 
-/// Convert byte offset to (line, column) - 1-indexed.
-pub(crate) fn offset_to_line_col(source: &str, offset: usize) -> (u32, u32) {
-    let mut line = 1u32;
-    let mut col = 0u32;
-    let mut current = 0;
-
-    for ch in source.chars() {
-        if current >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
-        }
-        current += ch.len_utf8();
-    }
-
-    (line, col)
+/// Convert byte offset to (line, column) - line is 1-indexed, column is 0-indexed.
+pub(crate) fn offset_to_line_col(lines: &LineColumnCache<'_>, offset: usize) -> (u32, u32) {
+    let pos = lines.position(offset as u32);
+    (pos.line + 1, pos.column)
 }
 
 /// Encode tokens using delta encoding.
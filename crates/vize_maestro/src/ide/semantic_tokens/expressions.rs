@@ -7,11 +7,12 @@ use super::{
     encoding::{is_ident_char, is_ident_start, offset_to_line_col},
     types::{AbsoluteToken, TokenType},
 };
+use vize_relief::LineColumnCache;
 
 /// Tokenize a JavaScript/TypeScript expression for syntax highlighting.
 pub(crate) fn tokenize_expression(
     expr: &str,
-    template: &str,
+    lines: &LineColumnCache<'_>,
     expr_offset: usize,
     base_line: u32,
     tokens: &mut Vec<AbsoluteToken>,
@@ -48,7 +49,7 @@ pub(crate) fn tokenize_expression(
                 }
             }
             let abs_offset = expr_offset + start;
-            let (line, col) = offset_to_line_col(template, abs_offset);
+            let (line, col) = offset_to_line_col(lines, abs_offset);
             tokens.push(AbsoluteToken {
                 line: base_line + line - 1,
                 start: col,
@@ -75,7 +76,7 @@ pub(crate) fn tokenize_expression(
                 i += 1; // closing quote
             }
             let abs_offset = expr_offset + start;
-            let (line, col) = offset_to_line_col(template, abs_offset);
+            let (line, col) = offset_to_line_col(lines, abs_offset);
             tokens.push(AbsoluteToken {
                 line: base_line + line - 1,
                 start: col,
@@ -94,7 +95,7 @@ pub(crate) fn tokenize_expression(
             }
             let ident = &expr[start..i];
             let abs_offset = expr_offset + start;
-            let (line, col) = offset_to_line_col(template, abs_offset);
+            let (line, col) = offset_to_line_col(lines, abs_offset);
 
             // Determine token type
             let token_type = if is_keyword(ident) || is_boolean_or_null(ident) {
@@ -124,7 +125,7 @@ pub(crate) fn tokenize_expression(
             let op_len = operator_length(&expr[i..]);
             i += op_len;
             let abs_offset = expr_offset + start;
-            let (line, col) = offset_to_line_col(template, abs_offset);
+            let (line, col) = offset_to_line_col(lines, abs_offset);
             tokens.push(AbsoluteToken {
                 line: base_line + line - 1,
                 start: col,
@@ -16,6 +16,7 @@ mod types;
 pub use types::{TokenModifier, TokenType};
 
 use tower_lsp::lsp_types::SemanticTokensResult;
+use vize_relief::LineColumnCache;
 
 use encoding::{encode_tokens, offset_to_line_col};
 use types::AbsoluteToken;
@@ -122,6 +123,7 @@ impl SemanticTokensService {
 
     /// Collect <art> and </art> tag tokens.
     fn collect_art_block_tokens(content: &str, tokens: &mut Vec<AbsoluteToken>) {
+        let lines = LineColumnCache::new(content);
         // Find <art ...> opening tags
         let mut pos = 0;
         while let Some(start) = content[pos..].find("<art") {
@@ -135,7 +137,7 @@ impl SemanticTokensService {
                     || next_char == b'\t'
                     || next_char == b'>'
                 {
-                    let (line, col) = offset_to_line_col(content, abs_start);
+                    let (line, col) = offset_to_line_col(&lines, abs_start);
                     tokens.push(AbsoluteToken {
                         line,
                         start: col,
@@ -152,7 +154,7 @@ impl SemanticTokensService {
         pos = 0;
         while let Some(start) = content[pos..].find("</art>") {
             let abs_start = pos + start;
-            let (line, col) = offset_to_line_col(content, abs_start);
+            let (line, col) = offset_to_line_col(&lines, abs_start);
             tokens.push(AbsoluteToken {
                 line,
                 start: col,
@@ -166,6 +168,7 @@ impl SemanticTokensService {
 
     /// Collect <variant> and </variant> tag tokens.
     fn collect_variant_block_tokens(content: &str, tokens: &mut Vec<AbsoluteToken>) {
+        let lines = LineColumnCache::new(content);
         // Find <variant ...> opening tags
         let mut pos = 0;
         while let Some(start) = content[pos..].find("<variant") {
@@ -178,7 +181,7 @@ impl SemanticTokensService {
                     || next_char == b'\t'
                     || next_char == b'>'
                 {
-                    let (line, col) = offset_to_line_col(content, abs_start);
+                    let (line, col) = offset_to_line_col(&lines, abs_start);
                     tokens.push(AbsoluteToken {
                         line,
                         start: col,
@@ -195,7 +198,7 @@ impl SemanticTokensService {
         pos = 0;
         while let Some(start) = content[pos..].find("</variant>") {
             let abs_start = pos + start;
-            let (line, col) = offset_to_line_col(content, abs_start);
+            let (line, col) = offset_to_line_col(&lines, abs_start);
             tokens.push(AbsoluteToken {
                 line,
                 start: col,
@@ -209,6 +212,7 @@ impl SemanticTokensService {
 
     /// Collect Art-specific attribute tokens.
     fn collect_art_attribute_tokens(content: &str, tokens: &mut Vec<AbsoluteToken>) {
+        let lines = LineColumnCache::new(content);
         // Art block attributes
         let art_attrs = [
             "title",
@@ -234,7 +238,7 @@ impl SemanticTokensService {
                 if abs_start > 0 {
                     let before = content.as_bytes()[abs_start - 1];
                     if before == b' ' || before == b'\n' || before == b'\t' {
-                        let (line, col) = offset_to_line_col(content, abs_start);
+                        let (line, col) = offset_to_line_col(&lines, abs_start);
 
                         // Highlight attribute name
                         tokens.push(AbsoluteToken {
@@ -254,7 +258,7 @@ impl SemanticTokensService {
                                     content[value_start + 1..].find(quote_char as char)
                                 {
                                     let (val_line, val_col) =
-                                        offset_to_line_col(content, value_start);
+                                        offset_to_line_col(&lines, value_start);
                                     tokens.push(AbsoluteToken {
                                         line: val_line,
                                         start: val_col,
@@ -286,7 +290,7 @@ impl SemanticTokensService {
                     || after == b'\t'
                     || after == b'/'
                 {
-                    let (line, col) = offset_to_line_col(content, abs_start);
+                    let (line, col) = offset_to_line_col(&lines, abs_start);
                     tokens.push(AbsoluteToken {
                         line,
                         start: col,
@@ -302,6 +306,7 @@ impl SemanticTokensService {
 
     /// Collect tokens from script in Art files.
     fn collect_art_script_tokens(content: &str, tokens: &mut Vec<AbsoluteToken>) {
+        let lines = LineColumnCache::new(content);
         // Find script setup block
         if let Some(script_start) = content.find("<script") {
             if let Some(script_end) = content[script_start..].find("</script>") {
@@ -319,7 +324,7 @@ impl SemanticTokensService {
                     let mut pos = 0;
                     while let Some(start) = script_content[pos..].find("import ") {
                         let abs_start = base_offset + pos + start;
-                        let (line, col) = offset_to_line_col(content, abs_start);
+                        let (line, col) = offset_to_line_col(&lines, abs_start);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -334,7 +339,7 @@ impl SemanticTokensService {
                     pos = 0;
                     while let Some(start) = script_content[pos..].find(" from ") {
                         let abs_start = base_offset + pos + start + 1; // skip leading space
-                        let (line, col) = offset_to_line_col(content, abs_start);
+                        let (line, col) = offset_to_line_col(&lines, abs_start);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -355,7 +360,7 @@ impl SemanticTokensService {
                             let after_quote = &remaining[start + 1..];
                             if let Some(end) = after_quote.find(quote_char as char) {
                                 let abs_start = base_offset + pos + start;
-                                let (line, col) = offset_to_line_col(content, abs_start);
+                                let (line, col) = offset_to_line_col(&lines, abs_start);
                                 tokens.push(AbsoluteToken {
                                     line,
                                     start: col,
@@ -385,6 +390,7 @@ impl SemanticTokensService {
         tokens: &mut Vec<AbsoluteToken>,
         loc: &vize_atelier_sfc::BlockLocation,
     ) {
+        let lines = LineColumnCache::new(content);
         let range_start = loc.tag_start;
         let range_end = loc.end;
 
@@ -409,7 +415,7 @@ impl SemanticTokensService {
                         || next_char == b'\t'
                         || next_char == b'>'
                     {
-                        let (line, col) = offset_to_line_col(content, abs_pos);
+                        let (line, col) = offset_to_line_col(&lines, abs_pos);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -425,7 +431,7 @@ impl SemanticTokensService {
             pos = 0;
             while let Some(start) = slice[pos..].find("</art>") {
                 let abs_pos = range_start + pos + start;
-                let (line, col) = offset_to_line_col(content, abs_pos);
+                let (line, col) = offset_to_line_col(&lines, abs_pos);
                 tokens.push(AbsoluteToken {
                     line,
                     start: col,
@@ -450,7 +456,7 @@ impl SemanticTokensService {
                         || next_char == b'\t'
                         || next_char == b'>'
                     {
-                        let (line, col) = offset_to_line_col(content, abs_pos);
+                        let (line, col) = offset_to_line_col(&lines, abs_pos);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -466,7 +472,7 @@ impl SemanticTokensService {
             pos = 0;
             while let Some(start) = slice[pos..].find("</variant>") {
                 let abs_pos = range_start + pos + start;
-                let (line, col) = offset_to_line_col(content, abs_pos);
+                let (line, col) = offset_to_line_col(&lines, abs_pos);
                 tokens.push(AbsoluteToken {
                     line,
                     start: col,
@@ -501,7 +507,7 @@ impl SemanticTokensService {
                 if rel_pos > 0 {
                     let before = slice.as_bytes()[rel_pos - 1];
                     if before == b' ' || before == b'\n' || before == b'\t' {
-                        let (line, col) = offset_to_line_col(content, abs_pos);
+                        let (line, col) = offset_to_line_col(&lines, abs_pos);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -518,7 +524,7 @@ impl SemanticTokensService {
                                 if let Some(end) = slice[value_start + 1..].find(quote_char as char)
                                 {
                                     let abs_val = range_start + value_start;
-                                    let (val_line, val_col) = offset_to_line_col(content, abs_val);
+                                    let (val_line, val_col) = offset_to_line_col(&lines, abs_val);
                                     tokens.push(AbsoluteToken {
                                         line: val_line,
                                         start: val_col,
@@ -551,7 +557,7 @@ impl SemanticTokensService {
                         || after == b'\t'
                         || after == b'/'
                     {
-                        let (line, col) = offset_to_line_col(content, abs_pos);
+                        let (line, col) = offset_to_line_col(&lines, abs_pos);
                         tokens.push(AbsoluteToken {
                             line,
                             start: col,
@@ -574,6 +580,7 @@ mod tests {
         TokenType,
     };
     use tower_lsp::lsp_types::SemanticTokensResult;
+    use vize_relief::LineColumnCache;
 
     #[test]
     fn test_extract_identifiers() {
@@ -596,10 +603,10 @@ mod tests {
 
     #[test]
     fn test_offset_to_line_col() {
-        let source = "abc\ndef\nghi";
-        assert_eq!(offset_to_line_col(source, 0), (1, 0));
-        assert_eq!(offset_to_line_col(source, 4), (2, 0));
-        assert_eq!(offset_to_line_col(source, 8), (3, 0));
+        let lines = LineColumnCache::new("abc\ndef\nghi");
+        assert_eq!(offset_to_line_col(&lines, 0), (1, 0));
+        assert_eq!(offset_to_line_col(&lines, 4), (2, 0));
+        assert_eq!(offset_to_line_col(&lines, 8), (3, 0));
     }
 
     #[test]
@@ -758,9 +765,10 @@ const count = ref(0)
     #[test]
     fn test_tokenize_expression() {
         let expr = "todoGuards.isActive(todo) || todoGuards.isCompleted(todo)";
-        let template_str = expr; // Use the expression as the "template" for position calculation
+        // Use the expression as the "template" for position calculation
+        let lines = LineColumnCache::new(expr);
         let mut tokens = Vec::new();
-        expressions::tokenize_expression(expr, template_str, 0, 1, &mut tokens);
+        expressions::tokenize_expression(expr, &lines, 0, 1, &mut tokens);
 
         // Debug: print all tokens
         for token in &tokens {
@@ -6,15 +6,17 @@ use super::{
     encoding::offset_to_line_col,
     types::{AbsoluteToken, TokenType},
 };
+use vize_relief::LineColumnCache;
 
 /// Collect tokens from style content.
 pub(crate) fn collect_style_tokens(style: &str, base_line: u32, tokens: &mut Vec<AbsoluteToken>) {
     // Find v-bind() in CSS
+    let lines = LineColumnCache::new(style);
     let pattern = "v-bind(";
     let mut pos = 0;
     while let Some(start) = style[pos..].find(pattern) {
         let abs_start = pos + start;
-        let (line, col) = offset_to_line_col(style, abs_start);
+        let (line, col) = offset_to_line_col(&lines, abs_start);
 
         // Highlight v-bind
         tokens.push(AbsoluteToken {
@@ -32,7 +34,7 @@ pub(crate) fn collect_style_tokens(style: &str, base_line: u32, tokens: &mut Vec
             let var = var.trim_matches(|c| c == '"' || c == '\'');
 
             if !var.is_empty() {
-                let (var_line, var_col) = offset_to_line_col(style, var_start);
+                let (var_line, var_col) = offset_to_line_col(&lines, var_start);
                 tokens.push(AbsoluteToken {
                     line: base_line + var_line - 1,
                     start: var_col,
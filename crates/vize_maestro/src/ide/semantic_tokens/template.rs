@@ -8,6 +8,7 @@ use super::{
     expressions::tokenize_expression,
     types::{AbsoluteToken, TokenType},
 };
+use vize_relief::LineColumnCache;
 
 /// Collect tokens from template content.
 pub(crate) fn collect_template_tokens(
@@ -33,6 +34,7 @@ pub(crate) fn collect_template_tokens(
 
 /// Collect directive tokens (v-if, v-for, v-model, etc.)
 fn collect_directive_tokens(template: &str, base_line: u32, tokens: &mut Vec<AbsoluteToken>) {
+    let lines = LineColumnCache::new(template);
     let directives = [
         "v-if",
         "v-else-if",
@@ -53,7 +55,7 @@ fn collect_directive_tokens(template: &str, base_line: u32, tokens: &mut Vec<Abs
         let mut pos = 0;
         while let Some(found) = template[pos..].find(directive) {
             let abs_pos = pos + found;
-            let (line, col) = offset_to_line_col(template, abs_pos);
+            let (line, col) = offset_to_line_col(&lines, abs_pos);
 
             tokens.push(AbsoluteToken {
                 line: base_line + line - 1,
@@ -74,6 +76,7 @@ pub(crate) fn collect_interpolation_tokens(
     base_line: u32,
     tokens: &mut Vec<AbsoluteToken>,
 ) {
+    let lines = LineColumnCache::new(template);
     let mut pos = 0;
     while let Some(start) = template[pos..].find("{{") {
         let abs_start = pos + start;
@@ -83,7 +86,7 @@ pub(crate) fn collect_interpolation_tokens(
             let expr = &template[expr_start..expr_end];
 
             // Tokenize the entire expression
-            tokenize_expression(expr, template, expr_start, base_line, tokens);
+            tokenize_expression(expr, &lines, expr_start, base_line, tokens);
 
             pos = abs_start + end + 2;
         } else {
@@ -94,6 +97,7 @@ pub(crate) fn collect_interpolation_tokens(
 
 /// Collect event handler tokens (@click, @input, etc.)
 fn collect_event_tokens(template: &str, base_line: u32, tokens: &mut Vec<AbsoluteToken>) {
+    let lines = LineColumnCache::new(template);
     let mut pos = 0;
     while let Some(start) = template[pos..].find('@') {
         let abs_start = pos + start;
@@ -105,7 +109,7 @@ fn collect_event_tokens(template: &str, base_line: u32, tokens: &mut Vec<Absolut
             .unwrap_or(remaining.len());
 
         if event_end > 0 {
-            let (line, col) = offset_to_line_col(template, abs_start);
+            let (line, col) = offset_to_line_col(&lines, abs_start);
 
             tokens.push(AbsoluteToken {
                 line: base_line + line - 1,
@@ -122,6 +126,7 @@ fn collect_event_tokens(template: &str, base_line: u32, tokens: &mut Vec<Absolut
 
 /// Collect v-bind tokens (:prop, :class, etc.)
 fn collect_bind_tokens(template: &str, base_line: u32, tokens: &mut Vec<AbsoluteToken>) {
+    let lines = LineColumnCache::new(template);
     // Find :prop patterns (but not ::)
     let mut pos = 0;
     while let Some(start) = template[pos..].find(':') {
@@ -143,7 +148,7 @@ fn collect_bind_tokens(template: &str, base_line: u32, tokens: &mut Vec<Absolute
                     .unwrap_or(remaining.len());
 
                 if prop_end > 0 {
-                    let (line, col) = offset_to_line_col(template, abs_start);
+                    let (line, col) = offset_to_line_col(&lines, abs_start);
 
                     tokens.push(AbsoluteToken {
                         line: base_line + line - 1,
@@ -166,6 +171,7 @@ pub(crate) fn collect_directive_expression_tokens(
     base_line: u32,
     tokens: &mut Vec<AbsoluteToken>,
 ) {
+    let lines = LineColumnCache::new(template);
     let bytes = template.as_bytes();
     let mut pos = 0;
 
@@ -215,7 +221,7 @@ pub(crate) fn collect_directive_expression_tokens(
                             // Tokenize the entire expression
                             tokenize_expression(
                                 expr,
-                                template,
+                                &lines,
                                 start + expr_start,
                                 base_line,
                                 tokens,
@@ -237,6 +243,8 @@ pub(crate) fn collect_directive_expression_tokens(
 pub(crate) fn collect_script_tokens(script: &str, base_line: u32, tokens: &mut Vec<AbsoluteToken>) {
     use super::types::TokenModifier;
 
+    let lines = LineColumnCache::new(script);
+
     // Vue compiler macros (special highlighting)
     let compiler_macros = [
         "defineProps",
@@ -277,7 +285,7 @@ pub(crate) fn collect_script_tokens(script: &str, base_line: u32, tokens: &mut V
             let is_start = abs_pos == 0 || !is_ident_char(script.as_bytes()[abs_pos - 1] as char);
 
             if is_start {
-                let (line, col) = offset_to_line_col(script, abs_pos);
+                let (line, col) = offset_to_line_col(&lines, abs_pos);
 
                 tokens.push(AbsoluteToken {
                     line: base_line + line - 1,
@@ -304,7 +312,7 @@ pub(crate) fn collect_script_tokens(script: &str, base_line: u32, tokens: &mut V
             let is_start = abs_pos == 0 || !is_ident_char(script.as_bytes()[abs_pos - 1] as char);
 
             if is_start {
-                let (line, col) = offset_to_line_col(script, abs_pos);
+                let (line, col) = offset_to_line_col(&lines, abs_pos);
 
                 tokens.push(AbsoluteToken {
                     line: base_line + line - 1,
@@ -0,0 +1,53 @@
+//! Style rule walking for the SFC's `<style>` blocks.
+//!
+//! Shared by the CSS class hover and the class completions. Blocks are parsed
+//! with lightningcss, so only plain CSS blocks are read.
+
+use lightningcss::rules::{style::StyleRule, CssRule};
+use lightningcss::stylesheet::{ParserOptions, StyleSheet};
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions, SfcStyleBlock};
+
+/// Call `f` with each CSS `<style>` block of the SFC and its parsed stylesheet.
+pub(crate) fn for_each_css_style(
+    content: &str,
+    mut f: impl FnMut(&SfcStyleBlock<'_>, &StyleSheet<'_, '_>),
+) {
+    let Ok(descriptor) = parse_sfc(content, SfcParseOptions::default()) else {
+        return;
+    };
+
+    for style in descriptor.styles.iter() {
+        // Preprocessor syntax is not understood by lightningcss
+        if style
+            .lang
+            .as_deref()
+            .is_some_and(|lang| !lang.eq_ignore_ascii_case("css"))
+        {
+            continue;
+        }
+        let Ok(stylesheet) = StyleSheet::parse(&style.content, ParserOptions::default()) else {
+            continue;
+        };
+        f(style, &stylesheet);
+    }
+}
+
+/// Call `f` with every style rule in `rules`, including rules nested in
+/// `@media`, `@supports` and `@layer` blocks or in other style rules.
+pub(crate) fn for_each_style_rule<'i>(
+    rules: &[CssRule<'i>],
+    f: &mut impl FnMut(&CssRule<'i>, &StyleRule<'i>),
+) {
+    for rule in rules {
+        match rule {
+            CssRule::Style(style_rule) => {
+                f(rule, style_rule);
+                for_each_style_rule(&style_rule.rules.0, f);
+            }
+            CssRule::Media(media) => for_each_style_rule(&media.rules.0, f),
+            CssRule::Supports(supports) => for_each_style_rule(&supports.rules.0, f),
+            CssRule::LayerBlock(layer) => for_each_style_rule(&layer.rules.0, f),
+            _ => {}
+        }
+    }
+}
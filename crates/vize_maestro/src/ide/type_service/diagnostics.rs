@@ -9,6 +9,7 @@ use tower_lsp::lsp_types::{
     CodeDescription, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location,
     NumberOrString, Position, Range, Url,
 };
+use vize_relief::LineColumnCache;
 use vize_vitrine::{type_check_sfc, TypeCheckOptions, TypeSeverity};
 
 use super::{LspTypeCheckOptions, TypeService};
@@ -47,14 +48,15 @@ impl TypeService {
         };
 
         let result = type_check_sfc(&content, &options);
+        let lines = LineColumnCache::new(&content);
 
         // Convert to LSP diagnostics
         result
             .diagnostics
             .into_iter()
             .map(|diag| {
-                let (start_line, start_col) = offset_to_line_col(&content, diag.start as usize);
-                let (end_line, end_col) = offset_to_line_col(&content, diag.end as usize);
+                let (start_line, start_col) = offset_to_line_col(&lines, diag.start as usize);
+                let (end_line, end_col) = offset_to_line_col(&lines, diag.end as usize);
 
                 // Build related information if present
                 let related_information: Option<Vec<DiagnosticRelatedInformation>> = if diag
@@ -68,9 +70,9 @@ impl TypeService {
                             .iter()
                             .map(|rel| {
                                 let (rel_start_line, rel_start_col) =
-                                    offset_to_line_col(&content, rel.start as usize);
+                                    offset_to_line_col(&lines, rel.start as usize);
                                 let (rel_end_line, rel_end_col) =
-                                    offset_to_line_col(&content, rel.end as usize);
+                                    offset_to_line_col(&lines, rel.end as usize);
 
                                 #[allow(clippy::disallowed_macros)]
                                 DiagnosticRelatedInformation {
@@ -181,15 +183,15 @@ impl TypeService {
 
         // Template block offset
         let template_start_line = template.loc.start_line as u32;
+        let lines = LineColumnCache::new(&template.content);
 
         // Convert to LSP diagnostics
         result
             .diagnostics
             .into_iter()
             .map(|diag| {
-                let (start_line, start_col) =
-                    offset_to_line_col(&template.content, diag.start as usize);
-                let (end_line, end_col) = offset_to_line_col(&template.content, diag.end as usize);
+                let (start_line, start_col) = offset_to_line_col(&lines, diag.start as usize);
+                let (end_line, end_col) = offset_to_line_col(&lines, diag.end as usize);
 
                 Diagnostic {
                     range: Range {
@@ -265,23 +267,7 @@ impl TypeService {
 }
 
 /// Convert byte offset to (line, column) - line is 1-indexed, column is 0-indexed.
-pub(super) fn offset_to_line_col(source: &str, offset: usize) -> (u32, u32) {
-    let mut line = 1u32;
-    let mut col = 0u32;
-    let mut current_offset = 0;
-
-    for ch in source.chars() {
-        if current_offset >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
-        }
-        current_offset += ch.len_utf8();
-    }
-
-    (line, col)
+pub(super) fn offset_to_line_col(lines: &LineColumnCache<'_>, offset: usize) -> (u32, u32) {
+    let pos = lines.position(offset as u32);
+    (pos.line + 1, pos.column)
 }
@@ -138,7 +138,7 @@ pub fn server_capabilities() -> ServerCapabilities {
 
         // Document links
         document_link_provider: Some(DocumentLinkOptions {
-            resolve_provider: Some(true),
+            resolve_provider: Some(false),
             work_done_progress_options: WorkDoneProgressOptions::default(),
         }),
 
@@ -167,13 +167,18 @@ pub fn server_capabilities() -> ServerCapabilities {
             }),
         }),
 
+        // Commands
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![crate::ide::CONVERT_TO_SCRIPT_SETUP_COMMAND.to_string()],
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        }),
+
         // Features not yet implemented
         type_definition_provider: None,
         implementation_provider: None,
         declaration_provider: None,
         color_provider: None,
         document_on_type_formatting_provider: None,
-        execute_command_provider: None,
         linked_editing_range_provider: None,
         call_hierarchy_provider: None,
         moniker_provider: None,
@@ -11,22 +11,24 @@ use tower_lsp::{
         CompletionParams, CompletionResponse, DidChangeTextDocumentParams,
         DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
         DocumentFormattingParams, DocumentLink, DocumentLinkParams, DocumentRangeFormattingParams,
-        DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, FoldingRange,
-        FoldingRangeKind, FoldingRangeParams, GotoDefinitionParams, GotoDefinitionResponse, Hover,
-        HoverParams, InitializeParams, InitializeResult, InitializedParams, InlayHint,
-        InlayHintParams, Location, MessageType, Position, PrepareRenameResponse, Range,
-        ReferenceParams, RenameFilesParams, RenameParams, SemanticTokensParams,
-        SemanticTokensResult, ServerInfo, SymbolInformation, SymbolKind,
-        TextDocumentPositionParams, TextEdit, WorkspaceEdit, WorkspaceSymbolParams,
+        DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, ExecuteCommandParams,
+        FoldingRange, FoldingRangeKind, FoldingRangeParams, GotoDefinitionParams,
+        GotoDefinitionResponse, Hover, HoverParams, InitializeParams, InitializeResult,
+        InitializedParams, InlayHint, InlayHintParams, Location, MessageType, Position,
+        PrepareRenameResponse, Range, ReferenceParams, RenameFilesParams, RenameParams,
+        SelectionRange, SelectionRangeParams, SemanticTokensParams, SemanticTokensResult,
+        ServerInfo, SymbolInformation, SymbolKind, TextDocumentPositionParams, TextEdit,
+        WorkspaceEdit, WorkspaceSymbolParams,
     },
     LanguageServer,
 };
 
 use super::{server_capabilities, MaestroServer};
 use crate::ide::{
-    CodeActionService, CodeLensService, CompletionService, DefinitionService, DocumentLinkService,
-    FileRenameService, HoverService, IdeContext, InlayHintService, ReferencesService,
-    RenameService, SemanticTokensService, WorkspaceSymbolsService,
+    CodeActionService, CodeLensService, CompletionService, DefinitionService, DiagnosticsConfig,
+    DocumentLinkService, FileRenameService, HoverService, IdeContext, InlayHintService,
+    ReferencesService, RenameService, ScriptSetupConversionService, SelectionRangeService,
+    SemanticTokensService, WorkspaceSymbolsService, CONVERT_TO_SCRIPT_SETUP_COMMAND,
 };
 
 #[tower_lsp::async_trait]
@@ -45,6 +47,12 @@ impl LanguageServer for MaestroServer {
                     .and_then(|f| f.uri.to_file_path().ok())
             });
 
+        // Enabled diagnostic sources (`diagnostics.{typescript,lint,compiler}`)
+        self.state
+            .set_diagnostics_config(DiagnosticsConfig::from_initialization_options(
+                params.initialization_options.as_ref(),
+            ));
+
         // Load format config from workspace root (always, regardless of feature)
         if let Some(ref path) = workspace_path {
             self.state.load_format_config(path);
@@ -117,6 +125,7 @@ impl LanguageServer for MaestroServer {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri;
         self.state.documents.close(&uri);
+        self.state.cancel_diagnostics_requests(&uri);
 
         // Clean up virtual documents cache
         self.state.remove_virtual_docs(&uri);
@@ -688,6 +697,24 @@ impl LanguageServer for MaestroServer {
         }
     }
 
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        let uri = &params.text_document.uri;
+
+        let Some(doc) = self.state.documents.get(uri) else {
+            return Ok(None);
+        };
+
+        let content = doc.text();
+        Ok(Some(SelectionRangeService::get_ranges(
+            &content,
+            uri,
+            &params.positions,
+        )))
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let uri = &params.text_document.uri;
 
@@ -724,4 +751,40 @@ impl LanguageServer for MaestroServer {
         #[cfg(not(feature = "glyph"))]
         Ok(None)
     }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        if params.command != CONVERT_TO_SCRIPT_SETUP_COMMAND {
+            return Err(tower_lsp::jsonrpc::Error::method_not_found());
+        }
+
+        // The first argument is the URI of the document to convert.
+        let Some(uri) = params
+            .arguments
+            .first()
+            .and_then(|arg| arg.as_str())
+            .and_then(|uri| tower_lsp::lsp_types::Url::parse(uri).ok())
+        else {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                "expected a document URI as the first argument",
+            ));
+        };
+
+        let Some(content) = self.state.documents.get(&uri).map(|doc| doc.text()) else {
+            return Ok(None);
+        };
+
+        match ScriptSetupConversionService::convert(&content, &uri) {
+            Ok(edit) => {
+                if let Err(error) = self.client.apply_edit(edit).await {
+                    tracing::warn!("Failed to apply script setup conversion: {error}");
+                }
+            }
+            Err(reason) => self.client.show_message(MessageType::INFO, reason).await,
+        }
+
+        Ok(None)
+    }
 }
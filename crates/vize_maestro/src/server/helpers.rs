@@ -18,8 +18,12 @@ impl MaestroServer {
     /// Publish diagnostics for a document.
     pub(crate) async fn publish_diagnostics(&self, uri: &Url) {
         // Use async version when native feature is enabled (includes Corsa diagnostics)
+        // A stale result is dropped; the newer request publishes its own.
         #[cfg(feature = "native")]
-        let diagnostics = DiagnosticService::collect_async(&self.state, uri).await;
+        let Some(diagnostics) = DiagnosticService::collect_async(&self.state, uri).await
+        else {
+            return;
+        };
 
         #[cfg(not(feature = "native"))]
         let diagnostics = DiagnosticService::collect(&self.state, uri);
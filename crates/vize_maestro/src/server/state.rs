@@ -16,6 +16,7 @@ use std::sync::OnceLock;
 use vize_canon::{BatchTypeChecker, BatchTypeCheckerTrait, CorsaBridge, CorsaBridgeConfig};
 
 use crate::document::DocumentStore;
+use crate::ide::DiagnosticsConfig;
 use crate::virtual_code::{VirtualCodeGenerator, VirtualDocuments};
 
 /// Batch type check result cache.
@@ -82,6 +83,13 @@ pub struct ServerState {
     virtual_gen: RwLock<VirtualCodeGenerator>,
     /// Cached virtual documents per file
     virtual_docs_cache: DashMap<Url, VirtualDocuments>,
+    /// Enabled diagnostic sources (from initialization options)
+    diagnostics_config: RwLock<DiagnosticsConfig>,
+    /// Latest diagnostics request token per document
+    diagnostics_tokens: DashMap<Url, u64>,
+    /// Source of diagnostics request tokens, shared by all documents so a
+    /// token is never reused after a document is closed and reopened
+    next_diagnostics_token: std::sync::atomic::AtomicU64,
     /// Formatting options (loaded from vize.config.json)
     #[cfg(feature = "glyph")]
     format_options: RwLock<vize_glyph::FormatOptions>,
@@ -115,6 +123,9 @@ impl ServerState {
             documents: DocumentStore::new(),
             virtual_gen: RwLock::new(VirtualCodeGenerator::new()),
             virtual_docs_cache: DashMap::new(),
+            diagnostics_config: RwLock::new(DiagnosticsConfig::default()),
+            diagnostics_tokens: DashMap::new(),
+            next_diagnostics_token: std::sync::atomic::AtomicU64::new(1),
             #[cfg(feature = "glyph")]
             format_options: RwLock::new(vize_glyph::FormatOptions::default()),
             #[cfg(feature = "native")]
@@ -374,6 +385,40 @@ impl ServerState {
         self.virtual_docs_cache.clear();
     }
 
+    /// Get the enabled diagnostic sources.
+    #[inline]
+    pub fn get_diagnostics_config(&self) -> DiagnosticsConfig {
+        *self.diagnostics_config.read()
+    }
+
+    /// Set the enabled diagnostic sources.
+    pub fn set_diagnostics_config(&self, config: DiagnosticsConfig) {
+        *self.diagnostics_config.write() = config;
+    }
+
+    /// Start a diagnostics request for a document, superseding earlier ones.
+    ///
+    /// Returns the token to pass to [`Self::is_current_diagnostics_request`].
+    pub fn begin_diagnostics_request(&self, uri: &Url) -> u64 {
+        let token = self
+            .next_diagnostics_token
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.diagnostics_tokens.insert(uri.clone(), token);
+        token
+    }
+
+    /// Check whether `token` is still the latest diagnostics request for a document.
+    pub fn is_current_diagnostics_request(&self, uri: &Url, token: u64) -> bool {
+        self.diagnostics_tokens
+            .get(uri)
+            .is_some_and(|current| *current == token)
+    }
+
+    /// Cancel all in-flight diagnostics requests for a document.
+    pub fn cancel_diagnostics_requests(&self, uri: &Url) {
+        self.diagnostics_tokens.remove(uri);
+    }
+
     /// Get a clone of the current format options.
     #[cfg(feature = "glyph")]
     #[inline]
@@ -408,6 +453,30 @@ impl ServerState {
 mod tests {
     use super::ServerState;
 
+    #[test]
+    fn diagnostics_request_tokens() {
+        let state = ServerState::new();
+        let uri = tower_lsp::lsp_types::Url::parse("file:///test.vue").unwrap();
+
+        let first = state.begin_diagnostics_request(&uri);
+        assert!(state.is_current_diagnostics_request(&uri, first));
+
+        // A newer request supersedes the first one
+        let second = state.begin_diagnostics_request(&uri);
+        assert!(!state.is_current_diagnostics_request(&uri, first));
+        assert!(state.is_current_diagnostics_request(&uri, second));
+
+        // Closing the document cancels everything in flight
+        state.cancel_diagnostics_requests(&uri);
+        assert!(!state.is_current_diagnostics_request(&uri, second));
+
+        // A request started before closing stays stale after reopening
+        let reopened = state.begin_diagnostics_request(&uri);
+        assert_ne!(reopened, first);
+        assert!(!state.is_current_diagnostics_request(&uri, first));
+        assert!(state.is_current_diagnostics_request(&uri, reopened));
+    }
+
     #[test]
     fn default_format_options() {
         let state = ServerState::new();
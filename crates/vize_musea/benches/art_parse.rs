@@ -14,7 +14,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use vize_carton::append;
 use vize_carton::Bump;
-use vize_musea::{parse_art, transform_to_csf, transform_to_vue, ArtParseOptions};
+use vize_musea::{parse_art, transform_to_csf, transform_to_vue, ArtParseOptions, CsfTarget};
 
 // =============================================================================
 // Test Data
@@ -326,7 +326,7 @@ fn bench_transform_csf(c: &mut Criterion) {
     let descriptor = parse_art(&allocator, COMPLEX_ART, ArtParseOptions::default()).unwrap();
 
     group.bench_function("to_csf", |b| {
-        b.iter(|| transform_to_csf(black_box(&descriptor)))
+        b.iter(|| transform_to_csf(black_box(&descriptor), CsfTarget::Ts))
     });
 
     group.finish();
@@ -359,7 +359,7 @@ fn bench_full_pipeline(c: &mut Criterion) {
                 ArtParseOptions::default(),
             )
             .unwrap();
-            transform_to_csf(&descriptor)
+            transform_to_csf(&descriptor, CsfTarget::Ts)
         })
     });
 
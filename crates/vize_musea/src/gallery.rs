@@ -0,0 +1,331 @@
+//! Gallery index for collections of Art files.
+//!
+//! The index is the data layer shared by the gallery server and catalog
+//! generation: every `*.art.vue` file is parsed once, reduced to owned
+//! metadata, and grouped by category and tag. Files that fail to parse are
+//! recorded as errors instead of aborting the whole build.
+//!
+//! The index can be kept up to date incrementally with
+//! [`GalleryIndex::update_file`] and [`GalleryIndex::remove_file`]; files
+//! whose content hasn't changed are not re-parsed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//! use vize_carton::String;
+//! use vize_musea::gallery::build_gallery_index;
+//!
+//! let files = [(
+//!     PathBuf::from("Button.art.vue"),
+//!     String::from(r#"<art title="Button" category="atoms"><variant name="Primary"><Button /></variant></art>"#),
+//! )];
+//!
+//! let index = build_gallery_index(&files);
+//! assert_eq!(index.entries.len(), 1);
+//! assert_eq!(index.categories[0].name, "atoms");
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use vize_carton::{hash::hash_str, Bump, String, ToCompactString};
+
+use crate::{docs::CatalogEntry, parse::parse_art, types::ArtParseOptions};
+
+/// Index of all Art files in a gallery.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryIndex {
+    /// Successfully parsed Art files, sorted by order, then title.
+    pub entries: Vec<GalleryEntry>,
+
+    /// Source paths grouped by category (sorted by category name).
+    pub categories: Vec<GalleryGroup>,
+
+    /// Source paths of entries without a category.
+    pub uncategorized: Vec<String>,
+
+    /// Source paths grouped by tag (sorted by tag name).
+    pub tags: Vec<GalleryGroup>,
+
+    /// Files that failed to parse.
+    pub errors: Vec<GalleryError>,
+}
+
+/// A single Art file in the gallery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryEntry {
+    /// Catalog metadata (title, category, tags, paths, ...).
+    #[serde(flatten)]
+    pub catalog: CatalogEntry,
+
+    /// Variants defined in the file, in source order.
+    pub variants: Vec<GalleryVariant>,
+
+    /// Hash of the file content the entry was built from.
+    #[serde(skip)]
+    content_hash: u64,
+}
+
+/// Variant summary for gallery navigation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryVariant {
+    /// Variant name.
+    pub name: String,
+
+    /// Whether this is the default variant.
+    pub is_default: bool,
+
+    /// Whether the variant is excluded from VRT.
+    pub skip_vrt: bool,
+}
+
+/// Entries sharing a category or tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryGroup {
+    /// Category or tag name.
+    pub name: String,
+
+    /// Source paths of the entries in this group.
+    pub paths: Vec<String>,
+}
+
+/// An Art file that could not be parsed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryError {
+    /// Source path of the file.
+    pub path: String,
+
+    /// Parse error message.
+    pub message: String,
+
+    /// Hash of the file content that failed to parse.
+    #[serde(skip)]
+    content_hash: u64,
+}
+
+/// Build a gallery index from `(path, source)` pairs.
+pub fn build_gallery_index(files: &[(PathBuf, String)]) -> GalleryIndex {
+    let mut index = GalleryIndex::default();
+    let mut allocator = Bump::new();
+    for (path, source) in files {
+        index.insert_file(&mut allocator, path, source);
+    }
+    index.rebuild_groups();
+    index
+}
+
+impl GalleryIndex {
+    /// Re-index a single file after it was added or changed.
+    ///
+    /// Returns `false` if the content is unchanged and the file was skipped.
+    pub fn update_file(&mut self, path: &Path, source: &str) -> bool {
+        let key = path_key(path);
+        let hash = hash_str(source);
+        let unchanged = self
+            .entries
+            .iter()
+            .find(|e| e.catalog.source_path == key)
+            .map(|e| e.content_hash)
+            .or_else(|| {
+                self.errors
+                    .iter()
+                    .find(|e| e.path == key)
+                    .map(|e| e.content_hash)
+            })
+            == Some(hash);
+        if unchanged {
+            return false;
+        }
+
+        self.remove_key(&key);
+        self.insert_file(&mut Bump::new(), path, source);
+        self.rebuild_groups();
+        true
+    }
+
+    /// Drop a deleted file from the index.
+    ///
+    /// Returns `false` if the file wasn't indexed.
+    pub fn remove_file(&mut self, path: &Path) -> bool {
+        let removed = self.remove_key(&path_key(path));
+        if removed {
+            self.rebuild_groups();
+        }
+        removed
+    }
+
+    /// Catalog entries for [`crate::docs::generate_catalog`].
+    pub fn catalog_entries(&self) -> Vec<CatalogEntry> {
+        self.entries.iter().map(|e| e.catalog.clone()).collect()
+    }
+
+    /// Parse one file and record it as an entry or an error.
+    fn insert_file(&mut self, allocator: &mut Bump, path: &Path, source: &str) {
+        let key = path_key(path);
+        let content_hash = hash_str(source);
+        let options = ArtParseOptions {
+            filename: key.clone(),
+        };
+
+        match parse_art(allocator, source, options) {
+            Ok(art) => {
+                let variants = art
+                    .variants
+                    .iter()
+                    .map(|v| GalleryVariant {
+                        name: v.name.to_compact_string(),
+                        is_default: v.is_default,
+                        skip_vrt: v.skip_vrt,
+                    })
+                    .collect();
+                self.entries.push(GalleryEntry {
+                    catalog: CatalogEntry::from_descriptor(&art, ""),
+                    variants,
+                    content_hash,
+                });
+            }
+            Err(err) => self.errors.push(GalleryError {
+                path: key,
+                message: err.to_compact_string(),
+                content_hash,
+            }),
+        }
+        allocator.reset();
+    }
+
+    fn remove_key(&mut self, key: &str) -> bool {
+        let before = self.entries.len() + self.errors.len();
+        self.entries.retain(|e| e.catalog.source_path != key);
+        self.errors.retain(|e| e.path != key);
+        before != self.entries.len() + self.errors.len()
+    }
+
+    /// Re-sort entries and recompute the category/tag groups.
+    fn rebuild_groups(&mut self) {
+        self.entries.sort_by(|a, b| {
+            let a = &a.catalog;
+            let b = &b.catalog;
+            a.order
+                .unwrap_or(u32::MAX)
+                .cmp(&b.order.unwrap_or(u32::MAX))
+                .then_with(|| a.title.cmp(&b.title))
+                .then_with(|| a.source_path.cmp(&b.source_path))
+        });
+        self.errors.sort_by(|a, b| a.path.cmp(&b.path));
+
+        self.categories.clear();
+        self.uncategorized.clear();
+        self.tags.clear();
+        for entry in &self.entries {
+            let catalog = &entry.catalog;
+            match &catalog.category {
+                Some(category) => add_to_group(&mut self.categories, category, catalog),
+                None => self.uncategorized.push(catalog.source_path.clone()),
+            }
+            for tag in &catalog.tags {
+                add_to_group(&mut self.tags, tag, catalog);
+            }
+        }
+        self.categories.sort_by(|a, b| a.name.cmp(&b.name));
+        self.tags.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+}
+
+fn add_to_group(groups: &mut Vec<GalleryGroup>, name: &str, entry: &CatalogEntry) {
+    match groups.iter_mut().find(|g| g.name == name) {
+        Some(group) => group.paths.push(entry.source_path.clone()),
+        None => groups.push(GalleryGroup {
+            name: name.to_compact_string(),
+            paths: vec![entry.source_path.clone()],
+        }),
+    }
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().as_ref().to_compact_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_gallery_index;
+    use std::path::{Path, PathBuf};
+    use vize_carton::String;
+
+    fn file(path: &str, source: &str) -> (PathBuf, String) {
+        (PathBuf::from(path), String::from(source))
+    }
+
+    #[test]
+    fn test_build_gallery_index_groups_entries() {
+        let files = [
+            file(
+                "Button.art.vue",
+                r#"<art title="Button" category="atoms" tags="ui,input">
+  <variant name="Primary" default><Button /></variant>
+  <variant name="Ghost" skip-vrt><Button ghost /></variant>
+</art>"#,
+            ),
+            file(
+                "Card.art.vue",
+                r#"<art title="Card" category="molecules" tags="ui"><variant name="Basic"><Card /></variant></art>"#,
+            ),
+            file(
+                "Misc.art.vue",
+                r#"<art title="Misc"><variant name="Only"><div /></variant></art>"#,
+            ),
+            file("Broken.art.vue", "<template><div /></template>"),
+        ];
+
+        let index = build_gallery_index(&files);
+
+        assert_eq!(index.entries.len(), 3);
+        assert_eq!(index.entries[0].catalog.title, "Button");
+        assert_eq!(index.entries[0].variants.len(), 2);
+        assert!(index.entries[0].variants[0].is_default);
+
+        let categories: Vec<_> = index.categories.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(categories, ["atoms", "molecules"]);
+        assert_eq!(index.uncategorized, ["Misc.art.vue"]);
+
+        let ui = index.tags.iter().find(|g| g.name == "ui").unwrap();
+        assert_eq!(ui.paths, ["Button.art.vue", "Card.art.vue"]);
+
+        assert_eq!(index.errors.len(), 1);
+        assert_eq!(index.errors[0].path, "Broken.art.vue");
+        assert_eq!(index.catalog_entries().len(), 3);
+    }
+
+    #[test]
+    fn test_gallery_index_incremental_updates() {
+        let source = r#"<art title="Button" category="atoms"><variant name="Primary"><Button /></variant></art>"#;
+        let mut index = build_gallery_index(&[file("Button.art.vue", source)]);
+        let path = Path::new("Button.art.vue");
+
+        // Unchanged content is skipped
+        assert!(!index.update_file(path, source));
+
+        // Changed content replaces the entry and regroups it
+        let moved = r#"<art title="Button" category="forms"><variant name="Primary"><Button /></variant></art>"#;
+        assert!(index.update_file(path, moved));
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(index.categories.len(), 1);
+        assert_eq!(index.categories[0].name, "forms");
+
+        // A broken edit turns into an error, and fixing it clears the error
+        assert!(index.update_file(path, "<art>"));
+        assert!(index.entries.is_empty());
+        assert_eq!(index.errors.len(), 1);
+        assert!(index.update_file(path, moved));
+        assert!(index.errors.is_empty());
+
+        assert!(index.remove_file(path));
+        assert!(!index.remove_file(path));
+        assert!(index.entries.is_empty() && index.categories.is_empty());
+    }
+}
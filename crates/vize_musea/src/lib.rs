@@ -29,7 +29,7 @@
 //! ```rust
 //! use vize_carton::Bump;
 //! use vize_musea::{parse_art, transform_to_csf};
-//! use vize_musea::types::ArtParseOptions;
+//! use vize_musea::types::{ArtParseOptions, CsfTarget};
 //!
 //! let allocator = Bump::new();
 //! let source = r#"
@@ -51,7 +51,7 @@
 //! let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
 //!
 //! // Transform to Storybook CSF
-//! let csf = transform_to_csf(&art);
+//! let csf = transform_to_csf(&art, CsfTarget::Ts);
 //! println!("Generated: {}", csf.filename);
 //! ```
 //!
@@ -59,12 +59,14 @@
 //!
 //! - Zero-copy parsing of `*.art.vue` files
 //! - Type-safe variant definitions
-//! - Storybook CSF 3.0 export
+//! - Storybook CSF 3.0 export (TypeScript or JavaScript)
 //! - Visual Regression Testing (VRT) support
 //! - Interactive props palette
+//! - Gallery index grouped by category and tag
 
 pub mod autogen;
 pub mod docs;
+pub mod gallery;
 pub mod palette;
 pub mod parse;
 pub mod transform;
@@ -72,12 +74,14 @@ pub mod types;
 pub mod vrt;
 
 // Re-exports for convenience
-pub use parse::parse_art;
+pub use gallery::{build_gallery_index, GalleryIndex};
+pub use parse::{parse_art, validate_variants};
 pub use transform::{transform_to_csf, transform_to_vue};
 pub use types::{
     ArtDescriptor, ArtDescriptorOwned, ArtMetadata, ArtMetadataOwned, ArtParseError,
     ArtParseOptions, ArtParseResult, ArtScriptBlock, ArtScriptBlockOwned, ArtStatus, ArtStyleBlock,
-    ArtStyleBlockOwned, ArtVariant, ArtVariantOwned, CsfOutput, SourceLocation, ViewportConfig,
+    ArtStyleBlockOwned, ArtVariant, ArtVariantOwned, CsfOutput, CsfTarget, SourceLocation,
+    VariantTemplateError, ViewportConfig,
 };
 
 // Re-export vize_carton::Bump for convenience
@@ -94,6 +98,7 @@ pub fn serve() {
 mod tests {
     use super::{
         parse_art, transform_to_csf, transform_to_vue, ArtDescriptorOwned, ArtParseOptions, Bump,
+        CsfTarget,
     };
 
     #[test]
@@ -145,7 +150,7 @@ import Button from './Button.vue'
         assert_eq!(art.styles.len(), 1);
 
         // Transform to CSF
-        let csf = transform_to_csf(&art);
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
         insta::assert_debug_snapshot!(csf);
 
         // Transform to Vue
@@ -88,6 +88,19 @@ pub(crate) fn parse_metadata<'a>(
         }
     }
 
+    // Parse decorators from the attribute and/or a `<decorators>` block
+    let mut decorators = vize_carton::Vec::new_in(allocator);
+    let decorator_lists = extract_attr(attrs, "decorators")
+        .into_iter()
+        .chain(find_decorators_block(block.content));
+    for list in decorator_lists {
+        for decorator in list.split(|c: char| c == ',' || c.is_ascii_whitespace()) {
+            if !decorator.is_empty() && !decorators.contains(&decorator) {
+                decorators.push(decorator);
+            }
+        }
+    }
+
     // Parse status
     let status = parse_status(attrs);
 
@@ -100,11 +113,25 @@ pub(crate) fn parse_metadata<'a>(
         component,
         category,
         tags,
+        decorators,
         status,
         order,
     })
 }
 
+/// Find the content of a `<decorators>` block inside the `<art>` content.
+#[inline]
+fn find_decorators_block(content: &str) -> Option<&str> {
+    let bytes = content.as_bytes();
+    let start = memmem::find(bytes, b"<decorators")?;
+    let tag_end = start + memchr(b'>', &bytes[start..])?;
+    if bytes[tag_end - 1] == b'/' {
+        return None;
+    }
+    let close = memmem::find(&bytes[tag_end..], b"</decorators>")?;
+    Some(&content[tag_end + 1..tag_end + close])
+}
+
 /// Parse the status attribute value.
 /// Uses fast byte comparison instead of string matching.
 #[inline]
@@ -130,7 +157,7 @@ fn parse_status(attrs: &str) -> ArtStatus {
 
 #[cfg(test)]
 mod tests {
-    use super::{find_art_block, parse_metadata, parse_status};
+    use super::{find_art_block, find_decorators_block, parse_metadata, parse_status};
     use crate::types::ArtStatus;
     use vize_carton::Bump;
 
@@ -172,6 +199,39 @@ mod tests {
         assert_eq!(metadata.status, ArtStatus::Draft);
     }
 
+    #[test]
+    fn test_parse_metadata_decorators() {
+        let allocator = Bump::new();
+        let source = r#"<art title="Button" decorators="withTheme, withRouter"></art>"#;
+        let block = find_art_block(source.as_bytes(), source).unwrap();
+        let metadata = parse_metadata(&allocator, &block).unwrap();
+        assert_eq!(metadata.decorators.as_slice(), ["withTheme", "withRouter"]);
+
+        let source = r#"<art title="Button" decorators="withTheme">
+  <decorators>
+    withTheme,
+    withI18n
+  </decorators>
+  <variant name="A"></variant>
+</art>"#;
+        let block = find_art_block(source.as_bytes(), source).unwrap();
+        let metadata = parse_metadata(&allocator, &block).unwrap();
+        assert_eq!(metadata.decorators.as_slice(), ["withTheme", "withI18n"]);
+    }
+
+    #[test]
+    fn test_find_decorators_block() {
+        assert_eq!(
+            find_decorators_block("<decorators>a, b</decorators>"),
+            Some("a, b")
+        );
+        assert_eq!(find_decorators_block("<decorators />"), None);
+        assert_eq!(
+            find_decorators_block("<variant name=\"A\"></variant>"),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_status() {
         assert_eq!(parse_status(r#"status="draft""#), ArtStatus::Draft);
@@ -4,8 +4,11 @@
 //! All string data is borrowed directly from the source.
 
 mod art_block;
+mod validate;
 mod variant;
 
+pub use validate::validate_variants;
+
 use crate::types::{
     ArtDescriptor, ArtParseError, ArtParseOptions, ArtParseResult, ArtScriptBlock, ArtStyleBlock,
     SourceLocation,
@@ -0,0 +1,125 @@
+//! Validation of `<variant>` templates.
+//!
+//! `parse_art` only extracts variant templates as raw text, so a broken
+//! template is not noticed until the gallery renders it. This pass runs each
+//! template through the Vue template parser and reports its errors with
+//! locations in the Art file.
+
+use super::calculate_location_fast;
+use crate::types::{ArtDescriptor, VariantTemplateError};
+use vize_carton::{Bump, ToCompactString};
+use vize_relief::{CompilerError, Position};
+
+/// Parse every variant template and collect the compiler errors.
+///
+/// Error locations are rewritten from template-relative to Art file offsets,
+/// lines and columns. Returns an empty list when all templates are valid.
+///
+/// # Example
+///
+/// ```
+/// use vize_carton::Bump;
+/// use vize_musea::parse::{parse_art, validate_variants};
+/// use vize_musea::types::ArtParseOptions;
+///
+/// let allocator = Bump::new();
+/// let source = r#"
+/// <art title="Button">
+///   <variant name="Broken"><div><span></div></variant>
+/// </art>
+/// "#;
+///
+/// let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+/// let errors = validate_variants(&art);
+/// assert_eq!(errors[0].variant, "Broken");
+/// ```
+pub fn validate_variants(descriptor: &ArtDescriptor<'_>) -> Vec<VariantTemplateError> {
+    let mut errors = Vec::new();
+
+    for variant in descriptor.variants.iter() {
+        let allocator = Bump::new();
+        let (_, template_errors) = vize_armature::parse(&allocator, variant.template);
+        if template_errors.is_empty() {
+            continue;
+        }
+
+        let base = template_offset(descriptor.source, variant.template);
+        for error in template_errors.iter() {
+            let mut error = error.clone();
+            if let (Some(base), Some(loc)) = (base, error.loc.as_mut()) {
+                loc.start = map_position(descriptor.source, base, loc.start);
+                loc.end = map_position(descriptor.source, base, loc.end);
+            }
+            errors.push(VariantTemplateError {
+                variant: variant.name.to_compact_string(),
+                error,
+            });
+        }
+    }
+
+    errors
+}
+
+/// Byte offset of `template` in `source`, if it is borrowed from it.
+fn template_offset(source: &str, template: &str) -> Option<u32> {
+    let offset = (template.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    (offset + template.len() <= source.len()).then_some(offset as u32)
+}
+
+/// Convert a template-relative position into an Art file position.
+fn map_position(source: &str, base: u32, position: Position) -> Position {
+    let offset = (base + position.offset).min(source.len() as u32);
+    let loc = calculate_location_fast(source, offset, offset);
+    Position::new(offset, loc.start_line, loc.start_column + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_variants;
+    use crate::parse::parse_art;
+    use crate::types::ArtParseOptions;
+    use vize_carton::Bump;
+    use vize_relief::ErrorCode;
+
+    #[test]
+    fn test_validate_variants_maps_errors_to_art_file() {
+        let allocator = Bump::new();
+        let source = r#"<art title="Button">
+  <variant name="Valid">
+    <Button>Click</Button>
+  </variant>
+  <variant name="Broken">
+    <Button>Click</button>
+  </variant>
+</art>
+"#;
+
+        let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+        let errors = validate_variants(&art);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].variant, "Broken");
+        assert_eq!(errors[0].error.code, ErrorCode::EndTagCaseMismatch);
+
+        let loc = errors[0].error.loc.as_ref().unwrap();
+        let offset = source.find("</button>").unwrap() as u32;
+        assert_eq!(loc.start.offset, offset);
+        assert_eq!(loc.start.line, 6);
+        assert_eq!(loc.start.column, 18);
+    }
+
+    #[test]
+    fn test_validate_variants_accepts_valid_templates() {
+        let allocator = Bump::new();
+        let source = r#"
+<art title="Button">
+  <variant name="Primary" default>
+    <Button v-if="show" :label="label">{{ text }}</Button>
+  </variant>
+</art>
+"#;
+
+        let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+        assert!(validate_variants(&art).is_empty());
+    }
+}
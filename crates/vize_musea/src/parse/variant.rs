@@ -91,6 +91,21 @@ fn parse_single_variant<'a>(
         .and_then(|s| parse_args_json(allocator, s).ok())
         .unwrap_or_default();
 
+    // Parse Storybook tags (comma-separated) - zero copy
+    let tags = extract_attr(attrs_str, "tags")
+        .map(|s| {
+            s.split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Parse Storybook parameters (JSON object)
+    let parameters = extract_attr(attrs_str, "parameters")
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&decode_html_entities(s)).ok())
+        .filter(serde_json::Value::is_object);
+
     // Parse viewport
     let viewport = parse_viewport(attrs_str);
 
@@ -119,6 +134,8 @@ fn parse_single_variant<'a>(
             template,
             is_default,
             args,
+            tags,
+            parameters,
             viewport,
             skip_vrt,
             loc: Some(loc),
@@ -134,21 +151,7 @@ fn parse_args_json<'a>(
     allocator: &'a Bump,
     s: &str,
 ) -> Result<FxHashMap<&'a str, serde_json::Value>, serde_json::Error> {
-    // Check if we need to decode HTML entities
-    let needs_decode = s.contains('&');
-
-    let json_str: std::borrow::Cow<'_, str> = if needs_decode {
-        // Decode common HTML entities - allocates only when needed
-        std::borrow::Cow::Owned(
-            s.replace("&quot;", "\"")
-                .replace("&apos;", "'")
-                .replace("&lt;", "<")
-                .replace("&gt;", ">")
-                .replace("&amp;", "&"),
-        )
-    } else {
-        std::borrow::Cow::Borrowed(s)
-    };
+    let json_str = decode_html_entities(s);
 
     #[allow(clippy::disallowed_types)]
     let map: FxHashMap<std::string::String, serde_json::Value> = serde_json::from_str(&json_str)?;
@@ -163,6 +166,22 @@ fn parse_args_json<'a>(
         .collect())
 }
 
+/// Decode common HTML entities in an attribute value.
+/// Allocates only when the value contains an entity.
+#[inline]
+fn decode_html_entities(s: &str) -> std::borrow::Cow<'_, str> {
+    if !s.contains('&') {
+        return std::borrow::Cow::Borrowed(s);
+    }
+    std::borrow::Cow::Owned(
+        s.replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&"),
+    )
+}
+
 /// Parse viewport configuration from attributes.
 /// Supports JSON format and simple "WxH" or "WxH@scale" format.
 #[inline]
@@ -293,6 +312,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_variant_with_tags_and_parameters() {
+        let allocator = Bump::new();
+        let content = r#"
+  <variant name="Dark" tags="!dev, autodocs" parameters='{"backgrounds":{"default":"dark"}}'>
+    <Button>Dark</Button>
+  </variant>
+"#;
+
+        let variants = parse_variants(&allocator, content, content, 0).unwrap();
+        assert_eq!(variants[0].tags, ["!dev", "autodocs"]);
+        assert_eq!(
+            variants[0].parameters,
+            Some(serde_json::json!({ "backgrounds": { "default": "dark" } }))
+        );
+    }
+
     #[test]
     fn test_parse_viewport_simple() {
         let vp = parse_viewport(r#"viewport="375x667""#);
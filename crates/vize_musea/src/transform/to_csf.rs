@@ -1,11 +1,12 @@
 //! Transform Art to Storybook CSF 3.0 format.
 //!
 //! This module generates Storybook-compatible Component Story Format (CSF) files
-//! from Art descriptors.
+//! from Art descriptors, either as TypeScript or as JavaScript with JSDoc
+//! type hints (see [`CsfTarget`]).
 
 #![allow(clippy::disallowed_macros)]
 
-use crate::types::{ArtDescriptor, ArtVariant, CsfOutput};
+use crate::types::{ArtDescriptor, ArtVariant, CsfOutput, CsfTarget};
 use vize_carton::{append, cstr, String, ToCompactString};
 
 /// Transform an Art descriptor to Storybook CSF 3.0 format.
@@ -15,6 +16,7 @@ use vize_carton::{append, cstr, String, ToCompactString};
 /// ```ignore
 /// use vize_musea::transform::transform_to_csf;
 /// use vize_musea::parse::parse_art;
+/// use vize_musea::types::CsfTarget;
 ///
 /// let source = r#"
 /// <art title="Button" component="./Button.vue">
@@ -25,22 +27,22 @@ use vize_carton::{append, cstr, String, ToCompactString};
 /// "#;
 ///
 /// let art = parse_art(source, Default::default()).unwrap();
-/// let csf = transform_to_csf(&art);
+/// let csf = transform_to_csf(&art, CsfTarget::Ts);
 /// ```
-pub fn transform_to_csf(art: &ArtDescriptor<'_>) -> CsfOutput {
+pub fn transform_to_csf(art: &ArtDescriptor<'_>, target: CsfTarget) -> CsfOutput {
     let mut output = String::default();
 
     // Generate imports
-    output.push_str(&generate_imports(art));
+    output.push_str(&generate_imports(art, target));
     output.push('\n');
 
     // Generate meta (default export)
-    output.push_str(&generate_meta(art));
+    output.push_str(&generate_meta(art, target));
     output.push('\n');
 
     // Generate stories (named exports)
     for variant in &art.variants {
-        output.push_str(&generate_story(variant, art));
+        output.push_str(&generate_story(variant, target));
         output.push('\n');
     }
 
@@ -52,30 +54,41 @@ pub fn transform_to_csf(art: &ArtDescriptor<'_>) -> CsfOutput {
         .next()
         .unwrap_or("Component");
 
+    let extension = match target {
+        CsfTarget::Ts => "ts",
+        CsfTarget::Js => "js",
+    };
+
     CsfOutput {
         code: output,
-        filename: cstr!("{}.stories.ts", base_name),
+        filename: cstr!("{}.stories.{}", base_name, extension),
     }
 }
 
 /// Generate import statements.
-fn generate_imports(art: &ArtDescriptor<'_>) -> String {
+fn generate_imports(art: &ArtDescriptor<'_>, target: CsfTarget) -> String {
     let mut imports = String::default();
 
-    // Import from Storybook
-    imports.push_str("import type { Meta, StoryObj } from '@storybook/vue3';\n");
+    // Import Storybook types (JS output references them through JSDoc instead)
+    if target == CsfTarget::Ts {
+        imports.push_str("import type { Meta, StoryObj } from '@storybook/vue3';\n");
+    }
 
     // Import the component
     let component_path = art.metadata.component.unwrap_or("./Component.vue");
 
     append!(imports, "import Component from '{component_path}';\n");
 
-    // Add script imports if present
-    if let Some(script) = &art.script_setup {
-        // Extract imports from script setup
+    // Add script imports if present (including decorator imports)
+    for script in [&art.script_setup, &art.script].into_iter().flatten() {
+        // Extract imports from the script blocks
         for line in script.content.lines() {
             let trimmed = line.trim();
-            if trimmed.starts_with("import ") && !trimmed.contains("Component") {
+            let is_type_import = trimmed.starts_with("import type ");
+            if trimmed.starts_with("import ")
+                && !trimmed.contains("Component")
+                && !(is_type_import && target == CsfTarget::Js)
+            {
                 imports.push_str(trimmed);
                 imports.push('\n');
             }
@@ -86,7 +99,7 @@ fn generate_imports(art: &ArtDescriptor<'_>) -> String {
 }
 
 /// Generate meta (default export).
-fn generate_meta(art: &ArtDescriptor<'_>) -> String {
+fn generate_meta(art: &ArtDescriptor<'_>, target: CsfTarget) -> String {
     let mut meta = String::default();
 
     // Build the title path
@@ -96,14 +109,22 @@ fn generate_meta(art: &ArtDescriptor<'_>) -> String {
         art.metadata.title.to_compact_string()
     };
 
-    meta.push_str("const meta: Meta<typeof Component> = {\n");
+    match target {
+        CsfTarget::Ts => meta.push_str("const meta: Meta<typeof Component> = {\n"),
+        CsfTarget::Js => {
+            meta.push_str("/** @type {import('@storybook/vue3').Meta<typeof Component>} */\n");
+            meta.push_str("const meta = {\n");
+        }
+    }
     append!(meta, "  title: '{}',\n", escape_string(&title));
     meta.push_str("  component: Component,\n");
 
-    // Add tags
+    // Add tags (art-level tags merge with the default `autodocs`)
     let mut tags: Vec<String> = vec!["autodocs".to_compact_string()];
     for tag in &art.metadata.tags {
-        tags.push(tag.to_compact_string());
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_compact_string());
+        }
     }
     append!(
         meta,
@@ -114,6 +135,15 @@ fn generate_meta(art: &ArtDescriptor<'_>) -> String {
             .join(", ")
     );
 
+    // Add decorators wrapping every story
+    if !art.metadata.decorators.is_empty() {
+        append!(
+            meta,
+            "  decorators: [{}],\n",
+            art.metadata.decorators.join(", ")
+        );
+    }
+
     // Add parameters for description
     if let Some(desc) = art.metadata.description {
         meta.push_str("  parameters: {\n");
@@ -127,25 +157,49 @@ fn generate_meta(art: &ArtDescriptor<'_>) -> String {
 
     meta.push_str("};\n\n");
     meta.push_str("export default meta;\n");
-    meta.push_str("type Story = StoryObj<typeof meta>;\n");
+    match target {
+        CsfTarget::Ts => meta.push_str("type Story = StoryObj<typeof meta>;\n"),
+        CsfTarget::Js => meta
+            .push_str("/** @typedef {import('@storybook/vue3').StoryObj<typeof meta>} Story */\n"),
+    }
 
     meta
 }
 
 /// Generate a story (named export) from a variant.
-fn generate_story(variant: &ArtVariant<'_>, _art: &ArtDescriptor<'_>) -> String {
+fn generate_story(variant: &ArtVariant<'_>, target: CsfTarget) -> String {
     let mut story = String::default();
 
     // Convert variant name to PascalCase for export name
     let export_name = to_pascal_case(variant.name);
 
-    append!(story, "export const {export_name}: Story = {{\n");
+    match target {
+        CsfTarget::Ts => append!(story, "export const {export_name}: Story = {{\n"),
+        CsfTarget::Js => {
+            story.push_str("/** @type {Story} */\n");
+            append!(story, "export const {export_name} = {{\n");
+        }
+    }
 
     // Add name if different from export name
     if export_name != variant.name {
         append!(story, "  name: '{}',\n", escape_string(variant.name));
     }
 
+    // Add tags if present
+    if !variant.tags.is_empty() {
+        append!(
+            story,
+            "  tags: [{}],\n",
+            variant
+                .tags
+                .iter()
+                .map(|t| cstr!("'{}'", escape_string(t)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
     // Add args if present
     if !variant.args.is_empty() {
         story.push_str("  args: {\n");
@@ -169,8 +223,21 @@ fn generate_story(variant: &ArtVariant<'_>, _art: &ArtDescriptor<'_>) -> String
 
     story.push_str("  }),\n");
 
-    // Add parameters for default story
-    if variant.is_default {
+    // Add parameters, merging the docs canvas setting for the default story
+    if let Some(parameters) = &variant.parameters {
+        let mut parameters = parameters.clone();
+        if variant.is_default {
+            let docs = parameters
+                .as_object_mut()
+                .map(|p| p.entry("docs").or_insert_with(|| serde_json::json!({})));
+            if let Some(docs) = docs.and_then(serde_json::Value::as_object_mut) {
+                docs.entry("canvas")
+                    .or_insert_with(|| serde_json::json!({ "sourceState": "shown" }));
+            }
+        }
+        let value_str = serde_json::to_string(&parameters).unwrap_or_else(|_| "{}".into());
+        append!(story, "  parameters: {value_str},\n");
+    } else if variant.is_default {
         story.push_str("  parameters: {\n");
         story.push_str("    docs: {\n");
         story.push_str("      canvas: { sourceState: 'shown' },\n");
@@ -225,7 +292,7 @@ fn escape_template(s: &str) -> String {
 mod tests {
     use super::{escape_string, escape_template, to_pascal_case, transform_to_csf};
     use crate::parse::parse_art;
-    use crate::types::ArtParseOptions;
+    use crate::types::{ArtParseOptions, CsfTarget};
     use vize_carton::Bump;
 
     #[test]
@@ -240,7 +307,7 @@ mod tests {
 "#;
 
         let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
-        let csf = transform_to_csf(&art);
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
 
         insta::assert_debug_snapshot!(csf);
     }
@@ -257,7 +324,7 @@ mod tests {
 "#;
 
         let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
-        let csf = transform_to_csf(&art);
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
 
         insta::assert_debug_snapshot!(csf);
     }
@@ -277,11 +344,116 @@ mod tests {
 "#;
 
         let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
-        let csf = transform_to_csf(&art);
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
 
         insta::assert_debug_snapshot!(csf);
     }
 
+    #[test]
+    fn test_transform_with_decorators() {
+        let allocator = Bump::new();
+        let source = r#"
+<art title="Button" component="./Button.vue">
+  <decorators>withTheme, withRouter</decorators>
+  <variant name="Default">
+    <Button>Click</Button>
+  </variant>
+</art>
+
+<script setup lang="ts">
+import { withTheme } from './decorators/theme'
+</script>
+
+<script lang="ts">
+import { withRouter } from './decorators/router'
+</script>
+"#;
+
+        let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
+
+        assert!(csf
+            .code
+            .contains("import { withTheme } from './decorators/theme'\n"));
+        assert!(csf
+            .code
+            .contains("import { withRouter } from './decorators/router'\n"));
+        assert!(csf
+            .code
+            .contains("  tags: ['autodocs'],\n  decorators: [withTheme, withRouter],\n};"));
+    }
+
+    #[test]
+    fn test_transform_variant_tags_and_parameters() {
+        let allocator = Bump::new();
+        let source = r#"
+<art title="Button" component="./Button.vue" tags="autodocs,ui">
+  <variant name="Primary" default parameters='{"backgrounds":{"default":"dark"}}'>
+    <Button>Primary</Button>
+  </variant>
+  <variant name="Internal" tags="!dev,!autodocs">
+    <Button>Internal</Button>
+  </variant>
+</art>
+"#;
+
+        let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+        let csf = transform_to_csf(&art, CsfTarget::Ts);
+
+        assert!(csf.code.contains("  tags: ['autodocs', 'ui'],\n"));
+        assert!(csf.code.contains(
+            r#"  parameters: {"backgrounds":{"default":"dark"},"docs":{"canvas":{"sourceState":"shown"}}},"#
+        ));
+        assert!(csf
+            .code
+            .contains("export const Internal: Story = {\n  tags: ['!dev', '!autodocs'],\n"));
+    }
+
+    #[test]
+    fn test_transform_js_target() {
+        let allocator = Bump::new();
+        let source = r#"
+<art title="Button" component="./Button.vue">
+  <variant name="Primary" default>
+    <Button>Primary</Button>
+  </variant>
+</art>
+
+<script setup lang="ts">
+import type { ButtonProps } from './Button.vue'
+import { withTheme } from './decorators/theme'
+</script>
+"#;
+
+        let art = parse_art(
+            &allocator,
+            source,
+            ArtParseOptions {
+                filename: "src/Button.art.vue".into(),
+            },
+        )
+        .unwrap();
+        let csf = transform_to_csf(&art, CsfTarget::Js);
+
+        assert_eq!(csf.filename, "Button.stories.js");
+        assert!(!csf.code.contains("import type"));
+        assert!(!csf.code.contains(": Meta"));
+        assert!(!csf.code.contains(": Story"));
+        assert!(csf
+            .code
+            .contains("import { withTheme } from './decorators/theme'\n"));
+        assert!(csf.code.contains(concat!(
+            "/** @type {import('@storybook/vue3').Meta<typeof Component>} */\n",
+            "const meta = {\n"
+        )));
+        assert!(csf
+            .code
+            .contains("/** @typedef {import('@storybook/vue3').StoryObj<typeof meta>} Story */\n"));
+        assert!(csf
+            .code
+            .contains("/** @type {Story} */\nexport const Primary = {\n"));
+    }
+
     #[test]
     fn test_to_pascal_case() {
         assert_eq!(to_pascal_case("primary"), "Primary");
@@ -20,7 +20,7 @@ pub struct VueOutput {
 /// Transform an Art descriptor to executable Vue component modules.
 ///
 /// Generates:
-/// 1. A main component that renders all variants
+/// 1. A main component that shows the variants as tabs
 /// 2. Individual variant components
 /// 3. A metadata module for the gallery UI
 pub fn transform_to_vue(art: &ArtDescriptor<'_>) -> VueOutput {
@@ -38,7 +38,7 @@ fn generate_main_component(art: &ArtDescriptor<'_>) -> String {
     let mut code = String::default();
 
     // Imports
-    code.push_str("import { defineComponent, h, reactive, markRaw } from 'vue';\n");
+    code.push_str("import { defineComponent, h, reactive, ref, markRaw } from 'vue';\n");
 
     // Import target component
     if let Some(ref component_path) = art.metadata.component {
@@ -116,10 +116,10 @@ fn generate_main_component(art: &ArtDescriptor<'_>) -> String {
         append!(code, "{component_name}.variantIndex = {i};\n\n");
     }
 
-    // Default export - the gallery component
+    // Default export - a tabbed playground over all variants
     code.push_str(
         r#"export default defineComponent({
-  name: 'ArtGallery',
+  name: 'ArtPlayground',
   props: {
     variant: { type: String, default: null },
     interactive: { type: Boolean, default: false },
@@ -141,15 +141,32 @@ fn generate_main_component(art: &ArtDescriptor<'_>) -> String {
 
     code.push_str(
         r#"    };
+    const initialVariant = variants.find(v => v.isDefault) || variants[0];
+    const activeVariant = ref(initialVariant ? initialVariant.name : null);
 
     return () => {
       if (props.variant && variantComponents[props.variant]) {
         return h(variantComponents[props.variant]);
       }
-      // Render all variants
-      return h('div', { class: 'musea-gallery' },
-        variants.map(v => h(variantComponents[v.name], { key: v.name }))
-      );
+      // Render a tab per variant and the active variant below
+      const active = activeVariant.value;
+      return h('div', { class: 'musea-playground' }, [
+        h('div', { class: 'musea-tabs', role: 'tablist' },
+          variants.map(v => h('button', {
+            key: v.name,
+            type: 'button',
+            role: 'tab',
+            class: ['musea-tab', { 'musea-tab--active': v.name === active }],
+            'aria-selected': v.name === active,
+            onClick: () => { activeVariant.value = v.name; },
+          }, v.name))
+        ),
+        h('div', { class: 'musea-panel', role: 'tabpanel', 'data-variant': active },
+          active && variantComponents[active]
+            ? [h(variantComponents[active], { key: active })]
+            : []
+        ),
+      ]);
     };
   }
 });
@@ -355,6 +372,31 @@ mod tests {
         insta::assert_debug_snapshot!(output);
     }
 
+    #[test]
+    fn test_transform_default_export_is_tabbed_playground() {
+        let allocator = Bump::new();
+        let source = r#"
+<art title="Button" component="./Button.vue">
+  <variant name="Primary"><Button>Primary</Button></variant>
+  <variant name="With Icon" default><Button icon="plus">Add</Button></variant>
+</art>
+"#;
+
+        let art = parse_art(&allocator, source, ArtParseOptions::default()).unwrap();
+        let code = transform_to_vue(&art).code;
+
+        assert!(code.contains("import TargetComponent from './Button.vue';"));
+        // Named exports stay available
+        assert!(code.contains("export const Primary = defineComponent("));
+        assert!(code.contains("export const WithIcon = defineComponent("));
+        // Default export renders a tab per variant, starting at the default one
+        assert!(code.contains("export default defineComponent({\n  name: 'ArtPlayground',"));
+        assert!(code.contains("'With Icon': WithIcon,"));
+        assert!(code.contains("variants.find(v => v.isDefault) || variants[0]"));
+        assert!(code.contains("h('div', { class: 'musea-tabs', role: 'tablist' },"));
+        assert!(code.contains("onClick: () => { activeVariant.value = v.name; },"));
+    }
+
     #[test]
     fn test_to_pascal_case() {
         assert_eq!(to_pascal_case("primary"), "Primary");
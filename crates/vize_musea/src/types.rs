@@ -54,6 +54,10 @@ pub struct ArtMetadata<'a> {
     /// Tags for filtering/searching (arena-allocated)
     pub tags: BumpVec<'a, &'a str>,
 
+    /// Decorator functions wrapping every variant, from the `decorators`
+    /// attribute or a `<decorators>` block (arena-allocated)
+    pub decorators: BumpVec<'a, &'a str>,
+
     /// Status indicator
     pub status: ArtStatus,
 
@@ -89,6 +93,12 @@ pub struct ArtVariant<'a> {
     /// Props/args override for this variant
     pub args: FxHashMap<&'a str, serde_json::Value>,
 
+    /// Storybook tags for this variant (e.g. `autodocs`, `!dev`)
+    pub tags: Vec<&'a str>,
+
+    /// Storybook parameters for this variant (JSON object)
+    pub parameters: Option<serde_json::Value>,
+
     /// Viewport configuration for VRT
     pub viewport: Option<ViewportConfig>,
 
@@ -185,6 +195,28 @@ pub enum ArtParseError {
     ParseError { line: u32, message: String },
 }
 
+/// Template error found in a `<variant>` block by
+/// [`validate_variants`](crate::parse::validate_variants).
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Invalid template in variant '{variant}': {}", .error.message)]
+pub struct VariantTemplateError {
+    /// Name of the variant containing the error
+    pub variant: String,
+    /// Compiler error, with its location mapped into the Art file
+    pub error: vize_relief::CompilerError,
+}
+
+/// Language of the generated Storybook CSF file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CsfTarget {
+    /// TypeScript (`*.stories.ts`) with `Meta`/`StoryObj` type annotations
+    #[default]
+    Ts,
+    /// JavaScript (`*.stories.js`) with JSDoc type hints
+    Js,
+}
+
 /// Output of Storybook CSF transformation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -230,6 +262,7 @@ impl<'a> ArtMetadata<'a> {
             component: None,
             category: None,
             tags: BumpVec::new_in(allocator),
+            decorators: BumpVec::new_in(allocator),
             status: ArtStatus::default(),
             order: None,
         }
@@ -245,6 +278,8 @@ impl<'a> ArtVariant<'a> {
             template,
             is_default: false,
             args: FxHashMap::default(),
+            tags: Vec::new(),
+            parameters: None,
             viewport: None,
             skip_vrt: false,
             loc: None,
@@ -301,6 +336,7 @@ pub struct ArtMetadataOwned {
     pub component: Option<String>,
     pub category: Option<String>,
     pub tags: Vec<String>,
+    pub decorators: Vec<String>,
     pub status: ArtStatus,
     pub order: Option<u32>,
 }
@@ -312,6 +348,8 @@ pub struct ArtVariantOwned {
     pub template: String,
     pub is_default: bool,
     pub args: FxHashMap<String, serde_json::Value>,
+    pub tags: Vec<String>,
+    pub parameters: Option<serde_json::Value>,
     pub viewport: Option<ViewportConfig>,
     pub skip_vrt: bool,
     pub loc: Option<SourceLocation>,
@@ -363,6 +401,11 @@ impl<'a> ArtMetadata<'a> {
                 .into_iter()
                 .map(|s| s.to_compact_string())
                 .collect(),
+            decorators: self
+                .decorators
+                .into_iter()
+                .map(|s| s.to_compact_string())
+                .collect(),
             status: self.status,
             order: self.order,
         }
@@ -381,6 +424,12 @@ impl<'a> ArtVariant<'a> {
                 .into_iter()
                 .map(|(k, v)| (k.to_compact_string(), v))
                 .collect(),
+            tags: self
+                .tags
+                .into_iter()
+                .map(|s| s.to_compact_string())
+                .collect(),
+            parameters: self.parameters,
             viewport: self.viewport,
             skip_vrt: self.skip_vrt,
             loc: self.loc,
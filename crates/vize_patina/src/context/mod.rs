@@ -14,10 +14,11 @@ use std::borrow::Cow;
 use vize_carton::String;
 use vize_carton::{
     directive::DirectiveSeverity,
-    i18n::{t, t_fmt, Locale},
+    i18n::{t, t_fmt, Locale, LocaleChain},
     Allocator, CompactString, FxHashMap, FxHashSet,
 };
 use vize_croquis::Croquis;
+use vize_relief::{CompilerError, LineColumnCache};
 
 /// Lint context provides utilities for rules during execution.
 ///
@@ -29,8 +30,8 @@ pub struct LintContext<'a> {
     pub source: &'a str,
     /// Filename for diagnostics.
     pub filename: &'a str,
-    /// Locale for i18n (default: English).
-    locale: Locale,
+    /// Locale chain for i18n (default: English).
+    locale: LocaleChain,
     /// Collected diagnostics (pre-allocated capacity).
     pub(crate) diagnostics: Vec<LintDiagnostic>,
     /// Current rule name (set by visitor before calling rule methods).
@@ -47,12 +48,14 @@ pub struct LintContext<'a> {
     disabled_all: Vec<DisabledRange>,
     /// Disabled ranges per rule name.
     disabled_rules: FxHashMap<CompactString, Vec<DisabledRange>>,
-    /// Line offsets for fast line number lookup.
-    line_offsets: Vec<u32>,
+    /// Line starts for fast line number lookup.
+    lines: LineColumnCache<'a>,
     /// Optional set of enabled rule names (if None, all rules are enabled).
     enabled_rules: Option<FxHashSet<String>>,
     /// Optional semantic analysis from croquis.
     pub(crate) analysis: Option<&'a Croquis>,
+    /// Errors reported while parsing the template.
+    parse_errors: &'a [CompilerError],
     /// SSR mode for linting.
     ssr_mode: SsrMode,
     /// Help display level.
@@ -75,19 +78,19 @@ impl<'a> LintContext<'a> {
         Self::with_locale(allocator, source, filename, Locale::default())
     }
 
-    /// Create a new lint context with specified locale.
+    /// Create a new lint context with specified locale (or locale chain).
     #[inline]
     pub fn with_locale(
         allocator: &'a Allocator,
         source: &'a str,
         filename: &'a str,
-        locale: Locale,
+        locale: impl Into<LocaleChain>,
     ) -> Self {
         Self {
             allocator,
             source,
             filename,
-            locale,
+            locale: locale.into(),
             diagnostics: Vec::with_capacity(Self::INITIAL_DIAGNOSTICS_CAPACITY),
             current_rule: "",
             element_stack: Vec::with_capacity(Self::INITIAL_STACK_CAPACITY),
@@ -96,9 +99,10 @@ impl<'a> LintContext<'a> {
             warning_count: 0,
             disabled_all: Vec::new(),
             disabled_rules: FxHashMap::default(),
-            line_offsets: Self::compute_line_offsets(source),
+            lines: LineColumnCache::new(source),
             enabled_rules: None,
             analysis: None,
+            parse_errors: &[],
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
             expected_error_lines: FxHashSet::default(),
@@ -118,7 +122,7 @@ impl<'a> LintContext<'a> {
             allocator,
             source,
             filename,
-            locale: Locale::default(),
+            locale: LocaleChain::default(),
             diagnostics: Vec::with_capacity(Self::INITIAL_DIAGNOSTICS_CAPACITY),
             current_rule: "",
             element_stack: Vec::with_capacity(Self::INITIAL_STACK_CAPACITY),
@@ -127,9 +131,10 @@ impl<'a> LintContext<'a> {
             warning_count: 0,
             disabled_all: Vec::new(),
             disabled_rules: FxHashMap::default(),
-            line_offsets: Self::compute_line_offsets(source),
+            lines: LineColumnCache::new(source),
             enabled_rules: None,
             analysis: Some(analysis),
+            parse_errors: &[],
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
             expected_error_lines: FxHashSet::default(),
@@ -155,6 +160,18 @@ impl<'a> LintContext<'a> {
         self.analysis.is_some()
     }
 
+    /// Set the errors reported while parsing the template.
+    #[inline]
+    pub fn set_parse_errors(&mut self, errors: &'a [CompilerError]) {
+        self.parse_errors = errors;
+    }
+
+    /// Get the errors reported while parsing the template.
+    #[inline]
+    pub fn parse_errors(&self) -> &'a [CompilerError] {
+        self.parse_errors
+    }
+
     /// Set SSR mode.
     #[inline]
     pub fn set_ssr_mode(&mut self, mode: SsrMode) {
@@ -203,10 +220,10 @@ impl<'a> LintContext<'a> {
         }
     }
 
-    /// Get the current locale.
+    /// Get the current (primary) locale.
     #[inline]
     pub fn locale(&self) -> Locale {
-        self.locale
+        self.locale.primary()
     }
 
     /// Translate a message key.
@@ -221,24 +238,10 @@ impl<'a> LintContext<'a> {
         t_fmt(self.locale, key, vars).into()
     }
 
-    /// Compute line offsets for fast line number lookup.
-    fn compute_line_offsets(source: &str) -> Vec<u32> {
-        let mut offsets = vec![0];
-        for (i, c) in source.char_indices() {
-            if c == '\n' {
-                offsets.push((i + 1) as u32);
-            }
-        }
-        offsets
-    }
-
     /// Get line number (1-indexed) from byte offset.
     #[inline]
     pub fn offset_to_line(&self, offset: u32) -> u32 {
-        match self.line_offsets.binary_search(&offset) {
-            Ok(line) => (line + 1) as u32,
-            Err(line) => line as u32,
-        }
+        self.lines.line(offset) + 1
     }
 
     /// Get the allocator.
@@ -102,7 +102,7 @@ pub use linter::script_rules::{builtin_script_rules, BuiltinScriptRuleMeta};
 pub use linter::{LintResult, Linter};
 pub use output::{format_results, format_summary, OutputFormat};
 pub use preset::LintPreset;
-pub use rule::{Rule, RuleCategory, RuleMeta, RuleRegistry};
+pub use rule::{Rule, RuleCategory, RuleMeta, RuleRegistry, RuleTag};
 pub use telegraph::{Emitter, JsonEmitter, LspDiagnostic, LspEmitter, Telegraph, TextEmitter};
 pub use vize_carton::i18n::Locale;
 
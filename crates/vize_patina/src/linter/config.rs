@@ -12,7 +12,10 @@ use crate::{
 };
 #[cfg(not(target_arch = "wasm32"))]
 use std::sync::Mutex;
-use vize_carton::{i18n::Locale, FxHashSet, String};
+use vize_carton::{
+    i18n::{Locale, LocaleChain},
+    FxHashSet, String,
+};
 
 /// Lint result for a single file.
 #[derive(Debug, Clone)]
@@ -51,8 +54,8 @@ pub struct Linter {
     pub(crate) registry: RuleRegistry,
     /// Estimated initial allocator capacity (in bytes).
     pub(crate) initial_capacity: usize,
-    /// Locale for i18n messages.
-    pub(crate) locale: Locale,
+    /// Locale chain for i18n messages.
+    pub(crate) locale: LocaleChain,
     /// Optional set of enabled rule names (if None, all rules are enabled).
     pub(crate) enabled_rules: Option<FxHashSet<String>>,
     /// Help display level.
@@ -75,7 +78,7 @@ impl Linter {
         Self {
             registry: RuleRegistry::with_preset(preset),
             initial_capacity: Self::DEFAULT_INITIAL_CAPACITY,
-            locale: Locale::default(),
+            locale: LocaleChain::default(),
             enabled_rules: None,
             help_level: HelpLevel::default(),
             script_rules: builtin_script_rule_names(preset),
@@ -90,7 +93,7 @@ impl Linter {
         Self {
             registry: RuleRegistry::with_preset(preset),
             initial_capacity: Self::DEFAULT_INITIAL_CAPACITY,
-            locale: Locale::default(),
+            locale: LocaleChain::default(),
             enabled_rules: None,
             help_level: HelpLevel::default(),
             script_rules: builtin_script_rule_names(preset),
@@ -105,7 +108,7 @@ impl Linter {
         Self {
             registry,
             initial_capacity: Self::DEFAULT_INITIAL_CAPACITY,
-            locale: Locale::default(),
+            locale: LocaleChain::default(),
             enabled_rules: None,
             help_level: HelpLevel::default(),
             script_rules: &[],
@@ -122,9 +125,12 @@ impl Linter {
     }
 
     /// Set the locale for i18n messages.
+    ///
+    /// Pass a [`LocaleChain`] (see [`Locale::with_fallback`]) to fill messages
+    /// missing from a partially translated locale.
     #[inline]
-    pub fn with_locale(mut self, locale: Locale) -> Self {
-        self.locale = locale;
+    pub fn with_locale(mut self, locale: impl Into<LocaleChain>) -> Self {
+        self.locale = locale.into();
         self
     }
 
@@ -145,10 +151,10 @@ impl Linter {
         self
     }
 
-    /// Get the current locale.
+    /// Get the current (primary) locale.
     #[inline]
     pub fn locale(&self) -> Locale {
-        self.locale
+        self.locale.primary()
     }
 
     /// Check if a rule is enabled.
@@ -79,12 +79,13 @@ impl Linter {
     ) -> LintResult {
         // Parse the template
         let parser = Parser::new(allocator.as_bump(), source);
-        let (root, _parse_errors) = profile!("patina.template.parse", parser.parse());
+        let (root, parse_errors) = profile!("patina.template.parse", parser.parse());
 
         // Create lint context with locale, help level, and enabled rules filter
         let mut ctx = LintContext::with_locale(allocator, source, filename, self.locale);
         ctx.set_enabled_rules(self.enabled_rules.clone());
         ctx.set_help_level(self.help_level);
+        ctx.set_parse_errors(&parse_errors);
 
         // Run visitor with all rules (filtering happens in context)
         let mut visitor = LintVisitor::new(&mut ctx, self.registry.rules());
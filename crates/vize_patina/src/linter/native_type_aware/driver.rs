@@ -55,6 +55,7 @@ pub(super) fn lint_with_descriptor<'a>(
 
     let analysis = profile!("patina.type_aware.croquis", {
         let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.set_script_lang(script_block.lang.as_deref());
         if let Some(script_setup) = descriptor.script_setup.as_ref() {
             let generic = script_setup
                 .attrs
@@ -81,9 +82,13 @@ pub(super) fn lint_with_descriptor<'a>(
                 .attrs
                 .get("generic")
                 .map(|value| value.as_ref());
-            script_parser::parse_script_setup_with_generic(script_content, generic)
+            script_parser::parse_script_setup_with_lang(
+                script_content,
+                generic,
+                script_block.lang.as_deref(),
+            )
         } else {
-            script_parser::parse_script(script_content)
+            script_parser::parse_script_with_lang(script_content, script_block.lang.as_deref())
         }
     });
     let config = VirtualTsConfig {
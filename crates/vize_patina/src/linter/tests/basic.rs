@@ -24,6 +24,17 @@ fn test_lint_with_allocator_reuse() {
     assert!(!result1.has_errors());
 }
 
+#[test]
+fn test_lint_malformed_template_reports_parsing_error() {
+    let linter = Linter::new();
+    let result = linter.lint_template("<div></span></div>", "test.vue");
+    assert!(result.has_errors());
+    assert!(result
+        .diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.rule_name == "vue/no-parsing-error"));
+}
+
 #[test]
 fn test_lint_files_batch() {
     let linter = Linter::new();
@@ -201,3 +212,34 @@ export default {
     assert_eq!(result.error_count, 1);
     assert_eq!(result.diagnostics[0].rule_name, "script/no-options-api");
 }
+
+#[test]
+fn test_partial_locale_falls_back_to_english() {
+    use vize_carton::i18n::Locale;
+
+    let linter = Linter::new().with_locale(Locale::with_fallback(Locale::Fr, Locale::En));
+    assert_eq!(linter.locale(), Locale::Fr);
+
+    let result = linter.lint_template(
+        r#"<ul><li v-for="item in items">{{ item }}</li><p v-if></p></ul>"#,
+        "test.vue",
+    );
+    let message = |rule: &str| {
+        result
+            .diagnostics
+            .iter()
+            .find(|d| d.rule_name == rule)
+            .map(|d| d.message.as_str())
+            .unwrap()
+    };
+
+    assert_eq!(
+        message("vue/require-v-for-key"),
+        "Les éléments itérés doivent avoir une directive 'v-bind:key'. Élément : <li>"
+    );
+    // Not translated in `fr.json`
+    assert_eq!(
+        message("vue/valid-v-if"),
+        "v-if directive requires an expression"
+    );
+}
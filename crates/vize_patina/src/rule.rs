@@ -3,6 +3,8 @@
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
 use crate::preset::LintPreset;
+use std::sync::OnceLock;
+use vize_carton::FxHashMap;
 use vize_relief::ast::{DirectiveNode, ElementNode, ForNode, IfNode, InterpolationNode, RootNode};
 
 /// Rule category for organization
@@ -26,6 +28,15 @@ pub enum RuleCategory {
     TypeAware,
 }
 
+/// Diagnostic tag reported for every diagnostic of a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleTag {
+    /// The rule flags unused or unnecessary code
+    Unnecessary,
+    /// The rule flags deprecated or unsupported APIs
+    Deprecated,
+}
+
 /// Rule metadata
 pub struct RuleMeta {
     /// Rule name (e.g., "vue/require-v-for-key")
@@ -38,6 +49,10 @@ pub struct RuleMeta {
     pub fixable: bool,
     /// Default severity
     pub default_severity: Severity,
+    /// Documentation URL, if the rule has a published page
+    pub docs_url: Option<&'static str>,
+    /// Diagnostic tags (e.g., unnecessary or deprecated code)
+    pub tags: &'static [RuleTag],
 }
 
 /// Rule trait for implementing lint rules
@@ -132,6 +147,24 @@ impl RuleRegistry {
         self.rules.iter().any(|rule| rule.meta().name == name)
     }
 
+    /// Look up the metadata of a built-in rule by name.
+    pub fn find_meta(name: &str) -> Option<&'static RuleMeta> {
+        static METAS: OnceLock<FxHashMap<&'static str, &'static RuleMeta>> = OnceLock::new();
+        METAS
+            .get_or_init(|| {
+                let mut metas = FxHashMap::default();
+                for registry in [Self::with_all(), Self::with_nuxt()] {
+                    for rule in registry.rules() {
+                        let meta = rule.meta();
+                        metas.insert(meta.name, meta);
+                    }
+                }
+                metas
+            })
+            .get(name)
+            .copied()
+    }
+
     /// Create a registry for a named preset.
     pub fn with_preset(preset: LintPreset) -> Self {
         match preset {
@@ -189,6 +222,8 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::SingleStyleBlock));
         registry.register(Box::new(crate::rules::vue::NoUselessTemplateAttributes));
         registry.register(Box::new(crate::rules::vue::ValidVMemo));
+        registry.register(Box::new(crate::rules::vue::NoParsingError));
+        registry.register(Box::new(crate::rules::vue::ThisInTemplate));
         registry.register(Box::new(crate::rules::vapor::NoVueLifecycleEvents));
 
         // Security rules.
@@ -293,6 +328,7 @@ impl RuleRegistry {
         registry.register(Box::new(crate::rules::vue::NoUselessTemplateAttributes));
         registry.register(Box::new(crate::rules::vue::ValidVMemo));
         registry.register(Box::new(crate::rules::vue::UseVOnExact));
+        registry.register(Box::new(crate::rules::vue::NoParsingError));
 
         // Security Rules
         registry.register(Box::new(crate::rules::vue::NoVHtml));
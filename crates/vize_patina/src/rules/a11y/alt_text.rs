@@ -36,6 +36,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Require alternative text for media elements
@@ -18,6 +18,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Require anchor elements to have accessible content
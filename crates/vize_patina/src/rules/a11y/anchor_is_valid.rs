@@ -31,6 +31,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Enforce valid href on anchor elements
@@ -22,6 +22,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Valid ARIA roles from WAI-ARIA specification.
@@ -19,6 +19,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Require form controls to have associated labels
@@ -21,6 +21,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Accessibility,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Disallow aria-hidden="true" on focusable elements
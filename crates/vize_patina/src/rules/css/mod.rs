@@ -49,6 +49,7 @@ use memchr::memmem;
 use crate::diagnostic::{LintDiagnostic, Severity};
 use vize_carton::String;
 use vize_carton::ToCompactString;
+use vize_relief::LineColumnCache;
 
 pub use no_display_none::NoDisplayNone;
 pub use no_hardcoded_values::NoHardcodedValues;
@@ -134,16 +135,9 @@ impl DisabledRules {
         let disable_line_finder = memmem::Finder::new(b"vize-disable-line ");
         let disable_next_line_finder = memmem::Finder::new(b"vize-disable-next-line ");
 
-        // Track line numbers
-        let mut line_starts: Vec<usize> = vec![0];
-        for (i, &b) in bytes.iter().enumerate() {
-            if b == b'\n' {
-                line_starts.push(i + 1);
-            }
-        }
-
-        let get_line_number =
-            |pos: usize| -> usize { line_starts.partition_point(|&start| start <= pos) };
+        // Track line numbers (1-indexed)
+        let lines = LineColumnCache::new(source);
+        let get_line_number = |pos: usize| -> usize { lines.line(pos as u32) as usize + 1 };
 
         // Find block disable/enable comments
         let mut search_start = 0;
@@ -409,18 +403,8 @@ impl CssLinter {
 
         // Filter out disabled diagnostics
         if let Some(disabled) = disabled_rules {
-            let line_starts: Vec<usize> = std::iter::once(0)
-                .chain(source.bytes().enumerate().filter_map(|(i, b)| {
-                    if b == b'\n' {
-                        Some(i + 1)
-                    } else {
-                        None
-                    }
-                }))
-                .collect();
-
-            let get_line =
-                |pos: u32| -> usize { line_starts.partition_point(|&start| start <= pos as usize) };
+            let lines = LineColumnCache::new(source);
+            let get_line = |pos: u32| -> usize { lines.line(pos) as usize + 1 };
 
             result.diagnostics.retain(|d| {
                 let line = get_line(d.start);
@@ -452,7 +436,7 @@ impl Default for CssLinter {
 
 #[cfg(test)]
 mod disable_tests {
-    use super::{strip_vize_comments, CssLinter, DisabledRules};
+    use super::{strip_vize_comments, CssLinter, DisabledRules, LineColumnCache};
 
     #[test]
     fn test_parse_disable_comments() {
@@ -513,20 +497,8 @@ mod disable_tests {
             disabled
         );
 
-        // Calculate line starts
-        let line_starts: Vec<usize> = std::iter::once(0)
-            .chain(source.bytes().enumerate().filter_map(|(i, b)| {
-                if b == b'\n' {
-                    Some(i + 1)
-                } else {
-                    None
-                }
-            }))
-            .collect();
-        println!("line_starts: {:?}", line_starts);
-
-        let get_line =
-            |pos: u32| -> usize { line_starts.partition_point(|&start| start <= pos as usize) };
+        let lines = LineColumnCache::new(source);
+        let get_line = |pos: u32| -> usize { lines.line(pos) as usize + 1 };
 
         let linter = CssLinter::with_all_rules();
         let result = linter.lint(source, 0);
@@ -23,7 +23,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_relief::ast::{ElementNode, ElementType, PropNode};
 
 use super::helpers::deprecated_attr_suggestion;
@@ -34,6 +34,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::HtmlConformance,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[RuleTag::Deprecated],
 };
 
 #[derive(Default)]
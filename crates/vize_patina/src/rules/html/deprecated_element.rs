@@ -24,7 +24,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_relief::ast::{ElementNode, ElementType};
 
 use super::helpers::DEPRECATED_ELEMENTS;
@@ -35,6 +35,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::HtmlConformance,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[RuleTag::Deprecated],
 };
 
 #[derive(Default)]
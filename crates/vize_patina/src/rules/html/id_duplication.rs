@@ -39,6 +39,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::HtmlConformance,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: None,
+    tags: &[],
 };
 
 #[derive(Default)]
@@ -32,6 +32,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::HtmlConformance,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 #[derive(Default)]
@@ -16,6 +16,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::TypeAware,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Metadata-only rule handle for the native floating Promise check.
@@ -25,7 +25,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_relief::ast::{ElementNode, PropNode};
 
 static META: RuleMeta = RuleMeta {
@@ -34,6 +34,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Vapor,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: None,
+    tags: &[RuleTag::Deprecated],
 };
 
 /// Disallow inline-template
@@ -22,7 +22,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_relief::ast::ElementNode;
 
 static META: RuleMeta = RuleMeta {
@@ -31,6 +31,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Vapor,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[RuleTag::Deprecated],
 };
 
 /// Warn about Suspense usage in Vapor-only apps
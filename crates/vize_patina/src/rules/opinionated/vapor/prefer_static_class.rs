@@ -33,6 +33,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Vapor,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Prefer static class in Vapor mode
@@ -20,6 +20,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Vapor,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Suggest vapor attribute on script setup
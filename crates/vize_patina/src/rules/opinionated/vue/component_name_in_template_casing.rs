@@ -31,6 +31,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/component-name-in-template-casing.html"),
+    tags: &[],
 };
 
 /// Casing style
@@ -30,6 +30,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/html-self-closing.html"),
+    tags: &[],
 };
 
 /// Void elements that should always self-close
@@ -37,6 +37,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/multi-word-component-names.html"),
+    tags: &[],
 };
 
 /// Require component names to be multi-word
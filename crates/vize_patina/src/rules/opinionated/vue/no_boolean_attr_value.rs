@@ -36,6 +36,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 #[derive(Default)]
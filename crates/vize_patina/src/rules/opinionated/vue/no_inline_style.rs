@@ -36,6 +36,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// No inline style rule
@@ -25,6 +25,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// No preprocessor lang rule
@@ -47,6 +47,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Allowed script lang values
@@ -37,6 +37,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-template-shadow.html"),
+    tags: &[],
 };
 
 /// Disallow template variable shadowing
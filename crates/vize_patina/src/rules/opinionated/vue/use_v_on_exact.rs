@@ -31,6 +31,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/use-v-on-exact.html"),
+    tags: &[],
 };
 
 #[derive(Default)]
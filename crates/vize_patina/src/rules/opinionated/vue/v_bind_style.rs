@@ -34,6 +34,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/v-bind-style.html"),
+    tags: &[],
 };
 
 /// Style preference for v-bind
@@ -57,6 +57,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::TypeAware,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Require typed emits rule
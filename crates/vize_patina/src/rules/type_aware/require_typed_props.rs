@@ -60,6 +60,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::TypeAware,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 /// Require typed props rule
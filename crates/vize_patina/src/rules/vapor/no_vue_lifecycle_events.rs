@@ -27,7 +27,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_relief::ast::{DirectiveNode, ElementNode, ExpressionNode};
 
 static META: RuleMeta = RuleMeta {
@@ -36,6 +36,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Vapor,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: None,
+    tags: &[RuleTag::Deprecated],
 };
 
 /// Disallow @vue:xxx lifecycle events
@@ -31,6 +31,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/component-definition-name-casing.html"),
+    tags: &[],
 };
 
 /// Enforce PascalCase component definition names
@@ -15,6 +15,7 @@ mod attribute_order;
 mod no_child_content;
 mod no_dupe_v_else_if;
 mod no_duplicate_attributes;
+mod no_parsing_error;
 mod no_reserved_component_names;
 mod no_template_key;
 mod no_textarea_mustache;
@@ -44,6 +45,7 @@ mod mustache_interpolation_spacing;
 mod no_lone_template;
 mod no_multi_spaces;
 mod sfc_element_order;
+mod this_in_template;
 mod v_on_style;
 mod v_slot_style;
 // Most implementations live under rules::opinionated::vue and are re-exported here.
@@ -76,6 +78,7 @@ pub use crate::rules::opinionated::vue::UseVOnExact;
 pub use no_child_content::NoChildContent;
 pub use no_dupe_v_else_if::NoDupeVElseIf;
 pub use no_duplicate_attributes::NoDuplicateAttributes;
+pub use no_parsing_error::NoParsingError;
 pub use no_reserved_component_names::NoReservedComponentNames;
 pub use no_template_key::NoTemplateKey;
 pub use no_textarea_mustache::NoTextareaMustache;
@@ -118,6 +121,7 @@ pub use crate::rules::opinionated::vue::ScopedEventNames;
 pub use attribute_order::AttributeOrder;
 pub use no_lone_template::NoLoneTemplate;
 pub use sfc_element_order::SfcElementOrder;
+pub use this_in_template::ThisInTemplate;
 
 // Security rules exports
 pub use no_unsafe_url::NoUnsafeUrl;
@@ -29,6 +29,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/mustache-interpolation-spacing.html"),
+    tags: &[],
 };
 
 /// Spacing style
@@ -33,6 +33,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-child-content.html"),
+    tags: &[],
 };
 
 #[derive(Default)]
@@ -37,6 +37,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-dupe-v-else-if.html"),
+    tags: &[],
 };
 
 /// Disallow duplicate v-else-if conditions
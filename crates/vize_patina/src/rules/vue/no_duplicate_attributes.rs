@@ -32,6 +32,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-duplicate-attributes.html"),
+    tags: &[],
 };
 
 /// Disallow duplicate attributes
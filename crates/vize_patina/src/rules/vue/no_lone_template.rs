@@ -35,6 +35,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-lone-template.html"),
+    tags: &[],
 };
 
 /// No lone template rule
@@ -27,6 +27,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: true,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-multi-spaces.html"),
+    tags: &[],
 };
 
 /// Disallow multiple spaces
@@ -57,6 +57,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-mutating-props.html"),
+    tags: &[],
 };
 
 /// Disallow mutating props
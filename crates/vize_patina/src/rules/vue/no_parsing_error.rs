@@ -0,0 +1,108 @@
+//! vue/no-parsing-error
+//!
+//! Disallow parsing errors in `<template>`.
+//!
+//! Reports the errors raised by the template parser so that structurally
+//! broken templates are surfaced even when no other rule matches the
+//! partially parsed tree.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <div>
+//!   <span>unclosed
+//! </div>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <div>
+//!   <span>closed</span>
+//! </div>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-parsing-error",
+    description: "Disallow parsing errors in `<template>`",
+    category: RuleCategory::Essential,
+    fixable: false,
+    default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-parsing-error.html"),
+    tags: &[],
+};
+
+/// Disallow template parsing errors
+#[derive(Default)]
+pub struct NoParsingError;
+
+impl Rule for NoParsingError {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        for error in ctx.parse_errors() {
+            let (start, end) = error
+                .loc
+                .as_ref()
+                .map_or((0, 0), |loc| (loc.start.offset, loc.end.offset));
+            let message = ctx.t_fmt(
+                "vue/no-parsing-error.message",
+                &[("message", error.message.as_str())],
+            );
+
+            ctx.report(LintDiagnostic::error(META.name, message, start, end));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NoParsingError;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoParsingError));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid_template() {
+        let linter = create_linter();
+        let result = linter.lint_template("<div><span>ok</span></div>", "test.vue");
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid_end_tag() {
+        let linter = create_linter();
+        let result = linter.lint_template("<div></span></div>", "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.diagnostics[0].rule_name, "vue/no-parsing-error");
+        assert_eq!(result.diagnostics[0].start, 5);
+    }
+
+    #[test]
+    fn test_missing_end_tag() {
+        let linter = create_linter();
+        let result = linter.lint_template("<div><span>unclosed</div>", "test.vue");
+        assert!(result.error_count > 0);
+    }
+
+    #[test]
+    fn test_sfc_offsets_point_into_file() {
+        let linter = create_linter();
+        let sfc = "<template>\n  <div></span></div>\n</template>\n";
+        let result = linter.lint_sfc(sfc, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.diagnostics[0].start, 18);
+    }
+}
@@ -38,6 +38,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-reserved-component-names.html"),
+    tags: &[],
 };
 
 /// Reserved names that cannot be used (specific edge cases)
@@ -34,6 +34,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-template-key.html"),
+    tags: &[],
 };
 
 /// Disallow key attribute on <template>
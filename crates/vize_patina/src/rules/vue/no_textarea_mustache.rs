@@ -28,6 +28,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-textarea-mustache.html"),
+    tags: &[],
 };
 
 /// Disallow mustache in textarea
@@ -30,7 +30,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_carton::String;
 use vize_carton::ToCompactString;
 use vize_croquis::naming::is_pascal_case;
@@ -43,6 +43,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-unused-components.html"),
+    tags: &[RuleTag::Unnecessary],
 };
 
 /// Disallow unused components
@@ -36,7 +36,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_carton::String;
 use vize_carton::ToCompactString;
 use vize_relief::ast::RootNode;
@@ -47,6 +47,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-unused-properties.html"),
+    tags: &[RuleTag::Unnecessary],
 };
 
 /// Disallow unused properties
@@ -40,7 +40,7 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::Severity;
-use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::rule::{Rule, RuleCategory, RuleMeta, RuleTag};
 use vize_carton::String;
 use vize_carton::ToCompactString;
 use vize_croquis::UnusedVarContext;
@@ -52,6 +52,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-unused-vars.html"),
+    tags: &[RuleTag::Unnecessary],
 };
 
 /// Disallow unused v-for and v-slot variables
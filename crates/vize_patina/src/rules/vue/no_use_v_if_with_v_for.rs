@@ -38,6 +38,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-use-v-if-with-v-for.html"),
+    tags: &[],
 };
 
 /// Disallow using v-if with v-for on the same element
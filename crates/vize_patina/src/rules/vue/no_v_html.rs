@@ -46,6 +46,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/no-v-html.html"),
+    tags: &[],
 };
 
 /// No v-html rule
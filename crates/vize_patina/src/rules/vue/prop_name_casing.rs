@@ -29,6 +29,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::StronglyRecommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/prop-name-casing.html"),
+    tags: &[],
 };
 
 /// Enforce kebab-case prop names
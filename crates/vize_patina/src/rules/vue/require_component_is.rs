@@ -30,6 +30,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/require-component-is.html"),
+    tags: &[],
 };
 
 #[derive(Default)]
@@ -37,6 +37,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Recommended,
     fixable: false,
     default_severity: Severity::Warning,
+    docs_url: None,
+    tags: &[],
 };
 
 static HELP_ORDER: &str = "Recommended order: <script> -> <template> -> <style>";
@@ -0,0 +1,318 @@
+//! vue/this-in-template
+//!
+//! Disallow `this` in template expressions.
+//!
+//! Templates compiled from `<script setup>` are inlined into the setup
+//! function, where `this` is not the component instance. Bindings are
+//! referenced directly instead.
+//!
+//! When semantic analysis (Croquis) is available and the identifier after
+//! `this.` is a known script binding, an autofix removes the `this.` prefix.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <div>{{ this.count }}</div>
+//! <button @click="this.increment()"></button>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <div>{{ count }}</div>
+//! <button @click="increment()"></button>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode, ExpressionNode, InterpolationNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/this-in-template",
+    description: "Disallow `this` in template expressions",
+    category: RuleCategory::Recommended,
+    fixable: true,
+    default_severity: Severity::Warning,
+    docs_url: Some("https://eslint.vuejs.org/rules/this-in-template.html"),
+    tags: &[],
+};
+
+/// Disallow `this` in template expressions
+#[derive(Default)]
+pub struct ThisInTemplate;
+
+/// A `this` reference found in an expression.
+struct ThisReference<'s> {
+    /// Byte offset of `this` within the expression
+    start: usize,
+    /// Identifier accessed with `this.<ident>`, if any
+    member: Option<&'s str>,
+}
+
+impl ThisInTemplate {
+    fn check_expression(&self, ctx: &mut LintContext<'_>, start: u32, end: u32) {
+        let Some(expr) = ctx.source.get(start as usize..end as usize) else {
+            return;
+        };
+
+        for reference in find_this_references(expr) {
+            let this_start = start + reference.start as u32;
+
+            let Some(member) = reference.member else {
+                ctx.report(
+                    LintDiagnostic::warn(
+                        META.name,
+                        ctx.t("vue/this-in-template.message"),
+                        this_start,
+                        this_start + 4,
+                    )
+                    .with_help(ctx.t("vue/this-in-template.help")),
+                );
+                continue;
+            };
+
+            let member_end = this_start + 5 + member.len() as u32;
+            let mut diagnostic = LintDiagnostic::warn(
+                META.name,
+                ctx.t("vue/this-in-template.message"),
+                this_start,
+                member_end,
+            )
+            .with_help(ctx.t("vue/this-in-template.help"));
+
+            // Only drop `this.` when the bare identifier resolves to the same
+            // script binding; v-for aliases would shadow it.
+            if ctx.get_binding_type(member).is_some() && !ctx.is_v_for_var(member) {
+                diagnostic = diagnostic.with_fix(Fix::new(
+                    "Remove `this.`",
+                    TextEdit::delete(this_start, this_start + 5),
+                ));
+            }
+
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+impl Rule for ThisInTemplate {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_interpolation<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        interpolation: &InterpolationNode<'a>,
+    ) {
+        if let ExpressionNode::Simple(expr) = &interpolation.content {
+            self.check_expression(ctx, expr.loc.start.offset, expr.loc.end.offset);
+        }
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        let mut arg_start = None;
+        if let Some(ExpressionNode::Simple(arg)) = &directive.arg {
+            arg_start = Some(arg.loc.start.offset);
+            if !arg.is_static {
+                self.check_expression(ctx, arg.loc.start.offset, arg.loc.end.offset);
+            }
+        }
+
+        if let Some(ExpressionNode::Simple(exp)) = &directive.exp {
+            // Same-name shorthand (`:foo`) synthesizes its expression from the argument
+            if arg_start == Some(exp.loc.start.offset) {
+                return;
+            }
+            self.check_expression(ctx, exp.loc.start.offset, exp.loc.end.offset);
+        }
+    }
+}
+
+/// Find `this` keywords in an expression, skipping string literals and
+/// property names such as `foo.this`.
+fn find_this_references(expr: &str) -> Vec<ThisReference<'_>> {
+    let bytes = expr.as_bytes();
+    let mut references = Vec::new();
+    let mut prev_significant = 0u8;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+
+        if matches!(b, b'\'' | b'"' | b'`') {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b {
+                if bytes[i] == b'\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            prev_significant = b;
+            continue;
+        }
+
+        if is_ident_char(b) {
+            let start = i;
+            while i < bytes.len() && is_ident_char(bytes[i]) {
+                i += 1;
+            }
+            if &expr[start..i] == "this" && prev_significant != b'.' {
+                let member = bytes
+                    .get(i)
+                    .filter(|&&c| c == b'.')
+                    .and_then(|_| {
+                        let member_start = i + 1;
+                        let member_end = bytes[member_start..]
+                            .iter()
+                            .position(|&c| !is_ident_char(c))
+                            .map_or(bytes.len(), |len| member_start + len);
+                        expr.get(member_start..member_end)
+                    })
+                    .filter(|member| member.starts_with(|c: char| !c.is_ascii_digit()));
+                references.push(ThisReference { start, member });
+            }
+            prev_significant = bytes[i - 1];
+            continue;
+        }
+
+        if b == b'.' && bytes.get(i + 1) == Some(&b'.') {
+            // Spread (`...this`) is not a member access
+            while bytes.get(i) == Some(&b'.') {
+                i += 1;
+            }
+            prev_significant = b',';
+            continue;
+        }
+
+        if !b.is_ascii_whitespace() {
+            prev_significant = b;
+        }
+        i += 1;
+    }
+
+    references
+}
+
+#[inline]
+fn is_ident_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThisInTemplate;
+    use crate::context::LintContext;
+    use crate::diagnostic::LintDiagnostic;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+    use crate::visitor::LintVisitor;
+    use vize_armature::Parser;
+    use vize_carton::Allocator;
+    use vize_croquis::Analyzer;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(ThisInTemplate));
+        Linter::with_registry(registry)
+    }
+
+    fn lint_with_script_setup(script: &str, template: &str) -> Vec<LintDiagnostic> {
+        let mut analyzer = Analyzer::for_lint();
+        analyzer.analyze_script_setup(script);
+        let analysis = analyzer.finish();
+
+        let allocator = Allocator::default();
+        let (root, _) = Parser::new(allocator.as_bump(), template).parse();
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(ThisInTemplate));
+
+        let mut ctx = LintContext::with_analysis(&allocator, template, "test.vue", &analysis);
+        LintVisitor::new(&mut ctx, registry.rules()).visit_root(&root);
+        ctx.into_diagnostics()
+    }
+
+    #[test]
+    fn test_valid_without_this() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div :class="cls" @click="toggle()">{{ count }}</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_ignores_this_in_strings_and_property_names() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div :title="'this.foo' + obj.this">{{ `this` }}</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_reports_this_without_analysis() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div :class="this.cls" @click="this.toggle()">{{ this.count }}</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 3);
+        assert!(result.diagnostics.iter().all(|d| !d.has_fix()));
+    }
+
+    #[test]
+    fn test_reports_bare_this() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<Child v-bind="{ ...this }" />"#, "test.vue");
+        assert_eq!(result.warning_count, 1);
+        assert!(!result.diagnostics[0].has_fix());
+    }
+
+    #[test]
+    fn test_fixes_known_binding() {
+        let source = r#"<div @click="this.increment()">{{ this.count }}</div>"#;
+        let diagnostics = lint_with_script_setup(
+            "const count = ref(0)\nfunction increment() { count.value++ }",
+            source,
+        );
+        assert_eq!(diagnostics.len(), 2);
+
+        let fixed = diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed.as_str(),
+            r#"<div @click="increment()">{{ this.count }}</div>"#
+        );
+        let fixed = diagnostics[1].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed.as_str(),
+            r#"<div @click="this.increment()">{{ count }}</div>"#
+        );
+    }
+
+    #[test]
+    fn test_does_not_fix_unknown_binding() {
+        let diagnostics =
+            lint_with_script_setup("const count = ref(0)", r#"<div>{{ this.$attrs }}</div>"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].has_fix());
+    }
+
+    #[test]
+    fn test_does_not_fix_shadowed_binding() {
+        let diagnostics = lint_with_script_setup(
+            "const item = ref(null)",
+            r#"<ul><li v-for="item in items">{{ this.item }}</li></ul>"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].has_fix());
+    }
+}
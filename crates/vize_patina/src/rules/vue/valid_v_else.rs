@@ -33,6 +33,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: true,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/valid-v-else.html"),
+    tags: &[],
 };
 
 /// Enforce valid v-else directives
@@ -32,6 +32,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/valid-v-if.html"),
+    tags: &[],
 };
 
 /// Enforce valid v-if directives
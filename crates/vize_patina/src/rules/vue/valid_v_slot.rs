@@ -29,6 +29,8 @@ static META: RuleMeta = RuleMeta {
     category: RuleCategory::Essential,
     fixable: false,
     default_severity: Severity::Error,
+    docs_url: Some("https://eslint.vuejs.org/rules/valid-v-slot.html"),
+    tags: &[],
 };
 
 /// Valid v-slot rule
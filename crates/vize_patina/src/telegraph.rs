@@ -23,10 +23,12 @@
 
 #![allow(clippy::disallowed_macros)]
 
-use crate::diagnostic::{render_help, HelpRenderTarget, Severity};
+use crate::diagnostic::{render_help, HelpRenderTarget, LintDiagnostic, Severity};
 use crate::linter::LintResult;
+use crate::rule::{RuleRegistry, RuleTag};
 use vize_carton::String;
 use vize_carton::ToCompactString;
+use vize_relief::LineColumnCache;
 
 /// An emitter that can transmit lint diagnostics to a destination.
 ///
@@ -187,6 +189,7 @@ pub struct LspEmitter;
 
 /// LSP-compatible diagnostic representation
 #[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LspDiagnostic {
     /// The range at which the diagnostic applies
     pub range: LspRange,
@@ -198,6 +201,26 @@ pub struct LspDiagnostic {
     pub source: String,
     /// The diagnostic's code (rule name)
     pub code: String,
+    /// Link to the documentation of the rule, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_description: Option<LspCodeDescription>,
+    /// Additional metadata (1 = Unnecessary, 2 = Deprecated)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<u8>,
+}
+
+impl LspDiagnostic {
+    /// Tag for unused or unnecessary code (rendered faded out)
+    pub const TAG_UNNECESSARY: u8 = 1;
+    /// Tag for deprecated or obsolete code (rendered struck through)
+    pub const TAG_DEPRECATED: u8 = 2;
+}
+
+/// LSP-compatible code description
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LspCodeDescription {
+    /// URI of the rule documentation
+    pub href: String,
 }
 
 /// LSP-compatible range
@@ -223,8 +246,8 @@ impl LspEmitter {
         result
             .diagnostics
             .iter()
-            .map(|d| LspDiagnostic {
-                range: LspRange {
+            .map(|d| {
+                let range = LspRange {
                     start: LspPosition {
                         // TODO: Convert byte offset to line/column using source
                         line: 0,
@@ -234,89 +257,73 @@ impl LspEmitter {
                         line: 0,
                         character: d.end,
                     },
-                },
-                severity: match d.severity {
-                    Severity::Error => 1,
-                    Severity::Warning => 2,
-                },
-                message: if let Some(help) = &d.help {
-                    format!(
-                        "{}\n{}",
-                        d.message,
-                        render_help(help, HelpRenderTarget::PlainText)
-                    )
-                    .into()
-                } else {
-                    d.message.to_compact_string()
-                },
-                source: "vize-patina".to_compact_string(),
-                code: d.rule_name.to_compact_string(),
+                };
+                Self::to_lsp_diagnostic(d, range)
             })
             .collect()
     }
 
     /// Convert a LintResult to LSP diagnostics with accurate line/column info
     pub fn to_lsp_diagnostics_with_source(result: &LintResult, source: &str) -> Vec<LspDiagnostic> {
+        let lines = LineColumnCache::new(source);
         result
             .diagnostics
             .iter()
             .map(|d| {
-                let (start_line, start_col) = offset_to_line_col(source, d.start as usize);
-                let (end_line, end_col) = offset_to_line_col(source, d.end as usize);
-
-                LspDiagnostic {
-                    range: LspRange {
-                        start: LspPosition {
-                            line: start_line,
-                            character: start_col,
-                        },
-                        end: LspPosition {
-                            line: end_line,
-                            character: end_col,
-                        },
-                    },
-                    severity: match d.severity {
-                        Severity::Error => 1,
-                        Severity::Warning => 2,
+                let start = lines.position(d.start);
+                let end = lines.position(d.end);
+
+                let range = LspRange {
+                    start: LspPosition {
+                        line: start.line,
+                        character: start.column,
                     },
-                    message: if let Some(help) = &d.help {
-                        format!(
-                            "{}\n{}",
-                            d.message,
-                            render_help(help, HelpRenderTarget::PlainText)
-                        )
-                        .into()
-                    } else {
-                        d.message.to_compact_string()
+                    end: LspPosition {
+                        line: end.line,
+                        character: end.column,
                     },
-                    source: "vize-patina".to_compact_string(),
-                    code: d.rule_name.to_compact_string(),
-                }
+                };
+                Self::to_lsp_diagnostic(d, range)
             })
             .collect()
     }
-}
 
-/// Convert byte offset to (line, column) - both 0-indexed for LSP
-fn offset_to_line_col(source: &str, offset: usize) -> (u32, u32) {
-    let mut line = 0u32;
-    let mut col = 0u32;
-    let mut current_offset = 0;
-
-    for ch in source.chars() {
-        if current_offset >= offset {
-            break;
-        }
-        if ch == '\n' {
-            line += 1;
-            col = 0;
-        } else {
-            col += 1;
+    fn to_lsp_diagnostic(d: &LintDiagnostic, range: LspRange) -> LspDiagnostic {
+        let meta = RuleRegistry::find_meta(d.rule_name);
+        LspDiagnostic {
+            range,
+            severity: match d.severity {
+                Severity::Error => 1,
+                Severity::Warning => 2,
+            },
+            message: if let Some(help) = &d.help {
+                format!(
+                    "{}\n{}",
+                    d.message,
+                    render_help(help, HelpRenderTarget::PlainText)
+                )
+                .into()
+            } else {
+                d.message.to_compact_string()
+            },
+            source: "vize-patina".to_compact_string(),
+            code: d.rule_name.to_compact_string(),
+            code_description: meta
+                .and_then(|meta| meta.docs_url)
+                .map(|href| LspCodeDescription { href: href.into() }),
+            tags: meta
+                .map(|meta| meta.tags.iter().map(|&tag| lsp_tag(tag)).collect())
+                .unwrap_or_default(),
         }
-        current_offset += ch.len_utf8();
     }
+}
 
-    (line, col)
+/// LSP diagnostic tag for a rule tag.
+fn lsp_tag(tag: RuleTag) -> u8 {
+    match tag {
+        RuleTag::Unnecessary => LspDiagnostic::TAG_UNNECESSARY,
+        RuleTag::Deprecated => LspDiagnostic::TAG_DEPRECATED,
+    }
 }
 
 impl Emitter for LspEmitter {
@@ -347,7 +354,7 @@ pub struct OxlintBridge {
 
 #[cfg(test)]
 mod tests {
-    use super::{offset_to_line_col, LintResult, LspEmitter, Telegraph};
+    use super::{LintResult, LspDiagnostic, LspEmitter, Telegraph};
     use crate::diagnostic::LintDiagnostic;
     use vize_carton::ToCompactString;
 
@@ -405,11 +412,62 @@ mod tests {
     }
 
     #[test]
-    fn test_offset_to_line_col() {
-        let source = "abc\ndef\nghi";
-        assert_eq!(offset_to_line_col(source, 0), (0, 0)); // 'a'
-        assert_eq!(offset_to_line_col(source, 3), (0, 3)); // '\n'
-        assert_eq!(offset_to_line_col(source, 4), (1, 0)); // 'd'
-        assert_eq!(offset_to_line_col(source, 8), (2, 0)); // 'g'
+    fn test_lsp_diagnostic_code_description_and_tags() {
+        let result = LintResult {
+            filename: "test.vue".to_compact_string(),
+            diagnostics: vec![
+                LintDiagnostic::warn("vue/no-unused-vars", "Unused", 0, 4),
+                LintDiagnostic::warn("vapor/no-vue-lifecycle-events", "Unsupported", 5, 9),
+                LintDiagnostic::warn("a11y/img-alt", "Missing alt", 10, 14),
+                LintDiagnostic::warn("vue/require-scoped-style", "Unscoped", 15, 19),
+                LintDiagnostic::warn("vapor/prefer-static-class", "Static", 20, 24),
+            ],
+            error_count: 0,
+            warning_count: 5,
+        };
+
+        let lsp_diagnostics = LspEmitter::to_lsp_diagnostics(&result);
+        assert_eq!(
+            lsp_diagnostics[0].code_description.as_ref().unwrap().href,
+            "https://eslint.vuejs.org/rules/no-unused-vars.html"
+        );
+        assert_eq!(lsp_diagnostics[0].tags, [LspDiagnostic::TAG_UNNECESSARY]);
+        assert_eq!(lsp_diagnostics[1].tags, [LspDiagnostic::TAG_DEPRECATED]);
+        assert!(lsp_diagnostics[2].code_description.is_none());
+        assert!(lsp_diagnostics[2].tags.is_empty());
+        // vize-only rules have no eslint-plugin-vue page
+        assert!(lsp_diagnostics[3].code_description.is_none());
+        assert!(lsp_diagnostics[4].tags.is_empty());
+
+        let json = serde_json::to_string(&lsp_diagnostics[0]).unwrap();
+        assert!(json.contains(r#""codeDescription":{"href":"#));
+        assert!(json.contains(r#""tags":[1]"#));
+    }
+
+    #[test]
+    fn test_lsp_diagnostic_positions() {
+        let source = "abc\ndéf\nghi";
+        let result = LintResult {
+            filename: "test.vue".to_compact_string(),
+            diagnostics: vec![
+                LintDiagnostic::warn("test", "a", 0, 3), // 'a'..'\n'
+                LintDiagnostic::warn("test", "b", 4, 8), // 'd'..'\n' across 'é'
+                LintDiagnostic::warn("test", "c", 9, 9), // 'g'
+            ],
+            error_count: 0,
+            warning_count: 3,
+        };
+
+        let lsp_diagnostics = LspEmitter::to_lsp_diagnostics_with_source(&result, source);
+        let range = |i: usize| {
+            let range = &lsp_diagnostics[i].range;
+            (
+                (range.start.line, range.start.character),
+                (range.end.line, range.end.character),
+            )
+        };
+        assert_eq!(range(0), ((0, 0), (0, 3)));
+        assert_eq!(range(1), ((1, 0), (1, 3)));
+        assert_eq!(range(2), ((2, 0), (2, 0)));
     }
 }
@@ -4,6 +4,7 @@
 //! node type discriminants, source locations, and constant types.
 
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use vize_carton::String;
 
 /// Node type discriminant
@@ -94,6 +95,26 @@ impl Position {
             column,
         }
     }
+
+    /// Position reached by moving over `text` from this position.
+    ///
+    /// Columns count bytes, like the positions produced by the parser.
+    pub fn advance(self, text: &str) -> Self {
+        let bytes = text.as_bytes();
+        let len = bytes.len() as u32;
+        match bytes.iter().rposition(|&b| b == b'\n') {
+            Some(last_newline) => Self {
+                offset: self.offset + len,
+                line: self.line + bytes.iter().filter(|&&b| b == b'\n').count() as u32,
+                column: (bytes.len() - last_newline) as u32,
+            },
+            None => Self {
+                offset: self.offset + len,
+                line: self.line,
+                column: self.column + len,
+            },
+        }
+    }
 }
 
 /// Source location span [start, end)
@@ -148,6 +169,80 @@ impl SourceLocation {
             source: source.into(),
         }
     }
+
+    /// Smallest location covering both `self` and `other`.
+    ///
+    /// The start and end positions (including line and column) are taken
+    /// from whichever location starts first and ends last. The source text
+    /// is stitched together when the two spans overlap or touch; if there is
+    /// a gap between them, the text in between is unknown and `source` is
+    /// left empty.
+    pub fn merge(&self, other: &SourceLocation) -> SourceLocation {
+        let (first, second) = if other.start.offset < self.start.offset {
+            (other, self)
+        } else {
+            (self, other)
+        };
+
+        if first.contains_range(second) {
+            return first.clone();
+        }
+        if second.contains_range(first) {
+            return second.clone();
+        }
+
+        // `first` starts no later than `second` and neither contains the other,
+        // so `second` ends last.
+        let source = if second.start.offset <= first.end.offset {
+            let overlap = (first.end.offset - second.start.offset) as usize;
+            let mut source = first.source.clone();
+            source.push_str(second.source.get(overlap..).unwrap_or_default());
+            source
+        } else {
+            String::default()
+        };
+
+        SourceLocation {
+            start: first.start,
+            end: second.end,
+            source,
+        }
+    }
+
+    /// Location of a byte sub-range of this location's source text.
+    ///
+    /// `range` is relative to `source`; the returned location has absolute
+    /// offsets and its line/column recomputed across any newlines skipped.
+    /// Returns `None` when the range is out of bounds or not on character
+    /// boundaries.
+    pub fn slice(&self, range: Range<usize>) -> Option<SourceLocation> {
+        let prefix = self.source.get(..range.start)?;
+        let text = self.source.get(range)?;
+        let start = self.start.advance(prefix);
+        Some(SourceLocation {
+            start,
+            end: start.advance(text),
+            source: String::from(text),
+        })
+    }
+
+    /// Location of `needle`'s first occurrence in this location's source text.
+    pub fn slice_str(&self, needle: &str) -> Option<SourceLocation> {
+        let start = self.source.find(needle)?;
+        self.slice(start..start + needle.len())
+    }
+
+    /// Whether the byte `offset` falls within `[start, end)`.
+    #[inline]
+    pub fn contains(&self, offset: u32) -> bool {
+        self.start.offset <= offset && offset < self.end.offset
+    }
+
+    /// Whether `other` lies entirely within this location.
+    #[inline]
+    pub fn contains_range(&self, other: &SourceLocation) -> bool {
+        self.start.offset <= other.start.offset && other.end.offset <= self.end.offset
+    }
 }
 
 /// Runtime helper symbols
@@ -26,6 +26,8 @@ pub struct ElementNode<'a> {
     pub codegen_node: Option<ElementCodegenNode<'a>>,
     /// If props are hoisted, this is the index into the hoists array (1-based for _hoisted_N)
     pub hoisted_props_index: Option<usize>,
+    /// `<!-- @vue-ignore -->` / `<!-- @vue-skip -->` comment directly preceding this element
+    pub type_check_directive: Option<TypeCheckDirective>,
 }
 
 impl<'a> ElementNode<'a> {
@@ -41,6 +43,7 @@ impl<'a> ElementNode<'a> {
             inner_loc: None,
             codegen_node: None,
             hoisted_props_index: None,
+            type_check_directive: None,
         }
     }
 
@@ -49,6 +52,26 @@ impl<'a> ElementNode<'a> {
     }
 }
 
+/// Comment directive controlling type checking of the following element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeCheckDirective {
+    /// `<!-- @vue-ignore -->`: suppress type errors in the element's bindings
+    Ignore,
+    /// `<!-- @vue-skip -->`: skip type checking of the element and its children
+    Skip,
+}
+
+impl TypeCheckDirective {
+    /// Parse a directive from the content of an HTML comment.
+    pub fn from_comment(content: &str) -> Option<Self> {
+        match content.trim() {
+            "@vue-ignore" => Some(Self::Ignore),
+            "@vue-skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
 /// Element codegen node (VNodeCall, SimpleExpression, CacheExpression, etc.)
 #[derive(Debug)]
 pub enum ElementCodegenNode<'a> {
@@ -79,6 +102,9 @@ pub struct AttributeNode {
     pub name: String,
     pub name_loc: SourceLocation,
     pub value: Option<TextNode>,
+    /// Quote character around the value as written (`'"'` or `'\''`).
+    /// `None` for unquoted values and valueless attributes.
+    pub quote: Option<char>,
     pub loc: SourceLocation,
 }
 
@@ -88,6 +114,7 @@ impl AttributeNode {
             name: name.into(),
             name_loc: loc.clone(),
             value: None,
+            quote: None,
             loc,
         }
     }
@@ -385,6 +385,77 @@ fn source_location_new() {
     assert_eq!(loc.source.as_str(), "hello");
 }
 
+#[test]
+fn source_location_merge_across_lines() {
+    // "<div>\n  {{ a }}\n</div>": `<div>` on line 1 and `</div>` on line 3
+    let open = SourceLocation::new(Position::new(0, 1, 1), Position::new(5, 1, 6), "<div>");
+    let close = SourceLocation::new(Position::new(16, 3, 1), Position::new(22, 3, 7), "</div>");
+
+    let merged = close.merge(&open);
+    assert_eq!(merged.start, Position::new(0, 1, 1));
+    assert_eq!(merged.end, Position::new(22, 3, 7));
+    // The gap between the spans is unknown
+    assert_eq!(merged.source.as_str(), "");
+    assert_eq!(merged, open.merge(&close));
+}
+
+#[test]
+fn source_location_merge_stitches_source() {
+    let a = SourceLocation::new(Position::new(0, 1, 1), Position::new(4, 1, 5), "foo.");
+    let b = SourceLocation::new(Position::new(2, 1, 3), Position::new(7, 1, 8), "o.bar");
+    assert_eq!(a.merge(&b).source.as_str(), "foo.bar");
+
+    let adjacent = SourceLocation::new(Position::new(4, 1, 5), Position::new(7, 1, 8), "bar");
+    assert_eq!(a.merge(&adjacent).source.as_str(), "foo.bar");
+
+    let inner = SourceLocation::new(Position::new(1, 1, 2), Position::new(3, 1, 4), "oo");
+    assert_eq!(a.merge(&inner), a);
+}
+
+#[test]
+fn source_location_contains() {
+    let loc = SourceLocation::new(Position::new(3, 1, 4), Position::new(8, 2, 2), "ab\ncd");
+    assert!(!loc.contains(2));
+    assert!(loc.contains(3));
+    assert!(loc.contains(7));
+    assert!(!loc.contains(8));
+
+    let inner = SourceLocation::new(Position::new(4, 1, 5), Position::new(8, 2, 2), "b\ncd");
+    let outside = SourceLocation::new(Position::new(6, 2, 1), Position::new(9, 2, 3), "cde");
+    assert!(loc.contains_range(&inner));
+    assert!(loc.contains_range(&loc));
+    assert!(!loc.contains_range(&outside));
+    assert!(!inner.contains_range(&loc));
+}
+
+#[test]
+fn source_location_slice() {
+    // `{{ foo.bar(\n  baz) }}` starting at line 2, column 5 of the template
+    let loc = SourceLocation::new(
+        Position::new(20, 2, 5),
+        Position::new(38, 3, 10),
+        "foo.bar(\n  baz) }}",
+    );
+
+    let foo = loc.slice(0..3).unwrap();
+    assert_eq!(foo.start, Position::new(20, 2, 5));
+    assert_eq!(foo.end, Position::new(23, 2, 8));
+    assert_eq!(foo.source.as_str(), "foo");
+
+    let baz = loc.slice_str("baz").unwrap();
+    assert_eq!(baz.start, Position::new(31, 3, 3));
+    assert_eq!(baz.end, Position::new(34, 3, 6));
+    assert_eq!(baz.source.as_str(), "baz");
+
+    let call = loc.slice(4..15).unwrap();
+    assert_eq!(call.start, Position::new(24, 2, 9));
+    assert_eq!(call.end, Position::new(35, 3, 7));
+    assert_eq!(call.end, loc.start.advance("foo.bar(\n  baz)"));
+
+    assert!(loc.slice(10..40).is_none());
+    assert!(loc.slice_str("qux").is_none());
+}
+
 #[test]
 fn position_new() {
     let pos = Position::new(42, 3, 10);
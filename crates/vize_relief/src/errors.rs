@@ -71,6 +71,9 @@ pub enum ErrorCode {
     MissingDynamicDirectiveArgumentEnd = 26,
     MissingDirectiveName = 27,
     MissingDirectiveModifier = 28,
+    // Numbered outside the upstream ranges to keep existing codes stable
+    EndTagCaseMismatch = 60,
+    UnexpectedDoctype = 61,
 
     // Transform errors
     VIfNoExpression = 29,
@@ -138,6 +141,8 @@ impl ErrorCode {
 
             Self::InvalidEndTag => "Invalid end tag.",
             Self::MissingEndTag => "Element is missing end tag.",
+            Self::EndTagCaseMismatch => "End tag does not match the case of the start tag.",
+            Self::UnexpectedDoctype => "DOCTYPE is not allowed in templates.",
             Self::MissingInterpolationEnd => "Interpolation end sign was not found.",
             Self::MissingDynamicDirectiveArgumentEnd => {
                 "End bracket for dynamic directive argument was not found."
@@ -186,6 +191,7 @@ impl ErrorCode {
 
     pub fn is_parse_error(&self) -> bool {
         (*self as u16) < (Self::VIfNoExpression as u16)
+            || matches!(self, Self::EndTagCaseMismatch | Self::UnexpectedDoctype)
     }
 
     pub fn is_transform_error(&self) -> bool {
@@ -257,6 +263,8 @@ mod tests {
             ErrorCode::MissingInterpolationEnd,
             ErrorCode::MissingDirectiveName,
             ErrorCode::MissingDirectiveModifier,
+            ErrorCode::EndTagCaseMismatch,
+            ErrorCode::UnexpectedDoctype,
         ];
         for code in &parse_errors {
             assert!(code.is_parse_error(), "{:?} should be parse error", code);
@@ -351,6 +359,8 @@ mod tests {
             ErrorCode::MissingDynamicDirectiveArgumentEnd,
             ErrorCode::MissingDirectiveName,
             ErrorCode::MissingDirectiveModifier,
+            ErrorCode::EndTagCaseMismatch,
+            ErrorCode::UnexpectedDoctype,
             ErrorCode::VIfNoExpression,
             ErrorCode::VIfSameKey,
             ErrorCode::VElseNoAdjacentIf,
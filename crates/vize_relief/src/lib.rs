@@ -15,15 +15,18 @@
 //! - Complete Vue template AST node definitions
 //! - Compiler error types and codes
 //! - Parser, transform, and codegen options
+//! - Cached offset <-> line/column conversion shared by the linter and LSP
 //! - Arena-allocated nodes for zero-copy JavaScript interop
 //! - Serialization support with serde
 
 pub mod ast;
 pub mod errors;
+pub mod line_column;
 pub mod options;
 
 pub use ast::*;
 pub use errors::*;
+pub use line_column::{LineColumn, LineColumnCache};
 pub use options::*;
 
 /// Re-export allocator types for convenience
@@ -0,0 +1,200 @@
+//! Offset <-> line/column conversion.
+//!
+//! [`LineColumnCache`] computes the line starts of a source once and then
+//! answers lookups in either direction with a binary search, so callers that
+//! convert many offsets (diagnostics, semantic tokens, references) do not
+//! rescan the source for each one.
+//!
+//! Lines and columns are 0-indexed, and columns count Unicode scalar values
+//! (`char`s) from the start of the line. A line ends at `\n`; a preceding
+//! `\r` is part of the line.
+
+/// A 0-indexed line and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct LineColumn {
+    /// 0-indexed line number
+    pub line: u32,
+    /// 0-indexed column, in characters from the start of the line
+    pub column: u32,
+}
+
+impl LineColumn {
+    pub const fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+}
+
+/// Line start table for a single source text.
+#[derive(Debug, Clone)]
+pub struct LineColumnCache<'s> {
+    source: &'s str,
+    /// Byte offset of the first character of each line
+    line_starts: Vec<u32>,
+    /// Whether byte columns equal character columns
+    is_ascii: bool,
+}
+
+impl<'s> LineColumnCache<'s> {
+    /// Build the cache for `source`.
+    pub fn new(source: &'s str) -> Self {
+        let mut line_starts = Vec::with_capacity(source.len() / 32 + 1);
+        line_starts.push(0);
+        line_starts.extend(
+            source
+                .bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'\n')
+                .map(|(i, _)| (i + 1) as u32),
+        );
+
+        Self {
+            source,
+            line_starts,
+            is_ascii: source.is_ascii(),
+        }
+    }
+
+    /// The source this cache was built for.
+    #[inline]
+    pub fn source(&self) -> &'s str {
+        self.source
+    }
+
+    /// Number of lines (a trailing newline starts an empty last line).
+    #[inline]
+    pub fn line_count(&self) -> u32 {
+        self.line_starts.len() as u32
+    }
+
+    /// Byte offset where `line` starts.
+    #[inline]
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        self.line_starts.get(line as usize).copied()
+    }
+
+    /// Byte offset where `line` ends, excluding its `\n`.
+    pub fn line_end(&self, line: u32) -> Option<u32> {
+        self.line_start(line)?;
+        Some(
+            self.line_start(line + 1)
+                .map_or(self.source.len() as u32, |next| next - 1),
+        )
+    }
+
+    /// 0-indexed line containing `offset`.
+    ///
+    /// Offsets past the end of the source map to the last line.
+    #[inline]
+    pub fn line(&self, offset: u32) -> u32 {
+        let offset = offset.min(self.source.len() as u32);
+        (self.line_starts.partition_point(|&start| start <= offset) - 1) as u32
+    }
+
+    /// Line and column of `offset`.
+    ///
+    /// Offsets past the end of the source clamp to the end; an offset inside
+    /// a multi-byte character counts that character.
+    pub fn position(&self, offset: u32) -> LineColumn {
+        let offset = offset.min(self.source.len() as u32);
+        let line = self.line(offset);
+        let start = self.line_starts[line as usize];
+
+        let column = if self.is_ascii {
+            offset - start
+        } else {
+            let text = &self.source.as_bytes()[start as usize..offset as usize];
+            // Count every byte that begins a character
+            text.iter().filter(|&&b| (b as i8) >= -0x40).count() as u32
+        };
+
+        LineColumn { line, column }
+    }
+
+    /// Byte offset of `line`/`column`.
+    ///
+    /// Columns past the end of the line clamp to the line end; returns `None`
+    /// when `line` does not exist.
+    pub fn offset(&self, line: u32, column: u32) -> Option<u32> {
+        let start = self.line_start(line)?;
+        let end = self.line_end(line)?;
+
+        if self.is_ascii {
+            return Some(start.saturating_add(column).min(end));
+        }
+
+        let text = &self.source[start as usize..end as usize];
+        Some(
+            text.char_indices()
+                .nth(column as usize)
+                .map_or(end, |(i, _)| start + i as u32),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineColumn, LineColumnCache};
+
+    #[test]
+    fn test_position() {
+        let cache = LineColumnCache::new("ab\ncd\n\nef");
+        assert_eq!(cache.line_count(), 4);
+        assert_eq!(cache.position(0), LineColumn::new(0, 0));
+        assert_eq!(cache.position(2), LineColumn::new(0, 2));
+        assert_eq!(cache.position(3), LineColumn::new(1, 0));
+        assert_eq!(cache.position(6), LineColumn::new(2, 0));
+        assert_eq!(cache.position(8), LineColumn::new(3, 1));
+        // Clamped to the end of the source
+        assert_eq!(cache.position(100), LineColumn::new(3, 2));
+    }
+
+    #[test]
+    fn test_offset() {
+        let cache = LineColumnCache::new("ab\ncd\n\nef");
+        assert_eq!(cache.offset(0, 0), Some(0));
+        assert_eq!(cache.offset(1, 1), Some(4));
+        assert_eq!(cache.offset(2, 0), Some(6));
+        assert_eq!(cache.offset(3, 2), Some(9));
+        // Columns clamp to the line end, on every line including the last
+        assert_eq!(cache.offset(0, 10), Some(2));
+        assert_eq!(cache.offset(3, 10), Some(9));
+        assert_eq!(cache.offset(4, 0), None);
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let cache = LineColumnCache::new("a\n");
+        assert_eq!(cache.line_count(), 2);
+        assert_eq!(cache.position(2), LineColumn::new(1, 0));
+        assert_eq!(cache.offset(1, 0), Some(2));
+    }
+
+    #[test]
+    fn test_multibyte_columns() {
+        let source = "<p>日本</p>\n<b>é</b>";
+        let cache = LineColumnCache::new(source);
+        let offset = source.find("</p>").unwrap() as u32;
+        assert_eq!(cache.position(offset), LineColumn::new(0, 5));
+        assert_eq!(cache.offset(0, 5), Some(offset));
+
+        let offset = source.find("</b>").unwrap() as u32;
+        assert_eq!(cache.position(offset), LineColumn::new(1, 4));
+        assert_eq!(cache.offset(1, 4), Some(offset));
+
+        // Round-trips for every character boundary
+        for (offset, _) in source.char_indices() {
+            let pos = cache.position(offset as u32);
+            assert_eq!(cache.offset(pos.line, pos.column), Some(offset as u32));
+        }
+    }
+
+    #[test]
+    fn test_line() {
+        let cache = LineColumnCache::new("a\nb\nc");
+        assert_eq!(cache.line(0), 0);
+        assert_eq!(cache.line(1), 0);
+        assert_eq!(cache.line(2), 1);
+        assert_eq!(cache.line(4), 2);
+        assert_eq!(cache.line(99), 2);
+    }
+}
@@ -1,6 +1,6 @@
 //! Compiler options.
 
-use vize_carton::{FxHashMap, String};
+use vize_carton::{FxHashMap, String, TagConfig};
 
 /// Parse mode for the tokenizer
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -43,6 +43,8 @@ pub struct ParserOptions {
     pub is_pre_tag: fn(&str) -> bool,
     /// Whether is a native tag
     pub is_native_tag: Option<fn(&str) -> bool>,
+    /// Extra tags treated as native on top of `is_native_tag`
+    pub tag_config: TagConfig,
     /// Whether is a custom element
     pub is_custom_element: Option<fn(&str) -> bool>,
     /// Whether is a void tag
@@ -65,6 +67,7 @@ impl Default for ParserOptions {
             delimiters: (String::from("{{"), String::from("}}")),
             is_pre_tag: |_| false,
             is_native_tag: None,
+            tag_config: TagConfig::default(),
             is_custom_element: None,
             is_void_tag: vize_carton::is_void_tag,
             get_namespace: |_, _| crate::Namespace::Html,
@@ -252,6 +255,10 @@ pub struct CodegenOptions {
     pub binding_metadata: Option<BindingMetadata>,
     /// Whether to cache inline event handlers
     pub cache_handlers: bool,
+    /// Whether to emit block tree optimizations (`openBlock`/`createElementBlock`
+    /// and patch flags). When false, plain `createElementVNode`/`createVNode`
+    /// calls without patch flags are generated.
+    pub blocks: bool,
 }
 
 impl Default for CodegenOptions {
@@ -270,6 +277,7 @@ impl Default for CodegenOptions {
             inline: false,
             binding_metadata: None,
             cache_handlers: false,
+            blocks: true,
         }
     }
 }
@@ -309,6 +317,7 @@ mod tests {
         assert_eq!(opts.delimiters.1.as_str(), "}}");
         assert!(opts.comments);
         assert!(opts.is_native_tag.is_none());
+        assert!(!opts.tag_config.has_additions());
         assert!(opts.is_custom_element.is_none());
         assert!(opts.on_error.is_none());
         assert!(opts.on_warn.is_none());
@@ -341,6 +350,7 @@ mod tests {
         assert!(!opts.inline);
         assert!(opts.scope_id.is_none());
         assert!(opts.binding_metadata.is_none());
+        assert!(opts.blocks);
     }
 
     #[test]
@@ -34,6 +34,7 @@ pub struct ArtMetadataNapi {
     pub component: Option<String>,
     pub category: Option<String>,
     pub tags: Vec<String>,
+    pub decorators: Vec<String>,
     pub status: String,
     pub order: Option<u32>,
 }
@@ -58,6 +59,15 @@ pub struct ArtDescriptorNapi {
     pub style_count: u32,
 }
 
+/// CSF options for NAPI
+#[napi(object)]
+#[derive(Default)]
+pub struct CsfOptionsNapi {
+    pub filename: Option<String>,
+    /// Output language: `"ts"` (default) or `"js"`; other values are rejected
+    pub target: Option<String>,
+}
+
 /// CSF output for NAPI
 #[napi(object)]
 pub struct CsfOutputNapi {
@@ -241,6 +251,12 @@ pub fn parse_art(
             .iter()
             .map(|t| t.to_string())
             .collect(),
+        decorators: descriptor
+            .metadata
+            .decorators
+            .iter()
+            .map(|d| d.to_string())
+            .collect(),
         status: match descriptor.metadata.status {
             ArtStatus::Draft => "draft".to_string(),
             ArtStatus::Ready => "ready".to_string(),
@@ -275,11 +291,23 @@ pub fn parse_art(
 
 /// Transform Art to Storybook CSF 3.0
 #[napi(js_name = "artToCsf")]
-pub fn art_to_csf(source: String, options: Option<ArtParseOptionsNapi>) -> Result<CsfOutputNapi> {
-    use vize_musea::{parse_art as musea_parse, transform_to_csf, ArtParseOptions, Bump};
+pub fn art_to_csf(source: String, options: Option<CsfOptionsNapi>) -> Result<CsfOutputNapi> {
+    use serde::de::{value::Error as ValueError, Deserialize, IntoDeserializer};
+    use vize_musea::{
+        parse_art as musea_parse, transform_to_csf, ArtParseOptions, Bump, CsfTarget,
+    };
 
     let allocator = Bump::new();
     let opts = options.unwrap_or_default();
+    let target = opts
+        .target
+        .as_deref()
+        .map(|target| CsfTarget::deserialize(target.into_deserializer()))
+        .transpose()
+        .map_err(|e: ValueError| {
+            Error::new(Status::InvalidArg, format!("Invalid CSF target: {e}"))
+        })?
+        .unwrap_or_default();
     let parse_opts = ArtParseOptions {
         filename: opts
             .filename
@@ -291,7 +319,7 @@ pub fn art_to_csf(source: String, options: Option<ArtParseOptionsNapi>) -> Resul
         .map_err(|e| Error::new(Status::GenericFailure, e.to_string()))?;
 
     // transform_to_csf returns owned CsfOutput, so this is safe
-    let csf = transform_to_csf(&descriptor);
+    let csf = transform_to_csf(&descriptor, target);
 
     // Create result before descriptor and allocator are dropped
     let result = CsfOutputNapi {
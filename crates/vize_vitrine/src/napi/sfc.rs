@@ -39,6 +39,12 @@ pub struct SfcCompileOptionsNapi {
     pub is_ts: Option<bool>,
     /// Scope ID for scoped CSS (e.g., "data-v-abc123")
     pub scope_id: Option<String>,
+    /// Attach `__file` / `__name` for devtools when true
+    pub dev: Option<bool>,
+    /// Emit HMR boilerplate (`__hmrId` + `import.meta.hot.accept`) when true
+    pub hmr: Option<bool>,
+    /// Only the template changed since the last compile (HMR rerender instead of reload)
+    pub hmr_rerender_only: Option<bool>,
 }
 
 /// SFC compile result for NAPI
@@ -288,6 +294,7 @@ pub fn compile_sfc(
         script: ScriptCompileOptions {
             id: Some(filename.clone()),
             is_ts,
+            dev: opts.dev.unwrap_or(false),
             ..Default::default()
         },
         template: TemplateCompileOptions {
@@ -305,6 +312,8 @@ pub fn compile_sfc(
         },
         vapor,
         scope_id: external_scope_id,
+        hmr: opts.hmr.unwrap_or(false),
+        hmr_rerender_only: opts.hmr_rerender_only.unwrap_or(false),
     };
 
     match sfc_compile(&descriptor, compile_opts) {
@@ -442,6 +451,8 @@ pub fn compile_sfc_batch(
             },
             vapor,
             scope_id: None,
+            hmr: false,
+            hmr_rerender_only: false,
         };
 
         match sfc_compile(&descriptor, compile_opts) {
@@ -591,6 +602,8 @@ pub fn compile_sfc_batch_with_results(
             },
             vapor,
             scope_id: Some(scope_id.clone()),
+            hmr: false,
+            hmr_rerender_only: false,
         };
 
         match sfc_compile(&descriptor, compile_opts) {
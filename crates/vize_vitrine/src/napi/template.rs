@@ -55,7 +55,7 @@ pub fn compile(template: String, options: Option<CompilerOptions>) -> Result<Com
     transform(&allocator, &mut root, transform_opts, None);
 
     // Codegen
-    let codegen_opts = CodegenOptions {
+    let mut codegen_opts = CodegenOptions {
         mode: if is_module_mode {
             CodegenMode::Module
         } else {
@@ -65,10 +65,20 @@ pub fn compile(template: String, options: Option<CompilerOptions>) -> Result<Com
         ssr: opts.ssr.unwrap_or(false),
         ..Default::default()
     };
+    if let Some(name) = opts.runtime_module_name.as_deref() {
+        codegen_opts.runtime_module_name = name.into();
+    }
+    if let Some(name) = opts.runtime_global_name.as_deref() {
+        codegen_opts.runtime_global_name = name.into();
+    }
     let result = generate(&root, codegen_opts);
 
-    // Collect helpers
-    let helpers: Vec<String> = root.helpers.iter().map(|h| h.name().to_string()).collect();
+    // Collect helpers actually referenced by the generated code
+    let helpers: Vec<String> = result
+        .used_helpers
+        .iter()
+        .map(|h| h.name().to_string())
+        .collect();
 
     // Build AST JSON
     let ast = build_ast_json(&root);
@@ -49,6 +49,12 @@ pub struct CompilerOptions {
     /// Defaults to "downcompile"
     #[serde(default)]
     pub script_ext: Option<String>,
+    /// Module runtime helpers are imported from (defaults to "vue")
+    #[serde(default)]
+    pub runtime_module_name: Option<String>,
+    /// Global runtime helpers are destructured from in function mode (defaults to "Vue")
+    #[serde(default)]
+    pub runtime_global_name: Option<String>,
 }
 
 /// Compile result
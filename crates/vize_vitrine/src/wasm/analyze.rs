@@ -50,9 +50,11 @@ pub fn analyze_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsVal
 
     // Analyze script if present, track script offset for coordinate adjustment
     let script_offset: u32 = if let Some(ref script_setup) = descriptor.script_setup {
+        analyzer.set_script_lang(script_setup.lang.as_deref());
         analyzer.analyze_script_setup(&script_setup.content);
         script_setup.loc.start as u32
     } else if let Some(ref script) = descriptor.script {
+        analyzer.set_script_lang(script.lang.as_deref());
         analyzer.analyze_script_plain(&script.content);
         script.loc.start as u32
     } else {
@@ -182,6 +184,45 @@ pub fn analyze_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsVal
         })
         .collect();
 
+    // Convert reactive sources to JSON
+    let reactivity: Vec<serde_json::Value> = summary
+        .reactivity
+        .sources()
+        .iter()
+        .map(|r| {
+            let offset = utf8_byte_to_utf16_offset(
+                source,
+                script_offset.saturating_add(r.declaration_offset),
+            );
+            serde_json::json!({
+                "name": r.name.as_str(),
+                "kind": format!("{:?}", r.kind),
+                "needsValue": r.kind.needs_value_access(),
+                "offset": offset,
+            })
+        })
+        .collect();
+
+    // Components used in template (sorted for deterministic output)
+    let mut used_components: Vec<&str> =
+        summary.used_components.iter().map(|c| c.as_str()).collect();
+    used_components.sort_unstable();
+
+    // Convert undefined template references to JSON
+    let undefined_refs: Vec<serde_json::Value> = summary
+        .undefined_refs
+        .iter()
+        .map(|r| {
+            let offset =
+                utf8_byte_to_utf16_offset(source, template_offset.saturating_add(r.offset));
+            serde_json::json!({
+                "name": r.name.as_str(),
+                "context": r.context.as_str(),
+                "offset": offset,
+            })
+        })
+        .collect();
+
     // Generate VIR (Vize Intermediate Representation) text
     let vir = summary.to_vir();
 
@@ -276,6 +317,9 @@ pub fn analyze_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsVal
             "emits": emits,
             "provides": provides,
             "injects": injects,
+            "reactivity": reactivity,
+            "usedComponents": used_components,
+            "undefinedRefs": undefined_refs,
             "typeExports": summary.type_exports.iter().map(|te| {
                 let (start, end) = to_sfc_utf16_range(source, script_offset, te.start, te.end);
                 serde_json::json!({
@@ -72,9 +72,11 @@ pub fn analyze_cross_file_wasm(files: JsValue, options: JsValue) -> Result<JsVal
                 // Extract and analyze script content
                 let (script_content, script_start): (&str, usize) =
                     if let Some(ref script_setup) = descriptor.script_setup {
+                        single_analyzer.set_script_lang(script_setup.lang.as_deref());
                         single_analyzer.analyze_script_setup(&script_setup.content);
                         (&script_setup.content, script_setup.loc.start)
                     } else if let Some(ref script) = descriptor.script {
+                        single_analyzer.set_script_lang(script.lang.as_deref());
                         single_analyzer.analyze_script_plain(&script.content);
                         (&script.content, script.loc.start)
                     } else {
@@ -86,8 +88,10 @@ pub fn analyze_cross_file_wasm(files: JsValue, options: JsValue) -> Result<JsVal
                 let plain_script_violations = if descriptor.script_setup.is_some() {
                     if let Some(ref script) = descriptor.script {
                         // Parse the plain script to detect setup context violations
-                        let plain_result =
-                            vize_croquis::script_parser::parse_script(&script.content);
+                        let plain_result = vize_croquis::script_parser::parse_script_with_lang(
+                            &script.content,
+                            script.lang.as_deref(),
+                        );
                         // Extract violations with adjusted offsets
                         plain_result
                             .setup_context
@@ -327,6 +331,99 @@ pub fn analyze_cross_file_wasm(files: JsValue, options: JsValue) -> Result<JsVal
     to_js_value(&output)
 }
 
+/// Resolve the `defineProps` shape of each component imported by an entry SFC.
+///
+/// `deps` maps import specifiers to SFC sources (e.g. `{ "./Child.vue": "..." }`).
+/// Returns an object keyed by the local component name in the entry file.
+#[wasm_bindgen(js_name = "resolveComponentProps")]
+pub fn resolve_component_props_wasm(entry_source: &str, deps: JsValue) -> Result<JsValue, JsValue> {
+    use vize_croquis::{ScopeData, ScopeKind};
+
+    let entry = analyze_sfc_script(entry_source, "entry.vue")?;
+
+    // Collect dependency sources keyed by import specifier
+    let mut dep_sources: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    if deps.is_object() {
+        let entries = js_sys::Object::entries(deps.unchecked_ref());
+        for kv in entries.iter() {
+            let pair = js_sys::Array::from(&kv);
+            if let (Some(specifier), Some(source)) =
+                (pair.get(0).as_string(), pair.get(1).as_string())
+            {
+                dep_sources.insert(specifier, source);
+            }
+        }
+    }
+
+    let mut components = serde_json::Map::new();
+    for scope in entry.scopes.iter() {
+        if scope.kind != ScopeKind::ExternalModule {
+            continue;
+        }
+        let ScopeData::ExternalModule(data) = scope.data() else {
+            continue;
+        };
+        if data.is_type_only {
+            continue;
+        }
+        let Some(dep_source) = dep_sources.get(data.source.as_str()) else {
+            continue;
+        };
+
+        let dep = analyze_sfc_script(dep_source, data.source.as_str())?;
+        let props: Vec<serde_json::Value> = dep
+            .macros
+            .props()
+            .iter()
+            .map(|p| {
+                serde_json::json!({
+                    "name": p.name.as_str(),
+                    "type": p.prop_type.as_ref().map(|t| t.as_str()),
+                    "required": p.required,
+                    "defaultValue": p.default_value.as_ref().map(|d| d.as_str()),
+                })
+            })
+            .collect();
+
+        // An import may bind several names (e.g. default + named); all map to the same props
+        for (local_name, _) in scope.bindings() {
+            components.insert(
+                local_name.to_string(),
+                serde_json::json!({
+                    "source": data.source.as_str(),
+                    "props": props,
+                }),
+            );
+        }
+    }
+
+    to_js_value(&serde_json::Value::Object(components))
+}
+
+/// Parse an SFC and run croquis over its script block.
+fn analyze_sfc_script(source: &str, filename: &str) -> Result<vize_croquis::Croquis, JsValue> {
+    use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+    use vize_croquis::{Analyzer, AnalyzerOptions};
+
+    let parse_opts = SfcParseOptions {
+        filename: filename.into(),
+        ..Default::default()
+    };
+    let descriptor = parse_sfc(source, parse_opts).map_err(|e| JsValue::from_str(&e.message))?;
+
+    let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+    if let Some(ref script_setup) = descriptor.script_setup {
+        analyzer.set_script_lang(script_setup.lang.as_deref());
+        analyzer.analyze_script_setup(&script_setup.content);
+    } else if let Some(ref script) = descriptor.script {
+        analyzer.set_script_lang(script.lang.as_deref());
+        analyzer.analyze_script_plain(&script.content);
+    }
+
+    Ok(analyzer.finish())
+}
+
 /// Parse CrossFileOptions from JsValue
 fn parse_cross_file_options(options: &JsValue) -> vize_croquis::cross_file::CrossFileOptions {
     use vize_croquis::cross_file::CrossFileOptions;
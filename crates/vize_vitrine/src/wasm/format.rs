@@ -8,7 +8,7 @@ use wasm_bindgen::prelude::*;
 pub fn format_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
     use vize_glyph::format_sfc;
 
-    let opts = parse_format_options(options);
+    let opts = parse_format_options(options)?;
     match format_sfc(source, &opts) {
         Ok(result) => {
             let output = serde_json::json!({
@@ -26,7 +26,7 @@ pub fn format_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValu
 pub fn format_template_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
     use vize_glyph::format_template;
 
-    let opts = parse_format_options(options);
+    let opts = parse_format_options(options)?;
     match format_template(source, &opts) {
         Ok(result) => {
             let output = serde_json::json!({
@@ -44,7 +44,7 @@ pub fn format_template_wasm(source: &str, options: JsValue) -> Result<JsValue, J
 pub fn format_script_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
     use vize_glyph::format_script;
 
-    let opts = parse_format_options(options);
+    let opts = parse_format_options(options)?;
     match format_script(source, &opts) {
         Ok(result) => {
             let output = serde_json::json!({
@@ -57,7 +57,53 @@ pub fn format_script_wasm(source: &str, options: JsValue) -> Result<JsValue, JsV
     }
 }
 
-/// Parse format options from JsValue
-pub(crate) fn parse_format_options(options: JsValue) -> vize_glyph::FormatOptions {
-    serde_wasm_bindgen::from_value(options).unwrap_or_default()
+/// Parse format options from JsValue.
+///
+/// `undefined`/`null` yield the defaults. Besides the `FormatOptions` fields,
+/// the shorthands `indent` (for `tabWidth`) and `quotes: "single" | "double"`
+/// (for `singleQuote`) are accepted. Invalid values are reported instead of
+/// silently falling back to the defaults.
+pub(crate) fn parse_format_options(options: JsValue) -> Result<vize_glyph::FormatOptions, JsValue> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(vize_glyph::FormatOptions::default());
+    }
+    if !options.is_object() {
+        return Err(JsValue::from_str(
+            "Invalid format options: expected an object",
+        ));
+    }
+
+    let get = |key: &str| {
+        js_sys::Reflect::get(&options, &JsValue::from_str(key))
+            .ok()
+            .filter(|value| !value.is_undefined() && !value.is_null())
+    };
+    let indent = get("indent");
+    let quotes = get("quotes");
+
+    let mut opts: vize_glyph::FormatOptions = serde_wasm_bindgen::from_value(options)
+        .map_err(|e| JsValue::from_str(&format!("Invalid format options: {e}")))?;
+
+    if let Some(indent) = indent {
+        opts.tab_width = indent
+            .as_f64()
+            .filter(|n| n.fract() == 0.0 && (0.0..=u8::MAX as f64).contains(n))
+            .map(|n| n as u8)
+            .ok_or_else(|| {
+                JsValue::from_str("Invalid format options: `indent` must be a small integer")
+            })?;
+    }
+    if let Some(quotes) = quotes {
+        opts.single_quote = match quotes.as_string().as_deref() {
+            Some("single") => true,
+            Some("double") => false,
+            _ => {
+                return Err(JsValue::from_str(
+                    "Invalid format options: `quotes` must be \"single\" or \"double\"",
+                ))
+            }
+        };
+    }
+
+    Ok(opts)
 }
@@ -166,6 +166,7 @@ pub fn lint_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue>
         Locale::En => CartonLocale::En,
         Locale::Ja => CartonLocale::Ja,
         Locale::Zh => CartonLocale::Zh,
+        Locale::Fr => CartonLocale::Fr,
     };
 
     let linter = create_linter(locale, &options);
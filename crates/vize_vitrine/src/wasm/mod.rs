@@ -35,6 +35,7 @@ use wasm_bindgen::prelude::*;
 use crate::{CompileResult, CompilerOptions};
 use vize_atelier_core::options::CodegenMode;
 use vize_atelier_core::parser::parse;
+use vize_atelier_core::CompilerError;
 use vize_atelier_dom::{compile_template_with_options, DomCompilerOptions};
 use vize_atelier_sfc::{
     compile_sfc as sfc_compile, parse_sfc, CssCompileOptions, CssTargets, ScriptCompileOptions,
@@ -97,6 +98,8 @@ fn parse_compiler_options(options: &JsValue) -> ParsedCompilerOptions {
             output_mode: get_string("outputMode"),
             is_ts: get_bool("isTs"),
             script_ext: get_string("scriptExt"),
+            runtime_module_name: get_string("runtimeModuleName"),
+            runtime_global_name: get_string("runtimeGlobalName"),
         },
         binding_metadata,
     }
@@ -113,9 +116,97 @@ pub(crate) fn utf8_byte_to_utf16_offset(content: &str, byte_offset: u32) -> u32
     content[..byte_offset].encode_utf16().count() as u32
 }
 
+/// Compile error exposed to JS as `{ message, start, end, code, sfcCode }`.
+///
+/// `start`/`end` are UTF-16 offsets into the compiled source, so editors can
+/// underline the exact range. `code` is a template compiler error code and
+/// `sfcCode` an SFC compiler one such as `TEMPLATE_ERROR`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct CompileErrorWasm {
+    message: String,
+    start: Option<u32>,
+    end: Option<u32>,
+    code: Option<u16>,
+    #[serde(rename = "sfcCode")]
+    sfc_code: Option<String>,
+}
+
+impl CompileErrorWasm {
+    fn from_compiler_error(error: &CompilerError, source: &str) -> Self {
+        let loc = error.loc.as_ref();
+        Self {
+            message: error.message.to_string(),
+            start: loc.map(|loc| utf8_byte_to_utf16_offset(source, loc.start.offset)),
+            end: loc.map(|loc| utf8_byte_to_utf16_offset(source, loc.end.offset)),
+            code: Some(error.code as u16),
+            sfc_code: None,
+        }
+    }
+
+    fn from_sfc_error(error: &vize_atelier_sfc::SfcError, source: &str) -> Self {
+        let loc = error.loc.as_ref();
+        Self {
+            message: error.message.to_string(),
+            start: loc.map(|loc| utf8_byte_to_utf16_offset(source, loc.start as u32)),
+            end: loc.map(|loc| utf8_byte_to_utf16_offset(source, loc.end as u32)),
+            code: None,
+            sfc_code: error.code.as_ref().map(|code| code.to_string()),
+        }
+    }
+
+    fn from_message(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            start: None,
+            end: None,
+            code: None,
+            sfc_code: None,
+        }
+    }
+
+    /// Shift the range by a UTF-16 offset (e.g. the start of a template block).
+    fn shifted(mut self, offset: u32) -> Self {
+        self.start = self.start.map(|start| start + offset);
+        self.end = self.end.map(|end| end + offset);
+        self
+    }
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const COMPILE_ERROR_TS: &str = r#"
+/**
+ * Compile error. `compile`, `compileVapor` and `compileSfc` throw a
+ * `CompileError[]` when compilation fails.
+ */
+export interface CompileError {
+  /** Error message */
+  message: string;
+  /** Start offset (UTF-16) into the compiled source */
+  start?: number;
+  /** End offset (UTF-16) into the compiled source */
+  end?: number;
+  /** Template compiler error code */
+  code?: number;
+  /** SFC compiler error code (e.g., "TEMPLATE_ERROR") */
+  sfcCode?: string;
+}
+"#;
+
+fn sfc_error_to_js(error: &vize_atelier_sfc::SfcError, source: &str) -> Result<JsValue, JsValue> {
+    to_js_value(&[CompileErrorWasm::from_sfc_error(error, source)])
+}
+
+fn compiler_errors_to_wasm(errors: &[CompilerError], source: &str) -> Vec<CompileErrorWasm> {
+    errors
+        .iter()
+        .map(|error| CompileErrorWasm::from_compiler_error(error, source))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::utf8_byte_to_utf16_offset;
+    use super::{compiler_errors_to_wasm, utf8_byte_to_utf16_offset, CompileErrorWasm};
+    use vize_atelier_core::{CompilerError, ErrorCode, Position, SourceLocation};
 
     #[test]
     fn test_utf8_byte_to_utf16_offset_handles_multibyte_and_astral_chars() {
@@ -131,6 +222,55 @@ mod tests {
         assert_eq!(utf8_byte_to_utf16_offset(source, latin_b_start), 4);
         assert_eq!(utf8_byte_to_utf16_offset(source, source.len() as u32), 5);
     }
+
+    #[test]
+    fn test_compile_errors_use_utf16_ranges() {
+        let template = "<p>あ</p><div>";
+        let start = template.find("<div>").unwrap() as u32;
+        let position = |offset: u32| Position {
+            offset,
+            line: 1,
+            column: offset + 1,
+        };
+        let error = CompilerError::new(
+            ErrorCode::MissingEndTag,
+            Some(SourceLocation {
+                start: position(start),
+                end: position(start + 5),
+                source: "<div>".into(),
+            }),
+        );
+
+        let errors = compiler_errors_to_wasm(&[error], template);
+        assert_eq!(
+            errors,
+            vec![CompileErrorWasm {
+                message: ErrorCode::MissingEndTag.message().to_string(),
+                start: Some(8),
+                end: Some(13),
+                code: Some(ErrorCode::MissingEndTag as u16),
+                sfc_code: None,
+            }]
+        );
+
+        // Template errors are reported relative to the SFC source
+        let shifted = errors[0].clone().shifted(10);
+        assert_eq!((shifted.start, shifted.end), (Some(18), Some(23)));
+    }
+
+    #[test]
+    fn test_sfc_errors_keep_their_code() {
+        let source = "<template><div></template>";
+        let error = vize_atelier_sfc::SfcError {
+            message: "Template compilation errors".into(),
+            code: Some("TEMPLATE_ERROR".into()),
+            loc: None,
+        };
+
+        let wasm_error = CompileErrorWasm::from_sfc_error(&error, source);
+        assert_eq!(wasm_error.code, None);
+        assert_eq!(wasm_error.sfc_code.as_deref(), Some("TEMPLATE_ERROR"));
+    }
 }
 
 /// Parse CSS options from JsValue
@@ -320,7 +460,7 @@ pub struct SfcBlockLocationWasm {
 }
 
 fn attrs_to_wasm(
-    attrs: &vize_carton::FxHashMap<std::borrow::Cow<'_, str>, std::borrow::Cow<'_, str>>,
+    attrs: &vize_carton::OrderedMap<std::borrow::Cow<'_, str>, std::borrow::Cow<'_, str>>,
 ) -> BTreeMap<String, String> {
     attrs
         .iter()
@@ -417,24 +557,28 @@ impl Compiler {
     }
 
     /// Compile template to VDom render function
+    ///
+    /// Throws a `CompileError[]` when compilation fails.
     #[wasm_bindgen]
     pub fn compile(&self, template: &str, options: JsValue) -> Result<JsValue, JsValue> {
         let parsed = parse_compiler_options(&options);
 
         match compile_internal(template, &parsed.options, false, parsed.binding_metadata) {
             Ok(result) => to_json_js_value(&result),
-            Err(e) => Err(JsValue::from_str(&e)),
+            Err(errors) => Err(to_js_value(&errors)?),
         }
     }
 
     /// Compile template to Vapor mode
+    ///
+    /// Throws a `CompileError[]` when compilation fails.
     #[wasm_bindgen(js_name = "compileVapor")]
     pub fn compile_vapor(&self, template: &str, options: JsValue) -> Result<JsValue, JsValue> {
         let parsed = parse_compiler_options(&options);
 
         match compile_internal(template, &parsed.options, true, None) {
             Ok(result) => to_json_js_value(&result),
-            Err(e) => Err(JsValue::from_str(&e)),
+            Err(errors) => Err(to_js_value(&errors)?),
         }
     }
 
@@ -446,7 +590,7 @@ impl Compiler {
         let (root, errors) = parse(&allocator, template);
 
         if !errors.is_empty() {
-            return Err(JsValue::from_str(&format!("Parse errors: {:?}", errors)));
+            return Err(to_js_value(&compiler_errors_to_wasm(&errors, template))?);
         }
 
         let ast = build_ast_json(&root);
@@ -484,6 +628,8 @@ impl Compiler {
     }
 
     /// Compile SFC template block
+    ///
+    /// Throws a `CompileError[]` when compilation fails.
     #[wasm_bindgen(js_name = "compileSfc")]
     pub fn compile_sfc(&self, source: &str, options: JsValue) -> Result<JsValue, JsValue> {
         let parsed = parse_compiler_options(&options);
@@ -503,7 +649,7 @@ impl Compiler {
         // Parse SFC
         let descriptor = match parse_sfc(source, parse_opts) {
             Ok(d) => d,
-            Err(e) => return Err(JsValue::from_str(&e.message)),
+            Err(e) => return Err(sfc_error_to_js(&e, source)?),
         };
 
         // Detect vapor mode from script setup attrs or options
@@ -520,18 +666,7 @@ impl Compiler {
         let use_vapor = has_vapor_attr || opts.output_mode.as_deref() == Some("vapor");
 
         // Detect TypeScript from script lang attribute (for source detection)
-        let source_is_ts = descriptor
-            .script_setup
-            .as_ref()
-            .and_then(|s| s.lang.as_ref())
-            .map(|l| l == "ts" || l == "tsx")
-            .unwrap_or(false)
-            || descriptor
-                .script
-                .as_ref()
-                .and_then(|s| s.lang.as_ref())
-                .map(|l| l == "ts" || l == "tsx")
-                .unwrap_or(false);
+        let source_is_ts = descriptor.is_typescript;
 
         // Determine output format: preserve TypeScript or downcompile to JavaScript
         // script_ext option: "preserve" keeps TypeScript, "downcompile" (default) transpiles to JS
@@ -551,7 +686,12 @@ impl Compiler {
         let template_result = if let Some(template) = &descriptor.template {
             match compile_internal(&template.content, &opts, use_vapor, None) {
                 Ok(r) => Some(r),
-                Err(e) => return Err(JsValue::from_str(&e)),
+                Err(errors) => {
+                    // Report ranges in the SFC rather than the template content
+                    let offset = utf8_byte_to_utf16_offset(source, template.loc.start as u32);
+                    let errors: Vec<_> = errors.into_iter().map(|e| e.shifted(offset)).collect();
+                    return Err(to_js_value(&errors)?);
+                }
             }
         } else {
             None
@@ -583,12 +723,14 @@ impl Compiler {
             },
             vapor: use_vapor,
             scope_id: None,
+            hmr: false,
+            hmr_rerender_only: false,
         };
 
         // Compile the full SFC
         let sfc_result = match sfc_compile(&descriptor, sfc_opts) {
             Ok(r) => r,
-            Err(e) => return Err(JsValue::from_str(&e.message)),
+            Err(e) => return Err(sfc_error_to_js(&e, source)?),
         };
 
         // Build result with compiled script code
@@ -637,7 +779,7 @@ fn compile_internal(
     opts: &CompilerOptions,
     vapor: bool,
     binding_metadata: Option<vize_atelier_core::options::BindingMetadata>,
-) -> Result<CompileResult, String> {
+) -> Result<CompileResult, Vec<CompileErrorWasm>> {
     let allocator = Bump::new();
 
     // SSR mode - use dedicated SSR compiler
@@ -645,11 +787,11 @@ fn compile_internal(
         let (root, errors, result) = ssr_compile(&allocator, template);
 
         if !errors.is_empty() {
-            return Err(format!("SSR compile errors: {:?}", errors));
+            return Err(compiler_errors_to_wasm(&errors, template));
         }
 
         // Collect helpers
-        let helpers: Vec<String> = root.helpers.iter().map(|h| h.name().to_string()).collect();
+        let helpers: Vec<String> = result.helpers.iter().map(|h| h.to_string()).collect();
 
         // Build AST JSON
         let ast = build_ast_json(&root);
@@ -677,9 +819,8 @@ fn compile_internal(
             return Err(result
                 .error_messages
                 .iter()
-                .map(|s| s.as_str())
-                .collect::<Vec<_>>()
-                .join("\n"));
+                .map(|message| CompileErrorWasm::from_message(message))
+                .collect());
         }
 
         return Ok(CompileResult {
@@ -714,17 +855,23 @@ fn compile_internal(
         is_ts: opts.is_ts.unwrap_or(false),
         binding_metadata,
         inline: has_binding_metadata,
+        runtime_module_name: opts.runtime_module_name.clone().map(|s| s.into()),
+        runtime_global_name: opts.runtime_global_name.clone().map(|s| s.into()),
         ..Default::default()
     };
 
     let (root, errors, result) = compile_template_with_options(&allocator, template, dom_opts);
 
     if !errors.is_empty() {
-        return Err(format!("Compile errors: {:?}", errors));
+        return Err(compiler_errors_to_wasm(&errors, template));
     }
 
-    // Collect helpers
-    let helpers: Vec<String> = root.helpers.iter().map(|h| h.name().to_string()).collect();
+    // Collect helpers actually referenced by the generated code
+    let helpers: Vec<String> = result
+        .used_helpers
+        .iter()
+        .map(|h| h.name().to_string())
+        .collect();
 
     // Build AST JSON
     let ast = build_ast_json(&root);
@@ -820,12 +967,16 @@ fn build_ast_json(root: &vize_atelier_core::RootNode<'_>) -> serde_json::Value {
 }
 
 /// Compile template to VDom (free function)
+///
+/// Throws a `CompileError[]` when compilation fails.
 #[wasm_bindgen]
 pub fn compile(template: &str, options: JsValue) -> Result<JsValue, JsValue> {
     Compiler::new().compile(template, options)
 }
 
 /// Compile template to Vapor mode (free function)
+///
+/// Throws a `CompileError[]` when compilation fails.
 #[wasm_bindgen(js_name = "compileVapor")]
 pub fn compile_vapor_fn(template: &str, options: JsValue) -> Result<JsValue, JsValue> {
     Compiler::new().compile_vapor(template, options)
@@ -844,6 +995,8 @@ pub fn parse_sfc_fn(source: &str, options: JsValue) -> Result<JsValue, JsValue>
 }
 
 /// Compile SFC (free function)
+///
+/// Throws a `CompileError[]` when compilation fails.
 #[wasm_bindgen(js_name = "compileSfc")]
 pub fn compile_sfc_fn(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
     Compiler::new().compile_sfc(source, options)
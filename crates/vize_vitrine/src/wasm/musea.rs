@@ -38,6 +38,7 @@ pub fn parse_art_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue
             "component": descriptor.metadata.component,
             "category": descriptor.metadata.category,
             "tags": descriptor.metadata.tags.iter().copied().collect::<Vec<_>>(),
+            "decorators": descriptor.metadata.decorators.iter().copied().collect::<Vec<_>>(),
             "status": match descriptor.metadata.status {
                 ArtStatus::Draft => "draft",
                 ArtStatus::Ready => "ready",
@@ -51,6 +52,8 @@ pub fn parse_art_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue
             "isDefault": v.is_default,
             "skipVrt": v.skip_vrt,
             "args": v.args,
+            "tags": v.tags,
+            "parameters": v.parameters,
         })).collect::<Vec<_>>(),
         "hasScriptSetup": descriptor.script_setup.is_some(),
         "hasScript": descriptor.script.is_some(),
@@ -64,7 +67,7 @@ pub fn parse_art_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue
 /// Transform Art to Storybook CSF 3.0
 #[wasm_bindgen(js_name = "artToCsf")]
 pub fn art_to_csf_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
-    use vize_musea::{parse_art, transform_to_csf, ArtParseOptions, Bump};
+    use vize_musea::{parse_art, transform_to_csf, ArtParseOptions, Bump, CsfTarget};
 
     let allocator = Bump::new();
     let filename: String = js_sys::Reflect::get(&options, &JsValue::from_str("filename"))
@@ -76,11 +79,19 @@ pub fn art_to_csf_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValu
         filename: filename.into(),
     };
 
+    let target = match js_sys::Reflect::get(&options, &JsValue::from_str("target")) {
+        Ok(value) if !value.is_undefined() && !value.is_null() => {
+            serde_wasm_bindgen::from_value(value)
+                .map_err(|e| JsValue::from_str(&format!("Invalid CSF target: {e}")))?
+        }
+        _ => CsfTarget::default(),
+    };
+
     let descriptor =
         parse_art(&allocator, source, parse_opts).map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     // transform_to_csf returns owned CsfOutput
-    let csf = transform_to_csf(&descriptor);
+    let csf = transform_to_csf(&descriptor, target);
 
     // Build result before allocator is dropped
     let result = serde_json::json!({
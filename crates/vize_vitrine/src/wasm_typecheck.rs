@@ -60,12 +60,24 @@ pub fn type_check_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValu
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
 
+    let template_severity = js_sys::Reflect::get(&options, &JsValue::from_str("templateSeverity"))
+        .ok()
+        .and_then(|v| v.as_string())
+        .and_then(|v| match v.as_str() {
+            "error" => Some(crate::typecheck::TypeSeverity::Error),
+            "warning" => Some(crate::typecheck::TypeSeverity::Warning),
+            "info" => Some(crate::typecheck::TypeSeverity::Info),
+            "hint" => Some(crate::typecheck::TypeSeverity::Hint),
+            _ => None,
+        });
+
     let mut opts = TypeCheckOptions::new(filename);
     opts.strict = strict;
     opts.include_virtual_ts = include_virtual_ts;
     opts.check_props = check_props;
     opts.check_emits = check_emits;
     opts.check_template_bindings = check_template_bindings;
+    opts.template_severity_override = template_severity;
 
     let result = type_check_sfc(source, &opts);
 
@@ -2,13 +2,15 @@
  * Text Component - Text display
  */
 
-import { defineComponent, h } from "@vue/runtime-core";
+import { defineComponent, h, type PropType } from "@vue/runtime-core";
 
 export interface TextProps {
   /** Text content (alternative to slot) */
   content?: string;
   /** Enable text wrapping */
   wrap?: boolean;
+  /** Overflow behavior when not wrapping */
+  overflow?: "visible" | "ellipsis" | "clip";
   /** Foreground color */
   fg?: string;
   /** Background color */
@@ -30,6 +32,7 @@ export const Text = defineComponent({
   props: {
     content: String,
     wrap: Boolean,
+    overflow: String as PropType<TextProps["overflow"]>,
     fg: String,
     bg: String,
     bold: Boolean,
@@ -57,6 +60,7 @@ export const Text = defineComponent({
       return h("text", {
         text,
         wrap: props.wrap,
+        overflow: props.overflow,
         fg: props.fg,
         bg: props.bg,
         bold: props.bold,
@@ -139,10 +139,13 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
   nodeType: string;
   text?: string;
   wrap?: boolean;
+  overflow?: string;
   value?: string;
   placeholder?: string;
   focused?: boolean;
   mask?: boolean;
+  autofocus?: boolean;
+  tabIndex?: number;
   style?: Record<string, unknown>;
   appearance?: Record<string, unknown>;
   border?: string;
@@ -153,10 +156,13 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
     nodeType: string;
     text?: string;
     wrap?: boolean;
+    overflow?: string;
     value?: string;
     placeholder?: string;
     focused?: boolean;
     mask?: boolean;
+    autofocus?: boolean;
+    tabIndex?: number;
     style?: Record<string, unknown>;
     appearance?: Record<string, unknown>;
     border?: string;
@@ -176,6 +182,9 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
     if (node.props.wrap !== undefined) {
       renderNode.wrap = Boolean(node.props.wrap);
     }
+    if (typeof node.props.overflow === "string") {
+      renderNode.overflow = node.props.overflow;
+    }
     if (node.props.value !== undefined) {
       const v = node.props.value;
       renderNode.value = typeof v === "string" || typeof v === "number" ? String(v) : "";
@@ -193,6 +202,12 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
     if (node.props.mask !== undefined) {
       renderNode.mask = Boolean(node.props.mask);
     }
+    if (node.props.autofocus !== undefined) {
+      renderNode.autofocus = Boolean(node.props.autofocus);
+    }
+    if (node.props.tabIndex !== undefined) {
+      renderNode.tabIndex = Number(node.props.tabIndex);
+    }
     if (node.props.border !== undefined) {
       const b = node.props.border;
       renderNode.border = typeof b === "string" ? b : "";
@@ -247,6 +262,9 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
       if (s.marginBottom !== undefined) style.marginBottom = s.marginBottom;
       if (s.marginLeft !== undefined) style.marginLeft = s.marginLeft;
       if (s.gap !== undefined) style.gap = s.gap;
+      if (s.rowGap !== undefined) style.rowGap = s.rowGap;
+      if (s.columnGap !== undefined) style.columnGap = s.columnGap;
+      if (s.aspectRatio !== undefined) style.aspectRatio = s.aspectRatio;
 
       renderNode.style = style as any;
     }
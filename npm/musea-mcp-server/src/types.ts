@@ -25,7 +25,7 @@ export interface NativeBinding {
   };
   artToCsf: (
     source: string,
-    options?: { filename?: string },
+    options?: { filename?: string; target?: "ts" | "js" },
   ) => {
     code: string;
     filename: string;
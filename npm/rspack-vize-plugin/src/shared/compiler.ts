@@ -153,15 +153,17 @@ export function generateOutput(
   const exportDefaultRegex = /^export default /m;
   const hasExportDefault = exportDefaultRegex.test(output);
   const hasSfcMainDefined = /\bconst\s+_sfc_main\s*=/.test(output);
+  // The compiler already attaches __scopeId when it exports _sfc_main
+  const needsScopeId = compiled.hasScoped && compiled.scopeId && !/^_sfc_main\.__scopeId = /m.test(output);
 
   if (hasExportDefault && !hasSfcMainDefined) {
     output = output.replace(exportDefaultRegex, "const _sfc_main = ");
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\nexport default _sfc_main;";
   } else if (hasExportDefault && hasSfcMainDefined) {
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       output = output.replace(
         /^export default _sfc_main/m,
         `_sfc_main.__scopeId = "data-v-${compiled.scopeId}";\nexport default _sfc_main`,
@@ -79,21 +79,23 @@ export function generateOutput(compiled: CompiledModule, options: GenerateOutput
   const hasExportDefault = exportDefaultRegex.test(output);
   const hasNamedRenderExport = /^export function render\b/m.test(output);
   const hasSfcMainDefined = /\bconst\s+_sfc_main\s*=/.test(output);
+  // The compiler already attaches __scopeId when it exports _sfc_main
+  const needsScopeId = compiled.hasScoped && !/^_sfc_main\.__scopeId = /m.test(output);
 
   if (hasExportDefault && !hasSfcMainDefined) {
     output = output.replace(exportDefaultRegex, "const _sfc_main = ");
-    if (compiled.hasScoped) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\nexport default _sfc_main;";
-  } else if (hasExportDefault && hasSfcMainDefined && compiled.hasScoped) {
+  } else if (hasExportDefault && hasSfcMainDefined && needsScopeId) {
     output = output.replace(
       /^export default _sfc_main/m,
       `_sfc_main.__scopeId = "data-v-${compiled.scopeId}";\nexport default _sfc_main`,
     );
   } else if (!hasExportDefault && !hasSfcMainDefined && hasNamedRenderExport) {
     output += "\nconst _sfc_main = {};";
-    if (compiled.hasScoped) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\n_sfc_main.render = render;";
@@ -20,6 +20,7 @@ export interface NativeBinding {
       component?: string;
       category?: string;
       tags: string[];
+      decorators: string[];
       status: string;
       order?: number;
     };
@@ -35,7 +36,7 @@ export interface NativeBinding {
   };
   artToCsf: (
     source: string,
-    options?: { filename?: string },
+    options?: { filename?: string; target?: "ts" | "js" },
   ) => {
     code: string;
     filename: string;
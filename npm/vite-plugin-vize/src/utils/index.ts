@@ -85,17 +85,19 @@ export function generateOutput(compiled: CompiledModule, options: GenerateOutput
   // Check if _sfc_main is already defined (Case 2: non-script-setup SFCs)
   // In this case, the compiler already outputs: const _sfc_main = ...; export default _sfc_main
   const hasSfcMainDefined = /\bconst\s+_sfc_main\s*=/.test(output);
+  // The compiler already attaches __scopeId when it exports _sfc_main
+  const needsScopeId = compiled.hasScoped && compiled.scopeId && !/^_sfc_main\.__scopeId = /m.test(output);
 
   if (hasExportDefault && !hasSfcMainDefined) {
     output = output.replace(exportDefaultRegex, "const _sfc_main = ");
     // Add __scopeId for scoped CSS support
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\nexport default _sfc_main;";
   } else if (hasExportDefault && hasSfcMainDefined) {
     // _sfc_main already defined, just add scopeId if needed
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       // Insert scopeId assignment before the export default line
       output = output.replace(
         /^export default _sfc_main/m,
@@ -104,14 +106,14 @@ export function generateOutput(compiled: CompiledModule, options: GenerateOutput
     }
   } else if (!hasExportDefault && !hasSfcMainDefined && hasNamedRenderExport) {
     output += "\nconst _sfc_main = {};";
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\n_sfc_main.render = render;";
     output += "\nexport default _sfc_main;";
   } else if (!hasExportDefault && !hasSfcMainDefined && hasNamedSsrRenderExport) {
     output += "\nconst _sfc_main = {};";
-    if (compiled.hasScoped && compiled.scopeId) {
+    if (needsScopeId) {
       output += `\n_sfc_main.__scopeId = "data-v-${compiled.scopeId}";`;
     }
     output += "\n_sfc_main.ssrRender = ssrRender;";
@@ -47,10 +47,14 @@ export function createEmptyCodeOutputs(): CodeOutputs {
   };
 }
 
-function getErrorMessage(error: unknown): string {
+export function getErrorMessage(error: unknown): string {
   if (error instanceof Error) {
     return error.message;
   }
+  // Compile errors are thrown as `CompileError[]`
+  if (Array.isArray(error)) {
+    return error.map((e) => (typeof e?.message === "string" ? e.message : String(e))).join("\n");
+  }
   return String(error);
 }
 
@@ -9,7 +9,12 @@ import type {
   WasmModule,
 } from "../../wasm/index";
 import { formatCss } from "./formatters";
-import { compileCodeOutputs, createEmptyCodeOutputs, type CodeOutputTarget } from "./codeOutputs";
+import {
+  compileCodeOutputs,
+  createEmptyCodeOutputs,
+  getErrorMessage,
+  type CodeOutputTarget,
+} from "./codeOutputs";
 import { mapToObject, filterAstProperties } from "./astHelpers";
 import { useClipboard } from "../../utils/useClipboard";
 
@@ -158,7 +163,7 @@ export function useAtelierCompiler(getCompiler: () => WasmModule | null) {
         compileTime.value = performance.now() - startTime;
       }
     } catch (e) {
-      error.value = e instanceof Error ? e.message : String(e);
+      error.value = getErrorMessage(e);
       codeOutputs.value = createEmptyCodeOutputs();
     } finally {
       isCompiling.value = false;